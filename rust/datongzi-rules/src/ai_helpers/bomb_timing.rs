@@ -0,0 +1,264 @@
+//! Bomb timing advisor.
+//!
+//! Estimates, for each trump (bomb, Tongzi, Dizha) in hand, whether playing
+//! it now beats holding it for a later trick.
+
+use crate::models::{Card, GameConfig};
+use crate::patterns::{PatternRecognizer, PlayPattern, PlayType, PlayValidator};
+use crate::scoring::ScoreComputation;
+
+use super::chain_split::remove_cards;
+use super::HandPatternAnalyzer;
+
+/// Points a trump is expected to capture when saved for a later trick.
+const FUTURE_TRICK_POINTS: f32 = 25.0;
+/// Opponent hand size at or above which the game is far from over.
+const OPPONENT_FULL_HAND: usize = 10;
+/// Plays left after winning the lead at which I expect to go out first.
+const EXIT_PLAYS: usize = 2;
+/// Share of the first-place bonus credited to an exit plan.
+const EXIT_WEIGHT: f32 = 0.5;
+
+/// Game state used by [`bomb_timing`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BombTimingView {
+    /// Pattern to beat (`None` when I am leading)
+    pub current_pattern: Option<PlayPattern>,
+    /// Points (5/10/K) in the current trick
+    pub points_on_table: i32,
+    /// Cards left in each opponent's hand
+    pub opponents_remaining: Vec<usize>,
+    /// Game configuration (trump bonuses, finish bonuses)
+    pub config: GameConfig,
+}
+
+/// Detonate-or-hold estimate for one trump in hand.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BombTiming {
+    /// Cards of the trump
+    pub cards: Vec<Card>,
+    /// Bomb, Tongzi or Dizha
+    pub play_type: PlayType,
+    /// Chance the trump is not beaten once played
+    pub hold_probability: f32,
+    /// Expected points from playing it now (0 if it cannot beat the trick)
+    pub ev_now: f32,
+    /// Expected points from keeping it for later
+    pub ev_hold: f32,
+    /// Whether to play it now
+    pub detonate: bool,
+}
+
+/// Estimate the value of playing each trump in `hand` now vs holding it.
+///
+/// Model, per trump (expected points):
+/// - `ev_now = p × (points on table + trump bonus) + exit`, where `exit` is
+///   half the first-place bonus × `p` when the rest of the hand can go out in
+///   2 plays or fewer after winning the lead
+/// - `ev_hold = p × (25 + trump bonus) × game_left`, where `game_left` is the
+///   smallest opponent hand over 10 (capped at 1): a trump is worth little
+///   if an opponent goes out before it is used
+///
+/// `p` is the chance the trump is not beaten: 0.6 for a 4-card bomb plus 0.05
+/// per extra card (max 0.9), 0.8 for Tongzi, 0.95 for Dizha. The trump bonus
+/// is the Tongzi/Dizha bonus from [`ScoreComputation`].
+///
+/// Results follow [`HandPatternAnalyzer::analyze_patterns`] order: Dizha,
+/// Tongzi, then bombs, strongest first.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::{Card, Rank, Suit};
+/// use datongzi_rules::ai_helpers::{bomb_timing, BombTimingView};
+///
+/// let hand: Vec<Card> = Suit::ALL.iter().map(|s| Card::new(*s, Rank::Seven)).collect();
+/// let view = BombTimingView {
+///     points_on_table: 60,
+///     opponents_remaining: vec![20, 25],
+///     ..Default::default()
+/// };
+///
+/// let timings = bomb_timing(&hand, &view);
+/// assert_eq!(timings.len(), 1);
+/// assert!(timings[0].detonate);
+/// ```
+#[must_use]
+pub fn bomb_timing(hand: &[Card], view: &BombTimingView) -> Vec<BombTiming> {
+    let patterns = HandPatternAnalyzer::analyze_patterns(hand);
+    let game_left = view
+        .opponents_remaining
+        .iter()
+        .filter(|&&n| n > 0)
+        .min()
+        .map_or(1.0, |&n| (n as f32 / OPPONENT_FULL_HAND as f32).min(1.0));
+    let first_place_bonus = view.config.finish_bonus().first().copied().unwrap_or(0) as f32;
+
+    patterns
+        .dizha
+        .iter()
+        .chain(&patterns.tongzi)
+        .chain(&patterns.bombs)
+        .filter_map(|cards| {
+            let pattern = PatternRecognizer::analyze_cards(cards)?;
+            let p = hold_probability(&pattern);
+            let bonus = trump_bonus(&pattern, &view.config);
+
+            let ev_now = if PlayValidator::can_beat_play_with_config(
+                cards,
+                view.current_pattern.as_ref(),
+                &view.config,
+            ) {
+                let rest = remove_cards(hand, cards);
+                let plays_left = HandPatternAnalyzer::optimize_for_fewest_plays(&rest).play_count();
+                let exit = if plays_left <= EXIT_PLAYS {
+                    p * first_place_bonus * EXIT_WEIGHT
+                } else {
+                    0.0
+                };
+                p * (view.points_on_table as f32 + bonus) + exit
+            } else {
+                0.0
+            };
+            let ev_hold = p * (FUTURE_TRICK_POINTS + bonus) * game_left;
+
+            Some(BombTiming {
+                cards: cards.clone(),
+                play_type: pattern.play_type,
+                hold_probability: p,
+                ev_now,
+                ev_hold,
+                detonate: ev_now > ev_hold,
+            })
+        })
+        .collect()
+}
+
+/// Chance a trump survives the rest of the trick.
+fn hold_probability(pattern: &PlayPattern) -> f32 {
+    match pattern.play_type {
+        PlayType::Dizha => 0.95,
+        PlayType::Tongzi => 0.8,
+        _ => (0.6 + 0.05 * pattern.card_count.saturating_sub(4) as f32).min(0.9),
+    }
+}
+
+/// Bonus awarded when the trump wins the trick.
+fn trump_bonus(pattern: &PlayPattern, config: &GameConfig) -> f32 {
+    let mut computation = ScoreComputation::new(config.clone());
+    computation
+        .create_special_bonus_events(String::new(), pattern, 0, true)
+        .iter()
+        .map(|event| event.points)
+        .sum::<i32>() as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+
+    fn bomb(rank: Rank) -> Vec<Card> {
+        Suit::ALL.iter().map(|s| Card::new(*s, rank)).collect()
+    }
+
+    fn tongzi(rank: Rank) -> Vec<Card> {
+        vec![Card::new(Suit::Spades, rank); 3]
+    }
+
+    fn loose_hand() -> Vec<Card> {
+        vec![
+            Card::new(Suit::Clubs, Rank::Five),
+            Card::new(Suit::Clubs, Rank::Seven),
+            Card::new(Suit::Clubs, Rank::Nine),
+            Card::new(Suit::Clubs, Rank::Jack),
+        ]
+    }
+
+    fn view(points_on_table: i32, opponents_remaining: Vec<usize>) -> BombTimingView {
+        BombTimingView {
+            points_on_table,
+            opponents_remaining,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_detonate_for_big_trick() {
+        let mut hand = bomb(Rank::Eight);
+        hand.extend(loose_hand());
+
+        let timings = bomb_timing(&hand, &view(60, vec![20, 25]));
+        assert_eq!(timings.len(), 1);
+        assert_eq!(timings[0].play_type, PlayType::Bomb);
+        assert!(timings[0].detonate);
+    }
+
+    #[test]
+    fn test_hold_on_empty_table() {
+        let mut hand = bomb(Rank::Eight);
+        hand.extend(loose_hand());
+
+        let timings = bomb_timing(&hand, &view(0, vec![20, 25]));
+        assert!(!timings[0].detonate);
+        assert!(timings[0].ev_hold > timings[0].ev_now);
+    }
+
+    #[test]
+    fn test_detonate_when_opponent_near_finish() {
+        let mut hand = bomb(Rank::Eight);
+        hand.extend(loose_hand());
+
+        // 10 points is not worth a bomb early, but is once an opponent is about to go out
+        assert!(!bomb_timing(&hand, &view(10, vec![20, 25]))[0].detonate);
+        assert!(bomb_timing(&hand, &view(10, vec![2, 25]))[0].detonate);
+    }
+
+    #[test]
+    fn test_detonate_for_exit_plan() {
+        let mut hand = bomb(Rank::Eight);
+        hand.extend([
+            Card::new(Suit::Clubs, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::Ace),
+        ]);
+
+        let timings = bomb_timing(&hand, &view(0, vec![20, 25]));
+        assert!(timings[0].detonate);
+    }
+
+    #[test]
+    fn test_cannot_beat_current_trick() {
+        let mut hand = bomb(Rank::Six);
+        hand.extend(loose_hand());
+        let current = PatternRecognizer::analyze_cards(&bomb(Rank::Ace)).unwrap();
+
+        let timings = bomb_timing(
+            &hand,
+            &BombTimingView {
+                current_pattern: Some(current),
+                points_on_table: 100,
+                opponents_remaining: vec![2],
+                ..Default::default()
+            },
+        );
+        assert_eq!(timings[0].ev_now, 0.0);
+        assert!(!timings[0].detonate);
+    }
+
+    #[test]
+    fn test_tongzi_bonus_counts_both_ways() {
+        let mut hand = tongzi(Rank::Ace);
+        hand.extend(loose_hand());
+
+        let timings = bomb_timing(&hand, &view(30, vec![20, 25]));
+        assert_eq!(timings[0].play_type, PlayType::Tongzi);
+        // 0.8 × (30 + 200) now vs 0.8 × (25 + 200) later
+        assert!((timings[0].ev_now - 184.0).abs() < 1e-3);
+        assert!((timings[0].ev_hold - 180.0).abs() < 1e-3);
+        assert!(timings[0].detonate);
+    }
+
+    #[test]
+    fn test_no_trumps() {
+        assert!(bomb_timing(&loose_hand(), &view(100, vec![1])).is_empty());
+    }
+}
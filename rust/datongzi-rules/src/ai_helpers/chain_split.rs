@@ -0,0 +1,275 @@
+//! Chain-splitting advisor.
+//!
+//! Decides whether breaking a consecutive-pair (连对) or airplane (飞机) chain
+//! to answer the current trick is worth it: winning the lead (tempo) against
+//! the extra plays the broken chain costs later (structure).
+
+use crate::models::Card;
+use crate::patterns::{PatternRecognizer, PlayPattern, PlayType};
+
+use super::{HandPatternAnalyzer, PlayGenerator};
+
+/// Value of winning the lead, in plays.
+const TEMPO_VALUE: f32 = 2.0;
+/// Extra tempo when the hand can go out within [`NEAR_EXIT_PLAYS`] plays after winning.
+const NEAR_EXIT_BONUS: f32 = 2.0;
+/// Remaining plays at which winning the lead lets me run out.
+const NEAR_EXIT_PLAYS: usize = 2;
+/// Cost of each extra play the split adds to the rest of the hand.
+const STRUCTURE_COST_PER_PLAY: f32 = 1.5;
+
+/// Recommendation returned by [`should_split_chain`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitRecommendation {
+    /// Whether to break the chain to answer the trick
+    pub split: bool,
+    /// `tempo_gain - structure_cost`; positive favours splitting
+    pub score: f32,
+    /// Cheapest response that breaks the chain, if any beats the trick
+    pub response: Option<Vec<Card>>,
+    /// Plays added to the rest of the hand by the split
+    pub extra_plays: usize,
+    /// Value of winning the lead
+    pub tempo_gain: f32,
+    /// Cost of the broken structure
+    pub structure_cost: f32,
+    /// Whether a normal (non-trump) play beats the trick without touching the chain
+    pub has_alternative: bool,
+}
+
+impl SplitRecommendation {
+    fn keep_chain(has_alternative: bool) -> Self {
+        Self {
+            split: false,
+            score: 0.0,
+            response: None,
+            extra_plays: 0,
+            tempo_gain: 0.0,
+            structure_cost: 0.0,
+            has_alternative,
+        }
+    }
+}
+
+/// Evaluate breaking `chain` to answer `current_pattern`.
+///
+/// `chain` must be a consecutive-pair or airplane chain taken from `hand`.
+/// Candidate responses are normal (non-trump) plays from `hand` that use
+/// some, but not all, of the chain's cards. The candidate costing the fewest
+/// extra plays (then the weakest) is scored:
+///
+/// - Tempo: winning the lead is worth 2 plays, plus 2 more when the rest of
+///   the hand can go out in 2 plays or fewer
+/// - Structure: each play the split adds to the fewest-plays decomposition
+///   ([`HandPatternAnalyzer::optimize_for_fewest_plays`]) costs 1.5
+///
+/// Splitting is recommended when the score is positive and no normal play
+/// beats the trick without touching the chain.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+/// use datongzi_rules::ai_helpers::should_split_chain;
+///
+/// let chain = vec![
+///     Card::new(Suit::Spades, Rank::Eight),
+///     Card::new(Suit::Hearts, Rank::Eight),
+///     Card::new(Suit::Spades, Rank::Nine),
+///     Card::new(Suit::Hearts, Rank::Nine),
+///     Card::new(Suit::Spades, Rank::Ten),
+///     Card::new(Suit::Hearts, Rank::Ten),
+/// ];
+/// let mut hand = chain.clone();
+/// hand.push(Card::new(Suit::Clubs, Rank::Ace));
+///
+/// let pair_of_sevens = PatternRecognizer::analyze_cards(&[
+///     Card::new(Suit::Spades, Rank::Seven),
+///     Card::new(Suit::Hearts, Rank::Seven),
+/// ])
+/// .unwrap();
+///
+/// // Pair of 8s wins the lead; 99-1010 and the Ace remain, two plays to go out
+/// let advice = should_split_chain(&hand, &chain, &pair_of_sevens);
+/// assert!(advice.split);
+/// ```
+#[must_use]
+pub fn should_split_chain(
+    hand: &[Card],
+    chain: &[Card],
+    current_pattern: &PlayPattern,
+) -> SplitRecommendation {
+    let is_chain = PatternRecognizer::analyze_cards(chain)
+        .is_some_and(|p| matches!(p.play_type, PlayType::ConsecutivePairs | PlayType::Airplane));
+    if !is_chain {
+        return SplitRecommendation::keep_chain(false);
+    }
+
+    let normal_responses: Vec<Vec<Card>> =
+        PlayGenerator::generate_beating_plays_with_same_type_or_trump(hand, current_pattern)
+            .into_iter()
+            .filter(|play| {
+                PatternRecognizer::analyze_cards(play).is_some_and(|p| {
+                    !matches!(
+                        p.play_type,
+                        PlayType::Bomb | PlayType::Tongzi | PlayType::Dizha
+                    )
+                })
+            })
+            .collect();
+
+    let has_alternative = normal_responses
+        .iter()
+        .any(|play| overlap(play, chain) == 0);
+
+    let plays_before = HandPatternAnalyzer::optimize_for_fewest_plays(hand).play_count();
+
+    // (extra plays, plays left, strength, response)
+    let best = normal_responses
+        .into_iter()
+        .filter(|play| {
+            let used = overlap(play, chain);
+            used > 0 && used < chain.len()
+        })
+        .map(|play| {
+            let rest = remove_cards(hand, &play);
+            let plays_left = HandPatternAnalyzer::optimize_for_fewest_plays(&rest).play_count();
+            let extra_plays = (plays_left + 1).saturating_sub(plays_before);
+            let strength = PatternRecognizer::analyze_cards(&play).map_or(0, |p| p.strength);
+            (extra_plays, plays_left, strength, play)
+        })
+        .min_by_key(|(extra_plays, _, strength, _)| (*extra_plays, *strength));
+
+    let Some((extra_plays, plays_left, _, response)) = best else {
+        return SplitRecommendation::keep_chain(has_alternative);
+    };
+
+    let mut tempo_gain = TEMPO_VALUE;
+    if plays_left <= NEAR_EXIT_PLAYS {
+        tempo_gain += NEAR_EXIT_BONUS;
+    }
+    let structure_cost = extra_plays as f32 * STRUCTURE_COST_PER_PLAY;
+    let score = tempo_gain - structure_cost;
+
+    SplitRecommendation {
+        split: score > 0.0 && !has_alternative,
+        score,
+        response: Some(response),
+        extra_plays,
+        tempo_gain,
+        structure_cost,
+        has_alternative,
+    }
+}
+
+/// Number of cards in `play` drawn from `chain` (multiset intersection).
+fn overlap(play: &[Card], chain: &[Card]) -> usize {
+    let mut pool = chain.to_vec();
+    play.iter()
+        .filter(|card| {
+            pool.iter()
+                .position(|c| c == *card)
+                .map(|index| pool.swap_remove(index))
+                .is_some()
+        })
+        .count()
+}
+
+/// `hand` minus one copy of each card in `cards`.
+pub(crate) fn remove_cards(hand: &[Card], cards: &[Card]) -> Vec<Card> {
+    let mut rest = hand.to_vec();
+    for card in cards {
+        if let Some(index) = rest.iter().position(|c| c == card) {
+            rest.swap_remove(index);
+        }
+    }
+    rest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+
+    fn pair(rank: Rank) -> Vec<Card> {
+        vec![Card::new(Suit::Spades, rank), Card::new(Suit::Hearts, rank)]
+    }
+
+    fn pattern(cards: &[Card]) -> PlayPattern {
+        PatternRecognizer::analyze_cards(cards).unwrap()
+    }
+
+    fn chain_of(ranks: &[Rank]) -> Vec<Card> {
+        ranks.iter().flat_map(|r| pair(*r)).collect()
+    }
+
+    #[test]
+    fn test_split_when_near_exit() {
+        let chain = chain_of(&[Rank::Eight, Rank::Nine, Rank::Ten]);
+        let mut hand = chain.clone();
+        hand.push(Card::new(Suit::Clubs, Rank::Ace));
+
+        let advice = should_split_chain(&hand, &chain, &pattern(&pair(Rank::Seven)));
+        assert!(advice.split);
+        assert_eq!(advice.response, Some(pair(Rank::Eight)));
+        assert_eq!(advice.extra_plays, 1);
+        assert!(advice.score > 0.0);
+    }
+
+    #[test]
+    fn test_keep_chain_when_split_breaks_structure() {
+        // A single 5 out of 55-66 leaves a loose 5 and a pair behind
+        let chain = chain_of(&[Rank::Five, Rank::Six]);
+        let mut hand = chain.clone();
+        hand.push(Card::new(Suit::Clubs, Rank::Three));
+
+        let single_four = [Card::new(Suit::Clubs, Rank::Four)];
+        let advice = should_split_chain(&hand, &chain, &pattern(&single_four));
+        assert!(!advice.split);
+        assert!(!advice.has_alternative);
+        assert_eq!(advice.extra_plays, 2);
+        assert!(advice.score < 0.0);
+    }
+
+    #[test]
+    fn test_split_top_of_long_chain() {
+        // 99 off the top of 66-77-88-99 still leaves a chain behind
+        let chain = chain_of(&[Rank::Six, Rank::Seven, Rank::Eight, Rank::Nine]);
+        let mut hand = chain.clone();
+        hand.extend([
+            Card::new(Suit::Clubs, Rank::Three),
+            Card::new(Suit::Clubs, Rank::Jack),
+            Card::new(Suit::Clubs, Rank::King),
+        ]);
+
+        let advice = should_split_chain(&hand, &chain, &pattern(&pair(Rank::Eight)));
+        assert!(advice.split);
+        assert_eq!(advice.response, Some(pair(Rank::Nine)));
+        assert_eq!(advice.extra_plays, 1);
+    }
+
+    #[test]
+    fn test_keep_chain_when_alternative_exists() {
+        let chain = chain_of(&[Rank::Eight, Rank::Nine]);
+        let mut hand = chain.clone();
+        hand.extend(pair(Rank::King));
+
+        let advice = should_split_chain(&hand, &chain, &pattern(&pair(Rank::Seven)));
+        assert!(advice.has_alternative);
+        assert!(!advice.split);
+    }
+
+    #[test]
+    fn test_no_response_from_chain() {
+        let chain = chain_of(&[Rank::Five, Rank::Six]);
+        let advice = should_split_chain(&chain, &chain, &pattern(&pair(Rank::Ace)));
+        assert_eq!(advice, SplitRecommendation::keep_chain(false));
+    }
+
+    #[test]
+    fn test_not_a_chain() {
+        let cards = pair(Rank::Five);
+        let advice = should_split_chain(&cards, &cards, &pattern(&pair(Rank::Four)));
+        assert!(!advice.split);
+        assert!(advice.response.is_none());
+    }
+}
@@ -6,8 +6,14 @@
 use std::collections::HashMap;
 use std::fmt;
 
-use crate::models::{Card, Rank, Suit};
-use crate::patterns::{PatternRecognizer, PlayType};
+use thiserror::Error;
+
+use crate::invariants;
+use crate::logging::{self, Level};
+use crate::metrics;
+use crate::models::{Card, Rank, RankCounts, Suit};
+use crate::patterns::kicker_rules::MAX_KICKERS_PER_GROUP;
+use crate::patterns::{runs, PatternRecognizer, PlayType, RULES};
 
 /// Structured representation of hand resources grouped by pattern types.
 ///
@@ -44,11 +50,13 @@ pub struct HandPatterns {
     pub bombs: Vec<Vec<Card>>,
 
     // Composite patterns (multi-card combinations)
-    /// Airplane chains (飞机) - consecutive triples
+    /// Airplane chains (飞机) - consecutive triples, followed by their wings
+    /// when built by [`HandPatternAnalyzer::optimize_for_fewest_plays`]
     pub airplane_chains: Vec<Vec<Card>>,
 
     // Basic patterns (triple has higher priority than consecutive pairs)
-    /// Triples (三张) - 3 same rank
+    /// Triples (三张) - 3 same rank, followed by their kickers when built by
+    /// [`HandPatternAnalyzer::optimize_for_fewest_plays`]
     pub triples: Vec<Vec<Card>>,
     /// Consecutive pair chains (连对) - 2+ consecutive pairs
     pub consecutive_pair_chains: Vec<Vec<Card>>,
@@ -64,8 +72,228 @@ pub struct HandPatterns {
     pub trump_count: usize,
     /// Has control cards (2s, As, or Ks)
     pub has_control_cards: bool,
+
+    /// Tongzi/Dizha one card short of complete, strongest first.
+    ///
+    /// Found on the whole hand, so these cards also appear in the
+    /// decomposition above.
+    pub near_trumps: Vec<NearTrump>,
 }
 
+/// A trump structure that is one card away from complete.
+///
+/// Useful for drawing/exchange variants and for valuing hands that are
+/// close to power. Near-Tongzi only matter with 3+ decks, near-Dizha with 2+.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NearTrump {
+    /// Structure that would be completed ([`PlayType::Tongzi`] or [`PlayType::Dizha`])
+    pub play_type: PlayType,
+    /// Cards already held toward the structure
+    pub cards: Vec<Card>,
+    /// The card that completes it
+    pub missing: Card,
+}
+
+impl HandPatterns {
+    /// Returns the number of plays needed to empty the hand with this decomposition
+    ///
+    /// Every resource (trump, chain, triple, pair, single) is one play.
+    #[must_use]
+    pub fn play_count(&self) -> usize {
+        self.dizha.len()
+            + self.tongzi.len()
+            + self.bombs.len()
+            + self.airplane_chains.len()
+            + self.triples.len()
+            + self.consecutive_pair_chains.len()
+            + self.pairs.len()
+            + self.singles.len()
+    }
+
+    /// Returns every card in the decomposition, trumps first
+    #[must_use]
+    pub fn cards(&self) -> Vec<Card> {
+        [
+            &self.dizha,
+            &self.tongzi,
+            &self.bombs,
+            &self.airplane_chains,
+            &self.triples,
+            &self.consecutive_pair_chains,
+            &self.pairs,
+        ]
+        .iter()
+        .flat_map(|groups| groups.iter().flatten())
+        .chain(&self.singles)
+        .copied()
+        .collect()
+    }
+
+    /// Remove a played pattern, re-analyzing only the groups it touched.
+    ///
+    /// A play matching one whole group removes just that group. Otherwise
+    /// cards come from singles first, then from the smallest groups (least
+    /// valuable category first); the leftovers of each group broken this way
+    /// are re-analyzed with [`HandPatternAnalyzer::analyze_patterns`] and
+    /// merged back. Untouched groups keep their shape.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConsumeError::InvalidPattern`] if `play` forms no pattern,
+    /// or [`ConsumeError::CardNotHeld`] for the first card the
+    /// decomposition does not hold
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::{Card, HandPatternAnalyzer, Rank, Suit};
+    ///
+    /// let nine = |suit| Card::new(suit, Rank::Nine);
+    /// let hand = vec![nine(Suit::Spades), nine(Suit::Hearts), nine(Suit::Clubs), Card::new(Suit::Spades, Rank::Ace)];
+    /// let patterns = HandPatternAnalyzer::analyze_patterns(&hand);
+    ///
+    /// // Taking a pair out of the triple leaves a single 9 beside the Ace
+    /// let rest = patterns.consume(&[nine(Suit::Spades), nine(Suit::Hearts)]).unwrap();
+    /// assert!(rest.triples.is_empty());
+    /// assert_eq!(rest.singles.len(), 2);
+    /// assert_eq!(rest.total_cards, 2);
+    /// ```
+    pub fn consume(&self, play: &[Card]) -> Result<HandPatterns, ConsumeError> {
+        if PatternRecognizer::analyze_cards(play).is_none() {
+            return Err(ConsumeError::InvalidPattern);
+        }
+
+        let mut next = self.clone();
+        let mut sorted_play = play.to_vec();
+        sorted_play.sort();
+        let same_cards = |group: &Vec<Card>| {
+            let mut group = group.clone();
+            group.sort();
+            group == sorted_play
+        };
+
+        // Least valuable category first, so trumps break last
+        let exact = next.groups_mut().into_iter().any(|groups| {
+            groups
+                .iter()
+                .position(same_cards)
+                .map(|index| groups.remove(index))
+                .is_some()
+        });
+
+        if !exact {
+            let mut freed: Vec<Card> = Vec::new();
+            for card in play {
+                if let Some(index) = freed.iter().position(|c| c == card) {
+                    freed.swap_remove(index);
+                } else if let Some(index) = next.singles.iter().position(|c| c == card) {
+                    next.singles.remove(index);
+                } else {
+                    let group = next
+                        .groups_mut()
+                        .into_iter()
+                        .filter_map(|groups| {
+                            let index = groups
+                                .iter()
+                                .enumerate()
+                                .filter(|(_, g)| g.contains(card))
+                                .min_by_key(|(_, g)| g.len())?
+                                .0;
+                            Some(groups.remove(index))
+                        })
+                        .next()
+                        .ok_or(ConsumeError::CardNotHeld(*card))?;
+                    freed.extend(group);
+                    if let Some(index) = freed.iter().position(|c| c == card) {
+                        freed.swap_remove(index);
+                    }
+                }
+            }
+
+            let reflowed = HandPatternAnalyzer::analyze_patterns(&freed);
+            next.dizha.extend(reflowed.dizha);
+            next.tongzi.extend(reflowed.tongzi);
+            next.bombs.extend(reflowed.bombs);
+            next.airplane_chains.extend(reflowed.airplane_chains);
+            next.triples.extend(reflowed.triples);
+            next.consecutive_pair_chains
+                .extend(reflowed.consecutive_pair_chains);
+            next.pairs.extend(reflowed.pairs);
+            next.singles.extend(reflowed.singles);
+            HandPatternAnalyzer::_sort_trump_groups(&mut next);
+            HandPatternAnalyzer::_sort_normal_groups(&mut next);
+        }
+
+        let hand = next.cards();
+        next.total_cards = hand.len();
+        next.trump_count = next.dizha.len() + next.tongzi.len() + next.bombs.len();
+        next.has_control_cards = hand
+            .iter()
+            .any(|c| matches!(c.rank, Rank::Two | Rank::Ace | Rank::King));
+        next.near_trumps = HandPatternAnalyzer::_find_near_trumps(&hand);
+        invariants::assert_partition(&hand, &next);
+        Ok(next)
+    }
+
+    /// Group categories, least valuable first
+    fn groups_mut(&mut self) -> [&mut Vec<Vec<Card>>; 7] {
+        [
+            &mut self.pairs,
+            &mut self.triples,
+            &mut self.consecutive_pair_chains,
+            &mut self.airplane_chains,
+            &mut self.bombs,
+            &mut self.tongzi,
+            &mut self.dizha,
+        ]
+    }
+}
+
+/// Why [`HandPatterns::consume`] rejected a play.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsumeError {
+    /// The cards form no pattern
+    #[error("cards form no pattern")]
+    InvalidPattern,
+    /// The card is not in the decomposition
+    #[error("{0} is not in the hand")]
+    CardNotHeld(Card),
+}
+
+/// How [`HandPatternAnalyzer`] extracts exactly-four-of-a-kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuadTreatment {
+    /// Lock every quad into a bomb (default)
+    #[default]
+    Bomb,
+    /// Leave quads to the normal extraction, typically a triple plus a single
+    TriplePlusSingle,
+    /// Try both and keep the decomposition with fewer plays (bomb on ties)
+    Both,
+}
+
+/// Options for [`HandPatternAnalyzer`] `*_with_options` entry points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AnalyzeOptions {
+    /// Extraction of 4-card bombs; bombs of 5+ cards are always kept
+    pub treat_quads_as: QuadTreatment,
+}
+
+/// Move chosen at one step of [`HandPatternAnalyzer::optimize_for_fewest_plays`].
+#[derive(Debug, Clone, Copy)]
+enum NormalMove {
+    /// Take `size` cards (1-3) of the lowest remaining rank
+    SameRank { size: u8 },
+    /// Chain of `length` ranks starting at the lowest remaining rank, `width` cards each
+    Chain { width: u8, length: usize },
+    /// Carry one card of the lowest remaining rank as a triple's kicker or
+    /// an airplane's wing
+    Kicker,
+}
+
+/// Memoized best (plays, singles) and move per search state, keyed on the
+/// remaining counts and the open kicker slots.
+type FewestPlaysMemo = HashMap<(RankCounts, i32), ((usize, usize), Option<NormalMove>)>;
+
 impl fmt::Display for HandPatterns {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "HandPatterns({} cards):", self.total_cards)?;
@@ -158,6 +386,38 @@ impl HandPatternAnalyzer {
     /// `HandPatterns` with structured decomposition
     #[must_use]
     pub fn analyze_patterns(hand: &[Card]) -> HandPatterns {
+        Self::analyze_patterns_with_options(hand, &AnalyzeOptions::default())
+    }
+
+    /// Analyze hand like [`analyze_patterns`](Self::analyze_patterns), honoring `options`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datongzi_rules::{Card, HandPatternAnalyzer, Rank, Suit};
+    /// use datongzi_rules::ai_helpers::{AnalyzeOptions, QuadTreatment};
+    ///
+    /// let hand: Vec<Card> = Suit::ALL.iter().map(|s| Card::new(*s, Rank::Nine)).collect();
+    /// let options = AnalyzeOptions {
+    ///     treat_quads_as: QuadTreatment::TriplePlusSingle,
+    /// };
+    ///
+    /// let patterns = HandPatternAnalyzer::analyze_patterns_with_options(&hand, &options);
+    /// assert!(patterns.bombs.is_empty());
+    /// assert_eq!((patterns.triples.len(), patterns.singles.len()), (1, 1));
+    /// ```
+    #[must_use]
+    pub fn analyze_patterns_with_options(hand: &[Card], options: &AnalyzeOptions) -> HandPatterns {
+        if options.treat_quads_as == QuadTreatment::Both {
+            return Self::_fewer_plays(
+                Self::analyze_patterns_with_options(hand, &Self::_with_quads(QuadTreatment::Bomb)),
+                Self::analyze_patterns_with_options(
+                    hand,
+                    &Self::_with_quads(QuadTreatment::TriplePlusSingle),
+                ),
+            );
+        }
+
         if hand.is_empty() {
             return HandPatterns::default();
         }
@@ -170,7 +430,7 @@ impl HandPatternAnalyzer {
         let mut remaining_cards = hand.to_vec();
 
         // Step 1: Extract trump cards (highest priority)
-        Self::_extract_trump_cards(&mut remaining_cards, &mut patterns);
+        Self::_extract_trump_cards(&mut remaining_cards, &mut patterns, options);
 
         // Step 2: Extract airplane chains (consecutive triples)
         Self::_extract_airplane_chains(&mut remaining_cards, &mut patterns);
@@ -192,16 +452,299 @@ impl HandPatternAnalyzer {
         patterns.has_control_cards = hand
             .iter()
             .any(|c| matches!(c.rank, Rank::Two | Rank::Ace | Rank::King));
+        patterns.near_trumps = Self::_find_near_trumps(hand);
+        invariants::assert_partition(hand, &patterns);
 
         // Debug logging removed for zero-dependency implementation
 
         patterns
     }
 
+    /// Find the decomposition with the fewest plays using exhaustive search.
+    ///
+    /// [`analyze_patterns`](Self::analyze_patterns) extracts greedily by
+    /// priority, which can strand singles (e.g. taking 666 out of 55 66 77
+    /// instead of playing the 5-6-7 consecutive pairs plus a single 6).
+    /// This method keeps the same trump extraction (trumps are never broken),
+    /// then searches every split of the remaining cards into airplanes,
+    /// triples, consecutive pairs, pairs and singles. Triples and airplanes
+    /// carry kickers up to their capacity (two cards per triple), so 999 + 55
+    /// is one play; kickers are appended to their group in
+    /// [`HandPatterns::triples`] or [`HandPatterns::airplane_chains`].
+    ///
+    /// Optimizes for the fewest plays ([`HandPatterns::play_count`]); ties
+    /// are broken by fewer singles. The search is memoized on per-rank card
+    /// counts, so it stays fast for full hands.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datongzi_rules::{Card, HandPatternAnalyzer, Rank, Suit};
+    ///
+    /// let hand = vec![
+    ///     Card::new(Suit::Spades, Rank::Five),
+    ///     Card::new(Suit::Hearts, Rank::Five),
+    ///     Card::new(Suit::Spades, Rank::Six),
+    ///     Card::new(Suit::Hearts, Rank::Six),
+    ///     Card::new(Suit::Spades, Rank::Seven),
+    ///     Card::new(Suit::Hearts, Rank::Seven),
+    ///     Card::new(Suit::Clubs, Rank::Seven),
+    /// ];
+    ///
+    /// let optimized = HandPatternAnalyzer::optimize_for_fewest_plays(&hand);
+    /// assert_eq!(optimized.play_count(), 2); // e.g. 55-66-77 + single 7
+    /// ```
+    #[must_use]
+    pub fn optimize_for_fewest_plays(hand: &[Card]) -> HandPatterns {
+        Self::optimize_for_fewest_plays_with_options(hand, &AnalyzeOptions::default())
+    }
+
+    /// Find the fewest-plays decomposition like
+    /// [`optimize_for_fewest_plays`](Self::optimize_for_fewest_plays), honoring `options`.
+    ///
+    /// With [`QuadTreatment::TriplePlusSingle`] quads join the search as
+    /// normal cards, so they may also feed airplanes and chains.
+    #[must_use]
+    pub fn optimize_for_fewest_plays_with_options(
+        hand: &[Card],
+        options: &AnalyzeOptions,
+    ) -> HandPatterns {
+        if options.treat_quads_as == QuadTreatment::Both {
+            return Self::_fewer_plays(
+                Self::optimize_for_fewest_plays_with_options(
+                    hand,
+                    &Self::_with_quads(QuadTreatment::Bomb),
+                ),
+                Self::optimize_for_fewest_plays_with_options(
+                    hand,
+                    &Self::_with_quads(QuadTreatment::TriplePlusSingle),
+                ),
+            );
+        }
+
+        if hand.is_empty() {
+            return HandPatterns::default();
+        }
+
+        let mut patterns = HandPatterns {
+            total_cards: hand.len(),
+            ..Default::default()
+        };
+
+        let mut remaining_cards = hand.to_vec();
+        Self::_extract_trump_cards(&mut remaining_cards, &mut patterns, options);
+
+        let counts = RankCounts::from_cards(&remaining_cards);
+        let mut pools: Vec<Vec<Card>> = vec![Vec::new(); Rank::ALL.len()];
+        for card in &remaining_cards {
+            pools[card.rank.index()].push(*card);
+        }
+
+        let mut memo = HashMap::new();
+        Self::_search_fewest_plays(counts, 0, &mut memo);
+        logging::log(Level::Trace, module_path!(), &|| {
+            format!(
+                "fewest-plays search over {} cards visited {} states",
+                remaining_cards.len(),
+                memo.len()
+            )
+        });
+
+        // Replay the recorded best moves, drawing physical cards from each rank pool
+        let mut state = counts;
+        let mut slots = 0;
+        let mut kickers = Vec::new();
+        while let Some(first) = state.0.iter().position(|&c| c > 0) {
+            slots = Self::_useful_slots(&state, slots);
+            let Some(&(_, Some(best_move))) = memo.get(&(state, slots)) else {
+                break;
+            };
+            slots += Self::_slot_change(best_move);
+            let (width, length) = match best_move {
+                NormalMove::SameRank { size } => (size, 1),
+                NormalMove::Chain { width, length } => (width, length),
+                NormalMove::Kicker => {
+                    state.0[first] -= 1;
+                    kickers.extend(pools[first].pop());
+                    continue;
+                }
+            };
+            let mut cards = Vec::new();
+            for (count, pool) in state.0[first..first + length]
+                .iter_mut()
+                .zip(&mut pools[first..first + length])
+            {
+                *count -= width;
+                for _ in 0..width {
+                    cards.extend(pool.pop());
+                }
+            }
+            match (best_move, width) {
+                (NormalMove::SameRank { .. }, 1) => patterns.singles.extend(cards),
+                (NormalMove::SameRank { .. }, 2) => patterns.pairs.push(cards),
+                (NormalMove::SameRank { .. }, _) => patterns.triples.push(cards),
+                (NormalMove::Chain { .. }, 2) => patterns.consecutive_pair_chains.push(cards),
+                (NormalMove::Chain { .. }, _) => patterns.airplane_chains.push(cards),
+                (NormalMove::Kicker, _) => unreachable!("kickers are collected above"),
+            }
+        }
+
+        // Hand out kickers, two per triple. A triple never carries its own
+        // rank (that would read as a bomb plus a card); such a kicker stays
+        // a single.
+        let mut open: Vec<(&mut Vec<Card>, usize, Option<Rank>)> = patterns
+            .airplane_chains
+            .iter_mut()
+            .map(|group| {
+                let capacity = group.len() / 3 * MAX_KICKERS_PER_GROUP;
+                (group, capacity, None)
+            })
+            .chain(patterns.triples.iter_mut().map(|group| {
+                let rank = group[0].rank;
+                (group, MAX_KICKERS_PER_GROUP, Some(rank))
+            }))
+            .collect();
+        for kicker in kickers {
+            match open
+                .iter_mut()
+                .find(|(_, capacity, rank)| *capacity > 0 && *rank != Some(kicker.rank))
+            {
+                Some((group, capacity, _)) => {
+                    group.push(kicker);
+                    *capacity -= 1;
+                }
+                None => patterns.singles.push(kicker),
+            }
+        }
+
+        // Same ordering conventions as analyze_patterns
+        Self::_sort_normal_groups(&mut patterns);
+
+        patterns.trump_count = patterns.dizha.len() + patterns.tongzi.len() + patterns.bombs.len();
+        patterns.has_control_cards = hand
+            .iter()
+            .any(|c| matches!(c.rank, Rank::Two | Rank::Ace | Rank::King));
+        patterns.near_trumps = Self::_find_near_trumps(hand);
+        invariants::assert_partition(hand, &patterns);
+
+        patterns
+    }
+
+    // ========== Private Search Methods ==========
+
+    /// Kicker slots opened (triples, airplanes) or taken (kickers) by a move.
+    fn _slot_change(normal_move: NormalMove) -> i32 {
+        let per_triple = MAX_KICKERS_PER_GROUP as i32;
+        match normal_move {
+            NormalMove::SameRank { size: 3 } => per_triple,
+            NormalMove::Chain { width: 3, length } => per_triple * length as i32,
+            NormalMove::SameRank { .. } | NormalMove::Chain { .. } => 0,
+            NormalMove::Kicker => -1,
+        }
+    }
+
+    /// Open kicker slots capped at the cards left to fill them.
+    fn _useful_slots(counts: &RankCounts, slots: i32) -> i32 {
+        slots.min(i32::try_from(counts.total()).unwrap_or(i32::MAX))
+    }
+
+    /// Minimum (plays, singles) to empty `counts`, memoizing the best move per state.
+    ///
+    /// The lowest remaining rank must be consumed by a move that starts at it,
+    /// which keeps the branching factor small. `slots` is the kicker capacity
+    /// opened by the triples taken so far minus the kickers taken so far; it
+    /// may dip below zero while a later (higher) triple is still to come, but
+    /// must end at zero or above.
+    fn _search_fewest_plays(
+        counts: RankCounts,
+        slots: i32,
+        memo: &mut FewestPlaysMemo,
+    ) -> (usize, usize) {
+        const UNSOLVABLE: (usize, usize) = (usize::MAX, usize::MAX);
+
+        let Some(first) = counts.0.iter().position(|&c| c > 0) else {
+            return if slots >= 0 { (0, 0) } else { UNSOLVABLE };
+        };
+        let slots = Self::_useful_slots(&counts, slots);
+        // Repaying the kickers taken needs a triple per two of them
+        let remaining = i32::try_from(counts.total()).unwrap_or(i32::MAX);
+        if slots < 0 && 3 * ((-slots + 1) / 2) > remaining {
+            return UNSOLVABLE;
+        }
+        if let Some((cost, _)) = memo.get(&(counts, slots)) {
+            metrics::record_cache_hit();
+            return *cost;
+        }
+        metrics::record_solver_node();
+
+        // Chains may not include Two (last index)
+        let chain_limit = Rank::ALL.len() - 1;
+        let mut candidates = Vec::new();
+        for size in 1..=counts.0[first].min(3) {
+            candidates.push(NormalMove::SameRank { size });
+        }
+        for width in [2u8, 3] {
+            if counts.0[first] < width {
+                continue;
+            }
+            let mut length = 2;
+            while first + length <= chain_limit && counts.0[first + length - 1] >= width {
+                candidates.push(NormalMove::Chain { width, length });
+                length += 1;
+            }
+        }
+
+        candidates.push(NormalMove::Kicker);
+
+        let mut best: ((usize, usize), Option<NormalMove>) = (UNSOLVABLE, None);
+        for candidate in candidates {
+            let (width, length, plays) = match candidate {
+                NormalMove::SameRank { size } => (size, 1, 1),
+                NormalMove::Chain { width, length } => (width, length, 1),
+                NormalMove::Kicker => (1, 1, 0),
+            };
+            let mut next = counts;
+            for count in &mut next.0[first..first + length] {
+                *count -= width;
+            }
+            let rest =
+                Self::_search_fewest_plays(next, slots + Self::_slot_change(candidate), memo);
+            if rest == UNSOLVABLE {
+                continue;
+            }
+            let is_single = matches!(candidate, NormalMove::SameRank { size: 1 });
+            let cost = (rest.0 + plays, rest.1 + usize::from(is_single));
+            if cost < best.0 {
+                best = (cost, Some(candidate));
+            }
+        }
+
+        memo.insert((counts, slots), best);
+        best.0
+    }
+
     // ========== Private Extraction Methods ==========
 
+    /// Options with only the quad treatment set.
+    fn _with_quads(treat_quads_as: QuadTreatment) -> AnalyzeOptions {
+        AnalyzeOptions { treat_quads_as }
+    }
+
+    /// Pick the decomposition with fewer plays, preferring `bombs` on ties.
+    fn _fewer_plays(bombs: HandPatterns, split: HandPatterns) -> HandPatterns {
+        if split.play_count() < bombs.play_count() {
+            split
+        } else {
+            bombs
+        }
+    }
+
     /// Extract dizha, tongzi, and bombs.
-    fn _extract_trump_cards(remaining_cards: &mut Vec<Card>, patterns: &mut HandPatterns) {
+    fn _extract_trump_cards(
+        remaining_cards: &mut Vec<Card>,
+        patterns: &mut HandPatterns,
+        options: &AnalyzeOptions,
+    ) {
         // Extract dizha (highest priority trump)
         let dizha_list = Self::_find_dizha(remaining_cards);
         for dizha in dizha_list {
@@ -227,6 +770,11 @@ impl HandPatternAnalyzer {
         // Extract bombs (4+ same rank)
         let bombs_list = Self::_find_bombs(remaining_cards);
         for bomb in bombs_list {
+            if bomb.len() == RULES.min_bomb
+                && options.treat_quads_as == QuadTreatment::TriplePlusSingle
+            {
+                continue;
+            }
             patterns.bombs.push(bomb.clone());
             for card in &bomb {
                 if let Some(pos) = remaining_cards.iter().position(|c| c == card) {
@@ -236,6 +784,11 @@ impl HandPatternAnalyzer {
         }
 
         // Sort by strength (descending)
+        Self::_sort_trump_groups(patterns);
+    }
+
+    /// Sort trump categories strongest first.
+    fn _sort_trump_groups(patterns: &mut HandPatterns) {
         patterns
             .dizha
             .sort_by(|a, b| b[0].rank.value().cmp(&a[0].rank.value()));
@@ -252,6 +805,29 @@ impl HandPatternAnalyzer {
         });
     }
 
+    /// Sort non-trump categories strongest first (longer chains first).
+    fn _sort_normal_groups(patterns: &mut HandPatterns) {
+        patterns.airplane_chains.sort_by(|a, b| {
+            b.len()
+                .cmp(&a.len())
+                .then(b[0].rank.value().cmp(&a[0].rank.value()))
+        });
+        patterns
+            .triples
+            .sort_by(|a, b| b[0].rank.value().cmp(&a[0].rank.value()));
+        patterns.consecutive_pair_chains.sort_by(|a, b| {
+            b.len()
+                .cmp(&a.len())
+                .then(b[0].rank.value().cmp(&a[0].rank.value()))
+        });
+        patterns
+            .pairs
+            .sort_by(|a, b| b[0].rank.value().cmp(&a[0].rank.value()));
+        patterns
+            .singles
+            .sort_by(|a, b| b.rank.value().cmp(&a.rank.value()));
+    }
+
     /// Extract airplane chains (consecutive triples).
     fn _extract_airplane_chains(remaining_cards: &mut Vec<Card>, patterns: &mut HandPatterns) {
         let airplane_chains = Self::_find_airplane_chains(remaining_cards);
@@ -360,7 +936,7 @@ impl HandPatternAnalyzer {
 
         let mut dizha_list = Vec::new();
         for (_rank, rank_cards) in rank_groups {
-            if rank_cards.len() < 8 {
+            if rank_cards.len() < RULES.dizha {
                 continue;
             }
 
@@ -370,15 +946,15 @@ impl HandPatternAnalyzer {
                 suit_groups.entry(card.suit).or_default().push(*card);
             }
 
-            // Check if all 4 suits have at least 2 cards
+            // Check that every suit has a full dizha share
             let all_suits = [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds];
             if all_suits
                 .iter()
-                .all(|suit| suit_groups.get(suit).map_or(0, |v| v.len()) >= 2)
+                .all(|suit| suit_groups.get(suit).map_or(0, |v| v.len()) >= RULES.dizha_per_suit())
             {
                 let mut dizha = Vec::new();
                 for suit in &all_suits {
-                    dizha.extend(&suit_groups[suit][0..2]);
+                    dizha.extend(&suit_groups[suit][..RULES.dizha_per_suit()]);
                 }
 
                 // Validate
@@ -424,6 +1000,65 @@ impl HandPatternAnalyzer {
         tongzi_list
     }
 
+    /// Find Tongzi (2 of one suit-rank) and Dizha (7 cards, one suit short)
+    /// that miss exactly one card.
+    fn _find_near_trumps(cards: &[Card]) -> Vec<NearTrump> {
+        let mut suit_rank_groups: HashMap<(Suit, Rank), Vec<Card>> = HashMap::new();
+        for card in cards {
+            suit_rank_groups
+                .entry((card.suit, card.rank))
+                .or_default()
+                .push(*card);
+        }
+
+        let mut near = Vec::new();
+        for rank in Rank::ALL {
+            let group = |suit: Suit| {
+                suit_rank_groups
+                    .get(&(suit, rank))
+                    .map_or(&[][..], Vec::as_slice)
+            };
+
+            // Dizha: a full share of each suit, exactly one card short
+            let short: Vec<Suit> = Suit::ALL
+                .iter()
+                .copied()
+                .filter(|suit| group(*suit).len() < RULES.dizha_per_suit())
+                .collect();
+            if let [suit] = short[..] {
+                if group(suit).len() == 1 {
+                    near.push(NearTrump {
+                        play_type: PlayType::Dizha,
+                        cards: Suit::ALL
+                            .iter()
+                            .flat_map(|s| group(*s).iter().take(RULES.dizha_per_suit()).copied())
+                            .collect(),
+                        missing: Card::new(suit, rank),
+                    });
+                }
+            }
+
+            // Tongzi: exactly two of one suit-rank
+            for suit in Suit::ALL {
+                if group(suit).len() == 2 {
+                    near.push(NearTrump {
+                        play_type: PlayType::Tongzi,
+                        cards: group(suit).to_vec(),
+                        missing: Card::new(suit, rank),
+                    });
+                }
+            }
+        }
+
+        near.sort_by(|a, b| {
+            b.play_type
+                .cmp(&a.play_type)
+                .then(b.missing.rank.value().cmp(&a.missing.rank.value()))
+                .then(b.missing.suit.value().cmp(&a.missing.suit.value()))
+        });
+        near
+    }
+
     /// Find all bombs (4+ same rank).
     fn _find_bombs(cards: &[Card]) -> Vec<Vec<Card>> {
         let mut rank_groups: HashMap<Rank, Vec<Card>> = HashMap::new();
@@ -433,7 +1068,7 @@ impl HandPatternAnalyzer {
 
         let mut bombs_list = Vec::new();
         for (_rank, rank_cards) in rank_groups {
-            if rank_cards.len() >= 4 {
+            if rank_cards.len() >= RULES.min_bomb {
                 // Take the largest possible bomb
                 let bomb = rank_cards.clone();
                 if let Some(pattern) = PatternRecognizer::analyze_cards(&bomb) {
@@ -479,50 +1114,26 @@ impl HandPatternAnalyzer {
             rank_groups.entry(card.rank).or_default().push(*card);
         }
 
-        // Get ranks with at least 3 cards, excluding Two (2 doesn't participate in sequences)
-        let mut valid_ranks: Vec<Rank> = rank_groups
-            .iter()
-            .filter(|(r, cards)| cards.len() >= 3 && **r != Rank::Two)
-            .map(|(r, _cards)| *r)
-            .collect();
-        valid_ranks.sort();
+        // Maximal runs of ranks with at least 3 cards (2 never joins a run)
+        let mask = RankCounts::from_cards(cards).mask_with_at_least(3);
 
         let mut chains = Vec::new();
-        let mut i = 0;
-        while i < valid_ranks.len() {
-            // Try to build longest chain starting from valid_ranks[i]
-            let mut chain_ranks = vec![valid_ranks[i]];
-            let mut j = i + 1;
-
-            while j < valid_ranks.len() {
-                if valid_ranks[j].value() == chain_ranks.last().unwrap().value() + 1 {
-                    chain_ranks.push(valid_ranks[j]);
-                    j += 1;
-                } else {
-                    break;
-                }
+        for run in runs::maximal_runs(mask) {
+            // Only keep chains of the minimum length
+            if usize::from(run.len) < RULES.min_airplane {
+                continue;
             }
 
-            // Only keep chains of length >= 2
-            if chain_ranks.len() >= 2 {
-                let mut chain_cards = Vec::new();
-                for rank in &chain_ranks {
-                    chain_cards.extend(&rank_groups[rank][0..3]);
-                }
+            let mut chain_cards = Vec::new();
+            for rank in run.ranks() {
+                chain_cards.extend(&rank_groups[rank][0..3]);
+            }
 
-                // Validate
-                if let Some(pattern) = PatternRecognizer::analyze_cards(&chain_cards) {
-                    if pattern.play_type == PlayType::Airplane {
-                        chains.push(chain_cards);
-                        i = j; // Skip to next unprocessed rank
-                    } else {
-                        i += 1;
-                    }
-                } else {
-                    i += 1;
-                }
-            } else {
-                i += 1;
+            // Validate
+            if PatternRecognizer::analyze_cards(&chain_cards)
+                .map_or(false, |pattern| pattern.play_type == PlayType::Airplane)
+            {
+                chains.push(chain_cards);
             }
         }
 
@@ -539,50 +1150,26 @@ impl HandPatternAnalyzer {
             rank_groups.entry(card.rank).or_default().push(*card);
         }
 
-        // Get ranks with at least 2 cards, excluding Two (2 doesn't participate in sequences)
-        let mut valid_ranks: Vec<Rank> = rank_groups
-            .iter()
-            .filter(|(r, cards)| cards.len() >= 2 && **r != Rank::Two)
-            .map(|(r, _cards)| *r)
-            .collect();
-        valid_ranks.sort();
+        // Maximal runs of ranks with at least 2 cards (2 never joins a run)
+        let mask = RankCounts::from_cards(cards).mask_with_at_least(2);
 
         let mut chains = Vec::new();
-        let mut i = 0;
-        while i < valid_ranks.len() {
-            // Try to build longest chain starting from valid_ranks[i]
-            let mut chain_ranks = vec![valid_ranks[i]];
-            let mut j = i + 1;
-
-            while j < valid_ranks.len() {
-                if valid_ranks[j].value() == chain_ranks.last().unwrap().value() + 1 {
-                    chain_ranks.push(valid_ranks[j]);
-                    j += 1;
-                } else {
-                    break;
-                }
+        for run in runs::maximal_runs(mask) {
+            // Only keep chains of the minimum length
+            if usize::from(run.len) < RULES.min_pair_chain {
+                continue;
             }
 
-            // Only keep chains of length >= 2
-            if chain_ranks.len() >= 2 {
-                let mut chain_cards = Vec::new();
-                for rank in &chain_ranks {
-                    chain_cards.extend(&rank_groups[rank][0..2]);
-                }
+            let mut chain_cards = Vec::new();
+            for rank in run.ranks() {
+                chain_cards.extend(&rank_groups[rank][0..2]);
+            }
 
-                // Validate
-                if let Some(pattern) = PatternRecognizer::analyze_cards(&chain_cards) {
-                    if pattern.play_type == PlayType::ConsecutivePairs {
-                        chains.push(chain_cards);
-                        i = j; // Skip to next unprocessed rank
-                    } else {
-                        i += 1;
-                    }
-                } else {
-                    i += 1;
-                }
-            } else {
-                i += 1;
+            // Validate
+            if PatternRecognizer::analyze_cards(&chain_cards).map_or(false, |pattern| {
+                pattern.play_type == PlayType::ConsecutivePairs
+            }) {
+                chains.push(chain_cards);
             }
         }
 
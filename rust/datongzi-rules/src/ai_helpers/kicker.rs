@@ -9,6 +9,7 @@ use crate::models::{Card, Rank};
 
 /// Kicker selection tactic.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Tactic {
     /// Efficiency first: reward whole-take, penalize splits
     Efficiency,
@@ -71,12 +72,68 @@ impl Default for KnapsackResult {
     }
 }
 
+/// Breakdown of the cost of taking cards from one block.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CostBreakdown {
+    /// Rank value × take count
+    pub base: f32,
+    /// Whole-take bonus or split penalty
+    pub integrity: f32,
+    /// Tactic-specific modifier
+    pub tactical: f32,
+    /// Bomb/tongzi/dizha protection penalty
+    pub power: f32,
+}
+
+impl CostBreakdown {
+    /// Returns the total cost
+    #[must_use]
+    pub fn total(&self) -> f32 {
+        self.base + self.integrity + self.tactical + self.power
+    }
+}
+
+/// One block considered by the solver, with its cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockChoice {
+    /// Rank of the block
+    pub rank: Rank,
+    /// Cards taken (for alternatives: cards that would have been taken)
+    pub take: usize,
+    /// Cost breakdown for taking `take` cards
+    pub cost: CostBreakdown,
+}
+
+/// Kicker selection with the reasoning behind it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KickerExplanation {
+    /// Selected kicker cards (same as [`select_kickers`])
+    pub kickers: Vec<Card>,
+    /// Tactic used (after auto-selection)
+    pub tactic: Tactic,
+    /// Whether the tactic was auto-selected
+    pub tactic_auto_selected: bool,
+    /// Chosen blocks with their cost breakdown
+    pub chosen: Vec<BlockChoice>,
+    /// Blocks that were available but not taken, costed at the largest take that fits
+    pub rejected: Vec<BlockChoice>,
+    /// Ranks excluded up front because they could form a bomb
+    pub protected_ranks: Vec<Rank>,
+    /// Total cost reported by the solver (including unfilled-capacity penalty)
+    pub total_cost: f32,
+}
+
 /// Calculate the cost of taking cards from a block.
 ///
 /// Cost formula: base + integrity_mod + tactical_mod + power_mod
 fn calculate_cost(block: &Block, take: usize, tactic: Tactic) -> f32 {
+    cost_breakdown(block, take, tactic).total()
+}
+
+/// Calculate the cost of taking cards from a block, term by term.
+fn cost_breakdown(block: &Block, take: usize, tactic: Tactic) -> CostBreakdown {
     if take == 0 {
-        return 0.0;
+        return CostBreakdown::default();
     }
 
     // 1. Base cost: rank value × take count
@@ -139,7 +196,12 @@ fn calculate_cost(block: &Block, take: usize, tactic: Tactic) -> f32 {
         0.0
     };
 
-    base + integrity_mod + tactical_mod + power_mod
+    CostBreakdown {
+        base,
+        integrity: integrity_mod,
+        tactical: tactical_mod,
+        power: power_mod,
+    }
 }
 
 /// DFS knapsack solver for kicker selection.
@@ -255,6 +317,73 @@ fn should_use_aggressive(hand: &[Card], main_cards: &[Card], capacity: usize) ->
     loose_cards.len() <= capacity + 1
 }
 
+/// Game context used by [`choose_tactic`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TacticContext {
+    /// Cards left in my hand
+    pub my_cards_remaining: usize,
+    /// How far I trail the leader in points (negative when ahead)
+    pub score_deficit: i32,
+    /// Cards left in each opponent's hand
+    pub opponents_remaining: Vec<usize>,
+    /// Points (5/10/K) not yet captured this game
+    pub score_points_left: i32,
+}
+
+/// Cards left at which I play to go out rather than for structure.
+const LETHAL_HAND_SIZE: usize = 5;
+/// Opponent hand size at which they threaten to finish soon.
+const OPPONENT_DANGER_SIZE: usize = 3;
+/// Points left at which score-card tactics are worth pursuing.
+const SCORE_TACTIC_MIN_POINTS: i32 = 50;
+
+/// Choose a kicker tactic from the game context.
+///
+/// Policy, first match wins:
+/// 1. [`Tactic::Aggressive`]: I have few cards left (≤5), shed as many as possible
+/// 2. [`Tactic::SaveHigh`]: An opponent is close to finishing (≤3 cards), keep A/2 to answer
+/// 3. [`Tactic::DumpScore`]: I am behind and enough points remain, put points on the table
+/// 4. [`Tactic::HoardScore`]: I am ahead and enough points remain, deny points
+/// 5. [`Tactic::Efficiency`]: Otherwise, keep the hand's structure
+///
+/// # Example
+/// ```
+/// use datongzi_rules::ai_helpers::{choose_tactic, Tactic, TacticContext};
+///
+/// let context = TacticContext {
+///     my_cards_remaining: 20,
+///     score_deficit: 80,
+///     opponents_remaining: vec![18, 25],
+///     score_points_left: 200,
+/// };
+/// assert_eq!(choose_tactic(&context), Tactic::DumpScore);
+/// ```
+#[must_use]
+pub fn choose_tactic(context: &TacticContext) -> Tactic {
+    if context.my_cards_remaining <= LETHAL_HAND_SIZE {
+        return Tactic::Aggressive;
+    }
+
+    if context
+        .opponents_remaining
+        .iter()
+        .any(|&n| n > 0 && n <= OPPONENT_DANGER_SIZE)
+    {
+        return Tactic::SaveHigh;
+    }
+
+    if context.score_points_left >= SCORE_TACTIC_MIN_POINTS {
+        if context.score_deficit > 0 {
+            return Tactic::DumpScore;
+        }
+        if context.score_deficit < 0 {
+            return Tactic::HoardScore;
+        }
+    }
+
+    Tactic::Efficiency
+}
+
 /// Select kickers using multi-track algorithm.
 ///
 /// # Arguments
@@ -273,19 +402,103 @@ pub fn select_kickers(
     capacity: usize,
     tactic: Option<Tactic>,
 ) -> Vec<Card> {
-    // 1. Build available cards (exclude main cards and protected cards)
-    let available_cards: Vec<Card> = hand
+    select_kickers_explained(hand, main_cards, capacity, tactic).kickers
+}
+
+/// Like [`select_kickers`], never proposing a card whose rank is in
+/// `forbidden_ranks` (see
+/// [`GameConfig::forbidden_kicker_ranks`](crate::GameConfig::forbidden_kicker_ranks)).
+///
+/// # Example
+/// ```
+/// use datongzi_rules::ai_helpers::{select_kickers_excluding, Tactic};
+/// use datongzi_rules::{Card, Rank, Suit};
+///
+/// let main = vec![
+///     Card::new(Suit::Spades, Rank::Nine),
+///     Card::new(Suit::Hearts, Rank::Nine),
+///     Card::new(Suit::Clubs, Rank::Nine),
+/// ];
+/// let mut hand = main.clone();
+/// hand.push(Card::new(Suit::Spades, Rank::Five));
+/// hand.push(Card::new(Suit::Spades, Rank::Two));
+///
+/// let kickers = select_kickers_excluding(&hand, &main, 2, Some(Tactic::Aggressive), &[Rank::Two]);
+/// assert_eq!(kickers, vec![Card::new(Suit::Spades, Rank::Five)]);
+/// ```
+pub fn select_kickers_excluding(
+    hand: &[Card],
+    main_cards: &[Card],
+    capacity: usize,
+    tactic: Option<Tactic>,
+    forbidden_ranks: &[Rank],
+) -> Vec<Card> {
+    explain(hand, main_cards, capacity, tactic, forbidden_ranks).kickers
+}
+
+/// Select kickers and explain the choice.
+///
+/// Same selection as [`select_kickers`], plus the tactic used, the cost
+/// breakdown of every chosen block, the rejected blocks and the protected
+/// ranks. Intended for AI tuning and player-facing hints.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::ai_helpers::{select_kickers_explained, Tactic};
+/// use datongzi_rules::{Card, Rank, Suit};
+///
+/// let main = vec![
+///     Card::new(Suit::Spades, Rank::Nine),
+///     Card::new(Suit::Hearts, Rank::Nine),
+///     Card::new(Suit::Clubs, Rank::Nine),
+/// ];
+/// let mut hand = main.clone();
+/// hand.push(Card::new(Suit::Spades, Rank::Five));
+/// hand.push(Card::new(Suit::Spades, Rank::Ace));
+///
+/// let explanation = select_kickers_explained(&hand, &main, 1, Some(Tactic::SaveHigh));
+/// assert_eq!(explanation.kickers, vec![Card::new(Suit::Spades, Rank::Five)]);
+/// assert_eq!(explanation.rejected[0].rank, Rank::Ace);
+/// ```
+pub fn select_kickers_explained(
+    hand: &[Card],
+    main_cards: &[Card],
+    capacity: usize,
+    tactic: Option<Tactic>,
+) -> KickerExplanation {
+    explain(hand, main_cards, capacity, tactic, &[])
+}
+
+fn explain(
+    hand: &[Card],
+    main_cards: &[Card],
+    capacity: usize,
+    tactic: Option<Tactic>,
+    forbidden_ranks: &[Rank],
+) -> KickerExplanation {
+    // 1. Build available cards (exclude main cards, forbidden ranks and protected cards)
+    let candidates: Vec<Card> = hand
+        .iter()
+        .filter(|c| !main_cards.contains(c) && !forbidden_ranks.contains(&c.rank))
+        .copied()
+        .collect();
+    let available_cards: Vec<Card> = candidates
         .iter()
-        .filter(|c| !main_cards.contains(c))
         .filter(|c| !is_protected(hand, c))
         .copied()
         .collect();
 
-    if available_cards.is_empty() || capacity == 0 {
-        return vec![];
-    }
+    let mut protected_ranks: Vec<Rank> = candidates
+        .iter()
+        .filter(|c| is_protected(hand, c))
+        .map(|c| c.rank)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    protected_ranks.sort();
 
     // 2. Determine tactic
+    let tactic_auto_selected = tactic.is_none();
     let tactic = tactic.unwrap_or_else(|| {
         if should_use_aggressive(hand, main_cards, capacity) {
             Tactic::Aggressive
@@ -294,6 +507,20 @@ pub fn select_kickers(
         }
     });
 
+    let mut explanation = KickerExplanation {
+        kickers: vec![],
+        tactic,
+        tactic_auto_selected,
+        chosen: vec![],
+        rejected: vec![],
+        protected_ranks,
+        total_cost: 0.0,
+    };
+
+    if available_cards.is_empty() || capacity == 0 {
+        return explanation;
+    }
+
     // 3. Build blocks from available cards
     let mut seen_ranks = HashSet::new();
     let blocks: Vec<Block> = available_cards
@@ -309,20 +536,37 @@ pub fn select_kickers(
 
     // 4. Run DFS knapsack solver
     let result = solve_knapsack(&blocks, capacity, tactic);
-
-    // 5. Convert result to actual cards
-    let mut kickers = Vec::new();
-    for (rank, count) in result.selected {
-        let cards: Vec<Card> = available_cards
-            .iter()
-            .filter(|c| c.rank == rank)
-            .take(count)
-            .copied()
-            .collect();
-        kickers.extend(cards);
+    explanation.total_cost = result.total_cost;
+
+    // 5. Convert result to actual cards and explain each block
+    for block in &blocks {
+        match result.selected.iter().find(|(rank, _)| *rank == block.rank) {
+            Some(&(rank, count)) => {
+                explanation.kickers.extend(
+                    available_cards
+                        .iter()
+                        .filter(|c| c.rank == rank)
+                        .take(count)
+                        .copied(),
+                );
+                explanation.chosen.push(BlockChoice {
+                    rank,
+                    take: count,
+                    cost: cost_breakdown(block, count, tactic),
+                });
+            }
+            None => {
+                let take = block.count.min(capacity);
+                explanation.rejected.push(BlockChoice {
+                    rank: block.rank,
+                    take,
+                    cost: cost_breakdown(block, take, tactic),
+                });
+            }
+        }
     }
 
-    kickers
+    explanation
 }
 
 #[cfg(test)]
@@ -940,4 +1184,122 @@ mod tests {
         // Aggressive should select all available cards
         assert_eq!(kickers.len(), 2);
     }
+
+    #[test]
+    fn test_select_kickers_explained_matches_select_kickers() {
+        let main_cards = vec![
+            make_card(Suit::Spades, Rank::Nine),
+            make_card(Suit::Hearts, Rank::Nine),
+            make_card(Suit::Clubs, Rank::Nine),
+        ];
+        let mut hand = main_cards.clone();
+        hand.extend([
+            make_card(Suit::Spades, Rank::Five),
+            make_card(Suit::Hearts, Rank::Five),
+            make_card(Suit::Spades, Rank::King),
+            make_card(Suit::Spades, Rank::Two),
+        ]);
+
+        for tactic in [None, Some(Tactic::SaveHigh), Some(Tactic::DumpScore)] {
+            let explanation = select_kickers_explained(&hand, &main_cards, 2, tactic);
+            assert_eq!(
+                explanation.kickers,
+                select_kickers(&hand, &main_cards, 2, tactic)
+            );
+            assert_eq!(explanation.tactic_auto_selected, tactic.is_none());
+
+            let taken: usize = explanation.chosen.iter().map(|c| c.take).sum();
+            assert_eq!(taken, explanation.kickers.len());
+            assert_eq!(explanation.chosen.len() + explanation.rejected.len(), 3);
+        }
+    }
+
+    #[test]
+    fn test_select_kickers_explained_reports_protection_and_costs() {
+        let main_cards = vec![
+            make_card(Suit::Spades, Rank::Nine),
+            make_card(Suit::Hearts, Rank::Nine),
+            make_card(Suit::Clubs, Rank::Nine),
+        ];
+        let mut hand = main_cards.clone();
+        hand.extend([
+            make_card(Suit::Spades, Rank::Seven),
+            make_card(Suit::Hearts, Rank::Seven),
+            make_card(Suit::Clubs, Rank::Seven),
+            make_card(Suit::Diamonds, Rank::Seven),
+            make_card(Suit::Spades, Rank::King),
+        ]);
+
+        let explanation = select_kickers_explained(&hand, &main_cards, 1, Some(Tactic::DumpScore));
+
+        assert_eq!(explanation.protected_ranks, vec![Rank::Seven]);
+        assert_eq!(explanation.chosen.len(), 1);
+        let king = &explanation.chosen[0];
+        assert_eq!(king.rank, Rank::King);
+        assert_eq!(king.cost.tactical, -50.0);
+        assert_eq!(
+            king.cost.total(),
+            calculate_cost(&Block::from_cards(&hand, Rank::King), 1, Tactic::DumpScore)
+        );
+    }
+
+    fn context(
+        my_cards_remaining: usize,
+        score_deficit: i32,
+        opponents_remaining: Vec<usize>,
+        score_points_left: i32,
+    ) -> TacticContext {
+        TacticContext {
+            my_cards_remaining,
+            score_deficit,
+            opponents_remaining,
+            score_points_left,
+        }
+    }
+
+    #[test]
+    fn test_choose_tactic_aggressive_when_nearly_out() {
+        assert_eq!(
+            choose_tactic(&context(4, 100, vec![2], 300)),
+            Tactic::Aggressive
+        );
+    }
+
+    #[test]
+    fn test_choose_tactic_save_high_when_opponent_near_finish() {
+        assert_eq!(
+            choose_tactic(&context(20, 100, vec![15, 3], 300)),
+            Tactic::SaveHigh
+        );
+        // Finished opponents (0 cards) are not a threat
+        assert_ne!(
+            choose_tactic(&context(20, 0, vec![0, 15], 0)),
+            Tactic::SaveHigh
+        );
+    }
+
+    #[test]
+    fn test_choose_tactic_score_tactics() {
+        assert_eq!(
+            choose_tactic(&context(20, 60, vec![15, 18], 120)),
+            Tactic::DumpScore
+        );
+        assert_eq!(
+            choose_tactic(&context(20, -60, vec![15, 18], 120)),
+            Tactic::HoardScore
+        );
+        // Too few points left to matter
+        assert_eq!(
+            choose_tactic(&context(20, 60, vec![15, 18], 20)),
+            Tactic::Efficiency
+        );
+    }
+
+    #[test]
+    fn test_choose_tactic_default_efficiency() {
+        assert_eq!(
+            choose_tactic(&context(20, 0, vec![15, 18], 200)),
+            Tactic::Efficiency
+        );
+    }
 }
@@ -2,20 +2,42 @@
 //!
 //! This module provides tools for AI decision making:
 //! - [`PlayGenerator`]: Generate valid plays from hand
+//! - [`PlayBuffer`]: Reusable play storage for [`PlayGenerator::generate_into`]
 //! - [`HandPatternAnalyzer`]: Analyze hand structure (non-overlapping decomposition)
 //! - [`HandPatterns`]: Structured representation of hand resources
+//! - [`ConsumeError`]: Why [`HandPatterns::consume`] rejected a play
 //! - [`kicker`]: Multi-track kicker selection algorithm
 //! - [`identical_play_filter`]: Identical play filtering to reduce duplicates
+//! - [`bomb_timing`]: Whether to play each trump now or hold it
+//! - [`should_split_chain`]: Whether to break a chain to answer a trick
+//! - [`OpponentModel`]: Per-opponent play tendencies and sampling priors
 
+mod bomb_timing;
+mod chain_split;
 mod hand_pattern_analyzer;
 mod identical_play_filter;
 mod kicker;
+mod opponent_model;
+mod play_buffer;
 mod play_generator;
 
-pub use hand_pattern_analyzer::{HandPatternAnalyzer, HandPatterns};
+pub use bomb_timing::{bomb_timing, BombTiming, BombTimingView};
+pub(crate) use chain_split::remove_cards;
+pub use chain_split::{should_split_chain, SplitRecommendation};
+pub use hand_pattern_analyzer::{
+    AnalyzeOptions, ConsumeError, HandPatternAnalyzer, HandPatterns, NearTrump, QuadTreatment,
+};
 pub use identical_play_filter::{
     detect_dizha, detect_tongzi, filter_consecutive_pairs, filter_pairs, filter_singles,
     filter_triples, get_protected_suits, select_safe_suit,
 };
-pub use kicker::{select_kickers, Block, KnapsackResult, Tactic};
-pub use play_generator::PlayGenerator;
+pub use kicker::{
+    choose_tactic, select_kickers, select_kickers_excluding, select_kickers_explained, Block,
+    BlockChoice, CostBreakdown, KickerExplanation, KnapsackResult, Tactic, TacticContext,
+};
+pub use opponent_model::{OpponentModel, OpponentPriors, Tendencies};
+pub use play_buffer::PlayBuffer;
+pub use play_generator::{
+    DecisionTrace, GenerationOptions, HandComplexity, MoveWeights, PlayGenerator, RejectReason,
+    TracedCandidate, WingPolicy,
+};
@@ -0,0 +1,232 @@
+//! Per-opponent play tendencies accumulated over a session.
+
+use std::collections::HashMap;
+
+use crate::error::Result;
+use crate::models::{Card, GameConfig};
+use crate::patterns::{PatternRecognizer, PlayPattern, PlayType};
+use crate::scoring::{replay, GameRecord, RecordedAction, ReplayStep};
+
+/// Raw counts observed for one opponent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tendencies {
+    /// Times the opponent had a single to answer
+    pub singles_faced: usize,
+    /// Times they beat it
+    pub singles_beaten: usize,
+    /// Times the opponent had any play to answer
+    pub plays_faced: usize,
+    /// Times they beat it
+    pub plays_beaten: usize,
+    /// Plays made, leads included
+    pub plays: usize,
+    /// Plays that were a Bomb, Tongzi or Dizha
+    pub trump_plays: usize,
+}
+
+/// Smoothed rates for one opponent, usable as sampling priors.
+///
+/// Each rate starts at 0.5 for an unseen opponent and moves toward the
+/// observed frequency as evidence accumulates (add-one smoothing).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpponentPriors {
+    /// Probability of beating a single rather than passing
+    pub beat_single: f64,
+    /// Probability of beating any play rather than passing
+    pub beat: f64,
+    /// Share of plays that use a trump
+    pub trump_usage: f64,
+}
+
+fn smoothed(hits: usize, trials: usize) -> f64 {
+    (hits as f64 + 1.0) / (trials as f64 + 2.0)
+}
+
+fn is_trump(play_type: PlayType) -> bool {
+    matches!(
+        play_type,
+        PlayType::Bomb | PlayType::Tongzi | PlayType::Dizha
+    )
+}
+
+/// Play tendencies of each opponent, accumulated across tricks and games.
+///
+/// Feed it every play and pass as they happen (or whole records with
+/// [`observe_record`](Self::observe_record)); a move sampler can then weight
+/// an opponent's likely responses with [`priors`](Self::priors).
+///
+/// With the `serde` feature the model serializes, so bots can carry what they
+/// learned from one game of a match into the next.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::ai_helpers::OpponentModel;
+/// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+///
+/// let five = [Card::new(Suit::Spades, Rank::Five)];
+/// let single = PatternRecognizer::analyze_cards(&five).unwrap();
+///
+/// let mut model = OpponentModel::new();
+/// for _ in 0..8 {
+///     model.observe_play("bob", Some(&single), &[Card::new(Suit::Hearts, Rank::Two)]);
+/// }
+/// model.observe_pass("carol", &single);
+///
+/// assert!(model.priors("bob").beat_single > 0.8);
+/// assert!(model.priors("carol").beat_single < 0.5);
+/// assert_eq!(model.priors("dave").beat_single, 0.5);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpponentModel {
+    tendencies: HashMap<String, Tendencies>,
+}
+
+impl OpponentModel {
+    /// Create an empty model
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a play; `current` is the play it answered (`None` when leading)
+    pub fn observe_play(&mut self, player_id: &str, current: Option<&PlayPattern>, cards: &[Card]) {
+        let stats = self.tendencies.entry(player_id.to_string()).or_default();
+        stats.plays += 1;
+        if PatternRecognizer::analyze_cards(cards).is_some_and(|p| is_trump(p.play_type)) {
+            stats.trump_plays += 1;
+        }
+        if let Some(current) = current {
+            stats.plays_faced += 1;
+            stats.plays_beaten += 1;
+            if current.play_type == PlayType::Single {
+                stats.singles_faced += 1;
+                stats.singles_beaten += 1;
+            }
+        }
+    }
+
+    /// Record a pass on `current`
+    pub fn observe_pass(&mut self, player_id: &str, current: &PlayPattern) {
+        let stats = self.tendencies.entry(player_id.to_string()).or_default();
+        stats.plays_faced += 1;
+        if current.play_type == PlayType::Single {
+            stats.singles_faced += 1;
+        }
+    }
+
+    /// Record every play and pass of a finished game, replayed under
+    /// `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record does not replay (see
+    /// [`score_record`](crate::scoring::score_record)); nothing from a
+    /// failing record is kept
+    pub fn observe_record(&mut self, record: &GameRecord, config: &GameConfig) -> Result<()> {
+        let mut updated = self.clone();
+        replay(record, config, |step| {
+            if let ReplayStep::Action { open, action, .. } = step {
+                let current = open.map(|round| &round.pattern);
+                match action {
+                    RecordedAction::Play { player_id, cards } => {
+                        updated.observe_play(player_id, current, cards);
+                    }
+                    RecordedAction::Pass { player_id } => {
+                        if let Some(current) = current {
+                            updated.observe_pass(player_id, current);
+                        }
+                    }
+                }
+            }
+        })?;
+        *self = updated;
+        Ok(())
+    }
+
+    /// Returns the raw counts for `player_id`, if any were observed
+    #[must_use]
+    pub fn tendencies(&self, player_id: &str) -> Option<&Tendencies> {
+        self.tendencies.get(player_id)
+    }
+
+    /// Returns smoothed rates for `player_id` (neutral for unseen opponents)
+    #[must_use]
+    pub fn priors(&self, player_id: &str) -> OpponentPriors {
+        let stats = self.tendencies(player_id).copied().unwrap_or_default();
+        OpponentPriors {
+            beat_single: smoothed(stats.singles_beaten, stats.singles_faced),
+            beat: smoothed(stats.plays_beaten, stats.plays_faced),
+            trump_usage: smoothed(stats.trump_plays, stats.plays),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+
+    #[test]
+    fn test_observe_record_counts_trumps_and_passes() {
+        let king = Card::new(Suit::Spades, Rank::King);
+        let bomb: Vec<Card> = Suit::ALL.iter().map(|s| Card::new(*s, Rank::Six)).collect();
+        let play = |id: &str, cards: &[Card]| RecordedAction::Play {
+            player_id: id.to_string(),
+            cards: cards.to_vec(),
+        };
+        let record = GameRecord {
+            player_ids: vec!["a".into(), "b".into(), "c".into()],
+            hands: vec![vec![king, king], bomb.clone(), vec![king]],
+            actions: vec![
+                play("a", &[king]),
+                play("b", &bomb),
+                RecordedAction::Pass {
+                    player_id: "c".into(),
+                },
+            ],
+            ..GameRecord::default()
+        };
+
+        let mut model = OpponentModel::new();
+        model
+            .observe_record(&record, &GameConfig::default())
+            .unwrap();
+        let b = model.tendencies("b").unwrap();
+        assert_eq!(
+            (b.singles_faced, b.singles_beaten, b.trump_plays),
+            (1, 1, 1)
+        );
+        let c = model.tendencies("c").unwrap();
+        assert_eq!((c.plays_faced, c.plays_beaten, c.plays), (1, 0, 0));
+        assert!((model.priors("b").trump_usage - 2.0 / 3.0).abs() < 1e-9);
+
+        // A record that does not replay leaves the model untouched
+        let before = model.clone();
+        let broken = GameRecord {
+            actions: vec![play("a", &bomb)],
+            ..record
+        };
+        assert!(model
+            .observe_record(&broken, &GameConfig::default())
+            .is_err());
+        assert_eq!(model, before);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let five = [Card::new(Suit::Spades, Rank::Five)];
+        let single = PatternRecognizer::analyze_cards(&five).unwrap();
+        let mut model = OpponentModel::new();
+        model.observe_play("bob", Some(&single), &[Card::new(Suit::Hearts, Rank::Two)]);
+        model.observe_pass("carol", &single);
+
+        let json = serde_json::to_string(&model).unwrap();
+        let restored: OpponentModel = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, model);
+        assert_eq!(restored.priors("bob"), model.priors("bob"));
+    }
+}
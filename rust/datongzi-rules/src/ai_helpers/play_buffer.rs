@@ -0,0 +1,142 @@
+//! Reusable play storage for hot generation loops.
+//!
+//! [`PlayBuffer`] stores plays back to back in one card array plus an
+//! offset table. [`clear`](PlayBuffer::clear) keeps both allocations, so a
+//! server that reuses one buffer per seat stops allocating output once the
+//! buffer has grown to its working size.
+
+use crate::models::Card;
+
+/// Flat, reusable storage for generated plays.
+///
+/// Filled by [`PlayGenerator::generate_into`](super::PlayGenerator::generate_into).
+///
+/// # Example
+/// ```
+/// use datongzi_rules::ai_helpers::PlayBuffer;
+/// use datongzi_rules::{Card, Rank, Suit};
+///
+/// let mut buffer = PlayBuffer::new();
+/// buffer.push(&[Card::new(Suit::Spades, Rank::Five)]);
+/// assert_eq!(buffer.len(), 1);
+///
+/// buffer.clear();
+/// assert!(buffer.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayBuffer {
+    /// Cards of every play, concatenated
+    cards: Vec<Card>,
+    /// End offset into `cards` of each play
+    ends: Vec<usize>,
+}
+
+impl PlayBuffer {
+    /// Create an empty buffer
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            cards: Vec::new(),
+            ends: Vec::new(),
+        }
+    }
+
+    /// Create a buffer with room for `plays` plays totalling `cards` cards
+    #[must_use]
+    pub fn with_capacity(plays: usize, cards: usize) -> Self {
+        Self {
+            cards: Vec::with_capacity(cards),
+            ends: Vec::with_capacity(plays),
+        }
+    }
+
+    /// Remove all plays, keeping the allocated capacity
+    pub fn clear(&mut self) {
+        self.cards.clear();
+        self.ends.clear();
+    }
+
+    /// Append one play
+    pub fn push(&mut self, play: &[Card]) {
+        self.cards.extend_from_slice(play);
+        self.ends.push(self.cards.len());
+    }
+
+    /// Number of plays stored
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns true if no plays are stored
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Play at `index`, if any
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&[Card]> {
+        let end = *self.ends.get(index)?;
+        let start = if index == 0 { 0 } else { self.ends[index - 1] };
+        Some(&self.cards[start..end])
+    }
+
+    /// Iterate over stored plays in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = &[Card]> + '_ {
+        (0..self.len()).filter_map(move |index| self.get(index))
+    }
+
+    /// Copy the stored plays out as owned vectors
+    #[must_use]
+    pub fn to_vecs(&self) -> Vec<Vec<Card>> {
+        self.iter().map(<[Card]>::to_vec).collect()
+    }
+}
+
+impl Extend<Vec<Card>> for PlayBuffer {
+    fn extend<I: IntoIterator<Item = Vec<Card>>>(&mut self, plays: I) {
+        for play in plays {
+            self.push(&play);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+
+    #[test]
+    fn test_push_and_get() {
+        let five = Card::new(Suit::Spades, Rank::Five);
+        let pair = [
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Clubs, Rank::Nine),
+        ];
+
+        let mut buffer = PlayBuffer::new();
+        buffer.push(&[five]);
+        buffer.push(&pair);
+        buffer.push(&[]);
+
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.get(0), Some(&[five][..]));
+        assert_eq!(buffer.get(1), Some(&pair[..]));
+        assert_eq!(buffer.get(2), Some(&[][..]));
+        assert_eq!(buffer.get(3), None);
+        assert_eq!(buffer.to_vecs(), vec![vec![five], pair.to_vec(), vec![]]);
+    }
+
+    #[test]
+    fn test_clear_keeps_capacity() {
+        let mut buffer = PlayBuffer::with_capacity(4, 16);
+        buffer.extend(vec![vec![Card::new(Suit::Spades, Rank::Ace); 3]; 4]);
+        let capacity = (buffer.cards.capacity(), buffer.ends.capacity());
+
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.iter().count(), 0);
+        assert_eq!((buffer.cards.capacity(), buffer.ends.capacity()), capacity);
+    }
+}
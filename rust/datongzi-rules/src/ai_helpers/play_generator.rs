@@ -5,9 +5,223 @@
 
 use std::collections::HashMap;
 
-use crate::ai_helpers::{filter_consecutive_pairs, filter_pairs, filter_singles, filter_triples};
-use crate::models::{Card, Rank, Suit};
-use crate::patterns::{PatternRecognizer, PlayPattern, PlayType, PlayValidator};
+use crate::ai_helpers::{
+    detect_dizha, filter_consecutive_pairs, filter_pairs, filter_singles, filter_triples,
+    select_kickers_excluding, PlayBuffer,
+};
+use crate::error::{DatongziError, Result};
+use crate::logging::{self, Level};
+use crate::metrics;
+use crate::models::{
+    Card, GameConfig, LeadRestrictions, Rank, RankCounts, RankOrdering, Suit, TongziSuitOrder,
+};
+use crate::patterns::{
+    kicker_rules, runs, CompareRules, PatternRecognizer, PlayPattern, PlayType, PlayValidator,
+    RULES,
+};
+
+/// Heuristic weights for [`PlayGenerator::sample_legal_move`].
+///
+/// Each candidate play gets a score
+/// `length_bonus * cards - rank_penalty * rank_value - trump_penalty * is_trump`,
+/// and is sampled with probability proportional to `exp(score / temperature)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoveWeights {
+    /// Softmax temperature; higher is more random, `<= 0` is greedy
+    pub temperature: f64,
+    /// Score per card played (favors shedding more cards)
+    pub length_bonus: f64,
+    /// Score subtracted per rank value (favors keeping high cards)
+    pub rank_penalty: f64,
+    /// Score subtracted for Bomb/Tongzi/Dizha (favors saving trumps)
+    pub trump_penalty: f64,
+}
+
+impl Default for MoveWeights {
+    fn default() -> Self {
+        Self {
+            temperature: 1.0,
+            length_bonus: 0.5,
+            rank_penalty: 0.2,
+            trump_penalty: 3.0,
+        }
+    }
+}
+
+impl MoveWeights {
+    /// Heuristic score of a play under these weights
+    fn score(&self, pattern: &PlayPattern) -> f64 {
+        let is_trump = matches!(
+            pattern.play_type,
+            PlayType::Bomb | PlayType::Tongzi | PlayType::Dizha
+        );
+        self.length_bonus * pattern.card_count as f64
+            - self.rank_penalty * f64::from(pattern.primary_rank.value())
+            - if is_trump { self.trump_penalty } else { 0.0 }
+    }
+}
+
+/// Why a candidate in a [`DecisionTrace`] was not chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectReason {
+    /// The cards do not form a recognizable play
+    Unrecognized,
+    /// Greedy selection (`temperature <= 0`) took a higher-scoring play
+    LowerScore,
+    /// The softmax draw landed on another play
+    NotSampled,
+}
+
+/// One candidate considered by [`PlayGenerator::sample_legal_move_traced`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TracedCandidate {
+    /// Cards of the candidate play
+    pub cards: Vec<Card>,
+    /// Recognized play type (`None` if unrecognized)
+    pub play_type: Option<PlayType>,
+    /// Heuristic score from [`MoveWeights`] (`None` if unrecognized)
+    pub score: Option<f64>,
+    /// Chance of being picked (1.0 for the greedy choice)
+    pub probability: f64,
+    /// Why the candidate lost (`None` for the chosen play)
+    pub rejected: Option<RejectReason>,
+}
+
+/// Every candidate behind a sampled move, for debugging bot decisions.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DecisionTrace {
+    /// Candidates in generation order
+    pub candidates: Vec<TracedCandidate>,
+    /// Index of the chosen candidate (`None` when passing)
+    pub chosen: Option<usize>,
+}
+
+/// How airplane-with-wings plays get their wings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WingPolicy {
+    /// One pair per triple, every combination of pair ranks
+    #[default]
+    Pairs,
+    /// One wing set per airplane, chosen by [`select_kickers`](crate::ai_helpers::select_kickers)
+    SelectedKickers,
+    /// No airplane-with-wings plays
+    NoWings,
+}
+
+/// Generation knobs accepted by the `*_with_options` entry points of [`PlayGenerator`].
+///
+/// The default matches [`generate_beating_plays_with_same_type_or_trump`](PlayGenerator::generate_beating_plays_with_same_type_or_trump).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenerationOptions {
+    /// Safety threshold for [`generate_all_plays_with_options`](PlayGenerator::generate_all_plays_with_options) (default 1000)
+    pub max_combinations: usize,
+    /// Keep one play per rank shape for singles, pairs, consecutive pairs and
+    /// triples (identical-play filtering)
+    pub canonical_dedup: bool,
+    /// Drop plays that would break a Tongzi (筒子) or Dizha (地炸) in the hand.
+    ///
+    /// Playing the Tongzi/Dizha itself is always allowed.
+    pub protect_trump_structures: bool,
+    /// Play types to generate; `None` allows every type
+    pub allowed_play_types: Option<Vec<PlayType>>,
+    /// How airplane wings are chosen
+    pub wing_policy: WingPolicy,
+    /// Also answer a single with an equal-rank single of a higher suit
+    /// (see [`GameConfig::singles_suit_tiebreak`](crate::GameConfig::singles_suit_tiebreak))
+    pub singles_suit_tiebreak: bool,
+    /// Rank order for bombs of equal size
+    /// (see [`GameConfig::bomb_rank_order`](crate::GameConfig::bomb_rank_order))
+    pub bomb_rank_order: RankOrdering,
+    /// Suit order for Tongzi of equal rank
+    /// (see [`GameConfig::tongzi_suit_order`](crate::GameConfig::tongzi_suit_order))
+    pub tongzi_suit_order: TongziSuitOrder,
+    /// Ranks never carried as triple kickers or airplane wings
+    /// (see [`GameConfig::forbidden_kicker_ranks`](crate::GameConfig::forbidden_kicker_ranks))
+    pub forbidden_kicker_ranks: Vec<Rank>,
+    /// Ranks no play may use
+    /// (see [`GameConfig::removed_ranks`](crate::GameConfig::removed_ranks))
+    pub removed_ranks: Vec<Rank>,
+    /// Trumps that may not open a trick, applied by the leading entry points
+    /// (see [`GameConfig::lead_restrictions`](crate::GameConfig::lead_restrictions))
+    pub lead_restrictions: LeadRestrictions,
+}
+
+impl Default for GenerationOptions {
+    fn default() -> Self {
+        Self {
+            max_combinations: 1000,
+            canonical_dedup: false,
+            protect_trump_structures: false,
+            allowed_play_types: None,
+            wing_policy: WingPolicy::Pairs,
+            singles_suit_tiebreak: false,
+            bomb_rank_order: RankOrdering::Natural,
+            tongzi_suit_order: TongziSuitOrder::Natural,
+            forbidden_kicker_ranks: Vec::new(),
+            removed_ranks: Vec::new(),
+            lead_restrictions: LeadRestrictions::default(),
+        }
+    }
+}
+
+impl GenerationOptions {
+    /// Returns true if plays of `play_type` may be generated
+    #[must_use]
+    pub fn allows(&self, play_type: PlayType) -> bool {
+        self.allowed_play_types
+            .as_ref()
+            .map_or(true, |types| types.contains(&play_type))
+    }
+
+    /// Default options with the rules of `config`: comparison and kicker
+    /// rules, removed ranks, lead restrictions, and a training drill's play
+    /// family as the allowed play types
+    #[must_use]
+    pub fn for_config(config: &GameConfig) -> Self {
+        let drill_types = config
+            .training()
+            .and_then(|training| training.drill_play_type)
+            .map(|drill| match drill {
+                PlayType::Airplane | PlayType::AirplaneWithWings => {
+                    vec![PlayType::Airplane, PlayType::AirplaneWithWings]
+                }
+                other => vec![other],
+            });
+        Self {
+            allowed_play_types: drill_types,
+            singles_suit_tiebreak: config.singles_suit_tiebreak(),
+            bomb_rank_order: config.bomb_rank_order().clone(),
+            tongzi_suit_order: *config.tongzi_suit_order(),
+            forbidden_kicker_ranks: config.forbidden_kicker_ranks().to_vec(),
+            removed_ranks: config.removed_ranks().to_vec(),
+            lead_restrictions: *config.lead_restrictions(),
+            ..Self::default()
+        }
+    }
+
+    /// Comparison rules these options select
+    pub(crate) fn compare_rules(&self) -> CompareRules<'_> {
+        CompareRules {
+            singles_suit_tiebreak: self.singles_suit_tiebreak,
+            bomb_rank_order: &self.bomb_rank_order,
+            tongzi_suit_order: &self.tongzi_suit_order,
+        }
+    }
+}
+
+/// Structural complexity of a hand, used to predict generation cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandComplexity {
+    /// Ranks with 3+ cards (triple/airplane/kicker sources)
+    pub triple_groups: usize,
+    /// Ranks with 4+ cards (bomb sources)
+    pub bomb_groups: usize,
+    /// Consecutive windows of 2+ ranks that could form consecutive pairs or airplanes
+    pub chain_potential: usize,
+    /// Upper-bound estimate from [`PlayGenerator::estimate_play_count`]
+    pub estimated_plays: usize,
+}
 
 /// Generate valid plays from a hand of cards.
 ///
@@ -56,7 +270,10 @@ impl PlayGenerator {
     /// # Returns
     ///
     /// `Ok(Vec<Vec<Card>>)` - All valid play combinations
-    /// `Err(String)` - If combinations exceed `max_combinations`
+    /// `Err(DatongziError::TooManyCombinations)` - If combinations exceed `max_combinations`
+    ///
+    /// Use [`estimate_play_count`](Self::estimate_play_count) to decide up front
+    /// whether generation is affordable.
     ///
     /// # Examples
     ///
@@ -71,65 +288,118 @@ impl PlayGenerator {
     /// let plays = PlayGenerator::generate_all_plays(&hand, 1000).unwrap();
     /// // Returns: singles, pairs
     /// ```
-    pub fn generate_all_plays(
+    pub fn generate_all_plays(hand: &[Card], max_combinations: usize) -> Result<Vec<Vec<Card>>> {
+        Self::generate_all_plays_with_options(
+            hand,
+            &GenerationOptions {
+                max_combinations,
+                canonical_dedup: true,
+                ..GenerationOptions::default()
+            },
+        )
+    }
+
+    /// Generate all plays like [`generate_all_plays`](Self::generate_all_plays),
+    /// honoring `options`.
+    ///
+    /// The same combinatorial-explosion warning applies.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TooManyCombinations` if more than `options.max_combinations`
+    /// plays are generated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datongzi_rules::{Card, PlayType, Rank, Suit, PlayGenerator};
+    /// use datongzi_rules::ai_helpers::GenerationOptions;
+    ///
+    /// let hand = vec![
+    ///     Card::new(Suit::Spades, Rank::Five),
+    ///     Card::new(Suit::Hearts, Rank::Five),
+    /// ];
+    ///
+    /// let options = GenerationOptions {
+    ///     allowed_play_types: Some(vec![PlayType::Pair]),
+    ///     ..GenerationOptions::default()
+    /// };
+    /// let plays = PlayGenerator::generate_all_plays_with_options(&hand, &options).unwrap();
+    /// assert_eq!(plays, vec![hand.clone()]);
+    /// ```
+    pub fn generate_all_plays_with_options(
         hand: &[Card],
-        max_combinations: usize,
-    ) -> Result<Vec<Vec<Card>>, String> {
+        options: &GenerationOptions,
+    ) -> Result<Vec<Vec<Card>>> {
         if hand.is_empty() {
             return Ok(Vec::new());
         }
 
-        // Warn about potential combinatorial explosion (in debug builds)
-        #[cfg(debug_assertions)]
+        // Warn about potential combinatorial explosion
         if hand.len() > 15 {
-            eprintln!(
-                "WARNING: generate_all_plays called with {} cards - may cause combinatorial explosion",
-                hand.len()
-            );
+            logging::log(Level::Warn, module_path!(), &|| {
+                format!(
+                    "generate_all_plays called with {} cards - may cause combinatorial explosion",
+                    hand.len()
+                )
+            });
         }
 
         let mut all_plays = Vec::new();
 
-        // Generate singles (with identical play filtering)
-        all_plays.extend(filter_singles(hand));
-
-        // Generate pairs (with identical play filtering)
-        all_plays.extend(filter_pairs(hand));
-
-        // Generate consecutive pairs (with identical play filtering)
-        all_plays.extend(filter_consecutive_pairs(hand));
+        if options.allows(PlayType::Single) {
+            all_plays.extend(Self::_generate_singles(hand, options));
+        }
 
-        // Generate triples (with identical play filtering)
-        all_plays.extend(filter_triples(hand));
+        if options.allows(PlayType::Pair) {
+            all_plays.extend(Self::_generate_pairs(hand, options));
+        }
 
-        // Generate triple with kickers (1-2 cards)
-        all_plays.extend(Self::_generate_triple_with_kickers(hand));
+        if options.allows(PlayType::ConsecutivePairs) {
+            all_plays.extend(Self::_generate_consecutive_pairs(hand, options));
+        }
 
-        // Generate airplanes
-        all_plays.extend(Self::_generate_airplanes(hand));
+        // Triples, then triple with kickers (1-2 cards)
+        if options.allows(PlayType::Triple) {
+            all_plays.extend(Self::_generate_triples(hand, options));
+            all_plays.extend(Self::_generate_triple_with_kickers(hand, options));
+        }
 
-        // Generate airplane with wings
-        all_plays.extend(Self::_generate_airplane_with_wings(hand));
+        if options.allows(PlayType::Airplane) {
+            all_plays.extend(Self::_generate_airplanes(hand, options));
+        }
 
-        // Generate bombs
-        all_plays.extend(Self::_generate_bombs(hand));
+        if options.allows(PlayType::AirplaneWithWings) {
+            all_plays.extend(Self::_generate_airplane_with_wings(hand, options));
+        }
 
-        // Generate tongzi
-        all_plays.extend(Self::_generate_tongzi(hand));
+        if options.allows(PlayType::Bomb) {
+            all_plays.extend(Self::_generate_bombs(hand, options));
+        }
 
-        // Generate dizha
-        all_plays.extend(Self::_generate_dizha(hand));
+        if options.allows(PlayType::Tongzi) {
+            all_plays.extend(Self::_generate_tongzi(hand, options));
+        }
 
-        if all_plays.len() > max_combinations {
-            return Err(format!(
-                "Generated {} combinations exceeds limit {}. Use generate_beating_plays_with_same_type_or_trump or count_all_plays instead.",
-                all_plays.len(),
-                max_combinations
-            ));
+        if options.allows(PlayType::Dizha) {
+            all_plays.extend(Self::_generate_dizha(hand, options));
         }
 
-        // Debug logging removed for zero-dependency implementation
+        if all_plays.len() > options.max_combinations {
+            logging::log(Level::Debug, module_path!(), &|| {
+                format!(
+                    "generated {} plays, over the limit of {}",
+                    all_plays.len(),
+                    options.max_combinations
+                )
+            });
+            return Err(DatongziError::TooManyCombinations {
+                found: all_plays.len(),
+                max: options.max_combinations,
+            });
+        }
 
+        metrics::record_plays(all_plays.len());
         Ok(all_plays)
     }
 
@@ -171,6 +441,44 @@ impl PlayGenerator {
     pub fn generate_beating_plays_with_same_type_or_trump(
         hand: &[Card],
         current_pattern: &PlayPattern,
+    ) -> Vec<Vec<Card>> {
+        Self::generate_beating_plays_with_options(
+            hand,
+            current_pattern,
+            &GenerationOptions::default(),
+        )
+    }
+
+    /// Generate beating plays like
+    /// [`generate_beating_plays_with_same_type_or_trump`](Self::generate_beating_plays_with_same_type_or_trump),
+    /// honoring `options`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datongzi_rules::{Card, Rank, Suit, PlayGenerator, PatternRecognizer};
+    /// use datongzi_rules::ai_helpers::GenerationOptions;
+    ///
+    /// // ♠7♠7♠7 is a Tongzi; a pair of 7s would break it
+    /// let hand = vec![Card::new(Suit::Spades, Rank::Seven); 3];
+    /// let pair_of_fives = PatternRecognizer::analyze_cards(&[
+    ///     Card::new(Suit::Spades, Rank::Five),
+    ///     Card::new(Suit::Hearts, Rank::Five),
+    /// ])
+    /// .unwrap();
+    ///
+    /// let options = GenerationOptions {
+    ///     protect_trump_structures: true,
+    ///     ..GenerationOptions::default()
+    /// };
+    /// let plays = PlayGenerator::generate_beating_plays_with_options(&hand, &pair_of_fives, &options);
+    /// assert_eq!(plays, vec![hand.clone()]); // only the Tongzi itself
+    /// ```
+    #[must_use]
+    pub fn generate_beating_plays_with_options(
+        hand: &[Card],
+        current_pattern: &PlayPattern,
+        options: &GenerationOptions,
     ) -> Vec<Vec<Card>> {
         if hand.is_empty() {
             return Vec::new();
@@ -186,28 +494,42 @@ impl PlayGenerator {
         // 1. Generate same-type plays with higher rank
         match current_type {
             PlayType::Single => {
-                beating_plays.extend(Self::_generate_higher_singles(hand, current_pattern));
+                beating_plays.extend(Self::_generate_higher_singles(
+                    hand,
+                    current_pattern,
+                    options,
+                ));
             }
             PlayType::Pair => {
-                beating_plays.extend(Self::_generate_higher_pairs(hand, current_pattern));
+                beating_plays.extend(Self::_generate_higher_pairs(hand, current_pattern, options));
             }
             PlayType::ConsecutivePairs => {
                 beating_plays.extend(Self::_generate_higher_consecutive_pairs(
                     hand,
                     current_pattern,
+                    options,
                 ));
             }
             PlayType::Triple => {
                 // Triple can have 0-2 kickers, generate matching patterns
-                beating_plays.extend(Self::_generate_higher_triples(hand, current_pattern));
+                beating_plays.extend(Self::_generate_higher_triples(
+                    hand,
+                    current_pattern,
+                    options,
+                ));
             }
             PlayType::Airplane => {
-                beating_plays.extend(Self::_generate_higher_airplanes(hand, current_pattern));
+                beating_plays.extend(Self::_generate_higher_airplanes(
+                    hand,
+                    current_pattern,
+                    options,
+                ));
             }
             PlayType::AirplaneWithWings => {
                 beating_plays.extend(Self::_generate_higher_airplane_with_wings(
                     hand,
                     current_pattern,
+                    options,
                 ));
             }
             _ => {}
@@ -216,26 +538,38 @@ impl PlayGenerator {
         // 2. Generate trump plays (if current is not trump, or higher trump)
         if !is_current_trump {
             // Any trump beats normal play
-            beating_plays.extend(Self::_generate_bombs(hand));
-            beating_plays.extend(Self::_generate_tongzi(hand));
-            beating_plays.extend(Self::_generate_dizha(hand));
+            beating_plays.extend(Self::_generate_bombs(hand, options));
+            beating_plays.extend(Self::_generate_tongzi(hand, options));
+            beating_plays.extend(Self::_generate_dizha(hand, options));
         } else {
             // Trump vs trump - must follow hierarchy
             match current_type {
                 PlayType::Bomb => {
                     // Higher bombs, or tongzi/dizha
-                    beating_plays.extend(Self::_generate_higher_bombs(hand, current_pattern));
-                    beating_plays.extend(Self::_generate_tongzi(hand));
-                    beating_plays.extend(Self::_generate_dizha(hand));
+                    beating_plays.extend(Self::_generate_higher_bombs(
+                        hand,
+                        current_pattern,
+                        options,
+                    ));
+                    beating_plays.extend(Self::_generate_tongzi(hand, options));
+                    beating_plays.extend(Self::_generate_dizha(hand, options));
                 }
                 PlayType::Tongzi => {
                     // Higher tongzi, or dizha
-                    beating_plays.extend(Self::_generate_higher_tongzi(hand, current_pattern));
-                    beating_plays.extend(Self::_generate_dizha(hand));
+                    beating_plays.extend(Self::_generate_higher_tongzi(
+                        hand,
+                        current_pattern,
+                        options,
+                    ));
+                    beating_plays.extend(Self::_generate_dizha(hand, options));
                 }
                 PlayType::Dizha => {
                     // Only higher dizha
-                    beating_plays.extend(Self::_generate_higher_dizha(hand, current_pattern));
+                    beating_plays.extend(Self::_generate_higher_dizha(
+                        hand,
+                        current_pattern,
+                        options,
+                    ));
                 }
                 _ => {}
             }
@@ -244,12 +578,184 @@ impl PlayGenerator {
         // 3. Validate all plays can actually beat current pattern
         let valid_plays: Vec<Vec<Card>> = beating_plays
             .into_iter()
-            .filter(|play| PlayValidator::can_beat_play(play, Some(current_pattern)))
+            .filter(|play| {
+                PlayValidator::can_beat_with(play, Some(current_pattern), options.compare_rules())
+            })
+            .filter(|play| {
+                options.allowed_play_types.is_none()
+                    || PatternRecognizer::analyze_cards(play)
+                        .map_or(false, |p| options.allows(p.play_type))
+            })
             .collect();
 
+        metrics::record_plays(valid_plays.len());
         valid_plays
     }
 
+    /// Generate plays into a reusable [`PlayBuffer`].
+    ///
+    /// Appends to `buffer` (call [`PlayBuffer::clear`] between turns):
+    /// - Following (`Some(pattern)`): the plays from
+    ///   [`generate_beating_plays_with_options`](Self::generate_beating_plays_with_options)
+    /// - Leading (`None`): the plays from
+    ///   [`generate_all_plays_adaptive_with_options`](Self::generate_all_plays_adaptive_with_options)
+    ///   that `options.lead_restrictions` allow
+    ///
+    /// All plays share the buffer's storage, so reusing one buffer avoids a
+    /// fresh allocation per play on every turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use datongzi_rules::{Card, Rank, Suit, PlayGenerator, PatternRecognizer};
+    /// use datongzi_rules::ai_helpers::{GenerationOptions, PlayBuffer};
+    ///
+    /// let hand = vec![
+    ///     Card::new(Suit::Spades, Rank::Seven),
+    ///     Card::new(Suit::Hearts, Rank::Seven),
+    /// ];
+    /// let single_five = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Five)]).unwrap();
+    ///
+    /// let mut buffer = PlayBuffer::new();
+    /// let options = GenerationOptions::default();
+    /// PlayGenerator::generate_into(&mut buffer, &hand, Some(&single_five), &options);
+    /// assert_eq!(buffer.len(), 2);
+    ///
+    /// buffer.clear();
+    /// PlayGenerator::generate_into(&mut buffer, &hand, None, &options);
+    /// assert_eq!(buffer.len(), 3); // two singles and the pair
+    /// ```
+    pub fn generate_into(
+        buffer: &mut PlayBuffer,
+        hand: &[Card],
+        current_pattern: Option<&PlayPattern>,
+        options: &GenerationOptions,
+    ) {
+        match current_pattern {
+            Some(pattern) => buffer.extend(Self::generate_beating_plays_with_options(
+                hand, pattern, options,
+            )),
+            None => buffer.extend(Self::_retain_allowed_leads(
+                hand,
+                Self::generate_all_plays_adaptive_with_options(hand, options),
+                options,
+            )),
+        }
+    }
+
+    /// Curated, bounded set of sensible leads.
+    ///
+    /// Unlike [`generate_all_plays`](Self::generate_all_plays), the output
+    /// stays small for any hand (at most one play per archetype plus one
+    /// per triple rank), so it suits AI search when leading:
+    /// - the smallest single and the smallest pair
+    /// - the longest consecutive pairs and the longest airplane (lowest
+    ///   first on ties)
+    /// - each triple, without kickers
+    /// - the weakest Bomb, Tongzi and Dizha
+    ///
+    /// `allowed_play_types`, `protect_trump_structures`, `removed_ranks` and
+    /// `lead_restrictions` in `options` are honored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datongzi_rules::ai_helpers::GenerationOptions;
+    /// use datongzi_rules::{Card, PlayGenerator, Rank, Suit};
+    ///
+    /// let hand: Vec<Card> = [Rank::Five, Rank::Six, Rank::Seven]
+    ///     .iter()
+    ///     .flat_map(|r| [Card::new(Suit::Spades, *r), Card::new(Suit::Hearts, *r)])
+    ///     .collect();
+    ///
+    /// let leads = PlayGenerator::generate_leading_candidates(&hand, &GenerationOptions::default());
+    /// // Smallest single, smallest pair, and 556677
+    /// assert_eq!(leads.len(), 3);
+    /// assert_eq!(leads[2].len(), 6);
+    /// ```
+    #[must_use]
+    pub fn generate_leading_candidates(
+        hand: &[Card],
+        options: &GenerationOptions,
+    ) -> Vec<Vec<Card>> {
+        let mut sorted = hand.to_vec();
+        sorted.sort_by_key(|c| (c.rank, c.suit));
+        let of_rank = |rank: Rank, count: usize| -> Vec<Card> {
+            sorted
+                .iter()
+                .filter(|c| c.rank == rank)
+                .take(count)
+                .copied()
+                .collect()
+        };
+        let counts = RankCounts::from_cards(hand);
+        let ranks_with = |min: u8| Rank::ALL.into_iter().filter(move |r| counts.get(*r) >= min);
+        let longest_chain = |min: u8, min_len: usize| {
+            runs::maximal_runs(counts.mask_with_at_least(min))
+                .iter()
+                .filter(|run| usize::from(run.len) >= min_len)
+                .fold(None, |best: Option<runs::RankRun>, run| match best {
+                    Some(best) if best.len >= run.len => Some(best),
+                    _ => Some(*run),
+                })
+                .map(|run| {
+                    run.ranks()
+                        .iter()
+                        .flat_map(|rank| of_rank(*rank, usize::from(min)))
+                        .collect::<Vec<Card>>()
+                })
+        };
+
+        let mut plays: Vec<Vec<Card>> = Vec::new();
+        plays.extend(sorted.first().map(|card| vec![*card]));
+        plays.extend(ranks_with(2).next().map(|rank| of_rank(rank, 2)));
+        plays.extend(longest_chain(2, RULES.min_pair_chain));
+        plays.extend(longest_chain(3, RULES.min_airplane));
+        plays.extend(ranks_with(3).map(|rank| of_rank(rank, 3)));
+
+        // One card per suit first, leaving a Tongzi intact where possible
+        plays.extend(ranks_with(4).next().map(|rank| {
+            let cards = of_rank(rank, usize::MAX);
+            let mut spread: Vec<(usize, Card)> = cards
+                .iter()
+                .enumerate()
+                .map(|(i, card)| {
+                    (
+                        cards[..i].iter().filter(|c| c.suit == card.suit).count(),
+                        *card,
+                    )
+                })
+                .collect();
+            spread.sort_by_key(|(layer, card)| (*layer, card.suit));
+            spread.into_iter().take(4).map(|(_, card)| card).collect()
+        }));
+        plays.extend(
+            ranks_with(3)
+                .flat_map(|rank| Suit::ALL.map(|suit| (rank, suit)))
+                .find_map(|(rank, suit)| {
+                    let cards: Vec<Card> = sorted
+                        .iter()
+                        .filter(|c| c.rank == rank && c.suit == suit)
+                        .take(3)
+                        .copied()
+                        .collect();
+                    (cards.len() == 3).then_some(cards)
+                }),
+        );
+        plays.extend(
+            Self::_generate_dizha(hand, options)
+                .into_iter()
+                .min_by_key(|dizha| dizha[0].rank),
+        );
+
+        plays.retain(|play| {
+            PatternRecognizer::analyze_cards(play).is_some_and(|p| options.allows(p.play_type))
+        });
+        plays.dedup();
+        let plays = Self::_retain_protected(hand, plays, options);
+        Self::_retain_allowed_leads(hand, plays, options)
+    }
+
     /// Count total number of valid plays without generating them.
     ///
     /// This is much more efficient than [`generate_all_plays`](Self::generate_all_plays) when you only
@@ -282,46 +788,630 @@ impl PlayGenerator {
             return 0;
         }
 
+        let options = &GenerationOptions::default();
         let mut count = 0;
 
         // Count singles
         count += hand.len();
 
         // Count pairs
-        count += Self::_generate_pairs(hand).len();
+        count += Self::_generate_pairs(hand, options).len();
 
         // Count consecutive pairs
-        count += Self::_generate_consecutive_pairs(hand).len();
+        count += Self::_generate_consecutive_pairs(hand, options).len();
 
         // Count triples
-        count += Self::_generate_triples(hand).len();
+        count += Self::_generate_triples(hand, options).len();
 
         // Count triple with kickers
-        count += Self::_generate_triple_with_kickers(hand).len();
+        count += Self::_generate_triple_with_kickers(hand, options).len();
 
         // Count airplanes
-        count += Self::_generate_airplanes(hand).len();
+        count += Self::_generate_airplanes(hand, options).len();
 
         // Count airplane with wings
-        count += Self::_generate_airplane_with_wings(hand).len();
+        count += Self::_generate_airplane_with_wings(hand, options).len();
 
         // Count bombs
-        count += Self::_generate_bombs(hand).len();
+        count += Self::_generate_bombs(hand, options).len();
 
         // Count tongzi
-        count += Self::_generate_tongzi(hand).len();
+        count += Self::_generate_tongzi(hand, options).len();
 
         // Count dizha
-        count += Self::_generate_dizha(hand).len();
+        count += Self::_generate_dizha(hand, options).len();
 
         // Debug logging removed for zero-dependency implementation
 
         count
     }
 
+    /// Estimate how many plays [`generate_all_plays`](Self::generate_all_plays) would produce.
+    ///
+    /// Computed from rank/suit counts only (no plays are generated), so it is
+    /// cheap enough to call before deciding whether to enumerate. The value is
+    /// an upper bound: identical-play filtering and pattern checks only ever
+    /// remove candidates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datongzi_rules::{Card, PlayGenerator, Rank, Suit};
+    ///
+    /// let hand = vec![
+    ///     Card::new(Suit::Spades, Rank::Five),
+    ///     Card::new(Suit::Hearts, Rank::Five),
+    /// ];
+    ///
+    /// let estimate = PlayGenerator::estimate_play_count(&hand);
+    /// let actual = PlayGenerator::generate_all_plays(&hand, 1000).unwrap().len();
+    /// assert!(estimate >= actual);
+    /// ```
+    #[must_use]
+    pub fn estimate_play_count(hand: &[Card]) -> usize {
+        let n = hand.len();
+        let rank_groups = Self::_group_by_rank(hand);
+        let mut suit_rank_counts: HashMap<(Suit, Rank), usize> = HashMap::new();
+        for card in hand {
+            *suit_rank_counts.entry((card.suit, card.rank)).or_insert(0) += 1;
+        }
+
+        let mut ranks: Vec<Rank> = rank_groups.keys().copied().collect();
+        ranks.sort();
+        let count_of = |rank: &Rank| rank_groups[rank].len();
+
+        // Number of consecutive windows of at least `min_len` ranks with >= `min` cards
+        let chain_windows = |min: usize, min_len: usize| -> Vec<usize> {
+            let eligible: Vec<Rank> = ranks
+                .iter()
+                .copied()
+                .filter(|r| count_of(r) >= min)
+                .collect();
+            let mut windows = Vec::new();
+            for length in min_len..=eligible.len() {
+                for window in eligible.windows(length) {
+                    if Self::_is_consecutive(window) {
+                        windows.push(length);
+                    }
+                }
+            }
+            windows
+        };
+
+        let mut total = suit_rank_counts.len();
+        for rank in &ranks {
+            let k = count_of(rank);
+            total = total
+                .saturating_add(binomial(k, 2))
+                .saturating_add(binomial(k, 3));
+            if k >= 3 {
+                let kickers = n - 3;
+                total = total
+                    .saturating_add(kickers)
+                    .saturating_add(binomial(kickers, 2));
+            }
+            if k >= RULES.min_bomb {
+                total = total.saturating_add((RULES.min_bomb..=k).map(|s| binomial(k, s)).sum());
+            }
+            let suit_pairs: Vec<usize> = Suit::ALL
+                .iter()
+                .map(|s| binomial(suit_rank_counts.get(&(*s, *rank)).copied().unwrap_or(0), 2))
+                .collect();
+            if suit_pairs.iter().all(|c| *c > 0) {
+                total = total.saturating_add(suit_pairs.iter().product());
+            }
+        }
+
+        total = total.saturating_add(chain_windows(2, RULES.min_pair_chain).len());
+
+        let pair_ranks = ranks.iter().filter(|r| count_of(r) >= 2).count();
+        for length in chain_windows(3, RULES.min_airplane) {
+            total = total
+                .saturating_add(1)
+                .saturating_add(binomial(pair_ranks, length));
+        }
+
+        for count in suit_rank_counts.values() {
+            total = total.saturating_add(binomial(*count, 3));
+        }
+
+        total
+    }
+
+    /// Measure the structural complexity of a hand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datongzi_rules::{Card, PlayGenerator, Rank, Suit};
+    ///
+    /// let hand = vec![
+    ///     Card::new(Suit::Spades, Rank::Five),
+    ///     Card::new(Suit::Hearts, Rank::Five),
+    ///     Card::new(Suit::Clubs, Rank::Five),
+    /// ];
+    ///
+    /// let complexity = PlayGenerator::analyze_complexity(&hand);
+    /// assert_eq!(complexity.triple_groups, 1);
+    /// assert_eq!(complexity.bomb_groups, 0);
+    /// ```
+    #[must_use]
+    pub fn analyze_complexity(hand: &[Card]) -> HandComplexity {
+        let rank_groups = Self::_group_by_rank(hand);
+        let mut pair_ranks: Vec<Rank> = rank_groups
+            .iter()
+            .filter(|(_r, cards)| cards.len() >= 2)
+            .map(|(r, _cards)| *r)
+            .collect();
+        pair_ranks.sort();
+
+        let chain_potential = (RULES.min_pair_chain..=pair_ranks.len())
+            .map(|length| {
+                pair_ranks
+                    .windows(length)
+                    .filter(|w| Self::_is_consecutive(w))
+                    .count()
+            })
+            .sum();
+
+        HandComplexity {
+            triple_groups: rank_groups.values().filter(|c| c.len() >= 3).count(),
+            bomb_groups: rank_groups
+                .values()
+                .filter(|c| c.len() >= RULES.min_bomb)
+                .count(),
+            chain_potential,
+            estimated_plays: Self::estimate_play_count(hand),
+        }
+    }
+
+    /// Generate all plays, switching to canonical generation when the hand is too complex.
+    ///
+    /// If the estimated play count fits in `max_combinations`, this is
+    /// [`generate_all_plays`](Self::generate_all_plays). Otherwise it returns
+    /// one representative per play shape instead of erroring:
+    /// - Identical-play filtered singles, pairs, consecutive pairs and triples
+    /// - Each triple/airplane body with kickers chosen by [`select_kickers`](crate::ai_helpers::select_kickers)
+    /// - One bomb per rank and size, one Tongzi per suit-rank, one Dizha per rank
+    ///
+    /// Intended for AI callers that need a usable move list for any hand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datongzi_rules::{Card, PlayGenerator, Rank, Suit};
+    ///
+    /// let mut hand = Vec::new();
+    /// for _ in 0..3 {
+    ///     for suit in [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds] {
+    ///         hand.push(Card::new(suit, Rank::Nine));
+    ///     }
+    /// }
+    ///
+    /// // Naive generation would exceed 50 plays; adaptive generation still answers
+    /// let plays = PlayGenerator::generate_all_plays_adaptive(&hand, 50);
+    /// assert!(!plays.is_empty());
+    /// ```
+    #[must_use]
+    pub fn generate_all_plays_adaptive(hand: &[Card], max_combinations: usize) -> Vec<Vec<Card>> {
+        Self::generate_all_plays_adaptive_with_options(
+            hand,
+            &GenerationOptions {
+                max_combinations,
+                canonical_dedup: true,
+                ..GenerationOptions::default()
+            },
+        )
+    }
+
+    /// Generate all plays adaptively like
+    /// [`generate_all_plays_adaptive`](Self::generate_all_plays_adaptive),
+    /// honoring `options`.
+    ///
+    /// The canonical fallback always dedups and picks wings with
+    /// [`select_kickers`](crate::ai_helpers::select_kickers); allowed play types and structure protection still apply.
+    #[must_use]
+    pub fn generate_all_plays_adaptive_with_options(
+        hand: &[Card],
+        options: &GenerationOptions,
+    ) -> Vec<Vec<Card>> {
+        if Self::estimate_play_count(hand) <= options.max_combinations {
+            if let Ok(plays) = Self::generate_all_plays_with_options(hand, options) {
+                return plays;
+            }
+        }
+
+        logging::log(Level::Debug, module_path!(), &|| {
+            format!(
+                "{} cards exceed {} combinations; using canonical generation",
+                hand.len(),
+                options.max_combinations
+            )
+        });
+        let mut plays = Self::_generate_canonical_plays(hand, options);
+        if options.allowed_play_types.is_some() {
+            plays.retain(|play| {
+                PatternRecognizer::analyze_cards(play)
+                    .map_or(false, |p| options.allows(p.play_type))
+            });
+        }
+        metrics::record_plays(plays.len());
+        plays
+    }
+
+    /// Generate every play of a single type without identical-play filtering.
+    ///
+    /// Unlike [`generate_all_plays`](Self::generate_all_plays), suit variants of
+    /// singles/pairs/triples are kept, so callers that care about specific
+    /// physical cards (e.g. puzzle templates) can see all of them.
+    pub(crate) fn generate_plays_of_type(
+        hand: &[Card],
+        play_type: PlayType,
+        options: &GenerationOptions,
+    ) -> Vec<Vec<Card>> {
+        match play_type {
+            PlayType::Single => Self::_generate_singles(hand, options),
+            PlayType::Pair => Self::_generate_pairs(hand, options),
+            PlayType::ConsecutivePairs => Self::_generate_consecutive_pairs(hand, options),
+            PlayType::Triple => {
+                let mut plays = Self::_generate_triples(hand, options);
+                plays.extend(Self::_generate_triple_with_kickers(hand, options));
+                plays
+            }
+            PlayType::Airplane => Self::_generate_airplanes(hand, options),
+            PlayType::AirplaneWithWings => Self::_generate_airplane_with_wings(hand, options),
+            PlayType::Bomb => Self::_generate_bombs(hand, options),
+            PlayType::Tongzi => Self::_generate_tongzi(hand, options),
+            PlayType::Dizha => Self::_generate_dizha(hand, options),
+        }
+    }
+
+    /// Sample one legal move with softmax over heuristic scores.
+    ///
+    /// Candidates are the beating plays from
+    /// [`generate_beating_plays_with_same_type_or_trump`](Self::generate_beating_plays_with_same_type_or_trump)
+    /// when following, or every kicker-free play (singles, pairs, consecutive
+    /// pairs, bare triples, airplanes and trumps) when leading. Intended for
+    /// rollout policies and easy bots that should look plausible rather than
+    /// uniformly random.
+    ///
+    /// # Arguments
+    ///
+    /// * `hand` - Slice of cards in hand
+    /// * `current_pattern` - Pattern to beat, or `None` when leading
+    /// * `weights` - Heuristic weights and temperature
+    /// * `rng` - Random source
+    ///
+    /// # Returns
+    ///
+    /// `None` if no legal play exists (the player must pass).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datongzi_rules::{Card, PlayGenerator, Rank, Suit};
+    /// use datongzi_rules::ai_helpers::MoveWeights;
+    /// use rand::SeedableRng;
+    ///
+    /// let hand = vec![
+    ///     Card::new(Suit::Spades, Rank::Five),
+    ///     Card::new(Suit::Hearts, Rank::King),
+    /// ];
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let play = PlayGenerator::sample_legal_move(&hand, None, &MoveWeights::default(), &mut rng);
+    /// assert!(play.is_some());
+    /// ```
+    pub fn sample_legal_move<R: rand::Rng + ?Sized>(
+        hand: &[Card],
+        current_pattern: Option<&PlayPattern>,
+        weights: &MoveWeights,
+        rng: &mut R,
+    ) -> Option<Vec<Card>> {
+        let scored: Vec<(Vec<Card>, f64)> = Self::_move_candidates(hand, current_pattern)
+            .into_iter()
+            .filter_map(|play| {
+                let pattern = PatternRecognizer::analyze_cards(&play)?;
+                let score = weights.score(&pattern);
+                Some((play, score))
+            })
+            .collect();
+
+        let scores: Vec<f64> = scored.iter().map(|(_, score)| *score).collect();
+        let (move_weights, total) = Self::_move_weights(&scores, weights.temperature);
+        let index = Self::_pick_move(&move_weights, total, weights.temperature <= 0.0, rng)?;
+        scored.into_iter().nth(index).map(|(play, _)| play)
+    }
+
+    /// Sample a move like [`sample_legal_move`](Self::sample_legal_move) and
+    /// return the [`DecisionTrace`] behind it.
+    ///
+    /// Given the same `rng` state, the chosen play matches
+    /// [`sample_legal_move`](Self::sample_legal_move).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datongzi_rules::{Card, PatternRecognizer, PlayGenerator, Rank, Suit};
+    /// use datongzi_rules::ai_helpers::MoveWeights;
+    /// use rand::SeedableRng;
+    ///
+    /// let hand = vec![
+    ///     Card::new(Suit::Spades, Rank::Nine),
+    ///     Card::new(Suit::Hearts, Rank::Ace),
+    /// ];
+    /// let single_six = PatternRecognizer::analyze_cards(&[Card::new(Suit::Clubs, Rank::Six)]).unwrap();
+    /// let greedy = MoveWeights { temperature: 0.0, ..MoveWeights::default() };
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    ///
+    /// let (play, trace) =
+    ///     PlayGenerator::sample_legal_move_traced(&hand, Some(&single_six), &greedy, &mut rng);
+    /// assert_eq!(play, Some(vec![Card::new(Suit::Spades, Rank::Nine)]));
+    /// assert_eq!(trace.candidates.len(), 2);
+    /// assert!(trace.candidates[1].rejected.is_some()); // the Ace scored lower
+    /// ```
+    pub fn sample_legal_move_traced<R: rand::Rng + ?Sized>(
+        hand: &[Card],
+        current_pattern: Option<&PlayPattern>,
+        weights: &MoveWeights,
+        rng: &mut R,
+    ) -> (Option<Vec<Card>>, DecisionTrace) {
+        let mut candidates: Vec<TracedCandidate> = Self::_move_candidates(hand, current_pattern)
+            .into_iter()
+            .map(|cards| {
+                let pattern = PatternRecognizer::analyze_cards(&cards);
+                TracedCandidate {
+                    play_type: pattern.as_ref().map(|p| p.play_type),
+                    score: pattern.as_ref().map(|p| weights.score(p)),
+                    cards,
+                    probability: 0.0,
+                    rejected: Some(RejectReason::Unrecognized),
+                }
+            })
+            .collect();
+
+        let scored: Vec<usize> = (0..candidates.len())
+            .filter(|&i| candidates[i].score.is_some())
+            .collect();
+        let scores: Vec<f64> = scored.iter().filter_map(|&i| candidates[i].score).collect();
+        let (move_weights, total) = Self::_move_weights(&scores, weights.temperature);
+        let greedy = weights.temperature <= 0.0;
+        let picked = Self::_pick_move(&move_weights, total, greedy, rng);
+
+        for (position, &index) in scored.iter().enumerate() {
+            let candidate = &mut candidates[index];
+            candidate.probability = move_weights[position] / total;
+            candidate.rejected = if picked == Some(position) {
+                None
+            } else if greedy {
+                Some(RejectReason::LowerScore)
+            } else {
+                Some(RejectReason::NotSampled)
+            };
+        }
+
+        let chosen = picked.map(|position| scored[position]);
+        let play = chosen.map(|index| candidates[index].cards.clone());
+        (play, DecisionTrace { candidates, chosen })
+    }
+
+    /// Candidate plays for [`sample_legal_move`](Self::sample_legal_move).
+    fn _move_candidates(hand: &[Card], current_pattern: Option<&PlayPattern>) -> Vec<Vec<Card>> {
+        match current_pattern {
+            Some(pattern) => Self::generate_beating_plays_with_same_type_or_trump(hand, pattern),
+            None => {
+                let plays = Self::_generate_leading_plays(hand, &GenerationOptions::default());
+                metrics::record_plays(plays.len());
+                plays
+            }
+        }
+    }
+
+    /// Unnormalized selection weights for `scores`, and their total.
+    ///
+    /// Greedy (`temperature <= 0`) puts all weight on the first best score.
+    fn _move_weights(scores: &[f64], temperature: f64) -> (Vec<f64>, f64) {
+        let max_score = scores.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        if temperature <= 0.0 {
+            let best = scores.iter().position(|score| *score >= max_score);
+            let one_hot = (0..scores.len())
+                .map(|i| if Some(i) == best { 1.0 } else { 0.0 })
+                .collect();
+            return (one_hot, 1.0);
+        }
+
+        // Subtract the max before exponentiating for numerical stability
+        let move_weights: Vec<f64> = scores
+            .iter()
+            .map(|score| ((score - max_score) / temperature).exp())
+            .collect();
+        let total = move_weights.iter().sum();
+        (move_weights, total)
+    }
+
+    /// Draw an index proportional to `move_weights`; greedy picks need no draw.
+    fn _pick_move<R: rand::Rng + ?Sized>(
+        move_weights: &[f64],
+        total: f64,
+        greedy: bool,
+        rng: &mut R,
+    ) -> Option<usize> {
+        if greedy {
+            return move_weights.iter().position(|w| *w > 0.0);
+        }
+        if move_weights.is_empty() {
+            return None;
+        }
+
+        let mut target = rng.gen::<f64>() * total;
+        for (index, weight) in move_weights.iter().enumerate() {
+            if target < *weight {
+                return Some(index);
+            }
+            target -= weight;
+        }
+        Some(move_weights.len() - 1)
+    }
+
     // ========== Private Helper Methods ==========
     // Basic pattern generation methods
 
+    /// One representative play per shape (see [`generate_all_plays_adaptive`](Self::generate_all_plays_adaptive)).
+    fn _generate_canonical_plays(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
+        let mut plays = Vec::new();
+        plays.extend(filter_singles(hand));
+        plays.extend(filter_pairs(hand));
+        plays.extend(filter_consecutive_pairs(hand));
+
+        let triples = filter_triples(hand);
+        for triple in &triples {
+            let kickers =
+                select_kickers_excluding(hand, triple, 2, None, &options.forbidden_kicker_ranks);
+            for take in 1..=kickers.len() {
+                let mut combo = triple.clone();
+                combo.extend(&kickers[..take]);
+                plays.push(combo);
+            }
+        }
+        plays.extend(triples);
+
+        for airplane in Self::_generate_airplanes(hand, options) {
+            let wings = select_kickers_excluding(
+                hand,
+                &airplane,
+                2 * (airplane.len() / 3),
+                None,
+                &options.forbidden_kicker_ranks,
+            );
+            if !wings.is_empty() {
+                let mut combo = airplane.clone();
+                combo.extend(wings);
+                plays.push(combo);
+            }
+            plays.push(airplane);
+        }
+
+        let rank_groups = Self::_group_by_rank(hand);
+        for cards in rank_groups.values() {
+            // Suit-grouped prefixes, so an 8-card prefix is never 2-of-each-suit (Dizha)
+            let mut cards = cards.clone();
+            cards.sort_by_key(|c| c.suit);
+            for size in 4..=cards.len() {
+                plays.push(cards[..size].to_vec());
+            }
+        }
+
+        let mut suit_rank_groups: HashMap<(Suit, Rank), Vec<Card>> = HashMap::new();
+        for card in hand {
+            suit_rank_groups
+                .entry((card.suit, card.rank))
+                .or_default()
+                .push(*card);
+        }
+        for cards in suit_rank_groups.values() {
+            if cards.len() >= 3 {
+                plays.push(cards[..3].to_vec());
+            }
+        }
+
+        plays.extend(Self::_generate_dizha(hand, options));
+
+        // Kicker selection may not produce a recognizable shape; keep only valid plays
+        plays.retain(|play| PatternRecognizer::analyze_cards(play).is_some());
+        Self::_retain_protected(hand, plays, options)
+    }
+
+    /// Kicker-free plays available when leading a round.
+    fn _generate_leading_plays(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
+        let mut plays: Vec<Vec<Card>> = hand.iter().map(|c| vec![*c]).collect();
+        plays.extend(Self::_generate_pairs(hand, options));
+        plays.extend(Self::_generate_consecutive_pairs(hand, options));
+        plays.extend(Self::_generate_triples(hand, options));
+        plays.extend(Self::_generate_airplanes(hand, options));
+        plays.extend(Self::_generate_bombs(hand, options));
+        plays.extend(Self::_generate_tongzi(hand, options));
+        plays.extend(Self::_generate_dizha(hand, options));
+        Self::_retain_protected(hand, plays, options)
+    }
+
+    /// Drop plays that break a Tongzi/Dizha when `options` asks for protection,
+    /// plays using a removed rank, and plays carrying a kicker of a forbidden
+    /// rank.
+    fn _retain_protected(
+        hand: &[Card],
+        mut plays: Vec<Vec<Card>>,
+        options: &GenerationOptions,
+    ) -> Vec<Vec<Card>> {
+        if options.protect_trump_structures {
+            plays.retain(|play| !Self::_breaks_trump_structure(hand, play));
+        }
+        if !options.removed_ranks.is_empty() {
+            plays.retain(|play| !play.iter().any(|c| options.removed_ranks.contains(&c.rank)));
+        }
+        if !options.forbidden_kicker_ranks.is_empty() {
+            plays.retain(|play| !Self::_has_forbidden_kicker(play, options));
+        }
+        plays
+    }
+
+    /// Drop leads that `options.lead_restrictions` forbid for `hand`.
+    fn _retain_allowed_leads(
+        hand: &[Card],
+        mut plays: Vec<Vec<Card>>,
+        options: &GenerationOptions,
+    ) -> Vec<Vec<Card>> {
+        if !options.lead_restrictions.is_unrestricted() {
+            plays.retain(|play| {
+                PatternRecognizer::analyze_cards(play).is_some_and(|pattern| {
+                    options
+                        .lead_restrictions
+                        .allows(pattern.play_type, hand.len().saturating_sub(play.len()))
+                })
+            });
+        }
+        plays
+    }
+
+    /// Check if `play` carries a kicker or wing whose rank `options` forbids.
+    fn _has_forbidden_kicker(play: &[Card], options: &GenerationOptions) -> bool {
+        PatternRecognizer::analyze_cards(play).is_some_and(|pattern| {
+            kicker_rules::split_kickers(play, &pattern)
+                .1
+                .iter()
+                .any(|card| options.forbidden_kicker_ranks.contains(&card.rank))
+        })
+    }
+
+    /// Check if playing `play` leaves fewer Tongzi/Dizha than the hand holds.
+    ///
+    /// A play that is itself a Tongzi or Dizha never counts as breaking.
+    fn _breaks_trump_structure(hand: &[Card], play: &[Card]) -> bool {
+        let is_structure = PatternRecognizer::analyze_cards(play)
+            .is_some_and(|p| matches!(p.play_type, PlayType::Tongzi | PlayType::Dizha));
+        if is_structure {
+            return false;
+        }
+
+        // Tongzi units: every 3 same-suit same-rank cards form one
+        let tongzi_units = |cards: &[Card]| -> usize {
+            let mut counts: HashMap<(Suit, Rank), usize> = HashMap::new();
+            for card in cards {
+                *counts.entry((card.suit, card.rank)).or_insert(0) += 1;
+            }
+            counts.values().map(|c| c / 3).sum()
+        };
+
+        let mut rest = hand.to_vec();
+        for card in play {
+            if let Some(index) = rest.iter().position(|c| c == card) {
+                rest.swap_remove(index);
+            }
+        }
+
+        tongzi_units(&rest) < tongzi_units(hand)
+            || detect_dizha(&rest).len() < detect_dizha(hand).len()
+    }
+
     /// Group cards by rank.
     fn _group_by_rank(cards: &[Card]) -> HashMap<Rank, Vec<Card>> {
         let mut groups: HashMap<Rank, Vec<Card>> = HashMap::new();
@@ -331,23 +1421,28 @@ impl PlayGenerator {
         groups
     }
 
-    /// Check if ranks are consecutive.
+    /// Check if ranks are consecutive (2 never is, see [`runs`]).
     fn _is_consecutive(ranks: &[Rank]) -> bool {
-        if ranks.len() <= 1 {
-            return true;
-        }
+        runs::is_consecutive(runs::rank_mask(ranks))
+    }
 
-        let values: Vec<u8> = ranks.iter().map(|r| r.value()).collect();
-        for i in 1..values.len() {
-            if values[i] != values[i - 1] + 1 {
-                return false;
-            }
-        }
-        true
+    /// Generate all singles from hand.
+    fn _generate_singles(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
+        let singles = if options.canonical_dedup {
+            filter_singles(hand)
+        } else {
+            hand.iter().map(|c| vec![*c]).collect()
+        };
+
+        Self::_retain_protected(hand, singles, options)
     }
 
     /// Generate all valid pairs from hand.
-    fn _generate_pairs(hand: &[Card]) -> Vec<Vec<Card>> {
+    fn _generate_pairs(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
+        if options.canonical_dedup {
+            return Self::_retain_protected(hand, filter_pairs(hand), options);
+        }
+
         let mut pairs = Vec::new();
         let rank_groups = Self::_group_by_rank(hand);
 
@@ -367,11 +1462,15 @@ impl PlayGenerator {
             }
         }
 
-        pairs
+        Self::_retain_protected(hand, pairs, options)
     }
 
     /// Generate all valid consecutive pairs from hand.
-    fn _generate_consecutive_pairs(hand: &[Card]) -> Vec<Vec<Card>> {
+    fn _generate_consecutive_pairs(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
+        if options.canonical_dedup {
+            return Self::_retain_protected(hand, filter_consecutive_pairs(hand), options);
+        }
+
         let mut consecutive_pairs = Vec::new();
         let rank_groups = Self::_group_by_rank(hand);
 
@@ -383,8 +1482,8 @@ impl PlayGenerator {
             .collect();
         valid_ranks.sort();
 
-        // Try all consecutive sequences of length 2+
-        for length in 2..=valid_ranks.len() {
+        // Try all consecutive sequences of chain length
+        for length in RULES.min_pair_chain..=valid_ranks.len() {
             for i in 0..=valid_ranks.len().saturating_sub(length) {
                 let ranks = &valid_ranks[i..i + length];
 
@@ -405,11 +1504,15 @@ impl PlayGenerator {
             }
         }
 
-        consecutive_pairs
+        Self::_retain_protected(hand, consecutive_pairs, options)
     }
 
     /// Generate all valid triples from hand.
-    fn _generate_triples(hand: &[Card]) -> Vec<Vec<Card>> {
+    fn _generate_triples(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
+        if options.canonical_dedup {
+            return Self::_retain_protected(hand, filter_triples(hand), options);
+        }
+
         let mut triples = Vec::new();
         let rank_groups = Self::_group_by_rank(hand);
 
@@ -431,14 +1534,14 @@ impl PlayGenerator {
             }
         }
 
-        triples
+        Self::_retain_protected(hand, triples, options)
     }
 
     /// Generate all valid triple with kicker combinations (1-2 kickers).
     ///
     /// According to GAME_RULE.md: 三张牌可以带牌（0-2张）
     /// This generates Triple with 1 or 2 kickers (any cards, not just pairs).
-    fn _generate_triple_with_kickers(hand: &[Card]) -> Vec<Vec<Card>> {
+    fn _generate_triple_with_kickers(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
         let mut results = Vec::new();
         let rank_groups = Self::_group_by_rank(hand);
 
@@ -457,7 +1560,9 @@ impl PlayGenerator {
             let available_kickers: Vec<Card> = hand
                 .iter()
                 .copied()
-                .filter(|c| !triple_cards.contains(c))
+                .filter(|c| {
+                    !triple_cards.contains(c) && !options.forbidden_kicker_ranks.contains(&c.rank)
+                })
                 .collect();
 
             // Generate triple with 1 kicker
@@ -488,11 +1593,11 @@ impl PlayGenerator {
             }
         }
 
-        results
+        Self::_retain_protected(hand, results, options)
     }
 
     /// Generate all valid airplane patterns (consecutive triples).
-    fn _generate_airplanes(hand: &[Card]) -> Vec<Vec<Card>> {
+    fn _generate_airplanes(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
         let mut airplanes = Vec::new();
         let rank_groups = Self::_group_by_rank(hand);
 
@@ -504,8 +1609,8 @@ impl PlayGenerator {
             .collect();
         valid_ranks.sort();
 
-        // Try all consecutive sequences of length 2+
-        for length in 2..=valid_ranks.len() {
+        // Try all consecutive sequences of airplane length
+        for length in RULES.min_airplane..=valid_ranks.len() {
             for i in 0..=valid_ranks.len().saturating_sub(length) {
                 let ranks = &valid_ranks[i..i + length];
 
@@ -526,11 +1631,17 @@ impl PlayGenerator {
             }
         }
 
-        airplanes
+        Self::_retain_protected(hand, airplanes, options)
     }
 
     /// Generate all valid airplane with wings patterns.
-    fn _generate_airplane_with_wings(hand: &[Card]) -> Vec<Vec<Card>> {
+    fn _generate_airplane_with_wings(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
+        match options.wing_policy {
+            WingPolicy::Pairs => {}
+            WingPolicy::SelectedKickers => return Self::_generate_selected_wings(hand, options),
+            WingPolicy::NoWings => return Vec::new(),
+        }
+
         let mut results = Vec::new();
         let rank_groups = Self::_group_by_rank(hand);
 
@@ -542,7 +1653,7 @@ impl PlayGenerator {
             .collect();
         valid_ranks.sort();
 
-        for length in 2..=valid_ranks.len() {
+        for length in RULES.min_airplane..=valid_ranks.len() {
             for i in 0..=valid_ranks.len().saturating_sub(length) {
                 let ranks = &valid_ranks[i..i + length];
 
@@ -560,7 +1671,10 @@ impl PlayGenerator {
                 let remaining_cards: Vec<Card> = hand
                     .iter()
                     .copied()
-                    .filter(|c| !airplane_cards.contains(c))
+                    .filter(|c| {
+                        !airplane_cards.contains(c)
+                            && !options.forbidden_kicker_ranks.contains(&c.rank)
+                    })
                     .collect();
                 let remaining_groups = Self::_group_by_rank(&remaining_cards);
 
@@ -589,7 +1703,35 @@ impl PlayGenerator {
             }
         }
 
-        results
+        Self::_retain_protected(hand, results, options)
+    }
+
+    /// Generate one airplane-with-wings per airplane, wings chosen by [`select_kickers`](crate::ai_helpers::select_kickers).
+    fn _generate_selected_wings(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
+        let mut results = Vec::new();
+
+        for airplane in Self::_generate_airplanes(hand, options) {
+            let wings = select_kickers_excluding(
+                hand,
+                &airplane,
+                2 * (airplane.len() / 3),
+                None,
+                &options.forbidden_kicker_ranks,
+            );
+            if wings.is_empty() {
+                continue;
+            }
+
+            let mut combo = airplane;
+            combo.extend(wings);
+            if let Some(pattern) = PatternRecognizer::analyze_cards(&combo) {
+                if pattern.play_type == PlayType::AirplaneWithWings {
+                    results.push(combo);
+                }
+            }
+        }
+
+        Self::_retain_protected(hand, results, options)
     }
 
     /// Generate pair combinations for airplane wings.
@@ -639,14 +1781,14 @@ impl PlayGenerator {
     }
 
     /// Generate all valid bombs from hand.
-    fn _generate_bombs(hand: &[Card]) -> Vec<Vec<Card>> {
+    fn _generate_bombs(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
         let mut bombs = Vec::new();
         let rank_groups = Self::_group_by_rank(hand);
 
         for (_rank, cards) in rank_groups {
-            if cards.len() >= 4 {
+            if cards.len() >= RULES.min_bomb {
                 // Generate bombs of all possible sizes (4, 5, 6, etc.)
-                for size in 4..=cards.len() {
+                for size in RULES.min_bomb..=cards.len() {
                     // Generate all combinations of `size` cards
                     Self::_combinations_of_cards(&cards, size)
                         .iter()
@@ -661,7 +1803,7 @@ impl PlayGenerator {
             }
         }
 
-        bombs
+        Self::_retain_protected(hand, bombs, options)
     }
 
     /// Generate all combinations of cards.
@@ -689,7 +1831,7 @@ impl PlayGenerator {
     }
 
     /// Generate all valid tongzi patterns (3 same suit, same rank).
-    fn _generate_tongzi(hand: &[Card]) -> Vec<Vec<Card>> {
+    fn _generate_tongzi(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
         let mut tongzi = Vec::new();
 
         // Group by (suit, rank)
@@ -720,32 +1862,31 @@ impl PlayGenerator {
             }
         }
 
-        tongzi
+        Self::_retain_protected(hand, tongzi, options)
     }
 
     /// Generate all valid dizha patterns (2 of each suit for same rank).
-    fn _generate_dizha(hand: &[Card]) -> Vec<Vec<Card>> {
+    fn _generate_dizha(hand: &[Card], options: &GenerationOptions) -> Vec<Vec<Card>> {
         let mut dizha = Vec::new();
         let rank_groups = Self::_group_by_rank(hand);
 
         for (_rank, cards) in rank_groups {
-            if cards.len() >= 8 {
+            if cards.len() >= RULES.dizha {
                 // Group by suit
                 let mut suit_groups: HashMap<Suit, Vec<Card>> = HashMap::new();
                 for card in cards {
                     suit_groups.entry(card.suit).or_default().push(card);
                 }
 
-                // Check if all 4 suits have at least 2 cards
+                // Check if all 4 suits have a full dizha share
                 let all_suits = [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds];
-                if all_suits
-                    .iter()
-                    .all(|suit| suit_groups.get(suit).map_or(0, |v| v.len()) >= 2)
-                {
-                    // Take 2 cards from each suit
+                if all_suits.iter().all(|suit| {
+                    suit_groups.get(suit).map_or(0, |v| v.len()) >= RULES.dizha_per_suit()
+                }) {
+                    // Take the dizha share from each suit
                     let mut dizha_cards = Vec::new();
                     for suit in &all_suits {
-                        dizha_cards.extend(&suit_groups[suit][0..2]);
+                        dizha_cards.extend(&suit_groups[suit][..RULES.dizha_per_suit()]);
                     }
 
                     if let Some(pattern) = PatternRecognizer::analyze_cards(&dizha_cards) {
@@ -757,28 +1898,48 @@ impl PlayGenerator {
             }
         }
 
-        dizha
+        Self::_retain_protected(hand, dizha, options)
     }
 
     // ========== Helper Methods for generate_beating_plays_with_same_type_or_trump ==========
 
     /// Generate single cards higher than current single.
-    fn _generate_higher_singles(hand: &[Card], current_pattern: &PlayPattern) -> Vec<Vec<Card>> {
-        let mut higher_singles = Vec::new();
+    fn _generate_higher_singles(
+        hand: &[Card],
+        current_pattern: &PlayPattern,
+        options: &GenerationOptions,
+    ) -> Vec<Vec<Card>> {
         let current_rank = current_pattern.primary_rank;
-
-        for card in hand {
-            if card.rank.value() > current_rank.value() {
-                higher_singles.push(vec![*card]);
+        let current_suit = current_pattern.primary_suit.map_or(0, Suit::value);
+        let beats = |card: &Card| {
+            card.rank.value() > current_rank.value()
+                || (options.singles_suit_tiebreak
+                    && card.rank == current_rank
+                    && card.suit.value() > current_suit)
+        };
+
+        let mut singles: Vec<Vec<Card>> = Self::_generate_singles(hand, options)
+            .into_iter()
+            .filter(|single| beats(&single[0]))
+            .collect();
+        if options.singles_suit_tiebreak {
+            // Canonical dedup may have kept a lower suit of the current rank
+            for card in hand.iter().filter(|c| c.rank == current_rank && beats(c)) {
+                if !singles.iter().any(|single| single[0] == *card) {
+                    singles.push(vec![*card]);
+                }
             }
         }
-
-        higher_singles
+        singles
     }
 
     /// Generate pairs higher than current pair.
-    fn _generate_higher_pairs(hand: &[Card], current_pattern: &PlayPattern) -> Vec<Vec<Card>> {
-        let all_pairs = Self::_generate_pairs(hand);
+    fn _generate_higher_pairs(
+        hand: &[Card],
+        current_pattern: &PlayPattern,
+        options: &GenerationOptions,
+    ) -> Vec<Vec<Card>> {
+        let all_pairs = Self::_generate_pairs(hand, options);
         let current_rank = current_pattern.primary_rank;
 
         all_pairs
@@ -794,8 +1955,9 @@ impl PlayGenerator {
     fn _generate_higher_consecutive_pairs(
         hand: &[Card],
         current_pattern: &PlayPattern,
+        options: &GenerationOptions,
     ) -> Vec<Vec<Card>> {
-        let all_consecutive = Self::_generate_consecutive_pairs(hand);
+        let all_consecutive = Self::_generate_consecutive_pairs(hand, options);
         let current_rank = current_pattern.primary_rank;
         let current_count = current_pattern.card_count;
 
@@ -811,8 +1973,12 @@ impl PlayGenerator {
     }
 
     /// Generate triples higher than current triple.
-    fn _generate_higher_triples(hand: &[Card], current_pattern: &PlayPattern) -> Vec<Vec<Card>> {
-        let all_triples = Self::_generate_triples(hand);
+    fn _generate_higher_triples(
+        hand: &[Card],
+        current_pattern: &PlayPattern,
+        options: &GenerationOptions,
+    ) -> Vec<Vec<Card>> {
+        let all_triples = Self::_generate_triples(hand, options);
         let current_rank = current_pattern.primary_rank;
 
         all_triples
@@ -825,8 +1991,12 @@ impl PlayGenerator {
     }
 
     /// Generate airplanes higher than current airplane.
-    fn _generate_higher_airplanes(hand: &[Card], current_pattern: &PlayPattern) -> Vec<Vec<Card>> {
-        let all_airplanes = Self::_generate_airplanes(hand);
+    fn _generate_higher_airplanes(
+        hand: &[Card],
+        current_pattern: &PlayPattern,
+        options: &GenerationOptions,
+    ) -> Vec<Vec<Card>> {
+        let all_airplanes = Self::_generate_airplanes(hand, options);
         let current_rank = current_pattern.primary_rank;
         let current_count = current_pattern.card_count;
 
@@ -844,8 +2014,9 @@ impl PlayGenerator {
     fn _generate_higher_airplane_with_wings(
         hand: &[Card],
         current_pattern: &PlayPattern,
+        options: &GenerationOptions,
     ) -> Vec<Vec<Card>> {
-        let all_airplane_wings = Self::_generate_airplane_with_wings(hand);
+        let all_airplane_wings = Self::_generate_airplane_with_wings(hand, options);
         let current_rank = current_pattern.primary_rank;
         let current_count = current_pattern.card_count;
 
@@ -860,8 +2031,12 @@ impl PlayGenerator {
     }
 
     /// Generate bombs higher than current bomb.
-    fn _generate_higher_bombs(hand: &[Card], current_pattern: &PlayPattern) -> Vec<Vec<Card>> {
-        let all_bombs = Self::_generate_bombs(hand);
+    fn _generate_higher_bombs(
+        hand: &[Card],
+        current_pattern: &PlayPattern,
+        options: &GenerationOptions,
+    ) -> Vec<Vec<Card>> {
+        let all_bombs = Self::_generate_bombs(hand, options);
         let current_rank = current_pattern.primary_rank;
         let current_size = current_pattern.card_count;
 
@@ -872,25 +2047,38 @@ impl PlayGenerator {
                     // Higher rank with same size, or more cards with any rank
                     bomb.len() > current_size
                         || (bomb.len() == current_size
-                            && p.primary_rank.value() > current_rank.value())
+                            && options
+                                .bomb_rank_order
+                                .compare(p.primary_rank, current_rank)
+                                == std::cmp::Ordering::Greater)
                 })
             })
             .collect()
     }
 
     /// Generate tongzi higher than current tongzi.
-    fn _generate_higher_tongzi(hand: &[Card], current_pattern: &PlayPattern) -> Vec<Vec<Card>> {
-        let all_tongzi = Self::_generate_tongzi(hand);
+    fn _generate_higher_tongzi(
+        hand: &[Card],
+        current_pattern: &PlayPattern,
+        options: &GenerationOptions,
+    ) -> Vec<Vec<Card>> {
+        let all_tongzi = Self::_generate_tongzi(hand, options);
 
         all_tongzi
             .into_iter()
-            .filter(|tongzi| PlayValidator::can_beat_play(tongzi, Some(current_pattern)))
+            .filter(|tongzi| {
+                PlayValidator::can_beat_with(tongzi, Some(current_pattern), options.compare_rules())
+            })
             .collect()
     }
 
     /// Generate dizha higher than current dizha.
-    fn _generate_higher_dizha(hand: &[Card], current_pattern: &PlayPattern) -> Vec<Vec<Card>> {
-        let all_dizha = Self::_generate_dizha(hand);
+    fn _generate_higher_dizha(
+        hand: &[Card],
+        current_pattern: &PlayPattern,
+        options: &GenerationOptions,
+    ) -> Vec<Vec<Card>> {
+        let all_dizha = Self::_generate_dizha(hand, options);
         let current_rank = current_pattern.primary_rank;
 
         all_dizha
@@ -902,3 +2090,20 @@ impl PlayGenerator {
             .collect()
     }
 }
+
+/// Binomial coefficient C(n, k), saturating at `usize::MAX`.
+fn binomial(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    let mut result: usize = 1;
+    for i in 0..k {
+        // result * (n - i) / (i + 1) stays integral at every step
+        result = match result.checked_mul(n - i) {
+            Some(v) => v / (i + 1),
+            None => return usize::MAX,
+        };
+    }
+    result
+}
@@ -0,0 +1,330 @@
+//! Beat-chain exploration: what could still beat a pattern.
+
+use std::collections::HashMap;
+
+use crate::models::{Card, GameConfig, Rank, Suit};
+use crate::patterns::{PatternRecognizer, PlayPattern, PlayType, PlayValidator, RULES};
+
+/// One level of the response hierarchy, e.g. "higher pairs" or "bombs".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BeatTier {
+    /// Play type of this tier
+    pub play_type: PlayType,
+    /// Distinct beating options, strongest first.
+    ///
+    /// One entry per rank (per suit-rank for Tongzi, per chain for chains),
+    /// using the largest variant the unseen cards allow.
+    pub options: Vec<PlayPattern>,
+}
+
+impl BeatTier {
+    /// Returns the number of distinct beating options in this tier
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.options.len()
+    }
+}
+
+/// Responses still possible against a pattern, ordered from the same-type
+/// tier up through bombs, Tongzi and Dizha.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BeatChain {
+    /// Non-empty tiers in escalation order
+    pub tiers: Vec<BeatTier>,
+}
+
+impl BeatChain {
+    /// Returns the total number of beating options across all tiers
+    #[must_use]
+    pub fn total_options(&self) -> usize {
+        self.tiers.iter().map(BeatTier::count).sum()
+    }
+
+    /// Returns true if nothing in the unseen cards can beat the pattern
+    #[must_use]
+    pub fn is_unbeatable(&self) -> bool {
+        self.tiers.is_empty()
+    }
+
+    /// Returns the tier for a play type, if any option exists
+    #[must_use]
+    pub fn tier(&self, play_type: PlayType) -> Option<&BeatTier> {
+        self.tiers.iter().find(|t| t.play_type == play_type)
+    }
+}
+
+/// Enumerate the responses that could still beat `pattern`.
+///
+/// `unseen_cards` is the pool of cards the caller has not seen (typically all
+/// cards minus own hand and played cards). The result is an upper bound on
+/// what opponents can do: options are counted as if one player held the
+/// whole pool.
+///
+/// ## Hierarchy
+/// 1. Same type, higher (e.g. higher pairs; same-length chains)
+/// 2. Bombs (higher bombs if `pattern` is a bomb)
+/// 3. Tongzi (higher Tongzi if `pattern` is a Tongzi)
+/// 4. Dizha (higher Dizha if `pattern` is a Dizha)
+///
+/// # Example
+/// ```
+/// use datongzi_rules::{Card, PatternRecognizer, PlayType, Rank, Suit};
+/// use datongzi_rules::analysis::beat_chain;
+///
+/// let pair = PatternRecognizer::analyze_cards(&[
+///     Card::new(Suit::Spades, Rank::Queen),
+///     Card::new(Suit::Hearts, Rank::Queen),
+/// ])
+/// .unwrap();
+/// let unseen = vec![
+///     Card::new(Suit::Spades, Rank::Ace),
+///     Card::new(Suit::Hearts, Rank::Ace),
+///     Card::new(Suit::Spades, Rank::Jack),
+///     Card::new(Suit::Hearts, Rank::Jack),
+/// ];
+///
+/// let chain = beat_chain(&pair, &unseen);
+/// assert_eq!(chain.tier(PlayType::Pair).unwrap().count(), 1); // only AA
+/// assert!(chain.tier(PlayType::Bomb).is_none());
+/// ```
+#[must_use]
+pub fn beat_chain(pattern: &PlayPattern, unseen_cards: &[Card]) -> BeatChain {
+    beat_chain_with_config(pattern, unseen_cards, &GameConfig::default())
+}
+
+/// Like [`beat_chain`], comparing plays with the options in `config`
+/// (see [`PlayValidator::can_beat_play_with_config`]).
+#[must_use]
+pub fn beat_chain_with_config(
+    pattern: &PlayPattern,
+    unseen_cards: &[Card],
+    config: &GameConfig,
+) -> BeatChain {
+    let mut rank_groups: HashMap<Rank, Vec<Card>> = HashMap::new();
+    for card in unseen_cards {
+        rank_groups.entry(card.rank).or_default().push(*card);
+    }
+
+    let mut tiers = Vec::new();
+    let mut push_tier = |play_type: PlayType, candidates: Vec<Vec<Card>>| {
+        let mut options: Vec<PlayPattern> = candidates
+            .iter()
+            .filter(|cards| PlayValidator::can_beat_play_with_config(cards, Some(pattern), config))
+            .filter_map(|cards| PatternRecognizer::analyze_cards(cards))
+            .collect();
+        if !options.is_empty() {
+            options.sort_by(|a, b| {
+                b.normalized_power(config)
+                    .cmp(&a.normalized_power(config))
+                    .then(b.strength.cmp(&a.strength))
+            });
+            tiers.push(BeatTier { play_type, options });
+        }
+    };
+
+    // 1. Same-type tier (normal patterns only)
+    match pattern.play_type {
+        PlayType::Single => push_tier(PlayType::Single, same_rank_sets(&rank_groups, 1)),
+        PlayType::Pair => push_tier(PlayType::Pair, same_rank_sets(&rank_groups, 2)),
+        PlayType::Triple => push_tier(PlayType::Triple, same_rank_sets(&rank_groups, 3)),
+        PlayType::ConsecutivePairs => push_tier(
+            PlayType::ConsecutivePairs,
+            chains(&rank_groups, 2, pattern.secondary_ranks.len()),
+        ),
+        PlayType::Airplane | PlayType::AirplaneWithWings => push_tier(
+            pattern.play_type,
+            chains(&rank_groups, 3, pattern.secondary_ranks.len()),
+        ),
+        _ => {}
+    }
+
+    // 2-4. Trump tiers; a rank group that forms a Dizha counts only there
+    if !matches!(pattern.play_type, PlayType::Tongzi | PlayType::Dizha) {
+        let bombs = rank_groups
+            .values()
+            .filter(|cards| cards.len() >= RULES.min_bomb)
+            .filter(|cards| {
+                PatternRecognizer::analyze_cards(cards)
+                    .is_some_and(|p| p.play_type == PlayType::Bomb)
+            })
+            .cloned()
+            .collect();
+        push_tier(PlayType::Bomb, bombs);
+    }
+    if pattern.play_type != PlayType::Dizha {
+        push_tier(PlayType::Tongzi, tongzi_sets(unseen_cards));
+    }
+    push_tier(PlayType::Dizha, dizha_sets(&rank_groups));
+
+    BeatChain { tiers }
+}
+
+/// One `size`-card set per rank that has enough cards.
+fn same_rank_sets(rank_groups: &HashMap<Rank, Vec<Card>>, size: usize) -> Vec<Vec<Card>> {
+    rank_groups
+        .values()
+        .filter(|cards| cards.len() >= size)
+        .map(|cards| cards[..size].to_vec())
+        .collect()
+}
+
+/// Every consecutive window of `length` ranks with `per_rank` cards each.
+fn chains(
+    rank_groups: &HashMap<Rank, Vec<Card>>,
+    per_rank: usize,
+    length: usize,
+) -> Vec<Vec<Card>> {
+    if length == 0 {
+        return Vec::new();
+    }
+
+    Rank::ALL
+        .windows(length)
+        .filter(|window| {
+            window
+                .iter()
+                .all(|r| rank_groups.get(r).map_or(0, Vec::len) >= per_rank)
+        })
+        .map(|window| {
+            window
+                .iter()
+                .flat_map(|r| rank_groups[r][..per_rank].iter().copied())
+                .collect()
+        })
+        .collect()
+}
+
+fn tongzi_sets(cards: &[Card]) -> Vec<Vec<Card>> {
+    let mut suit_rank_counts: HashMap<(Suit, Rank), usize> = HashMap::new();
+    for card in cards {
+        *suit_rank_counts.entry((card.suit, card.rank)).or_insert(0) += 1;
+    }
+
+    suit_rank_counts
+        .into_iter()
+        .filter(|(_, count)| *count >= 3)
+        .map(|((suit, rank), _)| vec![Card::new(suit, rank); 3])
+        .collect()
+}
+
+fn dizha_sets(rank_groups: &HashMap<Rank, Vec<Card>>) -> Vec<Vec<Card>> {
+    rank_groups
+        .iter()
+        .filter(|(_, cards)| {
+            Suit::ALL
+                .iter()
+                .all(|s| cards.iter().filter(|c| c.suit == *s).count() >= 2)
+        })
+        .map(|(rank, _)| {
+            Suit::ALL
+                .iter()
+                .flat_map(|s| [Card::new(*s, *rank), Card::new(*s, *rank)])
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards_of(rank: Rank, suits: &[Suit]) -> Vec<Card> {
+        suits.iter().map(|s| Card::new(*s, rank)).collect()
+    }
+
+    #[test]
+    fn test_single_escalation() {
+        let single =
+            PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::King)]).unwrap();
+
+        let mut unseen = cards_of(Rank::Ace, &[Suit::Hearts]);
+        unseen.extend(cards_of(Rank::Five, &Suit::ALL)); // bomb of 5s
+        unseen.extend(cards_of(
+            Rank::Nine,
+            &[Suit::Clubs, Suit::Clubs, Suit::Clubs],
+        )); // tongzi
+
+        let chain = beat_chain(&single, &unseen);
+        let types: Vec<PlayType> = chain.tiers.iter().map(|t| t.play_type).collect();
+        assert_eq!(
+            types,
+            vec![PlayType::Single, PlayType::Bomb, PlayType::Tongzi]
+        );
+        assert_eq!(chain.tier(PlayType::Single).unwrap().count(), 1);
+        assert_eq!(chain.total_options(), 3);
+    }
+
+    #[test]
+    fn test_bomb_only_beaten_by_bigger_bombs() {
+        let bomb = PatternRecognizer::analyze_cards(&cards_of(Rank::Ten, &Suit::ALL)).unwrap();
+
+        let mut unseen = cards_of(Rank::Six, &Suit::ALL); // 4x6 loses to 4x10
+        unseen.extend(cards_of(Rank::Jack, &Suit::ALL)); // 4xJ wins
+        unseen.push(Card::new(Suit::Spades, Rank::Seven));
+
+        let chain = beat_chain(&bomb, &unseen);
+        let bombs = chain.tier(PlayType::Bomb).unwrap();
+        assert_eq!(bombs.count(), 1);
+        assert_eq!(bombs.options[0].primary_rank, Rank::Jack);
+    }
+
+    #[test]
+    fn test_dizha_group_is_not_a_bomb() {
+        let single =
+            PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::King)]).unwrap();
+
+        let mut unseen = cards_of(Rank::Seven, &Suit::ALL);
+        unseen.extend(cards_of(Rank::Seven, &Suit::ALL));
+
+        let chain = beat_chain(&single, &unseen);
+        let types: Vec<PlayType> = chain.tiers.iter().map(|t| t.play_type).collect();
+        assert_eq!(types, vec![PlayType::Dizha]);
+        assert_eq!(chain.total_options(), 1);
+    }
+
+    #[test]
+    fn test_bomb_rank_order_from_config() {
+        use crate::models::RankOrdering;
+
+        let bomb = PatternRecognizer::analyze_cards(&cards_of(Rank::Ten, &Suit::ALL)).unwrap();
+        let unseen = cards_of(Rank::Six, &Suit::ALL);
+        assert!(beat_chain(&bomb, &unseen).is_unbeatable());
+
+        let config = GameConfig {
+            bomb_rank_order: RankOrdering::Custom(vec![Rank::Six]),
+            ..GameConfig::default()
+        };
+        let chain = beat_chain_with_config(&bomb, &unseen, &config);
+        assert_eq!(chain.tier(PlayType::Bomb).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_dizha_of_two_is_unbeatable() {
+        let mut dizha_cards = cards_of(Rank::Two, &Suit::ALL);
+        dizha_cards.extend(cards_of(Rank::Two, &Suit::ALL));
+        let dizha = PatternRecognizer::analyze_cards(&dizha_cards).unwrap();
+
+        let mut unseen = cards_of(Rank::Ace, &Suit::ALL);
+        unseen.extend(cards_of(Rank::Ace, &Suit::ALL));
+        assert!(beat_chain(&dizha, &unseen).is_unbeatable());
+    }
+
+    #[test]
+    fn test_consecutive_pairs_same_length() {
+        let current = PatternRecognizer::analyze_cards(&[
+            Card::new(Suit::Spades, Rank::Five),
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Spades, Rank::Six),
+            Card::new(Suit::Hearts, Rank::Six),
+        ])
+        .unwrap();
+
+        let mut unseen = cards_of(Rank::Eight, &[Suit::Spades, Suit::Hearts]);
+        unseen.extend(cards_of(Rank::Nine, &[Suit::Spades, Suit::Hearts]));
+        unseen.extend(cards_of(Rank::Ten, &[Suit::Spades, Suit::Hearts]));
+
+        let chain = beat_chain(&current, &unseen);
+        // 8-9 and 9-10
+        assert_eq!(chain.tier(PlayType::ConsecutivePairs).unwrap().count(), 2);
+    }
+}
@@ -0,0 +1,278 @@
+//! Unseen-card tracking with "known not to have" constraints.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::PassInference;
+use crate::audit::PassPolicy;
+use crate::models::{Card, GameConfig, Rank, RankCounts, Suit};
+use crate::patterns::PlayPattern;
+
+/// What one observer has not yet seen, and what passes revealed.
+///
+/// Seats are indexed like the game's hands. The observer's own seat (and
+/// any seat whose hand is known) should be given a hidden hand size of 0.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::analysis::CardTracker;
+/// use datongzi_rules::audit::PassPolicy;
+/// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+///
+/// let ace = Card::new(Suit::Spades, Rank::Ace);
+/// let six = Card::new(Suit::Hearts, Rank::Six);
+/// let mut tracker = CardTracker::new(vec![ace, six], vec![0, 1, 1], PassPolicy::MustPlayIfCanBeat);
+///
+/// // Seat 1 passed on a single King, so it cannot hold the Ace
+/// let king = PatternRecognizer::analyze_cards(&[Card::new(Suit::Clubs, Rank::King)]).unwrap();
+/// tracker.record_pass(1, &king);
+///
+/// let hands = tracker.sample_hidden_hands(&mut rand::thread_rng(), 10).unwrap();
+/// assert_eq!(hands[1], vec![six]);
+/// assert_eq!(hands[2], vec![ace]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardTracker {
+    unseen: Vec<Card>,
+    hidden_sizes: Vec<usize>,
+    voids: Vec<Vec<PassInference>>,
+    strictness: PassPolicy,
+    config: GameConfig,
+}
+
+impl CardTracker {
+    /// Create a tracker over `unseen` cards, with `hidden_sizes[seat]` of
+    /// them in each seat's hand and the rest set aside.
+    ///
+    /// `strictness` decides what a pass reveals: under
+    /// [`PassPolicy::MustFollowSameType`] a passer may be hoarding trumps,
+    /// and under [`PassPolicy::Voluntary`] passes reveal nothing.
+    #[must_use]
+    pub fn new(unseen: Vec<Card>, hidden_sizes: Vec<usize>, strictness: PassPolicy) -> Self {
+        Self::with_config(unseen, hidden_sizes, strictness, GameConfig::default())
+    }
+
+    /// Like [`new`](Self::new), reading passes under the rules of `config`
+    /// (comparison options, finish restrictions, must-beat alerts).
+    #[must_use]
+    pub fn with_config(
+        unseen: Vec<Card>,
+        hidden_sizes: Vec<usize>,
+        strictness: PassPolicy,
+        config: GameConfig,
+    ) -> Self {
+        let voids = vec![Vec::new(); hidden_sizes.len()];
+        Self {
+            unseen,
+            hidden_sizes,
+            voids,
+            strictness,
+            config,
+        }
+    }
+
+    /// Returns the cards not yet seen
+    #[must_use]
+    pub fn unseen(&self) -> &[Card] {
+        &self.unseen
+    }
+
+    /// Returns the hidden hand size of each seat
+    #[must_use]
+    pub fn hidden_sizes(&self) -> &[usize] {
+        &self.hidden_sizes
+    }
+
+    /// Returns the inference strictness
+    #[must_use]
+    pub const fn strictness(&self) -> PassPolicy {
+        self.strictness
+    }
+
+    /// Record that `seat` played `cards`.
+    ///
+    /// Cards already seen are ignored, so the observer's own plays can be
+    /// recorded too.
+    pub fn record_play(&mut self, seat: usize, cards: &[Card]) {
+        for card in cards {
+            if let Some(index) = self.unseen.iter().position(|c| c == card) {
+                self.unseen.swap_remove(index);
+                if let Some(size) = self.hidden_sizes.get_mut(seat) {
+                    *size = size.saturating_sub(1);
+                }
+            }
+        }
+    }
+
+    /// Record that `seat` passed on `pattern`, so holds nothing it was
+    /// obliged to beat it with (see [`PassInference`]).
+    ///
+    /// Record the pass before any later play by the seat, so its hidden hand
+    /// size is the one it passed with.
+    pub fn record_pass(&mut self, seat: usize, pattern: &PlayPattern) {
+        let Some(&cards_in_hand) = self.hidden_sizes.get(seat) else {
+            return;
+        };
+        let inference = PassInference::new(pattern, self.strictness, cards_in_hand, &self.config);
+        if inference.policy != PassPolicy::Voluntary {
+            self.voids[seat].push(inference);
+        }
+    }
+
+    /// Returns the constraints recorded for `seat`
+    #[must_use]
+    pub fn voids(&self, seat: usize) -> &[PassInference] {
+        self.voids.get(seat).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns true if `seat` could hold `hand` given every recorded pass
+    #[must_use]
+    pub fn admits(&self, seat: usize, hand: &[Card]) -> bool {
+        self.voids(seat)
+            .iter()
+            .all(|void| void.admits(hand, &self.config))
+    }
+
+    /// Deal the unseen cards into hidden hands consistent with every
+    /// recorded pass.
+    ///
+    /// Each card goes to a random seat with room whose per-rank caps allow
+    /// it (weighted by room left), or aside; the deal is kept only if every
+    /// seat [`admits`](Self::admits) its hand. Returns `None` if no
+    /// consistent deal is found within `max_attempts`.
+    pub fn sample_hidden_hands<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        max_attempts: usize,
+    ) -> Option<Vec<Vec<Card>>> {
+        // Unbounded ranks keep `u8::MAX`, above any real count
+        let rank_caps: Vec<RankCounts> = self
+            .voids
+            .iter()
+            .map(|voids| {
+                let mut caps = RankCounts([u8::MAX; Rank::ALL.len()]);
+                for rank in Rank::ALL {
+                    if let Some(cap) = voids.iter().filter_map(|v| v.max_count(rank)).min() {
+                        caps.0[rank.index()] = cap;
+                    }
+                }
+                caps
+            })
+            .collect();
+        let suit_caps: Vec<Option<u8>> = self
+            .voids
+            .iter()
+            .map(|voids| voids.iter().filter_map(|v| v.max_same_suit).min())
+            .collect();
+
+        let hidden: usize = self.hidden_sizes.iter().sum();
+        let mut deck = self.unseen.clone();
+
+        'attempt: for _ in 0..max_attempts {
+            deck.shuffle(rng);
+            let mut hands: Vec<Vec<Card>> = vec![Vec::new(); self.hidden_sizes.len()];
+            let mut rank_counts = vec![RankCounts::new(); hands.len()];
+            let mut suit_counts = vec![[RankCounts::new(); Suit::ALL.len()]; hands.len()];
+            let mut aside_room = deck.len().saturating_sub(hidden);
+
+            for card in &deck {
+                let open: Vec<(usize, usize)> = (0..hands.len())
+                    .filter_map(|seat| {
+                        let room = self.hidden_sizes[seat] - hands[seat].len();
+                        let rank_ok =
+                            rank_counts[seat].get(card.rank) < rank_caps[seat].get(card.rank);
+                        let suit_ok = suit_caps[seat].map_or(true, |cap| {
+                            suit_counts[seat][card.suit.index()].get(card.rank) < cap
+                        });
+                        (room > 0 && rank_ok && suit_ok).then_some((seat, room))
+                    })
+                    .collect();
+
+                let total_room: usize =
+                    open.iter().map(|(_, room)| room).sum::<usize>() + aside_room;
+                if total_room == 0 {
+                    continue 'attempt;
+                }
+                let mut pick = rng.gen_range(0..total_room);
+                let seat = open.iter().find_map(|(seat, room)| {
+                    if pick < *room {
+                        Some(*seat)
+                    } else {
+                        pick -= room;
+                        None
+                    }
+                });
+                match seat {
+                    Some(seat) => {
+                        hands[seat].push(*card);
+                        rank_counts[seat].add(card.rank);
+                        suit_counts[seat][card.suit.index()].add(card.rank);
+                    }
+                    None => aside_room -= 1,
+                }
+            }
+
+            let complete = hands
+                .iter()
+                .zip(&self.hidden_sizes)
+                .all(|(hand, size)| hand.len() == *size);
+            if complete && (0..hands.len()).all(|seat| self.admits(seat, &hands[seat])) {
+                return Some(hands);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns::PatternRecognizer;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn single(rank: Rank) -> PlayPattern {
+        PatternRecognizer::analyze_cards(&[Card::new(Suit::Clubs, rank)]).unwrap()
+    }
+
+    #[test]
+    fn test_samples_respect_voids() {
+        let unseen: Vec<Card> = [Rank::Six, Rank::Nine, Rank::Queen, Rank::Ace, Rank::Two]
+            .iter()
+            .flat_map(|r| [Card::new(Suit::Spades, *r), Card::new(Suit::Hearts, *r)])
+            .collect();
+        let mut tracker = CardTracker::new(unseen, vec![0, 4, 4], PassPolicy::MustPlayIfCanBeat);
+        tracker.record_pass(1, &single(Rank::Queen));
+
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..20 {
+            let hands = tracker.sample_hidden_hands(&mut rng, 50).unwrap();
+            assert!(hands[0].is_empty());
+            assert_eq!(hands[1].len(), 4);
+            assert!(hands[1]
+                .iter()
+                .all(|c| c.rank.value() <= Rank::Queen.value()));
+        }
+    }
+
+    #[test]
+    fn test_voluntary_passes_reveal_nothing() {
+        let mut tracker = CardTracker::new(vec![], vec![0, 3], PassPolicy::Voluntary);
+        tracker.record_pass(1, &single(Rank::Five));
+        assert!(tracker.voids(1).is_empty());
+    }
+
+    #[test]
+    fn test_impossible_constraints_give_none() {
+        let ace = Card::new(Suit::Spades, Rank::Ace);
+        let mut tracker = CardTracker::new(vec![ace], vec![0, 1], PassPolicy::MustPlayIfCanBeat);
+        tracker.record_pass(1, &single(Rank::King));
+        assert!(tracker
+            .sample_hidden_hands(&mut StdRng::seed_from_u64(1), 5)
+            .is_none());
+
+        tracker.record_play(1, &[ace]);
+        assert_eq!(tracker.hidden_sizes(), &[0, 0]);
+        assert!(tracker.unseen().is_empty());
+    }
+}
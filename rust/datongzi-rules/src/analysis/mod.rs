@@ -0,0 +1,27 @@
+//! Play analysis utilities.
+//!
+//! This module answers questions about plays relative to the cards still in
+//! circulation:
+//! - [`beat_chain`]: Which responses could still beat a given pattern
+//! - [`CardTracker`]: Unseen cards, pass constraints and consistent
+//!   hidden-hand sampling
+//! - [`PassInference`]: What a pass reveals about the passer's hand
+//! - [`power_ranking`]: Which opponent most likely holds a trump above mine
+//! - [`precompute_responses`]: Whether each pending player must play or may pass
+//! - [`response_requirements`]: Exactly what kinds of play beat a pattern
+
+mod beat_chain;
+mod card_tracker;
+mod pass_inference;
+mod power_ranking;
+mod response_spec;
+mod responses;
+
+pub use beat_chain::{beat_chain, beat_chain_with_config, BeatChain, BeatTier};
+pub use card_tracker::CardTracker;
+pub use pass_inference::PassInference;
+pub use power_ranking::{power_ranking, power_ranking_with_config, OpponentRisk, PowerRanking};
+pub use response_spec::{
+    response_requirements, response_requirements_with_config, Requirement, ResponseSpec,
+};
+pub use responses::{precompute_responses, precompute_responses_with_config, ResponseOutlook};
@@ -0,0 +1,282 @@
+//! Pass inference: what a pass reveals about the passer's hand.
+//!
+//! Under must-play rules (有牌必打) a pass proves the passer held no obliged
+//! beating play. [`CardTracker::record_pass`](super::CardTracker::record_pass)
+//! records one [`PassInference`] per pass so samplers can narrow the
+//! unseen-card distribution for that player.
+
+use std::cmp::Ordering;
+
+use crate::audit::{pass_was_forced_with_config, PassPolicy};
+use crate::models::{Card, GameConfig, Rank, RankCounts, Suit};
+use crate::patterns::{PlayPattern, PlayType};
+
+/// Largest rank count that does not form a bomb.
+const MAX_NON_BOMB: u8 = 3;
+/// Largest same-suit same-rank count that does not form a Tongzi.
+const MAX_NON_TONGZI: u8 = 2;
+
+/// Constraints on a passer's hand, derived from one pass.
+///
+/// Per-rank caps are necessary conditions only; [`admits`](Self::admits)
+/// gives the exact check (including chains, which caps cannot express).
+/// Hands only shrink, so the inference stays valid for the rest of the game.
+///
+/// Caps derived per pattern (higher = beats the pattern's primary rank):
+/// - Single / Pair / Triple: at most 0 / 1 / 2 cards of each higher rank
+/// - Chains, airplanes and triples with kickers: no per-rank cap
+/// - Bomb of n cards: at most n per rank and n-1 of each higher rank (in
+///   [`bomb_rank_order`](GameConfig::bomb_rank_order))
+///
+/// Under [`PassPolicy::MustPlayIfCanBeat`] the passer also held no trump able
+/// to beat the pattern: no Tongzi (≤2 per suit-rank) and, against a normal
+/// pattern, no bomb (≤3 per rank). A hand under a must-beat
+/// [`cards_left_alert`](GameConfig::cards_left_alert) is held to that policy
+/// whatever the tracker's strictness; under [`PassPolicy::Voluntary`] a pass
+/// reveals nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassInference {
+    /// The pattern the player declined to beat
+    pub current_pattern: PlayPattern,
+    /// Pass policy the passer was held to
+    pub policy: PassPolicy,
+    /// Upper bound on cards held per rank, indexed like [`Rank::ALL`]
+    pub rank_caps: [Option<u8>; Rank::ALL.len()],
+    /// Upper bound on cards held of any one suit and rank
+    pub max_same_suit: Option<u8>,
+}
+
+impl PassInference {
+    /// Infer what a pass on `current_pattern` by a player holding
+    /// `cards_in_hand` cards reveals under `strictness` and `config`.
+    pub(crate) fn new(
+        current_pattern: &PlayPattern,
+        strictness: PassPolicy,
+        cards_in_hand: usize,
+        config: &GameConfig,
+    ) -> Self {
+        let must_beat = config
+            .cards_left_alert()
+            .is_some_and(|alert| alert.must_beat && alert.applies_to(cards_in_hand));
+        let policy = if must_beat {
+            PassPolicy::MustPlayIfCanBeat
+        } else {
+            strictness
+        };
+        let mut inference = Self {
+            current_pattern: current_pattern.clone(),
+            policy,
+            rank_caps: [None; Rank::ALL.len()],
+            max_same_suit: None,
+        };
+        if policy == PassPolicy::Voluntary {
+            return inference;
+        }
+
+        // A higher single that would empty the hand may be barred as a finish
+        let finish_barred = current_pattern.play_type == PlayType::Single
+            && cards_in_hand <= 1
+            && config.finish_restrictions().no_single_score_card;
+        let primary = current_pattern.primary_rank;
+        let bomb_size = u8::try_from(current_pattern.card_count).unwrap_or(u8::MAX);
+        let higher_cap = match current_pattern.play_type {
+            _ if finish_barred => None,
+            PlayType::Single => Some(0),
+            PlayType::Pair => Some(1),
+            PlayType::Triple if current_pattern.card_count == 3 => Some(2),
+            PlayType::Bomb => Some(bomb_size.saturating_sub(1)),
+            _ => None,
+        };
+        let any_cap = match current_pattern.play_type {
+            PlayType::Bomb => Some(bomb_size),
+            PlayType::Tongzi | PlayType::Dizha => None,
+            _ if policy == PassPolicy::MustPlayIfCanBeat => Some(MAX_NON_BOMB),
+            _ => None,
+        };
+        let is_higher = |rank: Rank| match current_pattern.play_type {
+            PlayType::Bomb => config.bomb_rank_order().compare(rank, primary) == Ordering::Greater,
+            _ => rank.value() > primary.value(),
+        };
+
+        for rank in Rank::ALL {
+            let cap = if is_higher(rank) { higher_cap } else { None };
+            inference.rank_caps[rank.index()] = match (cap, any_cap) {
+                (Some(cap), Some(any)) => Some(cap.min(any)),
+                (cap, any) => cap.or(any),
+            };
+        }
+
+        if policy == PassPolicy::MustPlayIfCanBeat
+            && !matches!(
+                current_pattern.play_type,
+                PlayType::Tongzi | PlayType::Dizha
+            )
+        {
+            inference.max_same_suit = Some(MAX_NON_TONGZI);
+        }
+
+        inference
+    }
+
+    /// Returns the most cards of `rank` the passer can hold, if bounded
+    #[must_use]
+    pub fn max_count(&self, rank: Rank) -> Option<u8> {
+        self.rank_caps[rank.index()]
+    }
+
+    /// Returns true if the passer could have held `hand` when passing under
+    /// `config`
+    #[must_use]
+    pub fn admits(&self, hand: &[Card], config: &GameConfig) -> bool {
+        if self.policy == PassPolicy::Voluntary {
+            return true;
+        }
+
+        let rank_counts = RankCounts::from_cards(hand);
+        let mut suit_counts = [RankCounts::new(); Suit::ALL.len()];
+        for card in hand {
+            suit_counts[card.suit.index()].add(card.rank);
+        }
+
+        let within_rank_caps = rank_counts
+            .iter()
+            .all(|(rank, count)| self.max_count(rank).map_or(true, |cap| count <= cap));
+        let within_suit_cap = self.max_same_suit.map_or(true, |cap| {
+            suit_counts
+                .iter()
+                .all(|counts| counts.iter().all(|(_, count)| count <= cap))
+        });
+
+        within_rank_caps
+            && within_suit_cap
+            && pass_was_forced_with_config(hand, &self.current_pattern, self.policy, config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CardsLeftAlert, RankOrdering};
+    use crate::patterns::PatternRecognizer;
+
+    fn pattern(cards: &[Card]) -> PlayPattern {
+        PatternRecognizer::analyze_cards(cards).unwrap()
+    }
+
+    fn infer_from_pass(current_pattern: &PlayPattern, policy: PassPolicy) -> PassInference {
+        PassInference::new(current_pattern, policy, 10, &GameConfig::default())
+    }
+
+    fn admits(inference: &PassInference, hand: &[Card]) -> bool {
+        inference.admits(hand, &GameConfig::default())
+    }
+
+    fn bomb(rank: Rank, count: usize) -> Vec<Card> {
+        Suit::ALL
+            .iter()
+            .cycle()
+            .take(count)
+            .map(|s| Card::new(*s, rank))
+            .collect()
+    }
+
+    #[test]
+    fn test_single_pass_caps_higher_ranks() {
+        let king = pattern(&[Card::new(Suit::Spades, Rank::King)]);
+        let inference = infer_from_pass(&king, PassPolicy::MustPlayIfCanBeat);
+
+        assert_eq!(inference.max_count(Rank::Ace), Some(0));
+        assert_eq!(inference.max_count(Rank::Two), Some(0));
+        assert_eq!(inference.max_count(Rank::King), Some(3));
+        assert_eq!(inference.max_same_suit, Some(2));
+
+        assert!(admits(&inference, &[Card::new(Suit::Hearts, Rank::Queen)]));
+        assert!(!admits(&inference, &[Card::new(Suit::Hearts, Rank::Ace)]));
+        assert!(!admits(&inference, &bomb(Rank::Five, 4)));
+    }
+
+    #[test]
+    fn test_same_type_policy_leaves_trumps_open() {
+        let king = pattern(&[Card::new(Suit::Spades, Rank::King)]);
+        let inference = infer_from_pass(&king, PassPolicy::MustFollowSameType);
+
+        assert_eq!(inference.max_count(Rank::Ace), Some(0));
+        assert_eq!(inference.max_count(Rank::Five), None);
+        assert_eq!(inference.max_same_suit, None);
+        assert!(admits(&inference, &bomb(Rank::Five, 4)));
+    }
+
+    #[test]
+    fn test_bomb_pass_caps() {
+        let five_sixes = pattern(&bomb(Rank::Six, 5));
+        let inference = infer_from_pass(&five_sixes, PassPolicy::MustPlayIfCanBeat);
+
+        assert_eq!(inference.max_count(Rank::Five), Some(5));
+        assert_eq!(inference.max_count(Rank::Seven), Some(4));
+        assert!(admits(&inference, &bomb(Rank::Ace, 4)));
+        assert!(!admits(&inference, &bomb(Rank::Ace, 5)));
+        assert!(!admits(&inference, &bomb(Rank::Five, 6)));
+    }
+
+    #[test]
+    fn test_chain_pass_checked_exactly() {
+        let chain = pattern(&[
+            Card::new(Suit::Spades, Rank::Five),
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Spades, Rank::Six),
+            Card::new(Suit::Hearts, Rank::Six),
+        ]);
+        let inference = infer_from_pass(&chain, PassPolicy::MustPlayIfCanBeat);
+        assert_eq!(inference.max_count(Rank::Nine), Some(3));
+
+        let higher_chain = [
+            Card::new(Suit::Spades, Rank::Nine),
+            Card::new(Suit::Hearts, Rank::Nine),
+            Card::new(Suit::Spades, Rank::Ten),
+            Card::new(Suit::Hearts, Rank::Ten),
+        ];
+        assert!(!admits(&inference, &higher_chain));
+        assert!(admits(&inference, &higher_chain[..3]));
+    }
+
+    #[test]
+    fn test_voluntary_pass_reveals_nothing() {
+        let king = pattern(&[Card::new(Suit::Spades, Rank::King)]);
+        let inference = infer_from_pass(&king, PassPolicy::Voluntary);
+
+        assert!(inference.rank_caps.iter().all(Option::is_none));
+        assert!(admits(&inference, &[Card::new(Suit::Hearts, Rank::Ace)]));
+    }
+
+    #[test]
+    fn test_config_shapes_inference() {
+        let bomb_of_nines = pattern(&bomb(Rank::Nine, 4));
+        // 9 ranks above every other bomb rank
+        let config = GameConfig {
+            bomb_rank_order: RankOrdering::Custom(vec![Rank::Nine]),
+            ..GameConfig::default()
+        };
+        let inference =
+            PassInference::new(&bomb_of_nines, PassPolicy::MustPlayIfCanBeat, 10, &config);
+        assert_eq!(inference.max_count(Rank::Ace), Some(4));
+        assert!(inference.admits(&bomb(Rank::Ace, 4), &config));
+        assert_eq!(
+            infer_from_pass(&bomb_of_nines, PassPolicy::MustPlayIfCanBeat).max_count(Rank::Ace),
+            Some(3)
+        );
+
+        // A must-beat alert binds even a voluntary passer
+        let king = pattern(&[Card::new(Suit::Spades, Rank::King)]);
+        let alert = GameConfig {
+            cards_left_alert: Some(CardsLeftAlert {
+                threshold: 3,
+                must_beat: true,
+            }),
+            ..GameConfig::default()
+        };
+        let inference = PassInference::new(&king, PassPolicy::Voluntary, 2, &alert);
+        assert_eq!(inference.policy, PassPolicy::MustPlayIfCanBeat);
+        assert_eq!(inference.max_count(Rank::Ace), Some(0));
+        assert!(!inference.admits(&[Card::new(Suit::Hearts, Rank::Ace)], &alert));
+    }
+}
@@ -0,0 +1,312 @@
+//! Remaining-power ranking: which opponent most likely out-trumps me.
+
+use std::collections::HashMap;
+
+use crate::models::{Card, GameConfig, Rank, Suit};
+use crate::patterns::{PatternRecognizer, PlayPattern, PlayType, PlayValidator, RULES};
+
+/// Risk that one opponent holds a trump larger than my best.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpponentRisk {
+    /// Index of the opponent in the `opponent_hand_sizes` argument
+    pub opponent: usize,
+    /// Cards the opponent holds
+    pub hand_size: usize,
+    /// Estimated probability of holding at least one beating trump
+    pub probability: f64,
+}
+
+/// Opponents ordered by risk, most dangerous first.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PowerRanking {
+    /// My strongest trump (Bomb, Tongzi or Dizha), if any
+    pub my_best: Option<PlayPattern>,
+    /// Per-opponent risk, highest probability first (ties by opponent index)
+    pub risks: Vec<OpponentRisk>,
+}
+
+impl PowerRanking {
+    /// Returns the opponent most likely to out-trump me
+    #[must_use]
+    pub fn most_dangerous(&self) -> Option<&OpponentRisk> {
+        self.risks.first()
+    }
+}
+
+/// Cards an opponent must hold for one beating trump: `(available, required)` per group.
+type Requirement = Vec<(usize, usize)>;
+
+/// Rank opponents by the chance they still hold a Bomb/Tongzi/Dizha that
+/// beats my best trump (any trump if I have none).
+///
+/// `unseen_cards` is the pool not seen by me (all cards minus my hand and
+/// played cards); `opponent_hand_sizes` lists how many cards each opponent
+/// holds. Each opponent's hand is treated as a uniform random draw from the
+/// pool. Per-structure probabilities are exact (hypergeometric); they are
+/// combined as if independent, so the result is an estimate.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::analysis::power_ranking;
+/// use datongzi_rules::{Card, Rank, Suit};
+///
+/// let my_hand = vec![Card::new(Suit::Spades, Rank::Three)];
+/// let mut unseen: Vec<Card> = Suit::ALL.iter().map(|s| Card::new(*s, Rank::Nine)).collect();
+/// unseen.extend(Suit::ALL.iter().map(|s| Card::new(*s, Rank::Five)));
+///
+/// // Opponent 1 holds every unseen card, so it surely has a bomb
+/// let ranking = power_ranking(&unseen, &[2, 8], &my_hand);
+/// assert_eq!(ranking.most_dangerous().unwrap().opponent, 1);
+/// assert!((ranking.risks[0].probability - 1.0).abs() < 1e-9);
+/// ```
+#[must_use]
+pub fn power_ranking(
+    unseen_cards: &[Card],
+    opponent_hand_sizes: &[usize],
+    my_hand: &[Card],
+) -> PowerRanking {
+    power_ranking_with_config(
+        unseen_cards,
+        opponent_hand_sizes,
+        my_hand,
+        &GameConfig::default(),
+    )
+}
+
+/// Like [`power_ranking`], comparing trumps with the options in `config`
+/// (see [`PlayValidator::can_beat_play_with_config`]).
+#[must_use]
+pub fn power_ranking_with_config(
+    unseen_cards: &[Card],
+    opponent_hand_sizes: &[usize],
+    my_hand: &[Card],
+    config: &GameConfig,
+) -> PowerRanking {
+    let mut my_best: Option<PlayPattern> = None;
+    for cards in trump_candidates(my_hand) {
+        if PlayValidator::can_beat_play_with_config(&cards, my_best.as_ref(), config) {
+            my_best = PatternRecognizer::analyze_cards(&cards);
+        }
+    }
+
+    let threats = threats(unseen_cards, my_best.as_ref(), config);
+    let pool = unseen_cards.len();
+
+    let mut risks: Vec<OpponentRisk> = opponent_hand_sizes
+        .iter()
+        .enumerate()
+        .map(|(opponent, &hand_size)| {
+            let draw = hand_size.min(pool);
+            let none = threats
+                .iter()
+                .map(|groups| 1.0 - holds_all(groups, pool, draw))
+                .product::<f64>();
+            OpponentRisk {
+                opponent,
+                hand_size,
+                probability: (1.0 - none).clamp(0.0, 1.0),
+            }
+        })
+        .collect();
+    risks.sort_by(|a, b| {
+        b.probability
+            .total_cmp(&a.probability)
+            .then(a.opponent.cmp(&b.opponent))
+    });
+
+    PowerRanking { my_best, risks }
+}
+
+/// Every trump `cards` can form: each rank's full group (bomb), each
+/// suit-rank triple (Tongzi) and each two-per-suit rank (Dizha).
+fn trump_candidates(cards: &[Card]) -> Vec<Vec<Card>> {
+    let mut suit_rank_groups: HashMap<(Suit, Rank), Vec<Card>> = HashMap::new();
+    for card in cards {
+        suit_rank_groups
+            .entry((card.suit, card.rank))
+            .or_default()
+            .push(*card);
+    }
+
+    let mut candidates = Vec::new();
+    for rank in Rank::ALL {
+        let group = |suit: Suit| suit_rank_groups.get(&(suit, rank)).map_or(0, Vec::len);
+        let total: usize = Suit::ALL.iter().map(|s| group(*s)).sum();
+        if total >= RULES.min_bomb {
+            candidates.push(cards.iter().filter(|c| c.rank == rank).copied().collect());
+        }
+        for suit in Suit::ALL {
+            if group(suit) >= RULES.tongzi {
+                candidates.push(vec![Card::new(suit, rank); RULES.tongzi]);
+            }
+        }
+        if Suit::ALL
+            .iter()
+            .all(|s| group(*s) >= RULES.dizha_per_suit())
+        {
+            candidates.push(
+                Suit::ALL
+                    .iter()
+                    .flat_map(|s| [Card::new(*s, rank); RULES.dizha_per_suit()])
+                    .collect(),
+            );
+        }
+    }
+    candidates
+}
+
+/// Smallest card requirements, one per structure in `unseen_cards`, that beat `my_best`.
+fn threats(
+    unseen_cards: &[Card],
+    my_best: Option<&PlayPattern>,
+    config: &GameConfig,
+) -> Vec<Requirement> {
+    let beats = |cards: &[Card]| match my_best {
+        Some(best) => PlayValidator::can_beat_play_with_config(cards, Some(best), config),
+        None => PatternRecognizer::analyze_cards(cards).is_some_and(|p| {
+            matches!(
+                p.play_type,
+                PlayType::Bomb | PlayType::Tongzi | PlayType::Dizha
+            )
+        }),
+    };
+
+    let count = |suit: Suit, rank: Rank| {
+        unseen_cards
+            .iter()
+            .filter(|c| c.suit == suit && c.rank == rank)
+            .count()
+    };
+
+    let mut threats = Vec::new();
+    for rank in Rank::ALL {
+        let rank_cards: Vec<Card> = unseen_cards
+            .iter()
+            .filter(|c| c.rank == rank)
+            .copied()
+            .collect();
+
+        // Bomb: the fewest cards of this rank that still beat my best
+        if let Some(size) = (4..=rank_cards.len()).find(|&n| beats(&rank_cards[..n])) {
+            threats.push(vec![(rank_cards.len(), size)]);
+        }
+
+        for suit in Suit::ALL {
+            let available = count(suit, rank);
+            if available >= 3 && beats(&[Card::new(suit, rank); 3]) {
+                threats.push(vec![(available, 3)]);
+            }
+        }
+
+        let per_suit: Vec<usize> = Suit::ALL.iter().map(|s| count(*s, rank)).collect();
+        if per_suit.iter().all(|n| *n >= 2) {
+            let dizha: Vec<Card> = Suit::ALL
+                .iter()
+                .flat_map(|s| [Card::new(*s, rank); 2])
+                .collect();
+            if beats(&dizha) {
+                threats.push(per_suit.into_iter().map(|n| (n, 2)).collect());
+            }
+        }
+    }
+    threats
+}
+
+/// Probability that `draw` cards taken from `pool` include, for every
+/// `(available, required)` group, at least `required` of its `available` cards.
+fn holds_all(groups: &[(usize, usize)], pool: usize, draw: usize) -> f64 {
+    let in_groups: usize = groups.iter().map(|(available, _)| available).sum();
+    let outside = pool.saturating_sub(in_groups);
+
+    // Sum over how many cards are drawn from the groups in total
+    let mut ways = vec![1.0_f64];
+    for &(available, required) in groups {
+        let mut next = vec![0.0; ways.len() + available];
+        for (taken, w) in ways.iter().enumerate() {
+            for x in required..=available {
+                next[taken + x] += w * choose(available, x);
+            }
+        }
+        ways = next;
+    }
+
+    let favorable: f64 = ways
+        .iter()
+        .enumerate()
+        .filter(|(taken, _)| *taken <= draw)
+        .map(|(taken, w)| w * choose(outside, draw - taken))
+        .sum();
+    favorable / choose(pool, draw)
+}
+
+fn choose(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    let k = k.min(n - k);
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_holds_all_matches_hypergeometric() {
+        // 4 aces in a 10-card pool, draw 5: P(all four) = C(6,1) / C(10,5)
+        let p = holds_all(&[(4, 4)], 10, 5);
+        assert!((p - 6.0 / 252.0).abs() < 1e-12);
+        assert_eq!(holds_all(&[(4, 4)], 10, 3), 0.0);
+    }
+
+    #[test]
+    fn test_my_tongzi_ignores_bombs() {
+        let my_hand = vec![Card::new(Suit::Spades, Rank::Ace); 3];
+        let unseen: Vec<Card> = Suit::ALL
+            .iter()
+            .flat_map(|s| [Card::new(*s, Rank::King); 2])
+            .take(6)
+            .collect();
+
+        let ranking = power_ranking(&unseen, &[6], &my_hand);
+        assert_eq!(ranking.my_best.unwrap().play_type, PlayType::Tongzi);
+        assert_eq!(ranking.risks[0].probability, 0.0);
+    }
+
+    #[test]
+    fn test_tongzi_suit_order_from_config() {
+        use crate::models::TongziSuitOrder;
+
+        let my_hand = vec![Card::new(Suit::Spades, Rank::Ace); 3];
+        let unseen = vec![Card::new(Suit::Diamonds, Rank::Ace); 3];
+        let ranking = power_ranking(&unseen, &[3], &my_hand);
+        assert_eq!(ranking.risks[0].probability, 0.0);
+
+        let config = GameConfig {
+            tongzi_suit_order: TongziSuitOrder::Fixed([
+                Suit::Spades,
+                Suit::Hearts,
+                Suit::Clubs,
+                Suit::Diamonds,
+            ]),
+            ..GameConfig::default()
+        };
+        let ranking = power_ranking_with_config(&unseen, &[3], &my_hand, &config);
+        assert!((ranking.risks[0].probability - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_larger_hands_are_riskier() {
+        let my_hand = vec![Card::new(Suit::Hearts, Rank::Four)];
+        let mut unseen: Vec<Card> = Suit::ALL
+            .iter()
+            .map(|s| Card::new(*s, Rank::Queen))
+            .collect();
+        unseen.extend((0..12).map(|i| Card::new(Suit::ALL[i % 4], Rank::ALL[i % 8])));
+
+        let ranking = power_ranking(&unseen, &[4, 10, 7], &my_hand);
+        let order: Vec<usize> = ranking.risks.iter().map(|r| r.opponent).collect();
+        assert_eq!(order, vec![1, 2, 0]);
+        assert!(ranking.risks[0].probability > ranking.risks[2].probability);
+    }
+}
@@ -0,0 +1,359 @@
+//! Declarative description of what beats a play.
+
+use std::cmp::Ordering;
+
+use crate::models::{GameConfig, Rank, Suit};
+use crate::patterns::{CompareRules, PlayPattern, PlayType};
+
+/// One way to beat a play.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Requirement {
+    /// A single of `rank` in a suit above `above` (only with
+    /// [`GameConfig::singles_suit_tiebreak`])
+    SuitTiebreak {
+        /// Rank to match
+        rank: Rank,
+        /// Suit to exceed
+        above: Suit,
+    },
+    /// A play of one of `play_types`, with the same chain length, whose
+    /// primary rank is above `above`
+    SameType {
+        /// Accepted play types (airplanes with and without wings mix)
+        play_types: Vec<PlayType>,
+        /// Primary rank to exceed
+        above: Rank,
+        /// Ranks in the chain (0 for non-chain plays)
+        chain_length: usize,
+    },
+    /// A bomb with more than `min_size` cards, or with exactly `min_size`
+    /// cards and a rank above `rank_above` (`None` = any rank) under the
+    /// bomb rank order
+    Bomb {
+        /// Smallest accepted bomb size
+        min_size: usize,
+        /// Rank to exceed at `min_size`
+        rank_above: Option<Rank>,
+    },
+    /// A Tongzi above `(rank, suit)`, suits compared by the Tongzi suit
+    /// order (`None` = any Tongzi)
+    Tongzi {
+        /// Rank, then suit, to exceed
+        above: Option<(Rank, Suit)>,
+    },
+    /// A Dizha above `rank` (`None` = any Dizha)
+    Dizha {
+        /// Rank to exceed
+        above: Option<Rank>,
+    },
+}
+
+impl Requirement {
+    /// Returns true if `pattern` meets this requirement
+    #[must_use]
+    pub fn is_met_by(&self, pattern: &PlayPattern) -> bool {
+        self.is_met_with(pattern, CompareRules::standard())
+    }
+
+    /// Like [`is_met_by`](Self::is_met_by), comparing bombs and Tongzi by
+    /// the orders `config` selects.
+    #[must_use]
+    pub fn is_met_by_with_config(&self, pattern: &PlayPattern, config: &GameConfig) -> bool {
+        self.is_met_with(pattern, CompareRules::from_config(config))
+    }
+
+    fn is_met_with(&self, pattern: &PlayPattern, rules: CompareRules<'_>) -> bool {
+        let rank = pattern.primary_rank.value();
+        match self {
+            Self::SuitTiebreak { rank, above } => {
+                pattern.play_type == PlayType::Single
+                    && pattern.primary_rank == *rank
+                    && pattern.primary_suit.map_or(0, Suit::value) > above.value()
+            }
+            Self::SameType {
+                play_types,
+                above,
+                chain_length,
+            } => {
+                play_types.contains(&pattern.play_type)
+                    && pattern.secondary_ranks.len() == *chain_length
+                    && rank > above.value()
+            }
+            Self::Bomb {
+                min_size,
+                rank_above,
+            } => {
+                pattern.play_type == PlayType::Bomb
+                    && (pattern.card_count > *min_size
+                        || (pattern.card_count == *min_size
+                            && rank_above.map_or(true, |r| {
+                                rules.bomb_rank_order.compare(pattern.primary_rank, r)
+                                    == Ordering::Greater
+                            })))
+            }
+            Self::Tongzi { above } => {
+                pattern.play_type == PlayType::Tongzi
+                    && above.map_or(true, |(r, s)| {
+                        rank.cmp(&r.value()).then_with(|| {
+                            pattern.primary_suit.map_or(Ordering::Less, |suit| {
+                                rules.tongzi_suit_order.compare(suit, s)
+                            })
+                        }) == Ordering::Greater
+                    })
+            }
+            Self::Dizha { above } => {
+                pattern.play_type == PlayType::Dizha && above.map_or(true, |r| rank > r.value())
+            }
+        }
+    }
+}
+
+/// Everything that beats a play, weakest requirement first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseSpec {
+    /// Alternatives; meeting any one of them beats the play
+    pub requirements: Vec<Requirement>,
+}
+
+impl ResponseSpec {
+    /// Returns true if `pattern` beats the play this spec describes
+    #[must_use]
+    pub fn is_met_by(&self, pattern: &PlayPattern) -> bool {
+        self.requirements.iter().any(|r| r.is_met_by(pattern))
+    }
+
+    /// Like [`is_met_by`](Self::is_met_by), under the rules of `config`
+    #[must_use]
+    pub fn is_met_by_with_config(&self, pattern: &PlayPattern, config: &GameConfig) -> bool {
+        self.requirements
+            .iter()
+            .any(|r| r.is_met_by_with_config(pattern, config))
+    }
+}
+
+/// Describe exactly what beats `current_pattern`.
+///
+/// The spec agrees with
+/// [`PlayValidator::can_beat_play`](crate::PlayValidator::can_beat_play), so
+/// tooltips and bots can share one declarative source. A same-type
+/// requirement is omitted when nothing ranks above the play (e.g. a single 2).
+///
+/// # Example
+/// ```
+/// use datongzi_rules::analysis::{response_requirements, Requirement};
+/// use datongzi_rules::{Card, PatternRecognizer, PlayType, Rank, Suit};
+///
+/// let pair = PatternRecognizer::analyze_cards(&[
+///     Card::new(Suit::Spades, Rank::Nine),
+///     Card::new(Suit::Hearts, Rank::Nine),
+/// ])
+/// .unwrap();
+///
+/// let spec = response_requirements(&pair);
+/// assert_eq!(
+///     spec.requirements,
+///     vec![
+///         Requirement::SameType { play_types: vec![PlayType::Pair], above: Rank::Nine, chain_length: 0 },
+///         Requirement::Bomb { min_size: 4, rank_above: None },
+///         Requirement::Tongzi { above: None },
+///         Requirement::Dizha { above: None },
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn response_requirements(current_pattern: &PlayPattern) -> ResponseSpec {
+    requirements_with(current_pattern, CompareRules::standard())
+}
+
+/// Describe what beats `current_pattern` under the comparison rules of
+/// `config`.
+///
+/// Agrees with
+/// [`PlayValidator::can_beat_play_with_config`](crate::PlayValidator::can_beat_play_with_config)
+/// when checked with [`ResponseSpec::is_met_by_with_config`]. With
+/// `singles_suit_tiebreak`, a single also admits a
+/// [`Requirement::SuitTiebreak`].
+#[must_use]
+pub fn response_requirements_with_config(
+    current_pattern: &PlayPattern,
+    config: &GameConfig,
+) -> ResponseSpec {
+    requirements_with(current_pattern, CompareRules::from_config(config))
+}
+
+fn requirements_with(current_pattern: &PlayPattern, rules: CompareRules<'_>) -> ResponseSpec {
+    let rank = current_pattern.primary_rank;
+    let has_higher_rank = rank != Rank::Two;
+    let mut requirements = Vec::new();
+
+    match current_pattern.play_type {
+        PlayType::Dizha => {
+            if has_higher_rank {
+                requirements.push(Requirement::Dizha { above: Some(rank) });
+            }
+            return ResponseSpec { requirements };
+        }
+        PlayType::Tongzi => {
+            let suit = current_pattern.primary_suit.unwrap_or(Suit::Spades);
+            let has_higher_suit = Suit::ALL
+                .iter()
+                .any(|s| rules.tongzi_suit_order.compare(*s, suit) == Ordering::Greater);
+            if has_higher_rank || has_higher_suit {
+                requirements.push(Requirement::Tongzi {
+                    above: Some((rank, suit)),
+                });
+            }
+        }
+        PlayType::Bomb => {
+            requirements.push(Requirement::Bomb {
+                min_size: current_pattern.card_count,
+                rank_above: Some(rank),
+            });
+            requirements.push(Requirement::Tongzi { above: None });
+        }
+        play_type => {
+            if rules.singles_suit_tiebreak && play_type == PlayType::Single {
+                let suit = current_pattern.primary_suit.unwrap_or(Suit::Spades);
+                if suit != Suit::Spades {
+                    requirements.push(Requirement::SuitTiebreak { rank, above: suit });
+                }
+            }
+            if has_higher_rank {
+                let play_types = match play_type {
+                    PlayType::Airplane | PlayType::AirplaneWithWings => {
+                        vec![PlayType::Airplane, PlayType::AirplaneWithWings]
+                    }
+                    other => vec![other],
+                };
+                requirements.push(Requirement::SameType {
+                    play_types,
+                    above: rank,
+                    chain_length: current_pattern.secondary_ranks.len(),
+                });
+            }
+            requirements.push(Requirement::Bomb {
+                min_size: 4,
+                rank_above: None,
+            });
+            requirements.push(Requirement::Tongzi { above: None });
+        }
+    }
+
+    requirements.push(Requirement::Dizha { above: None });
+    ResponseSpec { requirements }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Card;
+    use crate::patterns::{PatternRecognizer, PlayValidator};
+
+    /// A spread of recognizable plays covering every type.
+    fn sample_plays() -> Vec<Vec<Card>> {
+        let mut plays = Vec::new();
+        for rank in [Rank::Five, Rank::Nine, Rank::King, Rank::Two] {
+            for n in [1, 2, 3, 4, 5, 6] {
+                plays.push((0..n).map(|i| Card::new(Suit::ALL[i % 4], rank)).collect());
+            }
+            for suit in [Suit::Diamonds, Suit::Spades] {
+                plays.push(vec![Card::new(suit, rank); 3]);
+            }
+            plays.push(
+                Suit::ALL
+                    .iter()
+                    .flat_map(|s| [Card::new(*s, rank); 2])
+                    .collect(),
+            );
+        }
+        let chain = |ranks: &[Rank], width: usize| -> Vec<Card> {
+            ranks
+                .iter()
+                .flat_map(|r| (0..width).map(move |i| Card::new(Suit::ALL[i], *r)))
+                .collect()
+        };
+        plays.push(chain(&[Rank::Six, Rank::Seven], 2));
+        plays.push(chain(&[Rank::Nine, Rank::Ten], 2));
+        plays.push(chain(&[Rank::Nine, Rank::Ten, Rank::Jack], 2));
+        plays.push(chain(&[Rank::Six, Rank::Seven], 3));
+        let mut wings = chain(&[Rank::Ten, Rank::Jack], 3);
+        wings.push(Card::new(Suit::Spades, Rank::Five));
+        plays.push(wings);
+        plays
+    }
+
+    #[test]
+    fn test_spec_matches_can_beat_play() {
+        let patterns: Vec<(Vec<Card>, PlayPattern)> = sample_plays()
+            .into_iter()
+            .filter_map(|cards| PatternRecognizer::analyze_cards(&cards).map(|p| (cards, p)))
+            .collect();
+
+        for (_, current) in &patterns {
+            let spec = response_requirements(current);
+            for (cards, candidate) in &patterns {
+                assert_eq!(
+                    spec.is_met_by(candidate),
+                    PlayValidator::can_beat_play(cards, Some(current)),
+                    "{candidate:?} against {current:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_spec_with_config_matches_can_beat_play_with_config() {
+        use crate::models::{RankOrdering, TongziSuitOrder};
+
+        let config = GameConfig {
+            singles_suit_tiebreak: true,
+            bomb_rank_order: RankOrdering::Custom(vec![Rank::Nine]),
+            tongzi_suit_order: TongziSuitOrder::Fixed([
+                Suit::Spades,
+                Suit::Hearts,
+                Suit::Clubs,
+                Suit::Diamonds,
+            ]),
+            ..GameConfig::default()
+        };
+        let patterns: Vec<(Vec<Card>, PlayPattern)> = sample_plays()
+            .into_iter()
+            .filter_map(|cards| PatternRecognizer::analyze_cards(&cards).map(|p| (cards, p)))
+            .collect();
+
+        for (_, current) in &patterns {
+            let spec = response_requirements_with_config(current, &config);
+            for (cards, candidate) in &patterns {
+                assert_eq!(
+                    spec.is_met_by_with_config(candidate, &config),
+                    PlayValidator::can_beat_play_with_config(cards, Some(current), &config),
+                    "{candidate:?} against {current:?}"
+                );
+            }
+        }
+
+        let two = PatternRecognizer::analyze_cards(&[Card::new(Suit::Hearts, Rank::Two)]).unwrap();
+        assert_eq!(
+            response_requirements_with_config(&two, &config).requirements[0],
+            Requirement::SuitTiebreak {
+                rank: Rank::Two,
+                above: Suit::Hearts
+            }
+        );
+    }
+
+    #[test]
+    fn test_top_plays_have_fewer_requirements() {
+        let two = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Two)]).unwrap();
+        assert_eq!(response_requirements(&two).requirements.len(), 3);
+
+        let top_dizha = PatternRecognizer::analyze_cards(
+            &Suit::ALL
+                .iter()
+                .flat_map(|s| [Card::new(*s, Rank::Two); 2])
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        assert!(response_requirements(&top_dizha).requirements.is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+//! Per-player response outlook right after a play.
+
+use crate::ai_helpers::{GenerationOptions, PlayGenerator};
+use crate::audit::{pass_was_forced_with_config, PassPolicy};
+use crate::models::{Card, GameConfig};
+use crate::patterns::{PatternRecognizer, PlayPattern, PlayType, PlayValidator};
+
+/// What one pending player can do against the current play.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseOutlook {
+    /// The player holds at least one beating play
+    pub can_beat: bool,
+    /// The policy obliges the player to play (passing is illegal)
+    pub must_play: bool,
+    /// Cheapest beating play, if requested and one exists
+    pub cheapest: Option<Vec<Card>>,
+}
+
+/// Compute each pending player's response outlook against `current_pattern`.
+///
+/// `hands` holds the pending players' hands in turn order; the result is
+/// indexed the same way. The function is pure and each hand is independent,
+/// so servers can run it off the request path right after a play and then
+/// answer "you must play / you may pass" instantly.
+///
+/// With `find_cheapest`, the cheapest beating play is also returned:
+/// same-family plays before trumps, then lowest
+/// [`normalized_power`](PlayPattern::normalized_power), then fewest cards.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::analysis::precompute_responses;
+/// use datongzi_rules::audit::PassPolicy;
+/// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+///
+/// let nine = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Nine)]).unwrap();
+/// let hands = vec![
+///     vec![Card::new(Suit::Hearts, Rank::Five)],
+///     vec![Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Clubs, Rank::Jack)],
+/// ];
+///
+/// let outlook = precompute_responses(&hands, &nine, PassPolicy::MustPlayIfCanBeat, true);
+/// assert!(!outlook[0].must_play);
+/// assert!(outlook[1].must_play);
+/// assert_eq!(outlook[1].cheapest, Some(vec![Card::new(Suit::Clubs, Rank::Jack)]));
+/// ```
+#[must_use]
+pub fn precompute_responses(
+    hands: &[Vec<Card>],
+    current_pattern: &PlayPattern,
+    policy: PassPolicy,
+    find_cheapest: bool,
+) -> Vec<ResponseOutlook> {
+    precompute_responses_with_config(
+        hands,
+        current_pattern,
+        policy,
+        find_cheapest,
+        &GameConfig::default(),
+    )
+}
+
+/// Like [`precompute_responses`], under the rules of `config`.
+///
+/// Only beating plays that pass
+/// [`PlayValidator::validate_play_in_hand`] count, and a hand that
+/// [`PlayValidator::validate_pass`] forbids from passing (the
+/// [`cards_left_alert`](GameConfig::cards_left_alert) must-beat rule) must
+/// play whatever the policy.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::analysis::precompute_responses_with_config;
+/// use datongzi_rules::audit::PassPolicy;
+/// use datongzi_rules::models::CardsLeftAlert;
+/// use datongzi_rules::{Card, GameConfig, PatternRecognizer, Rank, Suit};
+///
+/// let king = PatternRecognizer::analyze_cards(&[Card::new(Suit::Hearts, Rank::King)]).unwrap();
+/// let hands = vec![vec![Card::new(Suit::Spades, Rank::King)]];
+/// let config = GameConfig {
+///     singles_suit_tiebreak: true,
+///     cards_left_alert: Some(CardsLeftAlert { threshold: 1, must_beat: true }),
+///     ..GameConfig::default()
+/// };
+///
+/// let outlook = precompute_responses_with_config(&hands, &king, PassPolicy::Voluntary, false, &config);
+/// assert!(outlook[0].can_beat);
+/// assert!(outlook[0].must_play);
+/// ```
+#[must_use]
+pub fn precompute_responses_with_config(
+    hands: &[Vec<Card>],
+    current_pattern: &PlayPattern,
+    policy: PassPolicy,
+    find_cheapest: bool,
+    config: &GameConfig,
+) -> Vec<ResponseOutlook> {
+    let options = GenerationOptions::for_config(config);
+    hands
+        .iter()
+        .map(|hand| {
+            let plays: Vec<Vec<Card>> =
+                PlayGenerator::generate_beating_plays_with_options(hand, current_pattern, &options)
+                    .into_iter()
+                    .filter(|play| {
+                        PlayValidator::validate_play_in_hand(
+                            play,
+                            Some(current_pattern),
+                            hand.len(),
+                            config,
+                        )
+                        .is_ok()
+                    })
+                    .collect();
+            let cheapest = if find_cheapest {
+                plays
+                    .iter()
+                    .filter_map(|play| Some((cost(play, current_pattern, config)?, play)))
+                    .min_by_key(|(cost, _)| *cost)
+                    .map(|(_, play)| play.clone())
+            } else {
+                None
+            };
+            let obliged = policy != PassPolicy::Voluntary
+                && !pass_was_forced_with_config(hand, current_pattern, policy, config);
+            ResponseOutlook {
+                can_beat: !plays.is_empty(),
+                must_play: !plays.is_empty()
+                    && (obliged
+                        || PlayValidator::validate_pass(hand, current_pattern, config).is_err()),
+                cheapest,
+            }
+        })
+        .collect()
+}
+
+/// Ordering key for "cheapness": (uses a trump out of family, power, cards).
+fn cost(
+    play: &[Card],
+    current_pattern: &PlayPattern,
+    config: &GameConfig,
+) -> Option<(bool, u8, usize)> {
+    let pattern = PatternRecognizer::analyze_cards(play)?;
+    let is_trump = |t| matches!(t, PlayType::Bomb | PlayType::Tongzi | PlayType::Dizha);
+    let escalates = is_trump(pattern.play_type) && pattern.play_type != current_pattern.play_type;
+    Some((escalates, pattern.normalized_power(config), play.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{FinishRestrictions, Rank, Suit};
+
+    #[test]
+    fn test_cheapest_prefers_same_type_over_bomb() {
+        let pair = PatternRecognizer::analyze_cards(&[
+            Card::new(Suit::Spades, Rank::Eight),
+            Card::new(Suit::Hearts, Rank::Eight),
+        ])
+        .unwrap();
+        let mut hand: Vec<Card> = Suit::ALL
+            .iter()
+            .map(|s| Card::new(*s, Rank::Five))
+            .collect();
+        hand.extend([Card::new(Suit::Spades, Rank::King); 2]);
+        hand.extend([
+            Card::new(Suit::Spades, Rank::Queen),
+            Card::new(Suit::Hearts, Rank::Queen),
+        ]);
+
+        let outlook = precompute_responses(&[hand], &pair, PassPolicy::MustFollowSameType, true);
+        assert!(outlook[0].must_play);
+        let cheapest = outlook[0].cheapest.as_ref().unwrap();
+        assert!(cheapest.iter().all(|c| c.rank == Rank::Queen));
+    }
+
+    #[test]
+    fn test_finish_restriction_frees_the_pass() {
+        let queen =
+            PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Queen)]).unwrap();
+        let hand = vec![Card::new(Suit::Hearts, Rank::King)];
+        let outlook = precompute_responses(
+            std::slice::from_ref(&hand),
+            &queen,
+            PassPolicy::MustPlayIfCanBeat,
+            true,
+        );
+        assert!(outlook[0].must_play);
+
+        // Finishing on a single K is forbidden, so the K is no answer
+        let config = GameConfig {
+            finish_restrictions: FinishRestrictions {
+                no_single_score_card: true,
+                ..FinishRestrictions::default()
+            },
+            ..GameConfig::default()
+        };
+        let outlook = precompute_responses_with_config(
+            &[hand],
+            &queen,
+            PassPolicy::MustPlayIfCanBeat,
+            true,
+            &config,
+        );
+        assert!(!outlook[0].can_beat);
+        assert!(!outlook[0].must_play);
+        assert!(outlook[0].cheapest.is_none());
+    }
+
+    #[test]
+    fn test_voluntary_never_obliges() {
+        let single =
+            PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Six)]).unwrap();
+        let hand = vec![Card::new(Suit::Hearts, Rank::Two)];
+
+        let outlook = precompute_responses(&[hand], &single, PassPolicy::Voluntary, false);
+        assert!(outlook[0].can_beat);
+        assert!(!outlook[0].must_play);
+        assert!(outlook[0].cheapest.is_none());
+    }
+}
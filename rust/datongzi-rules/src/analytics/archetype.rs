@@ -0,0 +1,145 @@
+//! Hand archetype classification.
+
+use crate::ai_helpers::HandPatterns;
+use crate::models::{Card, Rank};
+
+/// Broad shape of a hand.
+///
+/// Criteria are checked in declaration order and the first match wins, so
+/// a hand with three bombs and many chains is `BombHeavy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HandArchetype {
+    /// 3+ trumps, or trumps hold at least 30% of the cards
+    BombHeavy,
+    /// Airplanes and consecutive pairs hold at least 40% of the cards
+    ChainHeavy,
+    /// 2s and Aces make up at least 25% of the cards
+    Control,
+    /// Singles make up at least 40% of the cards
+    Scattered,
+    /// None of the above (also the empty hand)
+    Balanced,
+}
+
+fn cards_in(groups: &[Vec<Card>]) -> usize {
+    groups.iter().map(Vec::len).sum()
+}
+
+/// Classify a decomposed hand.
+///
+/// Works on any decomposition; pass the output of
+/// [`HandPatternAnalyzer::analyze_patterns`](crate::HandPatternAnalyzer::analyze_patterns)
+/// for consistent results across games.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::analytics::{classify_hand, HandArchetype};
+/// use datongzi_rules::{Card, HandPatternAnalyzer, Rank, Suit};
+///
+/// let hand: Vec<Card> = [Rank::Five, Rank::Six, Rank::Seven, Rank::Eight]
+///     .iter()
+///     .flat_map(|r| [Card::new(Suit::Spades, *r), Card::new(Suit::Hearts, *r)])
+///     .chain([Card::new(Suit::Clubs, Rank::Jack)])
+///     .collect();
+///
+/// let patterns = HandPatternAnalyzer::analyze_patterns(&hand);
+/// assert_eq!(classify_hand(&patterns), HandArchetype::ChainHeavy);
+/// ```
+#[must_use]
+pub fn classify_hand(patterns: &HandPatterns) -> HandArchetype {
+    let trump_cards =
+        cards_in(&patterns.dizha) + cards_in(&patterns.tongzi) + cards_in(&patterns.bombs);
+    let chain_cards =
+        cards_in(&patterns.airplane_chains) + cards_in(&patterns.consecutive_pair_chains);
+    let total = trump_cards
+        + chain_cards
+        + cards_in(&patterns.triples)
+        + cards_in(&patterns.pairs)
+        + patterns.singles.len();
+    if total == 0 {
+        return HandArchetype::Balanced;
+    }
+
+    let control_cards = [
+        &patterns.dizha,
+        &patterns.tongzi,
+        &patterns.bombs,
+        &patterns.airplane_chains,
+        &patterns.triples,
+        &patterns.consecutive_pair_chains,
+        &patterns.pairs,
+    ]
+    .iter()
+    .flat_map(|groups| groups.iter().flatten())
+    .chain(&patterns.singles)
+    .filter(|c| matches!(c.rank, Rank::Two | Rank::Ace))
+    .count();
+
+    // Integer percentages keep the thresholds exact
+    let share = |cards: usize| cards * 100 / total;
+    let trumps = patterns.dizha.len() + patterns.tongzi.len() + patterns.bombs.len();
+
+    if trumps >= 3 || share(trump_cards) >= 30 {
+        HandArchetype::BombHeavy
+    } else if share(chain_cards) >= 40 {
+        HandArchetype::ChainHeavy
+    } else if share(control_cards) >= 25 {
+        HandArchetype::Control
+    } else if share(patterns.singles.len()) >= 40 {
+        HandArchetype::Scattered
+    } else {
+        HandArchetype::Balanced
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Suit;
+    use crate::HandPatternAnalyzer;
+
+    fn classify(hand: &[Card]) -> HandArchetype {
+        classify_hand(&HandPatternAnalyzer::analyze_patterns(hand))
+    }
+
+    #[test]
+    fn test_archetypes() {
+        let bombs: Vec<Card> = [Rank::Five, Rank::Nine, Rank::Queen]
+            .iter()
+            .flat_map(|r| Suit::ALL.map(|s| Card::new(s, *r)))
+            .chain([Card::new(Suit::Spades, Rank::Three)])
+            .collect();
+        assert_eq!(classify(&bombs), HandArchetype::BombHeavy);
+
+        let control = vec![
+            Card::new(Suit::Spades, Rank::Two),
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Spades, Rank::Six),
+            Card::new(Suit::Hearts, Rank::Six),
+            Card::new(Suit::Spades, Rank::Nine),
+            Card::new(Suit::Hearts, Rank::Nine),
+        ];
+        assert_eq!(classify(&control), HandArchetype::Control);
+
+        let scattered = vec![
+            Card::new(Suit::Spades, Rank::Three),
+            Card::new(Suit::Hearts, Rank::Six),
+            Card::new(Suit::Clubs, Rank::Nine),
+            Card::new(Suit::Spades, Rank::Queen),
+            Card::new(Suit::Spades, Rank::Jack),
+            Card::new(Suit::Hearts, Rank::Jack),
+        ];
+        assert_eq!(classify(&scattered), HandArchetype::Scattered);
+
+        let balanced = vec![
+            Card::new(Suit::Spades, Rank::Three),
+            Card::new(Suit::Hearts, Rank::Three),
+            Card::new(Suit::Spades, Rank::Eight),
+            Card::new(Suit::Hearts, Rank::Eight),
+            Card::new(Suit::Clubs, Rank::Eight),
+            Card::new(Suit::Spades, Rank::Jack),
+        ];
+        assert_eq!(classify(&balanced), HandArchetype::Balanced);
+        assert_eq!(classify(&[]), HandArchetype::Balanced);
+    }
+}
@@ -0,0 +1,152 @@
+//! Structural comparison of two decomposed hands.
+
+use std::cmp::Ordering;
+
+use crate::ai_helpers::HandPatterns;
+
+/// Which of two hands is ahead on a criterion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ahead {
+    /// The first hand
+    A,
+    /// The second hand
+    B,
+    /// Neither
+    Even,
+}
+
+impl Ahead {
+    fn from_ordering(ordering: Ordering) -> Self {
+        match ordering {
+            Ordering::Greater => Self::A,
+            Ordering::Less => Self::B,
+            Ordering::Equal => Self::Even,
+        }
+    }
+}
+
+/// Side-by-side structure of two hands, as `(a, b)` pairs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StructuralComparison {
+    /// Trump structures (dizha + tongzi + bombs); more is better
+    pub trumps: (usize, usize),
+    /// Cards in the longest airplane or consecutive-pair chain; longer is better
+    pub longest_chain: (usize, usize),
+    /// Orphan singles; fewer is better
+    pub orphans: (usize, usize),
+}
+
+impl StructuralComparison {
+    /// Which hand has more trumps
+    #[must_use]
+    pub fn trumps_ahead(&self) -> Ahead {
+        Ahead::from_ordering(self.trumps.0.cmp(&self.trumps.1))
+    }
+
+    /// Which hand has the longer chain
+    #[must_use]
+    pub fn chain_ahead(&self) -> Ahead {
+        Ahead::from_ordering(self.longest_chain.0.cmp(&self.longest_chain.1))
+    }
+
+    /// Which hand has fewer orphans
+    #[must_use]
+    pub fn orphans_ahead(&self) -> Ahead {
+        Ahead::from_ordering(self.orphans.1.cmp(&self.orphans.0))
+    }
+
+    /// Which hand leads on more of the three criteria
+    #[must_use]
+    pub fn overall(&self) -> Ahead {
+        let lead: i32 = [
+            self.trumps_ahead(),
+            self.chain_ahead(),
+            self.orphans_ahead(),
+        ]
+        .iter()
+        .map(|ahead| match ahead {
+            Ahead::A => 1,
+            Ahead::B => -1,
+            Ahead::Even => 0,
+        })
+        .sum();
+        Ahead::from_ordering(lead.cmp(&0))
+    }
+}
+
+fn longest_chain(patterns: &HandPatterns) -> usize {
+    patterns
+        .airplane_chains
+        .iter()
+        .chain(&patterns.consecutive_pair_chains)
+        .map(Vec::len)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Compare two decomposed hands for spectator and commentary features.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::analytics::{compare_hands, Ahead};
+/// use datongzi_rules::{Card, HandPatternAnalyzer, Rank, Suit};
+///
+/// let chains: Vec<Card> = [Rank::Five, Rank::Six, Rank::Seven]
+///     .iter()
+///     .flat_map(|r| [Card::new(Suit::Spades, *r), Card::new(Suit::Hearts, *r)])
+///     .collect();
+/// let scattered = vec![
+///     Card::new(Suit::Spades, Rank::Five),
+///     Card::new(Suit::Hearts, Rank::Nine),
+///     Card::new(Suit::Clubs, Rank::King),
+/// ];
+///
+/// let comparison = compare_hands(
+///     &HandPatternAnalyzer::analyze_patterns(&chains),
+///     &HandPatternAnalyzer::analyze_patterns(&scattered),
+/// );
+/// assert_eq!(comparison.longest_chain, (6, 0));
+/// assert_eq!(comparison.orphans_ahead(), Ahead::A);
+/// assert_eq!(comparison.overall(), Ahead::A);
+/// ```
+#[must_use]
+pub fn compare_hands(a: &HandPatterns, b: &HandPatterns) -> StructuralComparison {
+    StructuralComparison {
+        trumps: (a.trump_count, b.trump_count),
+        longest_chain: (longest_chain(a), longest_chain(b)),
+        orphans: (a.singles.len(), b.singles.len()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Card, Rank, Suit};
+    use crate::HandPatternAnalyzer;
+
+    #[test]
+    fn test_trumps_against_orphans() {
+        let bomb: Vec<Card> = Suit::ALL
+            .iter()
+            .map(|s| Card::new(*s, Rank::Nine))
+            .chain([
+                Card::new(Suit::Spades, Rank::Five),
+                Card::new(Suit::Hearts, Rank::Jack),
+            ])
+            .collect();
+        let pairs = vec![
+            Card::new(Suit::Spades, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::Ace),
+            Card::new(Suit::Spades, Rank::Six),
+        ];
+        let comparison = compare_hands(
+            &HandPatternAnalyzer::analyze_patterns(&bomb),
+            &HandPatternAnalyzer::analyze_patterns(&pairs),
+        );
+
+        assert_eq!(comparison.trumps_ahead(), Ahead::A);
+        assert_eq!(comparison.chain_ahead(), Ahead::Even);
+        assert_eq!(comparison.orphans_ahead(), Ahead::B);
+        assert_eq!(comparison.overall(), Ahead::Even);
+    }
+}
@@ -0,0 +1,17 @@
+//! Hand-level analytics for matchmaking and bot tuning.
+//!
+//! This module contains:
+//! - [`classify_hand`]: Sort a decomposed hand into a [`HandArchetype`]
+//! - [`compare_hands`]: Which of two hands is ahead structurally
+//! - [`score_card_flow`]: Who was dealt and who captured each scoring card
+//! - [`trick_value`]: Points a trick would hold if a candidate play won it
+
+mod archetype;
+mod hand_comparison;
+mod score_flow;
+mod trick_value;
+
+pub use archetype::{classify_hand, HandArchetype};
+pub use hand_comparison::{compare_hands, Ahead, StructuralComparison};
+pub use score_flow::{score_card_flow, ScoreCardFlow};
+pub use trick_value::{trick_value, TrickValue};
@@ -0,0 +1,188 @@
+//! Where each scoring card started and ended up.
+
+use crate::error::Result;
+use crate::models::{Card, GameConfig};
+use crate::scoring::{replay, GameRecord, RecordedAction, ReplayStep};
+
+/// Journey of one scoring card (5, 10 or K) through a game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoreCardFlow {
+    /// The card
+    pub card: Card,
+    /// Player dealt the card
+    pub dealt_to: String,
+    /// Player who captured it by winning its round (`None` if never played)
+    pub captured_by: Option<String>,
+    /// 1-based round it was captured in
+    pub round: Option<usize>,
+}
+
+impl ScoreCardFlow {
+    /// Points the card is worth
+    #[must_use]
+    pub fn points(&self) -> i32 {
+        self.card.score_value()
+    }
+}
+
+/// Trace every scoring card of a recorded game, replayed under `config`,
+/// from dealer to captor.
+///
+/// Played cards are listed in play order, then cards still in hand at the
+/// end of the record in seat order. Cards cannot change hands before being
+/// played, so the player who plays a card is the one it was dealt to.
+///
+/// # Errors
+///
+/// Returns the same errors as [`score_record`](crate::scoring::score_record)
+/// for an inconsistent record
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use datongzi_rules::analytics::score_card_flow;
+/// use datongzi_rules::scoring::{GameRecord, RecordedAction};
+/// use datongzi_rules::{Card, GameConfig, Rank, Suit};
+///
+/// let king = Card::new(Suit::Spades, Rank::King);
+/// let two = Card::new(Suit::Hearts, Rank::Two);
+/// let ten = Card::new(Suit::Clubs, Rank::Ten);
+/// let record = GameRecord {
+///     player_ids: vec!["a".to_string(), "b".to_string()],
+///     hands: vec![vec![king], vec![two, ten]],
+///     actions: vec![
+///         RecordedAction::Play { player_id: "a".to_string(), cards: vec![king] },
+///         RecordedAction::Play { player_id: "b".to_string(), cards: vec![two] },
+///     ],
+///     reveals: Vec::new(),
+///     shuffle: None,
+///     annotations: BTreeMap::new(),
+///     aside: Vec::new(),
+/// };
+///
+/// let flow = score_card_flow(&record, &GameConfig::default()).unwrap();
+/// assert_eq!(flow[0].dealt_to, "a");
+/// assert_eq!(flow[0].captured_by.as_deref(), Some("b"));
+/// assert_eq!(flow[1].card, ten);
+/// assert_eq!(flow[1].captured_by, None);
+/// ```
+pub fn score_card_flow(record: &GameRecord, config: &GameConfig) -> Result<Vec<ScoreCardFlow>> {
+    let mut flows = Vec::new();
+    let mut pending: Vec<ScoreCardFlow> = Vec::new();
+    let mut hands = record.hands.clone();
+
+    replay(record, config, |step| match step {
+        ReplayStep::Action {
+            action: RecordedAction::Play { player_id, cards },
+            seat,
+            ..
+        } => {
+            for card in cards {
+                if let Some(position) = hands[seat].iter().position(|c| c == card) {
+                    hands[seat].swap_remove(position);
+                }
+            }
+            pending.extend(
+                cards
+                    .iter()
+                    .filter(|card| card.score_value() > 0)
+                    .map(|card| ScoreCardFlow {
+                        card: *card,
+                        dealt_to: player_id.clone(),
+                        captured_by: None,
+                        round: None,
+                    }),
+            );
+        }
+        ReplayStep::Action { .. } => {}
+        ReplayStep::RoundClosed {
+            round,
+            round_number,
+        } => {
+            let winner = &record.player_ids[round.leader];
+            flows.extend(pending.drain(..).map(|flow| ScoreCardFlow {
+                captured_by: Some(winner.clone()),
+                round: Some(round_number),
+                ..flow
+            }));
+        }
+    })?;
+
+    for (player_id, hand) in record.player_ids.iter().zip(&hands) {
+        flows.extend(
+            hand.iter()
+                .filter(|card| card.score_value() > 0)
+                .map(|card| ScoreCardFlow {
+                    card: *card,
+                    dealt_to: player_id.clone(),
+                    captured_by: None,
+                    round: None,
+                }),
+        );
+    }
+    Ok(flows)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::models::{Rank, Suit};
+
+    #[test]
+    fn test_every_scoring_card_is_traced_once() {
+        let five = Card::new(Suit::Spades, Rank::Five);
+        let king = Card::new(Suit::Hearts, Rank::King);
+        let ace = Card::new(Suit::Clubs, Rank::Ace);
+        let six = Card::new(Suit::Clubs, Rank::Six);
+        let record = GameRecord {
+            player_ids: vec!["a".into(), "b".into(), "c".into()],
+            hands: vec![vec![five, six], vec![king], vec![ace, five]],
+            actions: vec![
+                RecordedAction::Play {
+                    player_id: "a".into(),
+                    cards: vec![five],
+                },
+                RecordedAction::Play {
+                    player_id: "b".into(),
+                    cards: vec![king],
+                },
+                RecordedAction::Play {
+                    player_id: "c".into(),
+                    cards: vec![ace],
+                },
+                RecordedAction::Pass {
+                    player_id: "a".into(),
+                },
+            ],
+            reveals: Vec::new(),
+            shuffle: None,
+            annotations: BTreeMap::new(),
+            aside: Vec::new(),
+        };
+
+        let flow = score_card_flow(&record, &GameConfig::default()).unwrap();
+        let summary: Vec<_> = flow
+            .iter()
+            .map(|f| {
+                (
+                    f.card,
+                    f.dealt_to.as_str(),
+                    f.captured_by.as_deref(),
+                    f.round,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (five, "a", Some("c"), Some(1)),
+                (king, "b", Some("c"), Some(1)),
+                (five, "c", None, None),
+            ]
+        );
+        assert_eq!(flow.iter().map(ScoreCardFlow::points).sum::<i32>(), 20);
+    }
+}
@@ -0,0 +1,106 @@
+//! Points riding on a trick before committing a play.
+
+use crate::models::Card;
+use crate::patterns::kicker_rules::split_kickers;
+use crate::patterns::PatternRecognizer;
+
+/// Scoring points a trick would hold if a candidate play won it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TrickValue {
+    /// Points already in the trick
+    pub at_stake: i32,
+    /// Points in the candidate's main structure
+    pub play_points: i32,
+    /// Points in the candidate's kickers or wings, which a cheaper kicker
+    /// choice could keep in hand
+    pub kicker_points: i32,
+}
+
+impl TrickValue {
+    /// Everything the winner of the trick would collect
+    #[must_use]
+    pub const fn total(&self) -> i32 {
+        self.at_stake + self.play_points + self.kicker_points
+    }
+}
+
+fn points(cards: &[Card]) -> i32 {
+    cards.iter().map(Card::score_value).sum()
+}
+
+/// Value of winning the current trick with `my_candidate_play`.
+///
+/// A trick with nothing at stake is often better conceded than beaten with
+/// strong cards; comparing [`TrickValue::total`] across candidates (and a
+/// pass) lets a strategy weigh that. An unrecognized candidate counts all
+/// its points as `play_points`.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::analytics::trick_value;
+/// use datongzi_rules::{Card, Rank, Suit};
+///
+/// let trick = [Card::new(Suit::Spades, Rank::Ten), Card::new(Suit::Hearts, Rank::Ten)];
+/// let play = [
+///     Card::new(Suit::Spades, Rank::Queen),
+///     Card::new(Suit::Hearts, Rank::Queen),
+///     Card::new(Suit::Clubs, Rank::Queen),
+///     Card::new(Suit::Clubs, Rank::King),
+/// ];
+///
+/// let value = trick_value(&trick, &play);
+/// assert_eq!((value.at_stake, value.play_points, value.kicker_points), (20, 0, 10));
+/// assert_eq!(value.total(), 30);
+/// ```
+#[must_use]
+pub fn trick_value(current_trick_cards: &[Card], my_candidate_play: &[Card]) -> TrickValue {
+    let at_stake = points(current_trick_cards);
+    let Some(pattern) = PatternRecognizer::analyze_cards(my_candidate_play) else {
+        return TrickValue {
+            at_stake,
+            play_points: points(my_candidate_play),
+            kicker_points: 0,
+        };
+    };
+
+    let (main, kickers) = split_kickers(my_candidate_play, &pattern);
+
+    TrickValue {
+        at_stake,
+        play_points: points(&main),
+        kicker_points: points(&kickers),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+
+    #[test]
+    fn test_airplane_wings_are_kickers() {
+        let mut play: Vec<Card> = [Rank::Five, Rank::Six]
+            .iter()
+            .flat_map(|r| [Suit::Spades, Suit::Hearts, Suit::Clubs].map(|s| Card::new(s, *r)))
+            .collect();
+        play.extend([
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Spades, Rank::Nine),
+        ]);
+
+        let value = trick_value(&[], &play);
+        assert_eq!(value.at_stake, 0);
+        assert_eq!(value.play_points, 15);
+        assert_eq!(value.kicker_points, 10);
+    }
+
+    #[test]
+    fn test_empty_trick_and_plain_plays() {
+        let tens = [
+            Card::new(Suit::Spades, Rank::Ten),
+            Card::new(Suit::Hearts, Rank::Ten),
+        ];
+        assert_eq!(trick_value(&[], &tens).total(), 20);
+        assert_eq!(trick_value(&tens, &[]).total(), 20);
+    }
+}
@@ -0,0 +1,16 @@
+//! Post-game auditing helpers.
+//!
+//! This module checks recorded decisions against the rules, e.g. for
+//! anti-collusion review:
+//! - [`pass_was_forced`]: Whether a single pass was unavoidable
+//! - [`audit_passes`]: Flag every pass made while holding a beating play
+//!
+//! Both have `_with_config` variants that judge passes under a
+//! [`GameConfig`](crate::GameConfig)'s rule options.
+
+mod pass_audit;
+
+pub use pass_audit::{
+    audit_passes, audit_passes_with_config, pass_was_forced, pass_was_forced_with_config,
+    PassObservation, PassPolicy, PassViolation,
+};
@@ -0,0 +1,276 @@
+//! Pass auditing under must-play rules (有牌必打).
+
+use crate::ai_helpers::{GenerationOptions, PlayGenerator};
+use crate::models::{Card, GameConfig};
+use crate::patterns::{PatternRecognizer, PlayPattern, PlayType, PlayValidator};
+
+/// Which beating plays oblige a player to play instead of passing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PassPolicy {
+    /// Standard 有牌必打: any beating play (same type or trump) obliges.
+    MustPlayIfCanBeat,
+    /// Only same-type beating plays oblige; holding back trumps is allowed.
+    MustFollowSameType,
+    /// Passing is always allowed.
+    Voluntary,
+}
+
+/// A pass recorded during a game, with the passer's hand at that moment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassObservation {
+    /// Player who passed
+    pub player_id: String,
+    /// Index of the turn in the game log (caller-defined)
+    pub turn_index: usize,
+    /// The passer's full hand when passing
+    pub hand: Vec<Card>,
+    /// The pattern the player declined to beat
+    pub current_pattern: PlayPattern,
+}
+
+/// A pass that broke the policy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassViolation {
+    /// Player who passed
+    pub player_id: String,
+    /// Index of the turn in the game log
+    pub turn_index: usize,
+    /// One play the player could have used instead (evidence)
+    pub available_play: Vec<Card>,
+}
+
+/// Returns true if passing was the only legal choice under `policy`.
+///
+/// A pass is forced when the hand holds no play that the policy obliges the
+/// player to make. Under [`PassPolicy::Voluntary`] every pass is allowed, so
+/// this reports whether the player could not have beaten the pattern at all.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::audit::{pass_was_forced, PassPolicy};
+/// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+///
+/// let king = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::King)]).unwrap();
+/// let hand = vec![Card::new(Suit::Hearts, Rank::Ace)];
+/// assert!(!pass_was_forced(&hand, &king, PassPolicy::MustPlayIfCanBeat));
+/// ```
+#[must_use]
+pub fn pass_was_forced(
+    observed_hand: &[Card],
+    current_pattern: &PlayPattern,
+    policy: PassPolicy,
+) -> bool {
+    pass_was_forced_with_config(
+        observed_hand,
+        current_pattern,
+        policy,
+        &GameConfig::default(),
+    )
+}
+
+/// Like [`pass_was_forced`], counting only the beating plays `config` allows
+/// (comparison options, forbidden kickers, finish restrictions; see
+/// [`PlayValidator::validate_play_in_hand`]).
+///
+/// # Example
+/// ```
+/// use datongzi_rules::audit::{pass_was_forced_with_config, PassPolicy};
+/// use datongzi_rules::{Card, GameConfig, PatternRecognizer, Rank, Suit};
+///
+/// let king = PatternRecognizer::analyze_cards(&[Card::new(Suit::Hearts, Rank::King)]).unwrap();
+/// let hand = vec![Card::new(Suit::Spades, Rank::King)];
+/// let config = GameConfig { singles_suit_tiebreak: true, ..GameConfig::default() };
+///
+/// // K♠ only beats K♥ with the suit tiebreak
+/// assert!(!pass_was_forced_with_config(&hand, &king, PassPolicy::MustPlayIfCanBeat, &config));
+/// assert!(pass_was_forced_with_config(
+///     &hand,
+///     &king,
+///     PassPolicy::MustPlayIfCanBeat,
+///     &GameConfig::default()
+/// ));
+/// ```
+#[must_use]
+pub fn pass_was_forced_with_config(
+    observed_hand: &[Card],
+    current_pattern: &PlayPattern,
+    policy: PassPolicy,
+    config: &GameConfig,
+) -> bool {
+    let policy = match policy {
+        PassPolicy::Voluntary => PassPolicy::MustPlayIfCanBeat,
+        other => other,
+    };
+    find_obliged_play(observed_hand, current_pattern, policy, config).is_none()
+}
+
+/// Flag every pass made while the policy obliged the player to play.
+///
+/// Violations are returned in input order. Under [`PassPolicy::Voluntary`]
+/// nothing is ever flagged.
+#[must_use]
+pub fn audit_passes(observations: &[PassObservation], policy: PassPolicy) -> Vec<PassViolation> {
+    audit_passes_with_config(observations, policy, &GameConfig::default())
+}
+
+/// Like [`audit_passes`], judging each pass under `config` (see
+/// [`pass_was_forced_with_config`]).
+#[must_use]
+pub fn audit_passes_with_config(
+    observations: &[PassObservation],
+    policy: PassPolicy,
+    config: &GameConfig,
+) -> Vec<PassViolation> {
+    if policy == PassPolicy::Voluntary {
+        return Vec::new();
+    }
+
+    observations
+        .iter()
+        .filter_map(|obs| {
+            let available_play =
+                find_obliged_play(&obs.hand, &obs.current_pattern, policy, config)?;
+            Some(PassViolation {
+                player_id: obs.player_id.clone(),
+                turn_index: obs.turn_index,
+                available_play,
+            })
+        })
+        .collect()
+}
+
+/// First legal beating play under `config` that the policy would have required.
+fn find_obliged_play(
+    hand: &[Card],
+    current_pattern: &PlayPattern,
+    policy: PassPolicy,
+    config: &GameConfig,
+) -> Option<Vec<Card>> {
+    if policy == PassPolicy::Voluntary {
+        return None;
+    }
+
+    PlayGenerator::generate_beating_plays_with_options(
+        hand,
+        current_pattern,
+        &GenerationOptions::for_config(config),
+    )
+    .into_iter()
+    .filter(|play| {
+        PlayValidator::validate_play_in_hand(play, Some(current_pattern), hand.len(), config)
+            .is_ok()
+    })
+    .find(|play| match policy {
+        PassPolicy::MustFollowSameType => PatternRecognizer::analyze_cards(play)
+            .is_some_and(|p| is_same_family(p.play_type, current_pattern.play_type)),
+        _ => true,
+    })
+}
+
+/// Airplane and airplane-with-wings beat each other, so they count as one family.
+fn is_same_family(a: PlayType, b: PlayType) -> bool {
+    let is_airplane = |t| matches!(t, PlayType::Airplane | PlayType::AirplaneWithWings);
+    a == b || (is_airplane(a) && is_airplane(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{FinishRestrictions, Rank, Suit};
+
+    fn single(rank: Rank) -> PlayPattern {
+        PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, rank)]).unwrap()
+    }
+
+    fn bomb_hand() -> Vec<Card> {
+        Suit::ALL.iter().map(|s| Card::new(*s, Rank::Six)).collect()
+    }
+
+    #[test]
+    fn test_pass_forced_without_beating_play() {
+        let hand = vec![Card::new(Suit::Hearts, Rank::Five)];
+        assert!(pass_was_forced(
+            &hand,
+            &single(Rank::King),
+            PassPolicy::MustPlayIfCanBeat
+        ));
+    }
+
+    #[test]
+    fn test_trump_only_obliges_under_strict_policy() {
+        let hand = bomb_hand();
+        let king = single(Rank::King);
+        assert!(!pass_was_forced(
+            &hand,
+            &king,
+            PassPolicy::MustPlayIfCanBeat
+        ));
+        assert!(pass_was_forced(
+            &hand,
+            &king,
+            PassPolicy::MustFollowSameType
+        ));
+    }
+
+    #[test]
+    fn test_audit_respects_config_rules() {
+        let observations = vec![PassObservation {
+            player_id: "alice".to_string(),
+            turn_index: 0,
+            hand: vec![Card::new(Suit::Hearts, Rank::King)],
+            current_pattern: single(Rank::Queen),
+        }];
+        assert_eq!(
+            audit_passes(&observations, PassPolicy::MustPlayIfCanBeat).len(),
+            1
+        );
+
+        // Finishing on a single K is forbidden, so the pass was allowed
+        let config = GameConfig {
+            finish_restrictions: FinishRestrictions {
+                no_single_score_card: true,
+                ..FinishRestrictions::default()
+            },
+            ..GameConfig::default()
+        };
+        assert!(
+            audit_passes_with_config(&observations, PassPolicy::MustPlayIfCanBeat, &config)
+                .is_empty()
+        );
+        assert!(pass_was_forced_with_config(
+            &observations[0].hand,
+            &observations[0].current_pattern,
+            PassPolicy::MustPlayIfCanBeat,
+            &config
+        ));
+    }
+
+    #[test]
+    fn test_audit_passes_flags_violations_in_order() {
+        let observations = vec![
+            PassObservation {
+                player_id: "alice".to_string(),
+                turn_index: 3,
+                hand: vec![Card::new(Suit::Hearts, Rank::Five)],
+                current_pattern: single(Rank::King),
+            },
+            PassObservation {
+                player_id: "bob".to_string(),
+                turn_index: 4,
+                hand: vec![Card::new(Suit::Hearts, Rank::Ace)],
+                current_pattern: single(Rank::King),
+            },
+        ];
+
+        let violations = audit_passes(&observations, PassPolicy::MustPlayIfCanBeat);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].player_id, "bob");
+        assert_eq!(violations[0].turn_index, 4);
+        assert_eq!(
+            violations[0].available_play,
+            vec![Card::new(Suit::Hearts, Rank::Ace)]
+        );
+
+        assert!(audit_passes(&observations, PassPolicy::Voluntary).is_empty());
+    }
+}
@@ -0,0 +1,192 @@
+//! Bid evaluation for the aside-pile auction.
+
+use crate::ai_helpers::HandPatternAnalyzer;
+use crate::models::{Card, GameConfig, Rank, Suit};
+
+/// Strength at which a hand is worth bidding for.
+const BID_THRESHOLD: f32 = 3.0;
+/// Strength of each bomb.
+const BOMB_VALUE: f32 = 1.0;
+/// Strength of each Tongzi.
+const TONGZI_VALUE: f32 = 1.5;
+/// Strength of each Dizha.
+const DIZHA_VALUE: f32 = 2.5;
+/// Strength of each 2 (control card).
+const TWO_VALUE: f32 = 0.3;
+/// Strength of each Ace (control card).
+const ACE_VALUE: f32 = 0.2;
+/// Weight of hand structure (1 − plays per card).
+const STRUCTURE_WEIGHT: f32 = 2.0;
+/// Highest multiple of the expected aside points worth bidding.
+const MAX_BID_FACTOR: f32 = 2.0;
+/// Bids are made in steps of this many points.
+const BID_STEP: i32 = 5;
+
+/// Bid advice returned by [`evaluate_bid`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BidRecommendation {
+    /// Whether to bid (always false when the config disables bidding)
+    pub declare: bool,
+    /// Hand strength; 3.0 or more is worth bidding
+    pub strength: f32,
+    /// Expected score points (5/10/K) in the aside pile
+    pub expected_aside_points: f32,
+    /// Highest bid worth making, in points (0 when not declaring)
+    pub max_bid: i32,
+}
+
+/// Evaluate whether `hand` is worth bidding for the aside pile.
+///
+/// Strength adds up:
+/// - Trumps (from [`HandPatternAnalyzer::analyze_patterns`]): 1.0 per bomb,
+///   1.5 per Tongzi, 2.5 per Dizha
+/// - Control cards: 0.3 per 2, 0.2 per Ace
+/// - Structure: 2 × (1 − plays per card), using
+///   [`HandPatternAnalyzer::optimize_for_fewest_plays`]
+///
+/// The aside is assumed to be drawn evenly from the cards not in `hand`, so
+/// its expected points are the unseen points per card × `cards_dealt_aside`.
+/// A declaring hand may bid up to that value scaled by strength / 3.0
+/// (at most ×2), rounded down to a multiple of 5.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::bidding::evaluate_bid;
+/// use datongzi_rules::{Card, GameConfig, Rank, Suit};
+///
+/// let config = GameConfig {
+///     allow_bidding: true,
+///     ..GameConfig::default()
+/// };
+/// let hand = vec![
+///     Card::new(Suit::Hearts, Rank::Six),
+///     Card::new(Suit::Clubs, Rank::Nine),
+///     Card::new(Suit::Spades, Rank::Jack),
+/// ];
+///
+/// let advice = evaluate_bid(&hand, &config);
+/// assert!(!advice.declare);
+/// assert_eq!(advice.max_bid, 0);
+/// ```
+#[must_use]
+pub fn evaluate_bid(hand: &[Card], config: &GameConfig) -> BidRecommendation {
+    let patterns = HandPatternAnalyzer::analyze_patterns(hand);
+    let trumps = patterns.bombs.len() as f32 * BOMB_VALUE
+        + patterns.tongzi.len() as f32 * TONGZI_VALUE
+        + patterns.dizha.len() as f32 * DIZHA_VALUE;
+
+    let control: f32 = hand
+        .iter()
+        .map(|card| match card.rank {
+            Rank::Two => TWO_VALUE,
+            Rank::Ace => ACE_VALUE,
+            _ => 0.0,
+        })
+        .sum();
+
+    let structure = if hand.is_empty() {
+        0.0
+    } else {
+        let plays = HandPatternAnalyzer::optimize_for_fewest_plays(hand).play_count();
+        STRUCTURE_WEIGHT * (1.0 - plays as f32 / hand.len() as f32)
+    };
+
+    let strength = trumps + control + structure;
+    let expected_aside_points = expected_aside_points(hand, config);
+    let declare = config.allow_bidding() && strength >= BID_THRESHOLD;
+
+    let max_bid = if declare {
+        let value = expected_aside_points * (strength / BID_THRESHOLD).min(MAX_BID_FACTOR);
+        value as i32 / BID_STEP * BID_STEP
+    } else {
+        0
+    };
+
+    BidRecommendation {
+        declare,
+        strength,
+        expected_aside_points,
+        max_bid,
+    }
+}
+
+/// Expected points in the aside, drawn evenly from the cards not in `hand`.
+fn expected_aside_points(hand: &[Card], config: &GameConfig) -> f32 {
+    let (unseen_cards, unseen_points) = Rank::ALL
+        .iter()
+        .filter(|rank| !config.is_rank_removed(**rank))
+        .fold((0, 0), |(cards, points), rank| {
+            let held = hand.iter().filter(|c| c.rank == *rank).count() as i32;
+            let unseen = (config.copies_of_rank(*rank) as i32 - held).max(0);
+            let score = Card::new(Suit::Spades, *rank).score_value();
+            (cards + unseen, points + unseen * score)
+        });
+
+    if unseen_cards == 0 {
+        return 0.0;
+    }
+    unseen_points as f32 / unseen_cards as f32 * config.cards_dealt_aside() as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bidding_config() -> GameConfig {
+        GameConfig {
+            allow_bidding: true,
+            ..GameConfig::default()
+        }
+    }
+
+    fn bomb(rank: Rank) -> Vec<Card> {
+        Suit::ALL.iter().map(|s| Card::new(*s, rank)).collect()
+    }
+
+    fn strong_hand() -> Vec<Card> {
+        let mut hand = bomb(Rank::Seven);
+        hand.extend(bomb(Rank::Jack));
+        hand.extend(vec![Card::new(Suit::Spades, Rank::Two); 3]);
+        hand.push(Card::new(Suit::Hearts, Rank::Ace));
+        hand
+    }
+
+    #[test]
+    fn test_strong_hand_declares() {
+        let advice = evaluate_bid(&strong_hand(), &bidding_config());
+        assert!(advice.declare);
+        assert!(advice.strength >= BID_THRESHOLD);
+        assert!(advice.max_bid > 0);
+        assert_eq!(advice.max_bid % BID_STEP, 0);
+    }
+
+    #[test]
+    fn test_weak_hand_passes() {
+        let hand = vec![
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Clubs, Rank::Eight),
+            Card::new(Suit::Spades, Rank::Jack),
+            Card::new(Suit::Diamonds, Rank::Queen),
+        ];
+        let advice = evaluate_bid(&hand, &bidding_config());
+        assert!(!advice.declare);
+        assert_eq!(advice.max_bid, 0);
+    }
+
+    #[test]
+    fn test_disabled_bidding_never_declares() {
+        let advice = evaluate_bid(&strong_hand(), &GameConfig::default());
+        assert!(!advice.declare);
+        assert_eq!(
+            advice.strength,
+            evaluate_bid(&strong_hand(), &bidding_config()).strength
+        );
+    }
+
+    #[test]
+    fn test_expected_aside_points() {
+        // 132-card deck holds 300 points; four 5s in hand leave 280 over 128 cards
+        let advice = evaluate_bid(&bomb(Rank::Five), &bidding_config());
+        assert!((advice.expected_aside_points - 280.0 / 128.0 * 9.0).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,211 @@
+//! Aside-pile exchange for the bid winner.
+
+use crate::error::{DatongziError, Result};
+use crate::i18n::Message;
+use crate::models::{Card, DiscardScoring, GameConfig};
+
+/// Outcome of a validated aside exchange.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsideExchange {
+    /// The bid winner's hand after taking the aside and discarding
+    pub hand: Vec<Card>,
+    /// Cards put back as the new aside
+    pub discarded: Vec<Card>,
+    /// Points (5/10/K) in the discards credited to the bid winner
+    pub credited_points: i32,
+}
+
+/// Take the aside into `hand` and discard the same number of cards.
+///
+/// Validation:
+/// - Bidding must be enabled ([`GameConfig::allow_bidding`])
+/// - `aside` must hold exactly `cards_dealt_aside` cards
+/// - `discard` must be the same size and come from the merged hand
+/// - Under [`DiscardScoring::Forbidden`], no scoring card may be discarded
+///
+/// Discarded points are credited to the bid winner under
+/// [`DiscardScoring::CreditBidder`] and score for nobody under
+/// [`DiscardScoring::Void`].
+///
+/// # Errors
+///
+/// Returns `ConfigError` if bidding is disabled, or `InvalidInput` if the
+/// aside or discard breaks a rule above.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::bidding::exchange_aside;
+/// use datongzi_rules::{Card, GameConfig, Rank, Suit};
+///
+/// let config = GameConfig {
+///     allow_bidding: true,
+///     cards_dealt_aside: 1,
+///     ..GameConfig::default()
+/// };
+/// let hand = vec![Card::new(Suit::Spades, Rank::Six)];
+/// let aside = vec![Card::new(Suit::Hearts, Rank::Ace)];
+///
+/// let exchange = exchange_aside(&hand, &aside, &hand, &config).unwrap();
+/// assert_eq!(exchange.hand, aside);
+/// ```
+pub fn exchange_aside(
+    hand: &[Card],
+    aside: &[Card],
+    discard: &[Card],
+    config: &GameConfig,
+) -> Result<AsideExchange> {
+    if !config.allow_bidding() {
+        return Err(DatongziError::ConfigError(Message::ExchangeWithoutBidding));
+    }
+
+    if aside.len() != config.cards_dealt_aside() {
+        return Err(DatongziError::InvalidInput(Message::AsideSize {
+            got: aside.len(),
+            expected: config.cards_dealt_aside(),
+        }));
+    }
+
+    if discard.len() != aside.len() {
+        return Err(DatongziError::InvalidInput(Message::DiscardCount {
+            expected: aside.len(),
+            got: discard.len(),
+        }));
+    }
+
+    if config.discard_scoring() == DiscardScoring::Forbidden {
+        if let Some(card) = discard.iter().find(|c| c.is_scoring_card()) {
+            return Err(DatongziError::InvalidInput(Message::ScoringCardDiscard {
+                card: *card,
+            }));
+        }
+    }
+
+    let mut merged: Vec<Card> = hand.iter().chain(aside).copied().collect();
+    for card in discard {
+        let Some(index) = merged.iter().position(|c| c == card) else {
+            return Err(DatongziError::InvalidInput(Message::DiscardNotHeld {
+                card: *card,
+            }));
+        };
+        merged.swap_remove(index);
+    }
+
+    let credited_points = match config.discard_scoring() {
+        DiscardScoring::CreditBidder => discard.iter().map(Card::score_value).sum(),
+        DiscardScoring::Void | DiscardScoring::Forbidden => 0,
+    };
+
+    Ok(AsideExchange {
+        hand: merged,
+        discarded: discard.to_vec(),
+        credited_points,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+
+    fn config(discard_scoring: DiscardScoring) -> GameConfig {
+        GameConfig {
+            allow_bidding: true,
+            cards_dealt_aside: 2,
+            discard_scoring,
+            ..GameConfig::default()
+        }
+    }
+
+    fn hand() -> Vec<Card> {
+        vec![
+            Card::new(Suit::Spades, Rank::Six),
+            Card::new(Suit::Hearts, Rank::King),
+        ]
+    }
+
+    fn aside() -> Vec<Card> {
+        vec![
+            Card::new(Suit::Clubs, Rank::Ace),
+            Card::new(Suit::Diamonds, Rank::Ten),
+        ]
+    }
+
+    #[test]
+    fn test_exchange_credits_discarded_points() {
+        let discard = vec![
+            Card::new(Suit::Spades, Rank::Six),
+            Card::new(Suit::Diamonds, Rank::Ten),
+        ];
+        let exchange = exchange_aside(
+            &hand(),
+            &aside(),
+            &discard,
+            &config(DiscardScoring::CreditBidder),
+        )
+        .unwrap();
+
+        assert_eq!(exchange.hand.len(), 2);
+        assert!(exchange.hand.contains(&Card::new(Suit::Clubs, Rank::Ace)));
+        assert!(exchange.hand.contains(&Card::new(Suit::Hearts, Rank::King)));
+        assert_eq!(exchange.discarded, discard);
+        assert_eq!(exchange.credited_points, 10);
+    }
+
+    #[test]
+    fn test_void_discards_score_nothing() {
+        let discard = vec![
+            Card::new(Suit::Hearts, Rank::King),
+            Card::new(Suit::Diamonds, Rank::Ten),
+        ];
+        let exchange =
+            exchange_aside(&hand(), &aside(), &discard, &config(DiscardScoring::Void)).unwrap();
+        assert_eq!(exchange.credited_points, 0);
+    }
+
+    #[test]
+    fn test_forbidden_scoring_discard() {
+        let discard = vec![
+            Card::new(Suit::Spades, Rank::Six),
+            Card::new(Suit::Diamonds, Rank::Ten),
+        ];
+        let result = exchange_aside(
+            &hand(),
+            &aside(),
+            &discard,
+            &config(DiscardScoring::Forbidden),
+        );
+        assert!(matches!(result, Err(DatongziError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_rejects_invalid_exchanges() {
+        let config = config(DiscardScoring::CreditBidder);
+
+        // Discard not from the merged hand
+        let foreign = vec![
+            Card::new(Suit::Spades, Rank::Six),
+            Card::new(Suit::Spades, Rank::Two),
+        ];
+        assert!(exchange_aside(&hand(), &aside(), &foreign, &config).is_err());
+
+        // Same card discarded twice but held once
+        let twice = vec![Card::new(Suit::Spades, Rank::Six); 2];
+        assert!(exchange_aside(&hand(), &aside(), &twice, &config).is_err());
+
+        // Wrong discard count
+        assert!(exchange_aside(&hand(), &aside(), &hand()[..1], &config).is_err());
+
+        // Wrong aside size
+        assert!(exchange_aside(&hand(), &aside()[..1], &hand()[..1], &config).is_err());
+
+        // Bidding disabled
+        let disabled = GameConfig {
+            allow_bidding: false,
+            ..config
+        };
+        assert!(matches!(
+            exchange_aside(&hand(), &aside(), &hand(), &disabled),
+            Err(DatongziError::ConfigError(_))
+        ));
+    }
+}
@@ -0,0 +1,11 @@
+//! Bidding (叫分) support for variants that auction the aside pile.
+//!
+//! This module covers the auction and what the winner does with the aside:
+//! - [`evaluate_bid`]: Hand strength and the most worth bidding for the aside
+//! - [`exchange_aside`]: Validated take-and-discard of the aside by the bid winner
+
+mod evaluation;
+mod exchange;
+
+pub use evaluation::{evaluate_bid, BidRecommendation};
+pub use exchange::{exchange_aside, AsideExchange};
@@ -0,0 +1,262 @@
+//! Strongest response to a play, compared against the move made.
+
+use crate::ai_helpers::{remove_cards, GenerationOptions, HandPatternAnalyzer, PlayGenerator};
+use crate::analysis::beat_chain_with_config;
+use crate::error::{DatongziError, Result};
+use crate::i18n::Message;
+use crate::models::{Card, GameConfig};
+use crate::patterns::{PatternRecognizer, PlayPattern, PlayType, PlayValidator};
+
+/// How one response (or a pass) scores.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseEvaluation {
+    /// Cards played (empty for a pass)
+    pub cards: Vec<Card>,
+    /// Pattern of the cards (`None` for a pass)
+    pub pattern: Option<PlayPattern>,
+    /// Nothing in the unseen cards can beat it
+    pub holds: bool,
+    /// Spends a bomb, Tongzi or Dizha
+    pub uses_trump: bool,
+    /// Fewest plays needed to empty the rest of the hand
+    pub plays_left: usize,
+}
+
+impl ResponseEvaluation {
+    fn new(hand: &[Card], cards: &[Card], unseen_cards: &[Card], config: &GameConfig) -> Self {
+        let pattern = PatternRecognizer::analyze_cards(cards);
+        let rest = remove_cards(hand, cards);
+        Self {
+            cards: cards.to_vec(),
+            holds: pattern
+                .as_ref()
+                .is_some_and(|p| beat_chain_with_config(p, unseen_cards, config).is_unbeatable()),
+            uses_trump: pattern.as_ref().is_some_and(|p| {
+                matches!(
+                    p.play_type,
+                    PlayType::Bomb | PlayType::Tongzi | PlayType::Dizha
+                )
+            }),
+            plays_left: HandPatternAnalyzer::optimize_for_fewest_plays(&rest).play_count(),
+            pattern,
+        }
+    }
+
+    /// Returns true if this is a pass
+    #[must_use]
+    pub fn is_pass(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Sort key, smallest is strongest
+    fn rank_key(&self) -> (bool, bool, usize, u32) {
+        (
+            !self.holds,
+            self.uses_trump,
+            self.plays_left,
+            self.pattern.as_ref().map_or(0, |p| p.strength),
+        )
+    }
+}
+
+/// Result of [`best_response`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoachReview {
+    /// Strongest response available
+    pub best: ResponseEvaluation,
+    /// The move actually made
+    pub actual: ResponseEvaluation,
+    /// Teaching note comparing the two
+    pub note: Message,
+}
+
+/// Find the strongest response to `current_pattern` and compare it with
+/// `actual_move` (empty for a pass).
+///
+/// `unseen_cards` is everything the player could not see (all cards minus
+/// own hand and cards played), as in
+/// [`beat_chain`](crate::analysis::beat_chain).
+///
+/// Responses are ranked by, in order:
+/// 1. Holding: nothing in the unseen cards beats it
+/// 2. Not spending a trump
+/// 3. Fewest plays left to empty the hand
+/// 4. Lowest strength
+///
+/// A pass never holds and spends nothing.
+///
+/// # Errors
+///
+/// Returns [`DatongziError::PlayError`] if `actual_move` is not a legal
+/// response to `current_pattern`
+///
+/// # Example
+/// ```
+/// use datongzi_rules::coach::best_response;
+/// use datongzi_rules::i18n::Message;
+/// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+///
+/// let hand = vec![
+///     Card::new(Suit::Spades, Rank::Two),
+///     Card::new(Suit::Hearts, Rank::Seven),
+/// ];
+/// let current = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Five)]).unwrap();
+/// let unseen = vec![Card::new(Suit::Clubs, Rank::Ace)];
+///
+/// // The 7 can be overtaken by the unseen Ace; the 2 cannot
+/// let review = best_response(&hand, &current, &unseen, &hand[1..]).unwrap();
+/// assert_eq!(review.best.cards, vec![hand[0]]);
+/// assert!(matches!(review.note, Message::CoachMissedSafePlay { .. }));
+/// ```
+pub fn best_response(
+    hand: &[Card],
+    current_pattern: &PlayPattern,
+    unseen_cards: &[Card],
+    actual_move: &[Card],
+) -> Result<CoachReview> {
+    best_response_with_config(
+        hand,
+        current_pattern,
+        unseen_cards,
+        actual_move,
+        &GameConfig::default(),
+    )
+}
+
+/// Like [`best_response`], comparing plays with the options in `config`
+/// (see [`PlayValidator::can_beat_play_with_config`]).
+///
+/// # Errors
+///
+/// Returns [`DatongziError::PlayError`] if `actual_move` is not a legal
+/// response to `current_pattern` under `config`
+pub fn best_response_with_config(
+    hand: &[Card],
+    current_pattern: &PlayPattern,
+    unseen_cards: &[Card],
+    actual_move: &[Card],
+    config: &GameConfig,
+) -> Result<CoachReview> {
+    if !actual_move.is_empty()
+        && !PlayValidator::can_beat_play_with_config(actual_move, Some(current_pattern), config)
+    {
+        return Err(DatongziError::PlayError(
+            match PatternRecognizer::analyze_cards(actual_move) {
+                Some(pattern) => Message::CannotBeat {
+                    play_type: pattern.play_type,
+                    cards: actual_move.to_vec(),
+                    current: current_pattern.play_type,
+                },
+                None => Message::InvalidPattern {
+                    cards: actual_move.to_vec(),
+                },
+            },
+        ));
+    }
+
+    let actual = ResponseEvaluation::new(hand, actual_move, unseen_cards, config);
+    let options = GenerationOptions::for_config(config);
+    let best = PlayGenerator::generate_beating_plays_with_options(hand, current_pattern, &options)
+        .iter()
+        .map(|cards| ResponseEvaluation::new(hand, cards, unseen_cards, config))
+        .chain(std::iter::once(ResponseEvaluation::new(
+            hand,
+            &[],
+            unseen_cards,
+            config,
+        )))
+        .min_by_key(ResponseEvaluation::rank_key)
+        .unwrap_or_else(|| actual.clone());
+
+    let note = teaching_note(&best, &actual);
+    Ok(CoachReview { best, actual, note })
+}
+
+/// The most important difference between `best` and `actual`
+fn teaching_note(best: &ResponseEvaluation, actual: &ResponseEvaluation) -> Message {
+    if best.rank_key() >= actual.rank_key() {
+        Message::CoachAgreed
+    } else if best.is_pass() {
+        Message::CoachShouldPass {
+            played: actual.cards.clone(),
+        }
+    } else if actual.is_pass() {
+        Message::CoachShouldPlay {
+            best: best.cards.clone(),
+        }
+    } else if best.holds && !actual.holds {
+        Message::CoachMissedSafePlay {
+            best: best.cards.clone(),
+        }
+    } else if actual.uses_trump && !best.uses_trump {
+        Message::CoachWastedTrump {
+            best: best.cards.clone(),
+        }
+    } else if best.plays_left < actual.plays_left {
+        Message::CoachLeavesMorePlays {
+            best: best.cards.clone(),
+            best_left: best.plays_left,
+            actual_left: actual.plays_left,
+        }
+    } else {
+        Message::CoachCheaperPlay {
+            best: best.cards.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+
+    fn single(rank: Rank) -> PlayPattern {
+        PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, rank)]).unwrap()
+    }
+
+    #[test]
+    fn test_agrees_with_the_best_move() {
+        let hand = vec![
+            Card::new(Suit::Spades, Rank::Two),
+            Card::new(Suit::Hearts, Rank::Seven),
+        ];
+        let unseen = [Card::new(Suit::Clubs, Rank::Ace)];
+        let review = best_response(&hand, &single(Rank::Five), &unseen, &hand[..1]).unwrap();
+        assert_eq!(review.note, Message::CoachAgreed);
+
+        // With nothing left to fear, the lower card does the same job
+        let review = best_response(&hand, &single(Rank::Five), &[], &hand[..1]).unwrap();
+        assert!(matches!(review.note, Message::CoachCheaperPlay { .. }));
+    }
+
+    #[test]
+    fn test_flags_a_wasted_trump() {
+        // Five 9s, so a single 9 would leave 9999 + 2 (two plays)
+        let mut hand: Vec<Card> = Suit::ALL
+            .iter()
+            .map(|s| Card::new(*s, Rank::Nine))
+            .collect();
+        hand.push(Card::new(Suit::Spades, Rank::Nine));
+        hand.push(Card::new(Suit::Hearts, Rank::Two));
+        let bomb = hand[..4].to_vec();
+
+        let review = best_response(&hand, &single(Rank::Five), &[], &bomb).unwrap();
+        assert_eq!(review.best.cards, vec![Card::new(Suit::Hearts, Rank::Two)]);
+        assert!(review.actual.uses_trump);
+        assert!(matches!(review.note, Message::CoachWastedTrump { .. }));
+    }
+
+    #[test]
+    fn test_pass_versus_play() {
+        let hand = vec![Card::new(Suit::Hearts, Rank::Seven)];
+        let review = best_response(&hand, &single(Rank::Five), &[], &[]).unwrap();
+        assert!(review.actual.is_pass());
+        assert_eq!(review.note, Message::CoachShouldPlay { best: hand.clone() });
+    }
+
+    #[test]
+    fn test_rejects_illegal_move() {
+        let hand = vec![Card::new(Suit::Hearts, Rank::Four)];
+        assert!(best_response(&hand, &single(Rank::Five), &[], &hand).is_err());
+    }
+}
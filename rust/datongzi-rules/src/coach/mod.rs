@@ -0,0 +1,14 @@
+//! Post-game review helpers.
+//!
+//! This module contains:
+//! - [`best_response`]: The strongest answer to a play, with a teaching note
+//!   comparing it to the move actually made
+//! - [`review_game`]: Replay a finished game and flag clear mistakes
+
+mod best_response;
+mod review;
+
+pub use best_response::{
+    best_response, best_response_with_config, CoachReview, ResponseEvaluation,
+};
+pub use review::{review_game, ReviewItem};
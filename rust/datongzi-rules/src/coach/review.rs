@@ -0,0 +1,234 @@
+//! Mistake detection over a finished game.
+
+use super::best_response_with_config;
+use crate::error::Result;
+use crate::i18n::Message;
+use crate::models::{Card, GameConfig};
+use crate::scoring::{replay, GameRecord, PlayAnnotation, RecordedAction, ReplayStep};
+
+/// Points credited to a trump kept for a later trick.
+const TRUMP_POINTS: i32 = 25;
+/// Points credited to each play saved on the way out.
+const PLAY_POINTS: i32 = 5;
+/// Smallest estimated swing flagged as a mistake.
+const MISTAKE_THRESHOLD: i32 = 10;
+
+/// One flagged move.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewItem {
+    /// 1-based trick (round) number
+    pub trick: usize,
+    /// Index into [`GameRecord::actions`]
+    pub turn: usize,
+    /// Player who moved
+    pub player_id: String,
+    /// Cards played (empty for a pass)
+    pub played: Vec<Card>,
+    /// Stronger response (empty for a pass)
+    pub suggested: Vec<Card>,
+    /// Estimated points lost by the move
+    pub point_swing: i32,
+    /// Teaching note
+    pub note: Message,
+    /// Client annotation of the move, if recorded
+    pub annotation: Option<PlayAnnotation>,
+}
+
+/// Replay a finished game under `config` and flag responses where a
+/// clearly better alternative existed.
+///
+/// Each play or pass against an open trick is compared with
+/// [`best_response`](super::best_response), treating the other players' remaining cards as
+/// unseen. The swing estimate is the first that applies:
+/// - the suggestion holds and the move did not: points on the table plus
+///   points in the suggestion
+/// - the move spent a trump the suggestion keeps: 25
+/// - otherwise: 5 per extra play left in hand
+///
+/// Moves with a swing of 10 or more are reported, in turn order. Leads are
+/// not reviewed.
+///
+/// # Errors
+///
+/// Returns [`DatongziError::InvalidInput`](crate::DatongziError::InvalidInput)
+/// if the record is inconsistent (see
+/// [`score_record`](crate::scoring::score_record))
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use datongzi_rules::coach::review_game;
+/// use datongzi_rules::scoring::{GameRecord, RecordedAction};
+/// use datongzi_rules::{Card, GameConfig, Rank, Suit};
+///
+/// let king = Card::new(Suit::Spades, Rank::King);
+/// let two = Card::new(Suit::Hearts, Rank::Two);
+/// let record = GameRecord {
+///     player_ids: vec!["a".to_string(), "b".to_string()],
+///     hands: vec![vec![king, king], vec![two, two]],
+///     actions: vec![
+///         RecordedAction::Play { player_id: "a".to_string(), cards: vec![king] },
+///         RecordedAction::Pass { player_id: "b".to_string() },
+///     ],
+///     reveals: Vec::new(),
+///     shuffle: None,
+///     annotations: BTreeMap::new(),
+///     aside: Vec::new(),
+/// };
+///
+/// // b let a K through while holding an unbeatable 2
+/// let items = review_game(&record, &GameConfig::default()).unwrap();
+/// assert_eq!(items.len(), 1);
+/// assert_eq!(items[0].suggested, vec![two]);
+/// assert_eq!(items[0].point_swing, 10);
+/// ```
+pub fn review_game(record: &GameRecord, config: &GameConfig) -> Result<Vec<ReviewItem>> {
+    let mut items = Vec::new();
+    let mut failure = None;
+
+    replay(record, config, |step| {
+        let ReplayStep::Action {
+            turn,
+            round_number,
+            seat,
+            hands,
+            open: Some(open),
+            action,
+            annotation,
+        } = step
+        else {
+            return;
+        };
+        if failure.is_some() {
+            return;
+        }
+
+        let (player_id, played) = match action {
+            RecordedAction::Play { player_id, cards } => (player_id, cards.as_slice()),
+            RecordedAction::Pass { player_id } => (player_id, [].as_slice()),
+        };
+        let unseen: Vec<Card> = hands
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != seat)
+            .flat_map(|(_, hand)| hand.iter().copied())
+            .collect();
+
+        let review =
+            match best_response_with_config(&hands[seat], &open.pattern, &unseen, played, config) {
+                Ok(review) => review,
+                Err(error) => {
+                    failure = Some(error);
+                    return;
+                }
+            };
+        let (best, actual) = (&review.best, &review.actual);
+
+        let point_swing = if best.holds && !actual.holds {
+            points(&open.cards) + points(&best.cards)
+        } else if actual.uses_trump && !best.uses_trump {
+            TRUMP_POINTS
+        } else {
+            let extra = actual.plays_left.saturating_sub(best.plays_left);
+            PLAY_POINTS * i32::try_from(extra).unwrap_or(i32::MAX / PLAY_POINTS)
+        };
+
+        if point_swing >= MISTAKE_THRESHOLD && review.note != Message::CoachAgreed {
+            items.push(ReviewItem {
+                trick: round_number,
+                turn,
+                player_id: player_id.clone(),
+                played: played.to_vec(),
+                suggested: review.best.cards.clone(),
+                point_swing,
+                note: review.note,
+                annotation: annotation.cloned(),
+            });
+        }
+    })?;
+
+    match failure {
+        Some(error) => Err(error),
+        None => Ok(items),
+    }
+}
+
+/// Score-card points in `cards`
+fn points(cards: &[Card]) -> i32 {
+    cards.iter().map(Card::score_value).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::models::{Rank, Suit};
+
+    fn play(player_id: &str, cards: &[Card]) -> RecordedAction {
+        RecordedAction::Play {
+            player_id: player_id.to_string(),
+            cards: cards.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_flags_wasted_trump_but_not_good_moves() {
+        let five = Card::new(Suit::Spades, Rank::Five);
+        // Five 9s, so a single 9 would leave 9999 + 2 (two plays)
+        let mut nines: Vec<Card> = Suit::ALL
+            .iter()
+            .map(|s| Card::new(*s, Rank::Nine))
+            .collect();
+        nines.push(Card::new(Suit::Spades, Rank::Nine));
+        let two = Card::new(Suit::Hearts, Rank::Two);
+        let mut b_hand = nines.clone();
+        b_hand.push(two);
+
+        let wasteful = GameRecord {
+            player_ids: vec!["a".into(), "b".into()],
+            hands: vec![vec![five, five], b_hand.clone()],
+            actions: vec![play("a", &[five]), play("b", &nines)],
+            reveals: Vec::new(),
+            shuffle: None,
+            annotations: BTreeMap::from([(
+                1,
+                PlayAnnotation {
+                    latency_ms: Some(900),
+                    hint_used: true,
+                    reaction: None,
+                },
+            )]),
+            aside: Vec::new(),
+        };
+        let items = review_game(&wasteful, &GameConfig::default()).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].trick, 1);
+        assert_eq!(items[0].suggested, vec![two]);
+        assert_eq!(items[0].point_swing, TRUMP_POINTS);
+        assert!(items[0].annotation.as_ref().is_some_and(|a| a.hint_used));
+
+        let sound = GameRecord {
+            actions: vec![play("a", &[five]), play("b", &[two])],
+            ..wasteful
+        };
+        assert!(review_game(&sound, &GameConfig::default())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_rejects_inconsistent_record() {
+        let record = GameRecord {
+            player_ids: vec!["a".into()],
+            hands: vec![vec![]],
+            actions: vec![play("a", &[Card::new(Suit::Spades, Rank::Five)])],
+            reveals: Vec::new(),
+            shuffle: None,
+            annotations: BTreeMap::new(),
+            aside: Vec::new(),
+        };
+        assert!(review_game(&record, &GameConfig::default()).is_err());
+    }
+}
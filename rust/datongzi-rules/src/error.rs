@@ -2,20 +2,22 @@
 
 use thiserror::Error;
 
+use crate::i18n::{self, Locale, Message};
+
 /// Main error type for the datongzi-rules library.
 #[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum DatongziError {
     /// Invalid pattern error
     #[error("Invalid pattern: {0}")]
-    PatternError(String),
+    PatternError(Message),
 
     /// Invalid play error
     #[error("Invalid play: {0}")]
-    PlayError(String),
+    PlayError(Message),
 
     /// Configuration error
     #[error("Configuration error: {0}")]
-    ConfigError(String),
+    ConfigError(Message),
 
     /// Too many combinations generated
     #[error("Too many combinations: found {found}, max allowed: {max}")]
@@ -28,7 +30,15 @@ pub enum DatongziError {
 
     /// Invalid input
     #[error("Invalid input: {0}")]
-    InvalidInput(String),
+    InvalidInput(Message),
+}
+
+impl DatongziError {
+    /// Full error text in `locale`; `Display` gives the en-US text
+    #[must_use]
+    pub fn localized(&self, locale: Locale) -> String {
+        i18n::render_error(self, locale)
+    }
 }
 
 /// Result type alias for the datongzi-rules library.
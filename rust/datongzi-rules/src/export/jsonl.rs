@@ -0,0 +1,165 @@
+//! JSON-lines export of scoring events.
+//!
+//! Each event is one line wrapping the event with the schema version:
+//! `{"v":1,"event":{...}}`. Readers reject lines from other versions, so the
+//! schema changes in exactly one place: [`SCHEMA_VERSION`].
+
+use std::io::{BufRead, Write};
+
+use serde::Serialize;
+
+use crate::error::{DatongziError, Result};
+use crate::i18n::Message;
+use crate::scoring::ScoringEvent;
+
+/// Version written on every line, and the only version read back.
+pub const SCHEMA_VERSION: u64 = 1;
+
+#[derive(Serialize)]
+struct Line<'a> {
+    v: u64,
+    event: &'a ScoringEvent,
+}
+
+fn line_error(line: usize, reason: impl ToString) -> DatongziError {
+    DatongziError::InvalidInput(Message::JsonlLine {
+        line,
+        reason: reason.to_string(),
+    })
+}
+
+/// Write `events` to `writer`, one JSON object per line.
+///
+/// # Errors
+///
+/// Returns `InvalidInput` with [`Message::JsonlLine`] if writing fails
+///
+/// # Example
+/// ```
+/// use datongzi_rules::export::jsonl::{read_events, write_events};
+/// use datongzi_rules::i18n::Message;
+/// use datongzi_rules::{BonusType, ScoringEvent};
+///
+/// let events = vec![ScoringEvent {
+///     player_id: "alice".to_string(),
+///     bonus_type: BonusType::RoundWin,
+///     points: 15,
+///     reason: Message::RoundWin { round: 1, points: 15 },
+///     round_number: Some(1),
+///     cards_involved: vec!["♠5".to_string(), "♥10".to_string()],
+/// }];
+///
+/// let mut buffer = Vec::new();
+/// write_events(&mut buffer, &events).unwrap();
+/// assert!(buffer.starts_with(br#"{"v":1,"#));
+/// assert_eq!(read_events(buffer.as_slice()).unwrap(), events);
+/// ```
+pub fn write_events<W: Write>(mut writer: W, events: &[ScoringEvent]) -> Result<()> {
+    for (index, event) in events.iter().enumerate() {
+        let line = index + 1;
+        serde_json::to_writer(
+            &mut writer,
+            &Line {
+                v: SCHEMA_VERSION,
+                event,
+            },
+        )
+        .map_err(|e| line_error(line, e))?;
+        writer.write_all(b"\n").map_err(|e| line_error(line, e))?;
+    }
+    writer.flush().map_err(|e| line_error(events.len(), e))
+}
+
+/// Read events written by [`write_events`]; blank lines are skipped.
+///
+/// # Errors
+///
+/// Returns `InvalidInput` with [`Message::JsonlLine`] for unreadable or
+/// malformed lines, and [`Message::JsonlVersion`] for lines written with
+/// another schema version
+pub fn read_events<R: BufRead>(reader: R) -> Result<Vec<ScoringEvent>> {
+    let mut events = Vec::new();
+    for (index, text) in reader.lines().enumerate() {
+        let line = index + 1;
+        let text = text.map_err(|e| line_error(line, e))?;
+        if text.trim().is_empty() {
+            continue;
+        }
+
+        let mut value: serde_json::Value =
+            serde_json::from_str(&text).map_err(|e| line_error(line, e))?;
+        let version = value
+            .get("v")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| line_error(line, "missing schema version"))?;
+        if version != SCHEMA_VERSION {
+            return Err(DatongziError::InvalidInput(Message::JsonlVersion {
+                line,
+                version,
+            }));
+        }
+        let event = value
+            .get_mut("event")
+            .map(serde_json::Value::take)
+            .ok_or_else(|| line_error(line, "missing event"))?;
+        events.push(serde_json::from_value(event).map_err(|e| line_error(line, e))?);
+    }
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Card, Rank, Suit};
+    use crate::scoring::BonusType;
+
+    fn event() -> ScoringEvent {
+        ScoringEvent {
+            player_id: "bob".to_string(),
+            bonus_type: BonusType::KTongzi,
+            points: 100,
+            reason: Message::CannotBeat {
+                play_type: crate::PlayType::Pair,
+                cards: vec![Card::new(Suit::Hearts, Rank::King)],
+                current: crate::PlayType::Bomb,
+            },
+            round_number: None,
+            cards_involved: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_skips_blank_lines() {
+        let mut buffer = Vec::new();
+        write_events(&mut buffer, &[event(), event()]).unwrap();
+        buffer.extend_from_slice(b"\n");
+        assert_eq!(
+            read_events(buffer.as_slice()).unwrap(),
+            vec![event(), event()]
+        );
+    }
+
+    #[test]
+    fn test_rejects_other_versions_and_garbage() {
+        let mut buffer = Vec::new();
+        write_events(&mut buffer, &[event()]).unwrap();
+        let future = String::from_utf8(buffer)
+            .unwrap()
+            .replace(r#""v":1"#, r#""v":2"#);
+        assert_eq!(
+            read_events(future.as_bytes()),
+            Err(DatongziError::InvalidInput(Message::JsonlVersion {
+                line: 1,
+                version: 2
+            }))
+        );
+
+        assert!(matches!(
+            read_events("\n{not json}".as_bytes()),
+            Err(DatongziError::InvalidInput(Message::JsonlLine {
+                line: 2,
+                ..
+            }))
+        ));
+    }
+}
@@ -0,0 +1,6 @@
+//! Serialized exports for analytics ingestion (requires the `serde` feature).
+//!
+//! This module contains:
+//! - [`jsonl`]: Versioned JSON-lines writer and reader for scoring events
+
+pub mod jsonl;
@@ -0,0 +1,195 @@
+//! Hash commitments of dealt hands.
+//!
+//! The server publishes a commitment for every hand at deal time and reveals
+//! the salt after the game. Anyone holding the final hand list can then check
+//! that no hand was altered mid-game.
+
+use std::fmt;
+
+use super::sha256::sha256;
+use crate::error::{DatongziError, Result};
+use crate::i18n::Message;
+use crate::models::Card;
+
+/// Domain separator so hand commitments never collide with other digests.
+const DOMAIN_TAG: &[u8] = b"datongzi-rules/hand-commitment/v1";
+
+/// Salted SHA-256 commitment of a dealt hand.
+///
+/// The commitment is order-independent: the hand is sorted before hashing,
+/// so the same multiset of cards always yields the same digest.
+///
+/// ## Encoding
+/// `SHA-256(DOMAIN_TAG || len(salt) as u32 BE || salt || (rank, suit) bytes of sorted hand)`
+///
+/// # Example
+/// ```
+/// use datongzi_rules::{Card, Rank, Suit};
+/// use datongzi_rules::fairness::HandCommitment;
+///
+/// let hand = vec![
+///     Card::new(Suit::Spades, Rank::Ace),
+///     Card::new(Suit::Hearts, Rank::Five),
+/// ];
+/// let commitment = HandCommitment::new(&hand, b"secret-salt");
+///
+/// assert!(commitment.verify(&hand, b"secret-salt"));
+/// assert!(!commitment.verify(&hand[..1], b"secret-salt"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandCommitment {
+    digest: [u8; 32],
+}
+
+impl HandCommitment {
+    /// Creates a commitment for a hand using the given salt.
+    ///
+    /// The salt must be kept secret until the game ends, otherwise players
+    /// could brute-force opponents' hands from the published digest.
+    #[must_use]
+    pub fn new(hand: &[Card], salt: &[u8]) -> Self {
+        let mut sorted_hand = hand.to_vec();
+        sorted_hand.sort();
+
+        let mut message = Vec::with_capacity(DOMAIN_TAG.len() + 4 + salt.len() + hand.len() * 2);
+        message.extend_from_slice(DOMAIN_TAG);
+        message.extend_from_slice(&(salt.len() as u32).to_be_bytes());
+        message.extend_from_slice(salt);
+        for card in &sorted_hand {
+            message.push(card.rank.value());
+            message.push(card.suit.value());
+        }
+
+        Self {
+            digest: sha256(&message),
+        }
+    }
+
+    /// Creates a commitment from a previously published digest.
+    #[must_use]
+    pub const fn from_digest(digest: [u8; 32]) -> Self {
+        Self { digest }
+    }
+
+    /// Parses a commitment from its 64-character hex representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not exactly 64 hex characters
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(DatongziError::InvalidInput(Message::CommitmentLength {
+                len: hex.len(),
+            }));
+        }
+        // `from_str_radix` also accepts a leading sign, so check digits first
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(DatongziError::InvalidInput(Message::CommitmentHex {
+                hex: hex.to_string(),
+            }));
+        }
+
+        let mut digest = [0u8; 32];
+        for (i, byte) in digest.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| {
+                DatongziError::InvalidInput(Message::CommitmentHex {
+                    hex: hex.to_string(),
+                })
+            })?;
+        }
+
+        Ok(Self { digest })
+    }
+
+    /// Checks that `hand` and `salt` match this commitment.
+    #[must_use]
+    pub fn verify(&self, hand: &[Card], salt: &[u8]) -> bool {
+        Self::new(hand, salt) == *self
+    }
+
+    /// Returns the raw digest bytes
+    #[must_use]
+    pub const fn digest(&self) -> &[u8; 32] {
+        &self.digest
+    }
+
+    /// Returns the lowercase hex representation of the digest
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for HandCommitment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.digest {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+
+    fn sample_hand() -> Vec<Card> {
+        vec![
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Spades, Rank::King),
+            Card::new(Suit::Diamonds, Rank::Two),
+        ]
+    }
+
+    #[test]
+    fn test_commitment_verifies_same_hand() {
+        let hand = sample_hand();
+        let commitment = HandCommitment::new(&hand, b"salt");
+        assert!(commitment.verify(&hand, b"salt"));
+    }
+
+    #[test]
+    fn test_commitment_is_order_independent() {
+        let hand = sample_hand();
+        let mut reversed = hand.clone();
+        reversed.reverse();
+        assert_eq!(
+            HandCommitment::new(&hand, b"salt"),
+            HandCommitment::new(&reversed, b"salt")
+        );
+    }
+
+    #[test]
+    fn test_commitment_rejects_altered_hand_or_salt() {
+        let hand = sample_hand();
+        let commitment = HandCommitment::new(&hand, b"salt");
+
+        let mut altered = hand.clone();
+        altered[1] = Card::new(Suit::Hearts, Rank::Ten);
+        assert!(!commitment.verify(&altered, b"salt"));
+        assert!(!commitment.verify(&hand[..3], b"salt"));
+        assert!(!commitment.verify(&hand, b"other"));
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let commitment = HandCommitment::new(&sample_hand(), b"salt");
+        let hex = commitment.to_hex();
+        assert_eq!(hex.len(), 64);
+        assert_eq!(HandCommitment::from_hex(&hex).unwrap(), commitment);
+
+        assert!(HandCommitment::from_hex("abc").is_err());
+        assert!(HandCommitment::from_hex(&"zz".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn test_from_hex_rejects_signs() {
+        let hex = format!("+a{}", "00".repeat(31));
+        assert!(matches!(
+            HandCommitment::from_hex(&hex),
+            Err(DatongziError::InvalidInput(Message::CommitmentHex { .. }))
+        ));
+    }
+}
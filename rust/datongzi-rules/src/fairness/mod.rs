@@ -0,0 +1,12 @@
+//! Provably fair dealing support.
+//!
+//! This module provides tools that let players audit a deal after the game:
+//! - [`HandCommitment`]: Salted hash commitment of a dealt hand
+//! - [`ShuffleAudit`]: Seed and permutation digest of a built-in shuffle
+
+mod commitment;
+mod sha256;
+mod shuffle_audit;
+
+pub use commitment::HandCommitment;
+pub use shuffle_audit::ShuffleAudit;
@@ -0,0 +1,151 @@
+//! Seed and permutation digest of a built-in shuffle.
+//!
+//! The server commits to a seed before the deal and records the digest of the
+//! shuffled deck. After the game anyone can re-run the shuffle from the seed
+//! and check both the permutation and the dealt hands.
+
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::sha256::sha256;
+use crate::models::{Card, Deck, GameConfig};
+use crate::puzzles::deal_from_seed;
+
+/// Domain separator so shuffle digests never collide with other digests.
+const DOMAIN_TAG: &[u8] = b"datongzi-rules/shuffle-permutation/v1";
+
+/// Seed of a built-in shuffle and the SHA-256 digest of the resulting deck order.
+///
+/// Shuffles use `StdRng`, so a digest only verifies under the same version of
+/// the `rand` crate.
+///
+/// ## Encoding
+/// `SHA-256(DOMAIN_TAG || seed as u64 BE || (rank, suit, copy) bytes of the deck in order)`
+///
+/// # Example
+/// ```
+/// use datongzi_rules::fairness::ShuffleAudit;
+/// use datongzi_rules::{Deck, GameConfig};
+///
+/// let config = GameConfig::default();
+/// let mut deck = Deck::from_config(&config);
+/// let audit = ShuffleAudit::shuffle(&mut deck, 42);
+///
+/// assert!(audit.verify(&config));
+/// assert!(!ShuffleAudit::shuffle(&mut Deck::from_config(&config), 43).verify_seed(&config, 42));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShuffleAudit {
+    seed: u64,
+    digest: [u8; 32],
+}
+
+impl ShuffleAudit {
+    /// Shuffles `deck` with a `StdRng` seeded from `seed` and records the result.
+    #[must_use]
+    pub fn shuffle(deck: &mut Deck, seed: u64) -> Self {
+        deck.shuffle_with_rng(&mut StdRng::seed_from_u64(seed));
+        Self {
+            seed,
+            digest: Self::permutation_digest(deck, seed),
+        }
+    }
+
+    /// Creates an audit from a previously published seed and digest.
+    #[must_use]
+    pub const fn from_parts(seed: u64, digest: [u8; 32]) -> Self {
+        Self { seed, digest }
+    }
+
+    /// Returns the shuffle seed
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the raw permutation digest
+    #[must_use]
+    pub const fn digest(&self) -> &[u8; 32] {
+        &self.digest
+    }
+
+    /// Checks that shuffling `config`'s deck with the recorded seed yields
+    /// the recorded permutation.
+    #[must_use]
+    pub fn verify(&self, config: &GameConfig) -> bool {
+        self.verify_seed(config, self.seed)
+    }
+
+    /// Checks that this audit is the shuffle of `config`'s deck under `seed`
+    /// (for example the seed committed before the deal).
+    #[must_use]
+    pub fn verify_seed(&self, config: &GameConfig, seed: u64) -> bool {
+        *self == Self::shuffle(&mut Deck::from_config(config), seed)
+    }
+
+    /// Checks the permutation and that `hands` (in seat order) are the hands
+    /// [`deal_from_seed`] deals from it; card order within a hand is ignored.
+    #[must_use]
+    pub fn verify_hands(&self, config: &GameConfig, hands: &[Vec<Card>]) -> bool {
+        if !self.verify(config) {
+            return false;
+        }
+        let Ok((dealt, _)) = deal_from_seed(config, self.seed) else {
+            return false;
+        };
+        let sorted = |hand: &Vec<Card>| {
+            let mut hand = hand.clone();
+            hand.sort();
+            hand
+        };
+        dealt.len() == hands.len() && dealt.iter().map(sorted).eq(hands.iter().map(sorted))
+    }
+
+    fn permutation_digest(deck: &Deck, seed: u64) -> [u8; 32] {
+        let cards = deck.tagged_cards();
+        let mut message = Vec::with_capacity(DOMAIN_TAG.len() + 8 + cards.len() * 3);
+        message.extend_from_slice(DOMAIN_TAG);
+        message.extend_from_slice(&seed.to_be_bytes());
+        for tagged in cards {
+            message.push(tagged.card.rank.value());
+            message.push(tagged.card.suit.value());
+            message.push(tagged.copy_index);
+        }
+        sha256(&message)
+    }
+}
+
+impl fmt::Display for ShuffleAudit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.seed)?;
+        for byte in &self.digest {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::puzzles::deal_from_seed_audited;
+
+    #[test]
+    fn test_audited_deal_verifies_hands() {
+        let config = GameConfig::default();
+        let (hands, _, audit) = deal_from_seed_audited(&config, 9).unwrap();
+        assert_eq!(audit.seed(), 9);
+        assert!(audit.verify_hands(&config, &hands));
+
+        let mut swapped = hands.clone();
+        swapped.swap(0, 1);
+        assert!(!audit.verify_hands(&config, &swapped));
+
+        let forged = ShuffleAudit::from_parts(10, *audit.digest());
+        assert!(!forged.verify(&config));
+        assert!(!forged.verify_hands(&config, &hands));
+    }
+}
@@ -0,0 +1,203 @@
+//! Glossary entries derived from the rules engine.
+
+use crate::i18n::Message;
+use crate::models::{Card, Rank, Suit};
+use crate::patterns::{PatternRecognizer, PlayType, PlayValidator};
+
+/// Play types in glossary order.
+const PLAY_TYPES: [PlayType; 9] = [
+    PlayType::Single,
+    PlayType::Pair,
+    PlayType::ConsecutivePairs,
+    PlayType::Triple,
+    PlayType::Airplane,
+    PlayType::AirplaneWithWings,
+    PlayType::Bomb,
+    PlayType::Tongzi,
+    PlayType::Dizha,
+];
+
+/// One play type, ready to render in a help screen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlossaryEntry {
+    /// The play type
+    pub play_type: PlayType,
+    /// Localized name; render with [`Message::render`]
+    pub name: Message,
+    /// Localized definition
+    pub definition: Message,
+    /// Example plays as card codes (e.g. `"K♠"`), weakest first
+    pub examples: Vec<Vec<String>>,
+    /// Types a play of this type can beat (its own type included when a
+    /// higher play of the same shape wins)
+    pub beats: Vec<PlayType>,
+    /// Types that can beat a play of this type
+    pub beaten_by: Vec<PlayType>,
+}
+
+/// Glossary entries for every play type, in [`PlayType`] order.
+///
+/// Beat relationships are computed with [`PlayValidator`] from the example
+/// plays rather than written out, so they follow the rules engine.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::glossary::entries;
+/// use datongzi_rules::i18n::Locale;
+/// use datongzi_rules::PlayType;
+///
+/// let glossary = entries();
+/// let bomb = glossary.iter().find(|e| e.play_type == PlayType::Bomb).unwrap();
+/// assert_eq!(bomb.name.render(Locale::ZhCn), "炸弹");
+/// assert!(bomb.beats.contains(&PlayType::Single));
+/// assert_eq!(bomb.beaten_by, vec![PlayType::Bomb, PlayType::Tongzi, PlayType::Dizha]);
+/// ```
+#[must_use]
+pub fn entries() -> Vec<GlossaryEntry> {
+    let examples: Vec<Vec<Vec<Card>>> = PLAY_TYPES.iter().map(|t| examples(*t)).collect();
+    // The strongest example of `a` against the weakest of `b`
+    let beats = |a: usize, b: usize| {
+        let target = PatternRecognizer::analyze_cards(&examples[b][0]);
+        examples[a]
+            .last()
+            .is_some_and(|cards| PlayValidator::can_beat_play(cards, target.as_ref()))
+    };
+
+    PLAY_TYPES
+        .iter()
+        .enumerate()
+        .map(|(i, play_type)| GlossaryEntry {
+            play_type: *play_type,
+            name: Message::PlayTypeName {
+                play_type: *play_type,
+            },
+            definition: Message::PlayTypeDefinition {
+                play_type: *play_type,
+            },
+            examples: examples[i]
+                .iter()
+                .map(|cards| cards.iter().map(ToString::to_string).collect())
+                .collect(),
+            beats: (0..PLAY_TYPES.len())
+                .filter(|j| beats(i, *j))
+                .map(|j| PLAY_TYPES[j])
+                .collect(),
+            beaten_by: (0..PLAY_TYPES.len())
+                .filter(|j| beats(*j, i))
+                .map(|j| PLAY_TYPES[j])
+                .collect(),
+        })
+        .collect()
+}
+
+fn cards(rank: Rank, suits: &[Suit]) -> Vec<Card> {
+    suits.iter().map(|suit| Card::new(*suit, rank)).collect()
+}
+
+fn pair(rank: Rank) -> Vec<Card> {
+    cards(rank, &[Suit::Spades, Suit::Hearts])
+}
+
+fn triple(rank: Rank) -> Vec<Card> {
+    cards(rank, &[Suit::Spades, Suit::Hearts, Suit::Clubs])
+}
+
+fn dizha(rank: Rank) -> Vec<Card> {
+    Suit::ALL
+        .iter()
+        .flat_map(|suit| [Card::new(*suit, rank); 2])
+        .collect()
+}
+
+/// Example plays, weakest first; the first and last share a shape.
+fn examples(play_type: PlayType) -> Vec<Vec<Card>> {
+    use Rank::{Ace, Eight, Five, Jack, King, Nine, Queen, Seven, Six, Ten, Two};
+
+    match play_type {
+        PlayType::Single => vec![
+            vec![Card::new(Suit::Spades, Five)],
+            vec![Card::new(Suit::Hearts, Ace)],
+        ],
+        PlayType::Pair => vec![pair(Eight), pair(King)],
+        PlayType::ConsecutivePairs => vec![
+            [pair(Five), pair(Six)].concat(),
+            [pair(Five), pair(Six), pair(Seven)].concat(),
+            [pair(Jack), pair(Queen)].concat(),
+        ],
+        PlayType::Triple => vec![
+            triple(Seven),
+            [triple(Nine), vec![Card::new(Suit::Diamonds, Five)]].concat(),
+            triple(Queen),
+        ],
+        PlayType::Airplane => vec![
+            [triple(Eight), triple(Nine)].concat(),
+            [triple(Jack), triple(Queen)].concat(),
+        ],
+        PlayType::AirplaneWithWings => vec![
+            [
+                triple(Eight),
+                triple(Nine),
+                cards(Five, &[Suit::Diamonds]),
+                cards(Six, &[Suit::Diamonds]),
+            ]
+            .concat(),
+            [
+                triple(Jack),
+                triple(Queen),
+                cards(Five, &[Suit::Diamonds]),
+                cards(Six, &[Suit::Diamonds]),
+            ]
+            .concat(),
+        ],
+        PlayType::Bomb => vec![
+            cards(Six, &Suit::ALL),
+            [cards(Ten, &Suit::ALL), vec![Card::new(Suit::Spades, Ten)]].concat(),
+        ],
+        PlayType::Tongzi => vec![
+            vec![Card::new(Suit::Clubs, King); 3],
+            vec![Card::new(Suit::Spades, Two); 3],
+        ],
+        PlayType::Dizha => vec![dizha(Ten), dizha(Two)],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Locale;
+
+    #[test]
+    fn test_examples_match_their_type() {
+        for play_type in PLAY_TYPES {
+            for cards in examples(play_type) {
+                let pattern = PatternRecognizer::analyze_cards(&cards).unwrap();
+                assert_eq!(pattern.play_type, play_type, "{cards:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_beat_relationships() {
+        let glossary = entries();
+        assert_eq!(glossary.len(), PLAY_TYPES.len());
+
+        let entry = |play_type| glossary.iter().find(|e| e.play_type == play_type).unwrap();
+        assert_eq!(
+            entry(PlayType::Single).beaten_by,
+            vec![
+                PlayType::Single,
+                PlayType::Bomb,
+                PlayType::Tongzi,
+                PlayType::Dizha
+            ]
+        );
+        assert_eq!(entry(PlayType::Dizha).beaten_by, vec![PlayType::Dizha]);
+        assert_eq!(entry(PlayType::Dizha).beats, PLAY_TYPES.to_vec());
+
+        for entry in &glossary {
+            for locale in Locale::ALL {
+                assert!(!entry.definition.render(locale).is_empty());
+            }
+        }
+    }
+}
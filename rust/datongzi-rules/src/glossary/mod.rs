@@ -0,0 +1,9 @@
+//! Play-type glossary data for help screens.
+//!
+//! This module contains:
+//! - [`entries`]: Every [`PlayType`](crate::PlayType) with localized name
+//!   and definition, example plays, and what it beats
+
+mod entries;
+
+pub use entries::{entries, GlossaryEntry};
@@ -0,0 +1,62 @@
+//! Supported message locales.
+
+use std::fmt;
+
+/// Language of a built-in message catalog.
+///
+/// English is the default so that `Display` output stays stable for logs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// Simplified Chinese (zh-CN)
+    ZhCn,
+    /// English (en-US)
+    #[default]
+    EnUs,
+}
+
+impl Locale {
+    /// Every supported locale
+    pub const ALL: [Self; 2] = [Self::ZhCn, Self::EnUs];
+
+    /// BCP 47 language tag, e.g. `"zh-CN"`
+    #[must_use]
+    pub const fn tag(self) -> &'static str {
+        match self {
+            Self::ZhCn => "zh-CN",
+            Self::EnUs => "en-US",
+        }
+    }
+
+    /// Locale for a language tag; matching ignores case and accepts `_`.
+    ///
+    /// A bare language (`"zh"`, `"en"`) selects its only catalog.
+    #[must_use]
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let tag = tag.replace('_', "-").to_ascii_lowercase();
+        match tag.as_str() {
+            "zh" | "zh-cn" | "zh-hans" | "zh-hans-cn" => Some(Self::ZhCn),
+            "en" | "en-us" => Some(Self::EnUs),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tag())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tags_round_trip() {
+        for locale in Locale::ALL {
+            assert_eq!(Locale::from_tag(locale.tag()), Some(locale));
+        }
+        assert_eq!(Locale::from_tag("zh_cn"), Some(Locale::ZhCn));
+        assert_eq!(Locale::from_tag("fr-FR"), None);
+    }
+}
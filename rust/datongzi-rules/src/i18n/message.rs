@@ -0,0 +1,934 @@
+//! Message keys and the built-in zh-CN / en-US catalogs.
+
+use std::fmt;
+
+use super::Locale;
+use crate::error::DatongziError;
+use crate::models::{Card, CardsLeftAlert, PlayType, Rank};
+
+/// A user-facing message: a catalog key plus its parameters.
+///
+/// `Display` renders the en-US text; use [`render`](Self::render) for
+/// another locale.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    // ========== Scoring reasons ==========
+    /// A round was won with scoring cards
+    RoundWin {
+        /// Round number
+        round: usize,
+        /// Points from 5/10/K
+        points: i32,
+    },
+    /// Bonus for winning a round with a K/A/2 Tongzi
+    TongziBonus {
+        /// Tongzi rank
+        rank: Rank,
+        /// Round number
+        round: usize,
+    },
+    /// Bonus for winning a round with a Dizha
+    DizhaBonus {
+        /// Dizha rank
+        rank: Rank,
+        /// Round number
+        round: usize,
+    },
+    /// Extra points for winning the game's final trick
+    LastTrick {
+        /// Round number
+        round: usize,
+        /// Extra points
+        points: i32,
+    },
+    /// Finish-position bonus (1 = 上游)
+    FinishPosition {
+        /// 1-based finish position
+        position: usize,
+    },
+    /// Revealed-hand (明牌) stake adjustment
+    RevealMultiplier {
+        /// Total before multiplying
+        total: i32,
+        /// Reveal multiplier
+        multiplier: i32,
+    },
+    /// Text from a house rule, shown as-is in every locale
+    Custom(String),
+
+    // ========== Announcements ==========
+    /// A player's hand came under the cards-left alert (报牌)
+    CardsLeft {
+        /// Player ID
+        player_id: String,
+        /// Cards the player holds
+        cards_left: usize,
+    },
+
+    // ========== Play validation ==========
+    /// The cards form no recognized pattern
+    InvalidPattern {
+        /// Cards played
+        cards: Vec<Card>,
+    },
+    /// A training drill only allows one play family
+    DrillMismatch {
+        /// Type played
+        play_type: PlayType,
+        /// Type being drilled
+        drill: PlayType,
+    },
+    /// The play does not beat the current one
+    CannotBeat {
+        /// Type played
+        play_type: PlayType,
+        /// Cards played
+        cards: Vec<Card>,
+        /// Type of the current play
+        current: PlayType,
+    },
+    /// The card's rank is removed by the configuration
+    RemovedRank {
+        /// Offending card
+        card: Card,
+    },
+    /// The card's rank may not be carried as a kicker or wing
+    ForbiddenKicker {
+        /// Offending kicker
+        card: Card,
+    },
+    /// The configuration forbids opening a trick with this trump
+    LeadRestricted {
+        /// Type led
+        play_type: PlayType,
+    },
+    /// A player under the cards-left alert passed while able to beat
+    MustBeat {
+        /// Cards the player holds
+        cards_left: usize,
+    },
+    /// The configuration forbids finishing on a single score card
+    FinishOnScoreCard {
+        /// The last card
+        card: Card,
+    },
+    /// The configuration forbids finishing on a play with kickers or wings
+    FinishWithKickers {
+        /// Type played
+        play_type: PlayType,
+    },
+    /// Revealing hands is disabled
+    RevealNotAllowed,
+    /// The player already revealed
+    AlreadyRevealed {
+        /// Player ID
+        player_id: String,
+    },
+
+    // ========== Configuration ==========
+    /// Player count outside 2-4
+    InvalidPlayerCount {
+        /// Configured players
+        players: u8,
+    },
+    /// No decks configured
+    NoDecks,
+    /// A per-card copy count exceeds the number of decks
+    CardCopiesExceedDecks {
+        /// The card
+        card: Card,
+        /// Copies requested
+        copies: u8,
+        /// Decks in the configuration
+        decks: u8,
+    },
+    /// The deck is too small for the deal
+    NotEnoughCards {
+        /// Cards required
+        need: usize,
+        /// Cards available
+        have: usize,
+    },
+    /// `finish_bonus` does not have one entry per player
+    FinishBonusLength {
+        /// Entries given
+        len: usize,
+        /// Configured players
+        players: u8,
+    },
+    /// `reveal_multiplier` below 1
+    RevealMultiplierTooSmall {
+        /// Configured multiplier
+        multiplier: i32,
+    },
+    /// Bidding enabled without cards dealt aside
+    BiddingWithoutAside,
+    /// Aside exchange attempted without bidding
+    ExchangeWithoutBidding,
+    /// A rules update would change what is legal in a game in progress
+    RulesChangeNotReloadable {
+        /// Configuration fields that may not change mid-game
+        fields: Vec<String>,
+    },
+
+    // ========== Input ==========
+    /// Wrong number of aside cards
+    AsideSize {
+        /// Cards given
+        got: usize,
+        /// Cards expected
+        expected: usize,
+    },
+    /// Discard count differs from the aside size
+    DiscardCount {
+        /// Cards to discard
+        expected: usize,
+        /// Cards given
+        got: usize,
+    },
+    /// A 5/10/K may not be discarded
+    ScoringCardDiscard {
+        /// Offending card
+        card: Card,
+    },
+    /// The discarded card is not held
+    DiscardNotHeld {
+        /// Offending card
+        card: Card,
+    },
+    /// A simulation was asked for zero deals
+    NoSeeds,
+    /// A simulation checkpoint was saved for a different seat count
+    CheckpointSeats {
+        /// Seats in the checkpoint
+        seats: usize,
+        /// Seats in the configuration
+        expected: usize,
+    },
+    /// A tournament needs at least two entrants
+    TooFewEntrants {
+        /// Entrants registered
+        count: usize,
+    },
+    /// A test fixture line could not be parsed
+    FixtureLine {
+        /// 1-based line number
+        line: usize,
+        /// Line text
+        text: String,
+    },
+    /// No deal met the puzzle criteria
+    NoDealFound {
+        /// Attempts made
+        attempts: usize,
+    },
+    /// A game record has a hand count different from its player count
+    RecordHandCount {
+        /// Hands in the record
+        hands: usize,
+        /// Players in the record
+        players: usize,
+    },
+    /// A player ID is not seated
+    UnknownPlayer {
+        /// Player ID
+        player_id: String,
+    },
+    /// A recorded play is illegal at that point
+    RecordIllegalPlay {
+        /// Action index
+        turn: usize,
+        /// Player ID
+        player_id: String,
+        /// Cards played
+        cards: Vec<Card>,
+    },
+    /// A recorded play forms no pattern
+    RecordUnrecognizedPlay {
+        /// Action index
+        turn: usize,
+    },
+    /// A recorded play uses a card the player does not hold
+    RecordCardNotHeld {
+        /// Action index
+        turn: usize,
+        /// Player ID
+        player_id: String,
+        /// Offending card
+        card: Card,
+    },
+    /// A recorded action breaks a rule of the configuration it is replayed
+    /// under
+    RecordRuleViolation {
+        /// Action index
+        turn: usize,
+        /// Player ID
+        player_id: String,
+        /// The rule broken
+        reason: Box<Message>,
+    },
+    /// A recorded pass with no play to beat
+    RecordPassWithoutPlay {
+        /// Action index
+        turn: usize,
+        /// Player ID
+        player_id: String,
+    },
+    /// A hand history line could not be parsed
+    HandHistoryLine {
+        /// 1-based line number
+        line: usize,
+        /// Line text
+        text: String,
+    },
+    /// A player ID that a hand history cannot hold (empty, or containing
+    /// whitespace, `:` or `|`)
+    HandHistoryPlayerId {
+        /// Player ID
+        player_id: String,
+    },
+    /// A hand commitment of the wrong length
+    CommitmentLength {
+        /// Characters given
+        len: usize,
+    },
+    /// A hand commitment with non-hex characters
+    CommitmentHex {
+        /// Text given
+        hex: String,
+    },
+    /// An exported event line could not be written or parsed
+    JsonlLine {
+        /// 1-based line number
+        line: usize,
+        /// Underlying I/O or JSON error
+        reason: String,
+    },
+    /// An exported event line uses an unsupported schema version
+    JsonlVersion {
+        /// 1-based line number
+        line: usize,
+        /// Version found
+        version: u64,
+    },
+
+    // ========== Coaching ==========
+    /// The player's move was the strongest response
+    CoachAgreed,
+    /// Passing was stronger than the move played
+    CoachShouldPass {
+        /// Cards played
+        played: Vec<Card>,
+    },
+    /// Playing was stronger than passing
+    CoachShouldPlay {
+        /// Strongest response
+        best: Vec<Card>,
+    },
+    /// A response no unseen cards can beat was available
+    CoachMissedSafePlay {
+        /// Strongest response
+        best: Vec<Card>,
+    },
+    /// A trump was spent where a normal play would do
+    CoachWastedTrump {
+        /// Strongest response
+        best: Vec<Card>,
+    },
+    /// The move left the hand harder to play out
+    CoachLeavesMorePlays {
+        /// Strongest response
+        best: Vec<Card>,
+        /// Plays needed after the strongest response
+        best_left: usize,
+        /// Plays needed after the move played
+        actual_left: usize,
+    },
+    /// The same result was available with lower cards
+    CoachCheaperPlay {
+        /// Strongest response
+        best: Vec<Card>,
+    },
+    /// Display name of a play type
+    PlayTypeName {
+        /// The play type
+        play_type: PlayType,
+    },
+    /// Rules definition of a play type
+    PlayTypeDefinition {
+        /// The play type
+        play_type: PlayType,
+    },
+}
+
+impl Message {
+    /// The [`CardsLeft`](Self::CardsLeft) announcement due under `alert` when
+    /// `player_id`'s hand goes from `before` to `after` cards, if it just
+    /// came under alert
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::i18n::Message;
+    /// use datongzi_rules::models::CardsLeftAlert;
+    ///
+    /// let alert = CardsLeftAlert { threshold: 3, must_beat: false };
+    /// assert!(Message::cards_left_announcement(&alert, "p1", 5, 2).is_some());
+    /// // Already announced
+    /// assert!(Message::cards_left_announcement(&alert, "p1", 2, 1).is_none());
+    /// // Finished instead
+    /// assert!(Message::cards_left_announcement(&alert, "p1", 5, 0).is_none());
+    /// ```
+    #[must_use]
+    pub fn cards_left_announcement(
+        alert: &CardsLeftAlert,
+        player_id: &str,
+        before: usize,
+        after: usize,
+    ) -> Option<Self> {
+        (!alert.applies_to(before) && alert.applies_to(after)).then(|| Self::CardsLeft {
+            player_id: player_id.to_string(),
+            cards_left: after,
+        })
+    }
+
+    /// Stable catalog key, e.g. `"scoring.round_win"`
+    #[must_use]
+    pub const fn key(&self) -> &'static str {
+        match self {
+            Self::RoundWin { .. } => "scoring.round_win",
+            Self::LastTrick { .. } => "scoring.last_trick",
+            Self::TongziBonus { .. } => "scoring.tongzi_bonus",
+            Self::DizhaBonus { .. } => "scoring.dizha_bonus",
+            Self::FinishPosition { .. } => "scoring.finish_position",
+            Self::RevealMultiplier { .. } => "scoring.reveal_multiplier",
+            Self::Custom(_) => "custom",
+            Self::InvalidPattern { .. } => "play.invalid_pattern",
+            Self::DrillMismatch { .. } => "play.drill_mismatch",
+            Self::CannotBeat { .. } => "play.cannot_beat",
+            Self::RemovedRank { .. } => "play.removed_rank",
+            Self::ForbiddenKicker { .. } => "play.forbidden_kicker",
+            Self::LeadRestricted { .. } => "play.lead_restricted",
+            Self::CardsLeft { .. } => "announce.cards_left",
+            Self::MustBeat { .. } => "play.must_beat",
+            Self::FinishOnScoreCard { .. } => "play.finish_on_score_card",
+            Self::FinishWithKickers { .. } => "play.finish_with_kickers",
+            Self::RevealNotAllowed => "play.reveal_not_allowed",
+            Self::AlreadyRevealed { .. } => "play.already_revealed",
+            Self::InvalidPlayerCount { .. } => "config.invalid_player_count",
+            Self::NoDecks => "config.no_decks",
+            Self::CardCopiesExceedDecks { .. } => "config.card_copies_exceed_decks",
+            Self::NotEnoughCards { .. } => "config.not_enough_cards",
+            Self::FinishBonusLength { .. } => "config.finish_bonus_length",
+            Self::RevealMultiplierTooSmall { .. } => "config.reveal_multiplier_too_small",
+            Self::BiddingWithoutAside => "config.bidding_without_aside",
+            Self::ExchangeWithoutBidding => "config.exchange_without_bidding",
+            Self::RulesChangeNotReloadable { .. } => "config.rules_change_not_reloadable",
+            Self::AsideSize { .. } => "input.aside_size",
+            Self::DiscardCount { .. } => "input.discard_count",
+            Self::ScoringCardDiscard { .. } => "input.scoring_card_discard",
+            Self::DiscardNotHeld { .. } => "input.discard_not_held",
+            Self::NoSeeds => "input.no_seeds",
+            Self::CheckpointSeats { .. } => "input.checkpoint_seats",
+            Self::TooFewEntrants { .. } => "input.too_few_entrants",
+            Self::FixtureLine { .. } => "input.fixture_line",
+            Self::NoDealFound { .. } => "input.no_deal_found",
+            Self::RecordHandCount { .. } => "record.hand_count",
+            Self::UnknownPlayer { .. } => "record.unknown_player",
+            Self::RecordIllegalPlay { .. } => "record.illegal_play",
+            Self::RecordUnrecognizedPlay { .. } => "record.unrecognized_play",
+            Self::RecordCardNotHeld { .. } => "record.card_not_held",
+            Self::RecordRuleViolation { .. } => "record.rule_violation",
+            Self::RecordPassWithoutPlay { .. } => "record.pass_without_play",
+            Self::HandHistoryLine { .. } => "record.hand_history_line",
+            Self::HandHistoryPlayerId { .. } => "record.hand_history_player_id",
+            Self::CommitmentLength { .. } => "fairness.commitment_length",
+            Self::CommitmentHex { .. } => "fairness.commitment_hex",
+            Self::JsonlLine { .. } => "export.jsonl_line",
+            Self::JsonlVersion { .. } => "export.jsonl_version",
+            Self::CoachAgreed => "coach.agreed",
+            Self::CoachShouldPass { .. } => "coach.should_pass",
+            Self::CoachShouldPlay { .. } => "coach.should_play",
+            Self::CoachMissedSafePlay { .. } => "coach.missed_safe_play",
+            Self::CoachWastedTrump { .. } => "coach.wasted_trump",
+            Self::CoachLeavesMorePlays { .. } => "coach.leaves_more_plays",
+            Self::CoachCheaperPlay { .. } => "coach.cheaper_play",
+            Self::PlayTypeName { .. } => "glossary.name",
+            Self::PlayTypeDefinition { .. } => "glossary.definition",
+        }
+    }
+
+    /// Text of this message in `locale`
+    #[must_use]
+    pub fn render(&self, locale: Locale) -> String {
+        match locale {
+            Locale::ZhCn => self.zh_cn(),
+            Locale::EnUs => self.en_us(),
+        }
+    }
+
+    fn en_us(&self) -> String {
+        match self {
+            Self::RoundWin { round, points } => {
+                format!("Round {round} win with {points} scoring cards")
+            }
+            Self::TongziBonus { rank, round } => format!("{rank:?} Tongzi in round {round}"),
+            Self::DizhaBonus { rank, round } => format!("{rank:?} Dizha in round {round}"),
+            Self::LastTrick { round, points } => {
+                format!("Final trick (round {round}): {points} extra points")
+            }
+            Self::FinishPosition { position } => {
+                format!(
+                    "Finished in position {position} ({})",
+                    finish_name(*position)
+                )
+            }
+            Self::RevealMultiplier { total, multiplier } => {
+                format!("Revealed hand: total {total} x{multiplier}")
+            }
+            Self::Custom(text) => text.clone(),
+            Self::InvalidPattern { cards } => {
+                format!("Cards {} do not form a valid pattern", join(cards))
+            }
+            Self::DrillMismatch { play_type, drill } => {
+                format!("{play_type:?} is not allowed in a {drill:?} drill")
+            }
+            Self::CannotBeat {
+                play_type,
+                cards,
+                current,
+            } => format!(
+                "{play_type:?} of {} cannot beat current {current:?}",
+                join(cards)
+            ),
+            Self::RemovedRank { card } => format!(
+                "Card {card} uses rank {} which is removed in this configuration",
+                card.rank
+            ),
+            Self::ForbiddenKicker { card } => format!(
+                "Card {card} cannot be used as a kicker: rank {} is forbidden as a kicker",
+                card.rank
+            ),
+            Self::LeadRestricted { play_type } => {
+                format!("{play_type:?} may only lead a trick when the hand is about to finish")
+            }
+            Self::CardsLeft {
+                player_id,
+                cards_left,
+            } => format!("Player {player_id} has {cards_left} cards left"),
+            Self::MustBeat { cards_left } => {
+                format!("Cannot pass with {cards_left} cards left while able to beat")
+            }
+            Self::FinishOnScoreCard { card } => {
+                format!("Cannot finish on the single score card {card}")
+            }
+            Self::FinishWithKickers { play_type } => {
+                format!("Cannot finish on a {play_type:?} carrying kickers")
+            }
+            Self::RevealNotAllowed => {
+                "Revealing hands is not allowed in this configuration".to_string()
+            }
+            Self::AlreadyRevealed { player_id } => {
+                format!("Player {player_id} has already revealed their hand")
+            }
+            Self::InvalidPlayerCount { players } => {
+                format!("Invalid number of players: {players} (must be 2-4)")
+            }
+            Self::NoDecks => "Number of decks must be at least 1".to_string(),
+            Self::CardCopiesExceedDecks {
+                card,
+                copies,
+                decks,
+            } => format!("{copies} copies of {card} requested, but only {decks} decks"),
+            Self::NotEnoughCards { need, have } => {
+                format!("Not enough cards: need {need}, have {have}")
+            }
+            Self::FinishBonusLength { len, players } => {
+                format!("finish_bonus length ({len}) must match num_players ({players})")
+            }
+            Self::RevealMultiplierTooSmall { multiplier } => {
+                format!("reveal_multiplier must be at least 1, got {multiplier}")
+            }
+            Self::BiddingWithoutAside => "allow_bidding requires cards_dealt_aside > 0".to_string(),
+            Self::ExchangeWithoutBidding => "aside exchange requires allow_bidding".to_string(),
+            Self::RulesChangeNotReloadable { fields } => format!(
+                "cannot change {} during a game: it alters play legality",
+                fields.join(", ")
+            ),
+            Self::AsideSize { got, expected } => {
+                format!("aside has {got} cards, expected {expected}")
+            }
+            Self::DiscardCount { expected, got } => {
+                format!("must discard {expected} cards, got {got}")
+            }
+            Self::ScoringCardDiscard { card } => {
+                format!("scoring card {card} may not be discarded")
+            }
+            Self::DiscardNotHeld { card } => {
+                format!("discarded card {card} is not in the merged hand")
+            }
+            Self::NoSeeds => "At least one seed is required".to_string(),
+            Self::CheckpointSeats { seats, expected } => {
+                format!("Checkpoint has {seats} seats, expected {expected}")
+            }
+            Self::TooFewEntrants { count } => {
+                format!("A tournament needs at least 2 entrants, got {count}")
+            }
+            Self::FixtureLine { line, text } => format!("Malformed fixture line {line}: {text}"),
+            Self::NoDealFound { attempts } => {
+                format!("No deal satisfying criteria within {attempts} attempts")
+            }
+            Self::RecordHandCount { hands, players } => {
+                format!("{hands} hands for {players} players")
+            }
+            Self::UnknownPlayer { player_id } => format!("Unknown player {player_id}"),
+            Self::RecordIllegalPlay {
+                turn,
+                player_id,
+                cards,
+            } => format!("Turn {turn}: {player_id} cannot play {}", join(cards)),
+            Self::RecordUnrecognizedPlay { turn } => format!("Turn {turn}: unrecognized play"),
+            Self::RecordCardNotHeld {
+                turn,
+                player_id,
+                card,
+            } => format!("Turn {turn}: {player_id} does not hold {card}"),
+            Self::RecordRuleViolation {
+                turn,
+                player_id,
+                reason,
+            } => format!("Turn {turn}: {player_id}: {}", reason.en_us()),
+            Self::RecordPassWithoutPlay { turn, player_id } => {
+                format!("Turn {turn}: {player_id} passed with nothing to beat")
+            }
+            Self::HandHistoryLine { line, text } => {
+                format!("Malformed hand history line {line}: {text}")
+            }
+            Self::HandHistoryPlayerId { player_id } => {
+                format!("Player ID {player_id:?} cannot be written to a hand history")
+            }
+            Self::CommitmentLength { len } => {
+                format!("Commitment must be 64 hex characters, got {len}")
+            }
+            Self::CommitmentHex { hex } => format!("Invalid hex in commitment: {hex}"),
+            Self::JsonlLine { line, reason } => format!("Event line {line}: {reason}"),
+            Self::JsonlVersion { line, version } => {
+                format!("Event line {line}: unsupported schema version {version}")
+            }
+            Self::CoachAgreed => "Good choice: this was the strongest response".to_string(),
+            Self::CoachShouldPass { played } => {
+                format!("Passing was stronger than playing {}", join(played))
+            }
+            Self::CoachShouldPlay { best } => {
+                format!("Playing {} was stronger than passing", join(best))
+            }
+            Self::CoachMissedSafePlay { best } => {
+                format!("{} could not be beaten by any unseen cards", join(best))
+            }
+            Self::CoachWastedTrump { best } => {
+                format!("{} wins here without spending a trump", join(best))
+            }
+            Self::CoachLeavesMorePlays {
+                best,
+                best_left,
+                actual_left,
+            } => format!(
+                "{} leaves {best_left} plays in hand instead of {actual_left}",
+                join(best)
+            ),
+            Self::CoachCheaperPlay { best } => {
+                format!("{} does the same job with lower cards", join(best))
+            }
+            Self::PlayTypeName { play_type } => play_type_name_en(*play_type).to_string(),
+            Self::PlayTypeDefinition { play_type } => match play_type {
+                PlayType::Single => "Any one card",
+                PlayType::Pair => "Two cards of the same rank",
+                PlayType::ConsecutivePairs => {
+                    "Two or more pairs of consecutive ranks, not including 2"
+                }
+                PlayType::Triple => "Three cards of the same rank, with up to two kickers",
+                PlayType::Airplane => "Two or more triples of consecutive ranks, not including 2",
+                PlayType::AirplaneWithWings => "An airplane carrying kicker cards as wings",
+                PlayType::Bomb => "Four or more cards of the same rank; beats any normal play",
+                PlayType::Tongzi => {
+                    "Three cards of the same rank and suit; beats bombs and normal plays"
+                }
+                PlayType::Dizha => {
+                    "Two cards of each suit of one rank (8 cards); beats everything else"
+                }
+            }
+            .to_string(),
+        }
+    }
+
+    fn zh_cn(&self) -> String {
+        match self {
+            Self::RoundWin { round, points } => format!("第{round}轮获胜，得分牌{points}分"),
+            Self::TongziBonus { rank, round } => format!("第{round}轮{rank}筒子奖励"),
+            Self::DizhaBonus { rank, round } => format!("第{round}轮{rank}地炸奖励"),
+            Self::LastTrick { round, points } => format!("第{round}轮为最后一墩，额外得{points}分"),
+            Self::FinishPosition { position } => {
+                format!("第{position}名完成（{}）", finish_name(*position))
+            }
+            Self::RevealMultiplier { total, multiplier } => {
+                format!("明牌：总分{total}×{multiplier}")
+            }
+            Self::Custom(text) => text.clone(),
+            Self::InvalidPattern { cards } => format!("{}不能组成有效牌型", join(cards)),
+            Self::DrillMismatch { play_type, drill } => format!(
+                "{}练习中不允许出{}",
+                play_type_name(*drill),
+                play_type_name(*play_type)
+            ),
+            Self::CannotBeat {
+                play_type,
+                cards,
+                current,
+            } => format!(
+                "{}（{}）压不过当前的{}",
+                play_type_name(*play_type),
+                join(cards),
+                play_type_name(*current)
+            ),
+            Self::RemovedRank { card } => {
+                format!("{card}的点数{}在当前配置中已被移除", card.rank)
+            }
+            Self::ForbiddenKicker { card } => {
+                format!("{card}不能作为带牌：当前配置禁止点数{}作带牌", card.rank)
+            }
+            Self::LeadRestricted { play_type } => {
+                format!("只有即将出完手牌时才能用{}首出", play_type_name(*play_type))
+            }
+            Self::CardsLeft {
+                player_id,
+                cards_left,
+            } => format!("玩家{player_id}报牌：剩{cards_left}张"),
+            Self::MustBeat { cards_left } => format!("只剩{cards_left}张牌且能压过时不能过牌"),
+            Self::FinishOnScoreCard { card } => format!("不能以单张分牌{card}出完"),
+            Self::FinishWithKickers { play_type } => {
+                format!("不能以带牌的{}出完", play_type_name(*play_type))
+            }
+            Self::RevealNotAllowed => "当前配置不允许明牌".to_string(),
+            Self::AlreadyRevealed { player_id } => format!("玩家{player_id}已经明牌"),
+            Self::InvalidPlayerCount { players } => {
+                format!("玩家人数无效：{players}（须为2-4人）")
+            }
+            Self::NoDecks => "牌副数至少为1".to_string(),
+            Self::CardCopiesExceedDecks {
+                card,
+                copies,
+                decks,
+            } => format!("{card}要求{copies}张，但只有{decks}副牌"),
+            Self::NotEnoughCards { need, have } => format!("牌数不足：需要{need}张，仅有{have}张"),
+            Self::FinishBonusLength { len, players } => {
+                format!("finish_bonus 长度（{len}）须与玩家人数（{players}）一致")
+            }
+            Self::RevealMultiplierTooSmall { multiplier } => {
+                format!("reveal_multiplier 至少为1，当前为{multiplier}")
+            }
+            Self::BiddingWithoutAside => "开启叫分需要 cards_dealt_aside > 0".to_string(),
+            Self::ExchangeWithoutBidding => "底牌交换需要开启叫分".to_string(),
+            Self::RulesChangeNotReloadable { fields } => {
+                format!("对局进行中不能修改{}：会改变出牌合法性", fields.join("、"))
+            }
+            Self::AsideSize { got, expected } => format!("底牌有{got}张，应为{expected}张"),
+            Self::DiscardCount { expected, got } => format!("须弃{expected}张牌，实际{got}张"),
+            Self::ScoringCardDiscard { card } => format!("分牌{card}不能弃掉"),
+            Self::DiscardNotHeld { card } => format!("弃牌{card}不在合并后的手牌中"),
+            Self::NoSeeds => "至少需要一个种子".to_string(),
+            Self::CheckpointSeats { seats, expected } => {
+                format!("检查点有{seats}个座位，应为{expected}个")
+            }
+            Self::TooFewEntrants { count } => format!("比赛至少需要2名参赛者，实际{count}名"),
+            Self::FixtureLine { line, text } => format!("测试用例第{line}行格式错误：{text}"),
+            Self::NoDealFound { attempts } => format!("{attempts}次尝试内未找到符合条件的牌局"),
+            Self::RecordHandCount { hands, players } => {
+                format!("{players}名玩家却有{hands}手牌")
+            }
+            Self::UnknownPlayer { player_id } => format!("未知玩家{player_id}"),
+            Self::RecordIllegalPlay {
+                turn,
+                player_id,
+                cards,
+            } => format!("第{turn}步：{player_id}不能出{}", join(cards)),
+            Self::RecordUnrecognizedPlay { turn } => format!("第{turn}步：无法识别的牌型"),
+            Self::RecordCardNotHeld {
+                turn,
+                player_id,
+                card,
+            } => format!("第{turn}步：{player_id}没有{card}"),
+            Self::RecordRuleViolation {
+                turn,
+                player_id,
+                reason,
+            } => format!("第{turn}步：{player_id}：{}", reason.zh_cn()),
+            Self::RecordPassWithoutPlay { turn, player_id } => {
+                format!("第{turn}步：{player_id}在无牌可压时过牌")
+            }
+            Self::HandHistoryLine { line, text } => format!("牌谱第{line}行格式错误：{text}"),
+            Self::HandHistoryPlayerId { player_id } => {
+                format!("玩家ID{player_id:?}无法写入牌谱")
+            }
+            Self::CommitmentLength { len } => format!("承诺值须为64个十六进制字符，实际{len}个"),
+            Self::CommitmentHex { hex } => format!("承诺值包含非十六进制字符：{hex}"),
+            Self::JsonlLine { line, reason } => format!("事件第{line}行：{reason}"),
+            Self::JsonlVersion { line, version } => {
+                format!("事件第{line}行：不支持的格式版本{version}")
+            }
+            Self::CoachAgreed => "好牌：这是最强的应对".to_string(),
+            Self::CoachShouldPass { played } => format!("过牌比出{}更好", join(played)),
+            Self::CoachShouldPlay { best } => format!("出{}比过牌更好", join(best)),
+            Self::CoachMissedSafePlay { best } => {
+                format!("{}是未见牌中无人能压的出法", join(best))
+            }
+            Self::CoachWastedTrump { best } => format!("出{}即可，无需动用炸弹", join(best)),
+            Self::CoachLeavesMorePlays {
+                best,
+                best_left,
+                actual_left,
+            } => format!(
+                "出{}后剩{best_left}手牌，而实际出法剩{actual_left}手",
+                join(best)
+            ),
+            Self::CoachCheaperPlay { best } => format!("出{}效果相同且牌更小", join(best)),
+            Self::PlayTypeName { play_type } => play_type_name(*play_type).to_string(),
+            Self::PlayTypeDefinition { play_type } => match play_type {
+                PlayType::Single => "任意一张牌",
+                PlayType::Pair => "两张同点数的牌",
+                PlayType::ConsecutivePairs => "两对或以上点数相连的对子，不含2",
+                PlayType::Triple => "三张同点数的牌，可带至多两张",
+                PlayType::Airplane => "两组或以上点数相连的三张，不含2",
+                PlayType::AirplaneWithWings => "飞机带若干张牌作为翅膀",
+                PlayType::Bomb => "四张或以上同点数的牌，可压任何普通牌型",
+                PlayType::Tongzi => "三张同点数同花色的牌，可压炸弹和普通牌型",
+                PlayType::Dizha => "同一点数每种花色各两张（共8张），可压其他一切牌型",
+            }
+            .to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(Locale::EnUs))
+    }
+}
+
+/// Full error text in `locale`, category prefix included.
+pub(crate) fn render_error(error: &DatongziError, locale: Locale) -> String {
+    let (prefix, detail) = match error {
+        DatongziError::PatternError(message) => {
+            (["Invalid pattern", "牌型无效"], message.render(locale))
+        }
+        DatongziError::PlayError(message) => (["Invalid play", "出牌无效"], message.render(locale)),
+        DatongziError::ConfigError(message) => {
+            (["Configuration error", "配置错误"], message.render(locale))
+        }
+        DatongziError::InvalidInput(message) => {
+            (["Invalid input", "输入无效"], message.render(locale))
+        }
+        DatongziError::TooManyCombinations { found, max } => (
+            ["Too many combinations", "组合数过多"],
+            match locale {
+                Locale::ZhCn => format!("找到{found}种，上限{max}种"),
+                Locale::EnUs => format!("found {found}, max allowed: {max}"),
+            },
+        ),
+    };
+    match locale {
+        Locale::ZhCn => format!("{}：{detail}", prefix[1]),
+        Locale::EnUs => format!("{}: {detail}", prefix[0]),
+    }
+}
+
+/// Cards in display form, space separated
+fn join(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Chinese name of a finish position
+fn finish_name(position: usize) -> &'static str {
+    match position {
+        1 => "上游",
+        2 => "二游",
+        _ => "三游",
+    }
+}
+
+/// English name of a play type
+fn play_type_name_en(play_type: PlayType) -> &'static str {
+    match play_type {
+        PlayType::Single => "Single",
+        PlayType::Pair => "Pair",
+        PlayType::ConsecutivePairs => "Consecutive pairs",
+        PlayType::Triple => "Triple",
+        PlayType::Airplane => "Airplane",
+        PlayType::AirplaneWithWings => "Airplane with wings",
+        PlayType::Bomb => "Bomb",
+        PlayType::Tongzi => "Tongzi",
+        PlayType::Dizha => "Dizha",
+    }
+}
+
+/// Chinese name of a play type
+fn play_type_name(play_type: PlayType) -> &'static str {
+    match play_type {
+        PlayType::Single => "单牌",
+        PlayType::Pair => "对子",
+        PlayType::ConsecutivePairs => "连对",
+        PlayType::Triple => "三张",
+        PlayType::Airplane => "飞机",
+        PlayType::AirplaneWithWings => "飞机带翅膀",
+        PlayType::Bomb => "炸弹",
+        PlayType::Tongzi => "筒子",
+        PlayType::Dizha => "地炸",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Suit;
+
+    #[test]
+    fn test_catalogs_differ_except_custom() {
+        let card = Card::new(Suit::Spades, Rank::King);
+        let messages = [
+            Message::TongziBonus {
+                rank: Rank::King,
+                round: 2,
+            },
+            Message::CannotBeat {
+                play_type: PlayType::Pair,
+                cards: vec![card, card],
+                current: PlayType::Bomb,
+            },
+            Message::NoDecks,
+        ];
+        for message in &messages {
+            assert_ne!(message.render(Locale::ZhCn), message.render(Locale::EnUs));
+            assert_eq!(message.to_string(), message.render(Locale::EnUs));
+        }
+
+        let custom = Message::Custom("house bonus".to_string());
+        assert_eq!(custom.render(Locale::ZhCn), custom.render(Locale::EnUs));
+    }
+
+    #[test]
+    fn test_errors_render_with_localized_prefix() {
+        let error = DatongziError::ConfigError(Message::NoDecks);
+        assert_eq!(
+            render_error(&error, Locale::EnUs),
+            "Configuration error: Number of decks must be at least 1"
+        );
+        assert_eq!(
+            render_error(&error, Locale::ZhCn),
+            "配置错误：牌副数至少为1"
+        );
+        assert_eq!(error.to_string(), render_error(&error, Locale::EnUs));
+    }
+}
@@ -0,0 +1,29 @@
+//! Localized rule and error messages.
+//!
+//! Every user-facing string the library produces is a [`Message`]: a key
+//! plus parameters, rendered through a built-in catalog for a [`Locale`].
+//! Scoring reasons and error payloads carry messages, so hosts can show
+//! them in the player's language or send [`Message::key`] to clients with
+//! their own catalogs.
+//!
+//! This module contains:
+//! - [`Locale`]: Supported catalogs (zh-CN, en-US)
+//! - [`Message`]: Keyed messages with their parameters
+//!
+//! # Example
+//! ```
+//! use datongzi_rules::i18n::{Locale, Message};
+//!
+//! let reason = Message::RoundWin { round: 3, points: 25 };
+//! assert_eq!(reason.key(), "scoring.round_win");
+//! assert_eq!(reason.render(Locale::EnUs), "Round 3 win with 25 scoring cards");
+//! assert_eq!(reason.render(Locale::ZhCn), "第3轮获胜，得分牌25分");
+//! ```
+
+mod locale;
+mod message;
+
+pub use locale::Locale;
+pub use message::Message;
+
+pub(crate) use message::render_error;
@@ -0,0 +1,151 @@
+//! Internal consistency checks, enabled by the `debug-invariants` feature.
+//!
+//! Each check is a pure `*_violation` function describing what went wrong,
+//! plus an `assert_*` wrapper that panics with that description. Without the
+//! feature the wrappers compile to nothing, so release builds pay no cost.
+
+use std::collections::HashMap;
+
+use crate::ai_helpers::HandPatterns;
+use crate::models::Card;
+use crate::patterns::PlayPattern;
+
+/// True when the `debug-invariants` feature is on.
+pub(crate) const ENABLED: bool = cfg!(feature = "debug-invariants");
+
+fn multiset<'a>(cards: impl IntoIterator<Item = &'a Card>) -> HashMap<Card, usize> {
+    let mut counts = HashMap::new();
+    for card in cards {
+        *counts.entry(*card).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn check(violation: impl FnOnce() -> Option<String>) {
+    if ENABLED {
+        if let Some(message) = violation() {
+            panic!("invariant violated: {message}");
+        }
+    }
+}
+
+/// `before` must equal `after` plus `played`, as multisets of cards.
+pub(crate) fn conservation_violation(
+    before: &[Card],
+    after: &[Card],
+    played: &[Card],
+) -> Option<String> {
+    (multiset(before) != multiset(after.iter().chain(played))).then(|| {
+        format!(
+            "hand not conserved: {} cards before, {} after, {} played ({played:?})",
+            before.len(),
+            after.len(),
+            played.len()
+        )
+    })
+}
+
+/// Panic unless `before` equals `after` plus `played`.
+pub(crate) fn assert_conservation(before: &[Card], after: &[Card], played: &[Card]) {
+    check(|| conservation_violation(before, after, played));
+}
+
+/// A decomposition must use each card of `hand` exactly once.
+///
+/// `near_trumps` are excluded; they overlap the decomposition by design.
+pub(crate) fn partition_violation(hand: &[Card], patterns: &HandPatterns) -> Option<String> {
+    let groups = [
+        &patterns.dizha,
+        &patterns.tongzi,
+        &patterns.bombs,
+        &patterns.airplane_chains,
+        &patterns.triples,
+        &patterns.consecutive_pair_chains,
+        &patterns.pairs,
+    ];
+    let used: Vec<&Card> = groups
+        .iter()
+        .flat_map(|group| group.iter().flatten())
+        .chain(&patterns.singles)
+        .collect();
+
+    (multiset(hand.iter()) != multiset(used.iter().copied())).then(|| {
+        format!(
+            "decomposition uses {} cards of a {}-card hand, or overlaps: {patterns:?}",
+            used.len(),
+            hand.len()
+        )
+    })
+}
+
+/// Panic unless `patterns` partitions `hand`.
+pub(crate) fn assert_partition(hand: &[Card], patterns: &HandPatterns) {
+    check(|| partition_violation(hand, patterns));
+}
+
+/// Within one shape (same type, chain length and card count), a play that
+/// beats another must have the higher strength.
+pub(crate) fn strength_violation(
+    new: &PlayPattern,
+    current: &PlayPattern,
+    beats: bool,
+) -> Option<String> {
+    let same_shape = new.play_type == current.play_type
+        && new.secondary_ranks.len() == current.secondary_ranks.len()
+        && new.card_count == current.card_count;
+
+    (same_shape && beats && new.strength <= current.strength).then(|| {
+        format!(
+            "{:?} with strength {} beats one with strength {}",
+            new.play_type, new.strength, current.strength
+        )
+    })
+}
+
+/// Panic if a comparison result contradicts strength ordering.
+pub(crate) fn assert_strength_monotonic(new: &PlayPattern, current: &PlayPattern, beats: bool) {
+    check(|| strength_violation(new, current, beats));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+    use crate::patterns::PlayType;
+    use crate::HandPatternAnalyzer;
+
+    #[test]
+    fn test_conservation() {
+        let ace = Card::new(Suit::Spades, Rank::Ace);
+        let king = Card::new(Suit::Hearts, Rank::King);
+
+        assert!(conservation_violation(&[ace, king], &[king], &[ace]).is_none());
+        assert!(conservation_violation(&[ace, king], &[king], &[king]).is_some());
+        assert!(conservation_violation(&[ace, ace], &[ace], &[]).is_some());
+    }
+
+    #[test]
+    fn test_partition() {
+        let hand: Vec<Card> = Suit::ALL
+            .iter()
+            .map(|s| Card::new(*s, Rank::Nine))
+            .chain([Card::new(Suit::Spades, Rank::Five)])
+            .collect();
+        let mut patterns = HandPatternAnalyzer::analyze_patterns(&hand);
+        assert!(partition_violation(&hand, &patterns).is_none());
+
+        patterns.singles.push(hand[0]);
+        assert!(partition_violation(&hand, &patterns).is_some());
+    }
+
+    #[test]
+    fn test_strength() {
+        let single = |rank: Rank, strength| {
+            PlayPattern::new(PlayType::Single, rank, None, vec![], 1, strength)
+        };
+
+        assert!(strength_violation(&single(Rank::Ace, 14), &single(Rank::Ten, 10), true).is_none());
+        assert!(strength_violation(&single(Rank::Ace, 1), &single(Rank::Ten, 10), true).is_some());
+        assert!(strength_violation(&single(Rank::Ace, 1), &single(Rank::Ten, 10), false).is_none());
+    }
+}
@@ -15,10 +15,34 @@
 //!
 //! - [`models`]: 核心数据模型（Card, Rank, Suit, Deck, GameConfig）
 //! - [`patterns`]: 牌型识别和验证
+//! - [`plays`]: 单手出牌工具（规范排序）
 //! - [`scoring`]: 计分系统
 //! - [`ai_helpers`]: AI 辅助工具
+//! - [`bidding`]: 叫分（底牌竞拍）评估
+//! - [`analysis`]: 出牌分析（可压制链等）
+//! - [`audit`]: 对局审计（过牌合法性检查）
+//! - [`coach`]: 复盘指导（最强应对与讲解）
 //! - [`variants`]: 规则变体配置
+//! - [`fairness`]: 可验证公平发牌（手牌承诺）
+//! - [`puzzles`]: 残局/每日挑战支持
+//! - [`simulation`]: 多种子发牌统计（公平性检测）
+//! - [`analytics`]: 手牌类型分类（匹配分析、机器人难度调节）与分牌流向
+//! - [`glossary`]: 牌型术语数据（帮助页面用的定义、示例与压制关系）
+//! - [`ui_helpers`]: 界面辅助（误选牌的修正建议）
+//! - `testkit`: 测试夹具记录（`record_case!`，需要 `testkit` 特性）
+//! - `export`: 事件导出（JSON Lines，需要 `serde` 特性）
+//! - [`logging`]: 可插拔诊断日志（无依赖）
+//! - [`metrics`]: 每线程工作量计数器
+//! - [`i18n`]: 规则与错误信息的多语言文本（zh-CN / en-US）
 //! - [`error`]: 错误类型定义
+//!
+//! ## 特性开关
+//!
+//! - `serde`: 序列化支持（核心类型、对手模型与出牌权重的 Serialize/Deserialize，以及 `export` 模块）
+//! - `reference-model`: 差分测试用的参考比较器
+//! - `debug-invariants`: 内部不变量断言（手牌守恒、牌型分解不重叠、强度单调），
+//!   违反时附带描述信息 panic；关闭时无开销
+//! - `testkit`: 测试辅助（`record_case!` 把识别/比较调用记录为回归夹具）
 
 #![warn(missing_docs)]
 #![warn(clippy::all)]
@@ -26,10 +50,29 @@
 #![allow(clippy::module_name_repetitions)]
 
 pub mod ai_helpers;
+pub mod analysis;
+pub mod analytics;
+pub mod audit;
+pub mod bidding;
+pub mod coach;
 pub mod error;
+#[cfg(feature = "serde")]
+pub mod export;
+pub mod fairness;
+pub mod glossary;
+pub mod i18n;
+mod invariants;
+pub mod logging;
+pub mod metrics;
 pub mod models;
 pub mod patterns;
+pub mod plays;
+pub mod puzzles;
 pub mod scoring;
+pub mod simulation;
+#[cfg(feature = "testkit")]
+pub mod testkit;
+pub mod ui_helpers;
 pub mod variants;
 
 // Re-export commonly used types
@@ -0,0 +1,121 @@
+//! Pluggable diagnostics without a logging dependency.
+//!
+//! The library never prints on its own. Hosts that want diagnostics install
+//! a [`Logger`] with [`set_logger`] and forward records to `log`, `tracing`
+//! or anything else. Messages are built lazily, so disabled levels cost one
+//! lock read.
+//!
+//! # Example
+//! ```
+//! use std::sync::{Arc, Mutex};
+//! use datongzi_rules::logging::{self, Level, Logger};
+//! use datongzi_rules::{Card, PlayGenerator, Rank, Suit};
+//!
+//! #[derive(Default)]
+//! struct Collect(Mutex<Vec<String>>);
+//!
+//! impl Logger for Collect {
+//!     fn log(&self, level: Level, module: &str, msg: &dyn Fn() -> String) {
+//!         self.0.lock().unwrap().push(format!("{level} {module}: {}", msg()));
+//!     }
+//! }
+//!
+//! let collector = Arc::new(Collect::default());
+//! logging::set_logger(collector.clone());
+//!
+//! let hand = vec![Card::new(Suit::Spades, Rank::Five); 20];
+//! let _ = PlayGenerator::generate_all_plays(&hand, 10);
+//! assert!(!collector.0.lock().unwrap().is_empty());
+//!
+//! logging::clear_logger();
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+/// Severity of a log record, most severe first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    /// Something failed
+    Error,
+    /// Something is likely wrong or expensive
+    Warn,
+    /// High-level progress
+    Info,
+    /// Details useful when debugging
+    Debug,
+    /// Very verbose internals
+    Trace,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Sink for library diagnostics.
+pub trait Logger: Send + Sync {
+    /// Record one message.
+    ///
+    /// `module` is the emitting module path (e.g.
+    /// `datongzi_rules::ai_helpers::play_generator`); `msg` builds the text
+    /// and need not be called if the record is dropped.
+    fn log(&self, level: Level, module: &str, msg: &dyn Fn() -> String);
+
+    /// Returns false to skip records at `level` before any work is done
+    fn enabled(&self, _level: Level) -> bool {
+        true
+    }
+}
+
+/// Logger that writes every record to stderr.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StderrLogger;
+
+impl Logger for StderrLogger {
+    fn log(&self, level: Level, module: &str, msg: &dyn Fn() -> String) {
+        eprintln!("[{level}] {module}: {}", msg());
+    }
+}
+
+static LOGGER: RwLock<Option<Arc<dyn Logger>>> = RwLock::new(None);
+
+/// Install `logger` for the whole process, replacing any previous one
+pub fn set_logger(logger: Arc<dyn Logger>) {
+    *LOGGER.write().unwrap_or_else(|e| e.into_inner()) = Some(logger);
+}
+
+/// Remove the installed logger
+pub fn clear_logger() {
+    *LOGGER.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Send a record to the installed logger, if any.
+pub(crate) fn log(level: Level, module: &str, msg: &dyn Fn() -> String) {
+    let logger = LOGGER.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(logger) = logger {
+        if logger.enabled(level) {
+            logger.log(level, module, msg);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_order_and_display() {
+        assert!(Level::Error < Level::Warn);
+        assert!(Level::Debug < Level::Trace);
+        assert_eq!(Level::Warn.to_string(), "WARN");
+    }
+}
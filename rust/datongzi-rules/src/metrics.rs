@@ -0,0 +1,153 @@
+//! Lightweight per-thread work counters.
+//!
+//! Rules functions bump thread-local counters as they run. An engine
+//! instance wraps its work in [`RulesMetrics::measure`] (or diffs two
+//! [`snapshot`](RulesMetrics::snapshot)s) to see how much work a hand cost,
+//! which makes pathological hands easy to spot.
+//!
+//! # Example
+//! ```
+//! use datongzi_rules::metrics::RulesMetrics;
+//! use datongzi_rules::{Card, PlayGenerator, Rank, Suit};
+//!
+//! let hand = vec![
+//!     Card::new(Suit::Spades, Rank::Five),
+//!     Card::new(Suit::Hearts, Rank::Five),
+//! ];
+//!
+//! let (plays, metrics) = RulesMetrics::measure(|| PlayGenerator::generate_all_plays(&hand, 1000));
+//! assert_eq!(metrics.plays_generated, plays.unwrap().len() as u64);
+//! ```
+
+use std::cell::Cell;
+use std::ops::AddAssign;
+
+thread_local! {
+    static COUNTERS: Cell<RulesMetrics> = const { Cell::new(RulesMetrics::new()) };
+}
+
+/// Work counters for rules evaluation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RulesMetrics {
+    /// Calls to [`PatternRecognizer::analyze_cards`](crate::PatternRecognizer::analyze_cards)
+    pub recognitions: u64,
+    /// Memoized states reused by the fewest-plays search
+    pub cache_hits: u64,
+    /// Plays returned by [`PlayGenerator`](crate::PlayGenerator) entry points
+    pub plays_generated: u64,
+    /// States expanded by the fewest-plays search
+    pub solver_nodes: u64,
+    /// Pattern checks and chain candidates tried by the recognizer
+    pub pattern_checks: u64,
+}
+
+impl RulesMetrics {
+    /// All-zero counters
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            recognitions: 0,
+            cache_hits: 0,
+            plays_generated: 0,
+            solver_nodes: 0,
+            pattern_checks: 0,
+        }
+    }
+
+    /// Running totals for the current thread
+    #[must_use]
+    pub fn snapshot() -> Self {
+        COUNTERS.with(Cell::get)
+    }
+
+    /// Counters accumulated between `earlier` and `self`
+    #[must_use]
+    pub const fn since(&self, earlier: &Self) -> Self {
+        Self {
+            recognitions: self.recognitions.saturating_sub(earlier.recognitions),
+            cache_hits: self.cache_hits.saturating_sub(earlier.cache_hits),
+            plays_generated: self.plays_generated.saturating_sub(earlier.plays_generated),
+            solver_nodes: self.solver_nodes.saturating_sub(earlier.solver_nodes),
+            pattern_checks: self.pattern_checks.saturating_sub(earlier.pattern_checks),
+        }
+    }
+
+    /// Run `f` and return its result with the work it did on this thread
+    pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Self) {
+        let before = Self::snapshot();
+        let result = f();
+        (result, Self::snapshot().since(&before))
+    }
+}
+
+impl AddAssign for RulesMetrics {
+    fn add_assign(&mut self, other: Self) {
+        self.recognitions = self.recognitions.saturating_add(other.recognitions);
+        self.cache_hits = self.cache_hits.saturating_add(other.cache_hits);
+        self.plays_generated = self.plays_generated.saturating_add(other.plays_generated);
+        self.solver_nodes = self.solver_nodes.saturating_add(other.solver_nodes);
+        self.pattern_checks = self.pattern_checks.saturating_add(other.pattern_checks);
+    }
+}
+
+/// Apply `update` to this thread's counters.
+fn bump(update: impl FnOnce(&mut RulesMetrics)) {
+    COUNTERS.with(|counters| {
+        let mut metrics = counters.get();
+        update(&mut metrics);
+        counters.set(metrics);
+    });
+}
+
+pub(crate) fn record_recognition() {
+    bump(|m| m.recognitions = m.recognitions.wrapping_add(1));
+}
+
+pub(crate) fn record_cache_hit() {
+    bump(|m| m.cache_hits = m.cache_hits.wrapping_add(1));
+}
+
+pub(crate) fn record_plays(count: usize) {
+    bump(|m| m.plays_generated = m.plays_generated.wrapping_add(count as u64));
+}
+
+pub(crate) fn record_solver_node() {
+    bump(|m| m.solver_nodes = m.solver_nodes.wrapping_add(1));
+}
+
+pub(crate) fn record_pattern_check() {
+    bump(|m| m.pattern_checks = m.pattern_checks.wrapping_add(1));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure_counts_only_the_closure() {
+        record_recognition();
+        let ((), metrics) = RulesMetrics::measure(|| {
+            record_recognition();
+            record_cache_hit();
+            record_plays(5);
+            record_solver_node();
+            record_pattern_check();
+        });
+
+        assert_eq!(
+            metrics,
+            RulesMetrics {
+                recognitions: 1,
+                cache_hits: 1,
+                plays_generated: 5,
+                solver_nodes: 1,
+                pattern_checks: 1,
+            }
+        );
+
+        let mut total = RulesMetrics::new();
+        total += metrics;
+        total += metrics;
+        assert_eq!(total.plays_generated, 10);
+    }
+}
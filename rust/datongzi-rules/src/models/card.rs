@@ -3,6 +3,7 @@
 use std::fmt;
 
 /// Card suit with ordering: SPADES > HEARTS > CLUBS > DIAMONDS
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
 pub enum Suit {
@@ -17,11 +18,30 @@ pub enum Suit {
 }
 
 impl Suit {
+    /// All suits in ascending order
+    pub const ALL: [Suit; 4] = [Self::Diamonds, Self::Clubs, Self::Hearts, Self::Spades];
+
     /// Returns the numeric value of the suit (1-4)
     #[must_use]
     pub const fn value(self) -> u8 {
         self as u8
     }
+
+    /// Position of the suit in [`Suit::ALL`] (Diamonds = 0, Spades = 3)
+    pub(crate) const fn index(self) -> usize {
+        (self.value() - Self::Diamonds.value()) as usize
+    }
+
+    /// Returns the lowercase English name used in asset keys (e.g. "spades")
+    #[must_use]
+    pub const fn asset_name(self) -> &'static str {
+        match self {
+            Self::Spades => "spades",
+            Self::Hearts => "hearts",
+            Self::Clubs => "clubs",
+            Self::Diamonds => "diamonds",
+        }
+    }
 }
 
 impl fmt::Display for Suit {
@@ -37,6 +57,7 @@ impl fmt::Display for Suit {
 }
 
 /// Card rank with ordering: TWO > ACE > KING > ... > THREE
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(u8)]
 pub enum Rank {
@@ -69,11 +90,53 @@ pub enum Rank {
 }
 
 impl Rank {
+    /// All ranks in ascending order (THREE to TWO)
+    pub const ALL: [Rank; 13] = [
+        Self::Three,
+        Self::Four,
+        Self::Five,
+        Self::Six,
+        Self::Seven,
+        Self::Eight,
+        Self::Nine,
+        Self::Ten,
+        Self::Jack,
+        Self::Queen,
+        Self::King,
+        Self::Ace,
+        Self::Two,
+    ];
+
     /// Returns the numeric value of the rank (3-15)
     #[must_use]
     pub const fn value(self) -> u8 {
         self as u8
     }
+
+    /// Position of the rank in [`Rank::ALL`] (Three = 0, Two = 12)
+    pub(crate) const fn index(self) -> usize {
+        (self.value() - Self::Three.value()) as usize
+    }
+
+    /// Returns the lowercase name used in asset keys (e.g. "10", "king")
+    #[must_use]
+    pub const fn asset_name(self) -> &'static str {
+        match self {
+            Self::Three => "3",
+            Self::Four => "4",
+            Self::Five => "5",
+            Self::Six => "6",
+            Self::Seven => "7",
+            Self::Eight => "8",
+            Self::Nine => "9",
+            Self::Ten => "10",
+            Self::Jack => "jack",
+            Self::Queen => "queen",
+            Self::King => "king",
+            Self::Ace => "ace",
+            Self::Two => "2",
+        }
+    }
 }
 
 impl fmt::Display for Rank {
@@ -98,6 +161,7 @@ impl fmt::Display for Rank {
 }
 
 /// A playing card with suit and rank
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Card {
     /// Card suit
@@ -141,6 +205,46 @@ impl Card {
         }
     }
 
+    /// Returns a stable artwork identifier such as `"spades_10"` or `"hearts_king"`.
+    ///
+    /// Format: `{suit}_{rank}` using [`Suit::asset_name`] and [`Rank::asset_name`].
+    #[must_use]
+    pub fn asset_key(&self) -> String {
+        format!("{}_{}", self.suit.asset_name(), self.rank.asset_name())
+    }
+
+    /// Returns the index of this card in the standard 52-sprite sheet (0-51).
+    ///
+    /// Layout: 4 rows × 13 columns, row-major.
+    /// - Rows by suit: ♠ (0), ♥ (1), ♣ (2), ♦ (3)
+    /// - Columns by face value: A, 2, 3, ..., 10, J, Q, K (0-12)
+    ///
+    /// Example: A♠ = 0, K♠ = 12, 2♥ = 14, K♦ = 51
+    #[must_use]
+    pub const fn sprite_index(&self) -> usize {
+        let row = match self.suit {
+            Suit::Spades => 0,
+            Suit::Hearts => 1,
+            Suit::Clubs => 2,
+            Suit::Diamonds => 3,
+        };
+        let column = match self.rank {
+            Rank::Ace => 0,
+            Rank::Two => 1,
+            // Three..King map to columns 2..12
+            other => other.value() as usize - 1,
+        };
+        row * 13 + column
+    }
+
+    /// Parse a card written by its `Display` form (e.g. `10♠`, `K♥`)
+    pub(crate) fn from_display(token: &str) -> Option<Self> {
+        Suit::ALL
+            .iter()
+            .flat_map(|suit| Rank::ALL.iter().map(|rank| Self::new(*suit, *rank)))
+            .find(|card| card.to_string() == token)
+    }
+
     /// Compare cards for display purposes
     ///
     /// Orders cards by rank (3-10, J-K, A-2), then by suit (♠♥♣♦) for same rank.
@@ -170,10 +274,52 @@ impl fmt::Display for Card {
     }
 }
 
+/// A card tagged with its physical copy in a multi-deck shoe
+///
+/// With several decks, two ♠K are equal as [`Card`]s. `copy_index` records
+/// which deck a card came from (0-based), so hands, plays and replays can
+/// track physical identity where it matters (e.g. auditing).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaggedCard {
+    /// The card face
+    pub card: Card,
+    /// Which deck copy this card is (0..num_decks)
+    pub copy_index: u8,
+}
+
+impl TaggedCard {
+    /// Creates a new tagged card
+    #[must_use]
+    pub const fn new(card: Card, copy_index: u8) -> Self {
+        Self { card, copy_index }
+    }
+
+    /// Strips the tags from a slice of tagged cards
+    #[must_use]
+    pub fn untag_all(tagged: &[Self]) -> Vec<Card> {
+        tagged.iter().map(|t| t.card).collect()
+    }
+}
+
+impl From<TaggedCard> for Card {
+    fn from(tagged: TaggedCard) -> Self {
+        tagged.card
+    }
+}
+
+impl fmt::Display for TaggedCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}#{}", self.card, self.copy_index)
+    }
+}
+
 /// A deck of cards
+///
+/// Cards are tracked internally as [`TaggedCard`]s so that multi-deck deals
+/// can preserve physical identity via [`deal_tagged_cards`](Self::deal_tagged_cards).
 #[derive(Debug, Clone)]
 pub struct Deck {
-    cards: Vec<Card>,
+    cards: Vec<TaggedCard>,
 }
 
 impl Deck {
@@ -187,25 +333,11 @@ impl Deck {
     pub fn new(num_decks: u8, excluded_ranks: &[Rank]) -> Self {
         let mut cards = Vec::with_capacity(usize::from(num_decks) * 52);
 
-        for _ in 0..num_decks {
-            for suit in [Suit::Diamonds, Suit::Clubs, Suit::Hearts, Suit::Spades] {
-                for rank in [
-                    Rank::Three,
-                    Rank::Four,
-                    Rank::Five,
-                    Rank::Six,
-                    Rank::Seven,
-                    Rank::Eight,
-                    Rank::Nine,
-                    Rank::Ten,
-                    Rank::Jack,
-                    Rank::Queen,
-                    Rank::King,
-                    Rank::Ace,
-                    Rank::Two,
-                ] {
+        for copy_index in 0..num_decks {
+            for suit in Suit::ALL {
+                for rank in Rank::ALL {
                     if !excluded_ranks.contains(&rank) {
-                        cards.push(Card::new(suit, rank));
+                        cards.push(TaggedCard::new(Card::new(suit, rank), copy_index));
                     }
                 }
             }
@@ -220,6 +352,27 @@ impl Deck {
         Self::new(num_decks, &[])
     }
 
+    /// Creates the deck described by a game configuration
+    ///
+    /// Honors `num_decks`, `removed_ranks` and `card_copies`.
+    #[must_use]
+    pub fn from_config(config: &super::GameConfig) -> Self {
+        let mut cards = Vec::with_capacity(config.deck_size());
+
+        for copy_index in 0..config.num_decks() {
+            for suit in Suit::ALL {
+                for rank in Rank::ALL {
+                    let card = Card::new(suit, rank);
+                    if copy_index < config.copies_of(card) {
+                        cards.push(TaggedCard::new(card, copy_index));
+                    }
+                }
+            }
+        }
+
+        Self { cards }
+    }
+
     /// Shuffles the deck
     pub fn shuffle(&mut self) {
         use rand::seq::SliceRandom;
@@ -229,6 +382,14 @@ impl Deck {
         self.cards.shuffle(&mut rng);
     }
 
+    /// Shuffles the deck with a caller-provided RNG (e.g. a seeded `StdRng`
+    /// for reproducible deals)
+    pub fn shuffle_with_rng<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) {
+        use rand::seq::SliceRandom;
+
+        self.cards.shuffle(rng);
+    }
+
     /// Deals the specified number of cards from the deck
     ///
     /// # Panics
@@ -236,6 +397,16 @@ impl Deck {
     /// Panics if there are not enough cards in the deck
     #[must_use]
     pub fn deal_cards(&mut self, count: usize) -> Vec<Card> {
+        TaggedCard::untag_all(&self.deal_tagged_cards(count))
+    }
+
+    /// Deals the specified number of cards, keeping their copy tags
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are not enough cards in the deck
+    #[must_use]
+    pub fn deal_tagged_cards(&mut self, count: usize) -> Vec<TaggedCard> {
         self.cards.split_off(self.cards.len() - count)
     }
 
@@ -249,6 +420,12 @@ impl Deck {
         self.deal_cards(count)
     }
 
+    /// Returns the remaining cards in order; dealing takes from the end
+    #[must_use]
+    pub fn tagged_cards(&self) -> &[TaggedCard] {
+        &self.cards
+    }
+
     /// Returns the number of cards remaining in the deck
     #[must_use]
     pub fn len(&self) -> usize {
@@ -286,6 +463,13 @@ mod tests {
         assert!(Rank::King > Rank::Three);
     }
 
+    #[test]
+    fn test_rank_index_follows_all() {
+        for rank in Rank::ALL {
+            assert_eq!(Rank::ALL[rank.index()], rank);
+        }
+    }
+
     #[test]
     fn test_card_creation() {
         let card = Card::new(Suit::Spades, Rank::Ace);
@@ -309,12 +493,64 @@ mod tests {
         assert_eq!(Card::new(Suit::Spades, Rank::Ace).score_value(), 0);
     }
 
+    #[test]
+    fn test_asset_key() {
+        assert_eq!(Card::new(Suit::Spades, Rank::Ten).asset_key(), "spades_10");
+        assert_eq!(
+            Card::new(Suit::Hearts, Rank::King).asset_key(),
+            "hearts_king"
+        );
+        assert_eq!(
+            Card::new(Suit::Diamonds, Rank::Two).asset_key(),
+            "diamonds_2"
+        );
+    }
+
+    #[test]
+    fn test_sprite_index_layout() {
+        assert_eq!(Card::new(Suit::Spades, Rank::Ace).sprite_index(), 0);
+        assert_eq!(Card::new(Suit::Spades, Rank::Three).sprite_index(), 2);
+        assert_eq!(Card::new(Suit::Spades, Rank::King).sprite_index(), 12);
+        assert_eq!(Card::new(Suit::Hearts, Rank::Two).sprite_index(), 14);
+        assert_eq!(Card::new(Suit::Diamonds, Rank::King).sprite_index(), 51);
+
+        // Every card of a single deck maps to a distinct index
+        let deck = Deck::create_standard_deck(1);
+        let mut indices: Vec<usize> = deck.cards.iter().map(|t| t.card.sprite_index()).collect();
+        indices.sort_unstable();
+        indices.dedup();
+        assert_eq!(indices, (0..52).collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_deck_creation() {
         let deck = Deck::create_standard_deck(3);
         assert_eq!(deck.len(), 156); // 3 * 52 cards
     }
 
+    #[test]
+    fn test_deck_shuffle_with_seeded_rng() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut deck_a = Deck::create_standard_deck(1);
+        let mut deck_b = Deck::create_standard_deck(1);
+        deck_a.shuffle_with_rng(&mut StdRng::seed_from_u64(42));
+        deck_b.shuffle_with_rng(&mut StdRng::seed_from_u64(42));
+        assert_eq!(deck_a.cards, deck_b.cards);
+    }
+
+    #[test]
+    fn test_deck_from_config() {
+        let config = super::super::GameConfig::default();
+        let deck = Deck::from_config(&config);
+        assert_eq!(deck.len(), config.deck_size());
+        assert!(deck
+            .cards
+            .iter()
+            .all(|t| t.card.rank != Rank::Three && t.card.rank != Rank::Four));
+    }
+
     #[test]
     fn test_deck_deal() {
         let mut deck = Deck::create_standard_deck(1);
@@ -322,4 +558,24 @@ mod tests {
         assert_eq!(hand.len(), 13);
         assert_eq!(deck.len(), 39);
     }
+
+    #[test]
+    fn test_tagged_deal_distinguishes_copies() {
+        let mut deck = Deck::create_standard_deck(3);
+        let tagged = deck.deal_tagged_cards(deck.len());
+
+        let king_of_spades: Vec<&TaggedCard> = tagged
+            .iter()
+            .filter(|t| t.card == Card::new(Suit::Spades, Rank::King))
+            .collect();
+        assert_eq!(king_of_spades.len(), 3);
+
+        let mut copies: Vec<u8> = king_of_spades.iter().map(|t| t.copy_index).collect();
+        copies.sort_unstable();
+        assert_eq!(copies, vec![0, 1, 2]);
+        assert_eq!(
+            TaggedCard::new(Card::new(Suit::Spades, Rank::King), 2).to_string(),
+            "K♠#2"
+        );
+    }
 }
@@ -1,6 +1,128 @@
 //! Game configuration.
 
-use crate::Rank;
+use super::{FirstLeader, PlayType, RankOrdering, Rotation, TongziSuitOrder};
+use crate::i18n::Message;
+use crate::{Card, Rank, Suit};
+
+/// What happens to scoring cards (5/10/K) the bid winner discards from the aside exchange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiscardScoring {
+    /// Discarded points are credited to the bid winner
+    #[default]
+    CreditBidder,
+    /// Discarded points leave the game and score for nobody
+    Void,
+    /// Scoring cards may not be discarded
+    Forbidden,
+}
+
+/// Extra points for the game's final trick (最后一墩).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LastTrickScoring {
+    /// The final trick scores like any other
+    #[default]
+    Standard,
+    /// The final trick's points count twice for its winner
+    Double,
+    /// The final trick's winner also takes the points in the aside pile
+    TakeAside,
+}
+
+/// Practice/tutorial relaxations, set on [`GameConfig::training`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TrainingMode {
+    /// Players may take back moves without limit
+    pub unlimited_undo: bool,
+    /// Only plays of this family are legal (airplanes with and without
+    /// wings count as one family)
+    pub drill_play_type: Option<PlayType>,
+}
+
+/// Trumps that may not open a trick, set on [`GameConfig::lead_restrictions`].
+///
+/// Some tables forbid leading with a Tongzi or Dizha so they are only used
+/// to answer, except as a player's finishing play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LeadRestrictions {
+    /// A Tongzi may not open a trick
+    pub no_tongzi_lead: bool,
+    /// A Dizha may not open a trick
+    pub no_dizha_lead: bool,
+    /// A restricted lead is still allowed when at most this many cards stay
+    /// in hand after it (0: only a lead that empties the hand)
+    pub finishing_allowance: usize,
+}
+
+impl LeadRestrictions {
+    /// Returns true if nothing is restricted
+    #[must_use]
+    pub const fn is_unrestricted(&self) -> bool {
+        !self.no_tongzi_lead && !self.no_dizha_lead
+    }
+
+    /// Returns true if a `play_type` lead leaving `cards_left` cards in hand
+    /// is allowed
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::models::LeadRestrictions;
+    /// use datongzi_rules::PlayType;
+    ///
+    /// let restrictions = LeadRestrictions { no_dizha_lead: true, ..LeadRestrictions::default() };
+    /// assert!(!restrictions.allows(PlayType::Dizha, 5));
+    /// assert!(restrictions.allows(PlayType::Dizha, 0));
+    /// assert!(restrictions.allows(PlayType::Tongzi, 5));
+    /// ```
+    #[must_use]
+    pub const fn allows(&self, play_type: PlayType, cards_left: usize) -> bool {
+        let restricted = match play_type {
+            PlayType::Tongzi => self.no_tongzi_lead,
+            PlayType::Dizha => self.no_dizha_lead,
+            _ => false,
+        };
+        !restricted || cards_left <= self.finishing_allowance
+    }
+}
+
+/// Plays that may not empty a hand, set on [`GameConfig::finish_restrictions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FinishRestrictions {
+    /// A hand may not finish on a single 5, 10 or K
+    pub no_single_score_card: bool,
+    /// A hand may not finish on a play carrying kickers or wings
+    pub no_kickers: bool,
+}
+
+impl FinishRestrictions {
+    /// Returns true if any finishing play is allowed
+    #[must_use]
+    pub const fn is_unrestricted(&self) -> bool {
+        !self.no_single_score_card && !self.no_kickers
+    }
+}
+
+/// Few-cards-left announcement (报牌), set on [`GameConfig::cards_left_alert`].
+///
+/// Once a player's hand drops to `threshold` cards or fewer, the table is
+/// told how many they hold; with `must_beat` such a player may also not pass
+/// while holding a play that beats the current one. The announcement itself
+/// is [`Message::cards_left_announcement`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardsLeftAlert {
+    /// Announce when a hand drops to this many cards or fewer
+    pub threshold: usize,
+    /// Players at or under the threshold may not pass when they can beat
+    pub must_beat: bool,
+}
+
+impl CardsLeftAlert {
+    /// Returns true if a hand holding `cards_left` cards is under alert
+    /// (an empty hand has finished and is not)
+    #[must_use]
+    pub const fn applies_to(&self, cards_left: usize) -> bool {
+        cards_left > 0 && cards_left <= self.threshold
+    }
+}
 
 /// Game configuration parameters
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,6 +137,9 @@ pub struct GameConfig {
     pub cards_dealt_aside: usize,
     /// Ranks to remove from deck (e.g., [Three, Four] for standard 3-deck game)
     pub removed_ranks: Vec<Rank>,
+    /// Per-card copy counts overriding `num_decks` (0 removes the card);
+    /// the first entry for a card wins
+    pub card_copies: Vec<(Card, u8)>,
     /// Finish position bonuses (上游, 二游, 三游)
     pub finish_bonus: Vec<i32>,
     /// K Tongzi bonus points
@@ -25,6 +150,36 @@ pub struct GameConfig {
     pub two_tongzi_bonus: i32,
     /// Dizha bonus points
     pub dizha_bonus: i32,
+    /// Whether players may reveal their hand (明牌) for multiplied stakes
+    pub allow_reveal: bool,
+    /// Score multiplier applied to a player who revealed their hand
+    pub reveal_multiplier: i32,
+    /// Whether players bid (叫分) for the aside pile before play
+    pub allow_bidding: bool,
+    /// How scoring cards discarded in the aside exchange are handled
+    pub discard_scoring: DiscardScoring,
+    /// Extra points for the game's final trick
+    pub last_trick_scoring: LastTrickScoring,
+    /// Practice/tutorial mode; `None` for real games
+    pub training: Option<TrainingMode>,
+    /// Direction play passes around the table
+    pub rotation: Rotation,
+    /// Who leads the first trick
+    pub first_leader: FirstLeader,
+    /// Whether a single beats an equal-rank single of a lower suit
+    pub singles_suit_tiebreak: bool,
+    /// Rank order for bombs of equal size
+    pub bomb_rank_order: RankOrdering,
+    /// Suit order for Tongzi of equal rank
+    pub tongzi_suit_order: TongziSuitOrder,
+    /// Ranks that may not be carried as triple kickers or airplane wings
+    pub forbidden_kicker_ranks: Vec<Rank>,
+    /// Trumps that may not open a trick
+    pub lead_restrictions: LeadRestrictions,
+    /// Few-cards-left announcement (报牌); `None` disables it
+    pub cards_left_alert: Option<CardsLeftAlert>,
+    /// Plays that may not empty a hand
+    pub finish_restrictions: FinishRestrictions,
 }
 
 impl Default for GameConfig {
@@ -35,11 +190,27 @@ impl Default for GameConfig {
             cards_per_player: 41,
             cards_dealt_aside: 9,
             removed_ranks: vec![Rank::Three, Rank::Four], // Standard: remove 3 and 4
+            card_copies: Vec::new(),
             finish_bonus: vec![100, -40, -60],
             k_tongzi_bonus: 100,
             a_tongzi_bonus: 200,
             two_tongzi_bonus: 300,
             dizha_bonus: 400,
+            allow_reveal: false,
+            reveal_multiplier: 2,
+            allow_bidding: false,
+            discard_scoring: DiscardScoring::CreditBidder,
+            last_trick_scoring: LastTrickScoring::Standard,
+            training: None,
+            rotation: Rotation::Clockwise,
+            first_leader: FirstLeader::LeftOfDealer,
+            singles_suit_tiebreak: false,
+            bomb_rank_order: RankOrdering::Natural,
+            tongzi_suit_order: TongziSuitOrder::Natural,
+            forbidden_kicker_ranks: Vec::new(),
+            lead_restrictions: LeadRestrictions::default(),
+            cards_left_alert: None,
+            finish_restrictions: FinishRestrictions::default(),
         }
     }
 }
@@ -95,11 +266,27 @@ impl GameConfig {
             cards_per_player,
             cards_dealt_aside,
             removed_ranks,
+            card_copies: Vec::new(),
             finish_bonus,
             k_tongzi_bonus,
             a_tongzi_bonus,
             two_tongzi_bonus,
             dizha_bonus,
+            allow_reveal: false,
+            reveal_multiplier: 2,
+            allow_bidding: false,
+            discard_scoring: DiscardScoring::CreditBidder,
+            last_trick_scoring: LastTrickScoring::Standard,
+            training: None,
+            rotation: Rotation::Clockwise,
+            first_leader: FirstLeader::LeftOfDealer,
+            singles_suit_tiebreak: false,
+            bomb_rank_order: RankOrdering::Natural,
+            tongzi_suit_order: TongziSuitOrder::Natural,
+            forbidden_kicker_ranks: Vec::new(),
+            lead_restrictions: LeadRestrictions::default(),
+            cards_left_alert: None,
+            finish_restrictions: FinishRestrictions::default(),
         }
     }
 
@@ -133,6 +320,64 @@ impl GameConfig {
         &self.removed_ranks
     }
 
+    /// Returns true if `rank` is removed from the deck in this configuration
+    #[must_use]
+    pub fn is_rank_removed(&self, rank: Rank) -> bool {
+        self.removed_ranks.contains(&rank)
+    }
+
+    /// Returns the per-card copy count overrides
+    #[must_use]
+    pub fn card_copies(&self) -> &[(Card, u8)] {
+        &self.card_copies
+    }
+
+    /// Returns how many copies of `card` the deck holds, honoring
+    /// `removed_ranks` and `card_copies`
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::{Card, GameConfig, Rank, Suit};
+    ///
+    /// let two = Card::new(Suit::Spades, Rank::Two);
+    /// let config = GameConfig {
+    ///     card_copies: vec![(two, 2)],
+    ///     ..GameConfig::default()
+    /// };
+    /// assert_eq!(config.copies_of(two), 2);
+    /// assert_eq!(config.copies_of(Card::new(Suit::Hearts, Rank::Two)), 3);
+    /// assert_eq!(config.copies_of(Card::new(Suit::Hearts, Rank::Three)), 0);
+    /// ```
+    #[must_use]
+    pub fn copies_of(&self, card: Card) -> u8 {
+        if self.is_rank_removed(card.rank) {
+            return 0;
+        }
+        self.card_copies
+            .iter()
+            .find(|(c, _)| *c == card)
+            .map_or(self.num_decks, |(_, copies)| *copies)
+    }
+
+    /// Returns how many cards of `rank` the deck holds across all suits
+    #[must_use]
+    pub fn copies_of_rank(&self, rank: Rank) -> usize {
+        Suit::ALL
+            .iter()
+            .map(|suit| usize::from(self.copies_of(Card::new(*suit, rank))))
+            .sum()
+    }
+
+    /// Returns the number of cards in the deck after removing
+    /// `removed_ranks` and applying `card_copies`
+    #[must_use]
+    pub fn deck_size(&self) -> usize {
+        Rank::ALL
+            .iter()
+            .map(|rank| self.copies_of_rank(*rank))
+            .sum()
+    }
+
     /// Returns the finish bonus list
     #[must_use]
     pub fn finish_bonus(&self) -> &[i32] {
@@ -163,6 +408,131 @@ impl GameConfig {
         self.dizha_bonus
     }
 
+    /// Returns whether revealing a hand (明牌) is allowed
+    #[must_use]
+    pub const fn allow_reveal(&self) -> bool {
+        self.allow_reveal
+    }
+
+    /// Returns the score multiplier for revealed hands
+    #[must_use]
+    pub const fn reveal_multiplier(&self) -> i32 {
+        self.reveal_multiplier
+    }
+
+    /// Returns whether the aside pile is auctioned (叫分)
+    #[must_use]
+    pub const fn allow_bidding(&self) -> bool {
+        self.allow_bidding
+    }
+
+    /// Returns how discarded scoring cards are handled in the aside exchange
+    #[must_use]
+    pub const fn discard_scoring(&self) -> DiscardScoring {
+        self.discard_scoring
+    }
+
+    /// Returns the extra scoring for the game's final trick
+    #[must_use]
+    pub const fn last_trick_scoring(&self) -> LastTrickScoring {
+        self.last_trick_scoring
+    }
+
+    /// Returns the training-mode settings, if this is a practice game
+    #[must_use]
+    pub const fn training(&self) -> Option<TrainingMode> {
+        self.training
+    }
+
+    /// Returns the direction play passes around the table
+    #[must_use]
+    pub const fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    /// Returns who leads the first trick
+    #[must_use]
+    pub const fn first_leader(&self) -> FirstLeader {
+        self.first_leader
+    }
+
+    /// Returns whether equal-rank singles are ordered by suit
+    #[must_use]
+    pub const fn singles_suit_tiebreak(&self) -> bool {
+        self.singles_suit_tiebreak
+    }
+
+    /// Returns the rank order for bombs of equal size
+    #[must_use]
+    pub const fn bomb_rank_order(&self) -> &RankOrdering {
+        &self.bomb_rank_order
+    }
+
+    /// Returns the suit order for Tongzi of equal rank
+    #[must_use]
+    pub const fn tongzi_suit_order(&self) -> &TongziSuitOrder {
+        &self.tongzi_suit_order
+    }
+
+    /// The configuration for the game dealt from `seed`, with a
+    /// [`TongziSuitOrder::RandomPerGame`] order drawn for that game
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::models::TongziSuitOrder;
+    /// use datongzi_rules::GameConfig;
+    ///
+    /// let config = GameConfig {
+    ///     tongzi_suit_order: TongziSuitOrder::RandomPerGame,
+    ///     ..GameConfig::default()
+    /// };
+    /// let game = config.for_game(42);
+    /// assert!(matches!(game.tongzi_suit_order(), TongziSuitOrder::Fixed(_)));
+    /// ```
+    #[must_use]
+    pub fn for_game(&self, seed: u64) -> Self {
+        Self {
+            tongzi_suit_order: self.tongzi_suit_order.for_game(seed),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the ranks that may not be carried as kickers or wings
+    #[must_use]
+    pub fn forbidden_kicker_ranks(&self) -> &[Rank] {
+        &self.forbidden_kicker_ranks
+    }
+
+    /// Returns true if `rank` may not be carried as a kicker or wing
+    #[must_use]
+    pub fn is_kicker_rank_forbidden(&self, rank: Rank) -> bool {
+        self.forbidden_kicker_ranks.contains(&rank)
+    }
+
+    /// Returns the trumps that may not open a trick
+    #[must_use]
+    pub const fn lead_restrictions(&self) -> &LeadRestrictions {
+        &self.lead_restrictions
+    }
+
+    /// Returns the few-cards-left announcement settings, if enabled
+    #[must_use]
+    pub const fn cards_left_alert(&self) -> Option<CardsLeftAlert> {
+        self.cards_left_alert
+    }
+
+    /// Returns the plays that may not empty a hand
+    #[must_use]
+    pub const fn finish_restrictions(&self) -> &FinishRestrictions {
+        &self.finish_restrictions
+    }
+
+    /// Returns true for practice/tutorial games
+    #[must_use]
+    pub const fn is_training(&self) -> bool {
+        self.training.is_some()
+    }
+
     /// Validates the configuration
     ///
     /// # Errors
@@ -171,37 +541,66 @@ impl GameConfig {
     pub fn validate(&self) -> crate::Result<()> {
         // Check player count is valid
         if !(2..=4).contains(&self.num_players) {
-            return Err(crate::DatongziError::ConfigError(format!(
-                "Invalid number of players: {} (must be 2-4)",
-                self.num_players
-            )));
+            return Err(crate::DatongziError::ConfigError(
+                Message::InvalidPlayerCount {
+                    players: self.num_players,
+                },
+            ));
         }
 
         // Check deck count is valid
         if self.num_decks == 0 {
+            return Err(crate::DatongziError::ConfigError(Message::NoDecks));
+        }
+
+        if let Some((card, copies)) = self
+            .card_copies
+            .iter()
+            .find(|(_, copies)| *copies > self.num_decks)
+        {
             return Err(crate::DatongziError::ConfigError(
-                "Number of decks must be at least 1".to_string(),
+                Message::CardCopiesExceedDecks {
+                    card: *card,
+                    copies: *copies,
+                    decks: self.num_decks,
+                },
             ));
         }
 
         // Check enough cards for all players
-        let total_cards = usize::from(self.num_decks) * 52;
+        let total_cards = self.deck_size();
         let required_cards =
             self.cards_per_player * usize::from(self.num_players) + self.cards_dealt_aside;
 
         if required_cards > total_cards {
-            return Err(crate::DatongziError::ConfigError(format!(
-                "Not enough cards: need {required_cards}, have {total_cards}"
-            )));
+            return Err(crate::DatongziError::ConfigError(Message::NotEnoughCards {
+                need: required_cards,
+                have: total_cards,
+            }));
         }
 
         // Check finish_bonus length matches player count
         if self.finish_bonus.len() != usize::from(self.num_players) {
-            return Err(crate::DatongziError::ConfigError(format!(
-                "finish_bonus length ({}) must match num_players ({})",
-                self.finish_bonus.len(),
-                self.num_players
-            )));
+            return Err(crate::DatongziError::ConfigError(
+                Message::FinishBonusLength {
+                    len: self.finish_bonus.len(),
+                    players: self.num_players,
+                },
+            ));
+        }
+
+        if self.allow_reveal && self.reveal_multiplier < 1 {
+            return Err(crate::DatongziError::ConfigError(
+                Message::RevealMultiplierTooSmall {
+                    multiplier: self.reveal_multiplier,
+                },
+            ));
+        }
+
+        if self.allow_bidding && self.cards_dealt_aside == 0 {
+            return Err(crate::DatongziError::ConfigError(
+                Message::BiddingWithoutAside,
+            ));
         }
 
         Ok(())
@@ -219,4 +618,71 @@ mod tests {
         assert_eq!(config.num_players, 3);
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn test_deck_size_honors_removed_ranks() {
+        let config = GameConfig::default();
+        assert_eq!(config.deck_size(), 132);
+        assert!(config.is_rank_removed(Rank::Three));
+        assert!(!config.is_rank_removed(Rank::Five));
+
+        let full = GameConfig {
+            removed_ranks: vec![],
+            ..GameConfig::default()
+        };
+        assert_eq!(full.deck_size(), 156);
+    }
+
+    #[test]
+    fn test_card_copies_shrink_deck() {
+        let twos = Suit::ALL.map(|suit| (Card::new(suit, Rank::Two), 2));
+        let config = GameConfig {
+            card_copies: twos.to_vec(),
+            cards_dealt_aside: 8,
+            cards_per_player: 40,
+            ..GameConfig::default()
+        };
+        assert_eq!(config.deck_size(), 128);
+        assert_eq!(crate::Deck::from_config(&config).len(), 128);
+        assert_eq!(config.copies_of_rank(Rank::Two), 8);
+        assert!(config.validate().is_ok());
+
+        // Cannot add copies beyond the deck count
+        let too_many = GameConfig {
+            card_copies: vec![(Card::new(Suit::Spades, Rank::Ace), 4)],
+            ..GameConfig::default()
+        };
+        assert!(too_many.validate().is_err());
+    }
+
+    #[test]
+    fn test_deal_size_honors_removed_ranks() {
+        // Removing 3-6 leaves 108 cards for a 132-card deal
+        let config = GameConfig {
+            removed_ranks: vec![Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            ..GameConfig::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(crate::DatongziError::ConfigError(Message::NotEnoughCards {
+                need: 132,
+                have: 108
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_bidding_requires_aside_pile() {
+        let config = GameConfig {
+            allow_bidding: true,
+            ..GameConfig::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let no_aside = GameConfig {
+            cards_dealt_aside: 0,
+            ..config
+        };
+        assert!(no_aside.validate().is_err());
+    }
 }
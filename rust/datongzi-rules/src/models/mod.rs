@@ -4,11 +4,36 @@
 //! - [`Card`]: A playing card with suit and rank
 //! - [`Rank`]: Card rank (THREE to TWO)
 //! - [`Suit`]: Card suit (DIAMONDS to SPADES)
+//! - [`TaggedCard`]: A card tagged with its physical deck copy
 //! - [`Deck`]: A collection of cards
+//! - [`PlayType`]: Kind of play, in order of strength
+//! - [`RankCounts`]: Fixed-size per-rank card counts
+//! - [`RankOrdering`]: Configurable rank order for comparisons
+//! - [`TongziSuitOrder`]: Configurable suit order for equal-rank Tongzi
 //! - [`GameConfig`]: Game configuration and rules
+//! - [`DiscardScoring`]: Handling of scoring cards discarded from the aside
+//! - [`LastTrickScoring`]: Extra points for the final trick
+//! - [`TrainingMode`]: Practice/tutorial relaxations
+//! - [`LeadRestrictions`]: Trumps that may not open a trick
+//! - [`CardsLeftAlert`]: Few-cards-left announcement (报牌)
+//! - [`FinishRestrictions`]: Plays that may not empty a hand
+//! - [`Seating`]: Seat indices, turn order and first leader
 
 pub mod card;
 pub mod config;
+pub mod play_type;
+pub mod rank_counts;
+pub mod rank_ordering;
+pub mod seating;
+pub mod suit_ordering;
 
-pub use card::{Card, Deck, Rank, Suit};
-pub use config::GameConfig;
+pub use card::{Card, Deck, Rank, Suit, TaggedCard};
+pub use config::{
+    CardsLeftAlert, DiscardScoring, FinishRestrictions, GameConfig, LastTrickScoring,
+    LeadRestrictions, TrainingMode,
+};
+pub use play_type::PlayType;
+pub use rank_counts::RankCounts;
+pub use rank_ordering::RankOrdering;
+pub use seating::{FirstLeader, Rotation, Seating};
+pub use suit_ordering::TongziSuitOrder;
@@ -0,0 +1,41 @@
+//! Play types shared by configuration and pattern recognition.
+
+/// Play types in order of strength.
+///
+/// Higher values beat lower values, with special rules for some types.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum PlayType {
+    /// Single card (单牌)
+    Single = 1,
+    /// Pair of cards (对子)
+    Pair = 2,
+    /// Consecutive pairs (连对, 2+ pairs in sequence)
+    ConsecutivePairs = 3,
+    /// Triple with 0-2 kickers (三张，可带0-2张)
+    Triple = 4,
+    /// Airplane - consecutive triples (飞机)
+    Airplane = 6,
+    /// Airplane with wings (飞机带翅膀)
+    AirplaneWithWings = 7,
+    /// Bomb - 4+ same rank (炸弹)
+    Bomb = 8,
+    /// Tongzi - 3 same rank same suit (筒子)
+    Tongzi = 9,
+    /// Dizha - 2 of each suit for same rank (地炸, 8 cards)
+    Dizha = 10,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_play_type_ordering() {
+        assert!(PlayType::Single < PlayType::Pair);
+        assert!(PlayType::Pair < PlayType::Bomb);
+        assert!(PlayType::Bomb < PlayType::Tongzi);
+        assert!(PlayType::Tongzi < PlayType::Dizha);
+    }
+}
@@ -0,0 +1,193 @@
+//! Fixed-size per-rank card counts.
+
+use super::{Card, Rank};
+
+/// Number of ranks in the deck.
+const RANK_COUNT: usize = Rank::ALL.len();
+
+/// Cards held per rank, indexed like [`Rank::ALL`].
+///
+/// A flat `[u8; 13]` replacement for `HashMap<Rank, usize>` in hot paths:
+/// no hashing, no allocation, and iteration is already in rank order.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::models::RankCounts;
+/// use datongzi_rules::{Card, Rank, Suit};
+///
+/// let counts = RankCounts::from_cards(&[
+///     Card::new(Suit::Spades, Rank::Five),
+///     Card::new(Suit::Hearts, Rank::Five),
+///     Card::new(Suit::Clubs, Rank::King),
+/// ]);
+/// assert_eq!(counts.get(Rank::Five), 2);
+/// assert_eq!(counts.distinct_ranks(), 2);
+/// assert_eq!(counts.total(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RankCounts(pub [u8; RANK_COUNT]);
+
+impl RankCounts {
+    /// Create empty counts
+    #[must_use]
+    pub const fn new() -> Self {
+        Self([0; RANK_COUNT])
+    }
+
+    /// Count the cards of each rank in `cards`
+    #[must_use]
+    pub fn from_cards(cards: &[Card]) -> Self {
+        let mut counts = Self::new();
+        for card in cards {
+            counts.add(card.rank);
+        }
+        counts
+    }
+
+    /// Cards held of `rank`
+    #[must_use]
+    pub const fn get(&self, rank: Rank) -> u8 {
+        self.0[rank.index()]
+    }
+
+    /// Add one card of `rank`
+    pub fn add(&mut self, rank: Rank) {
+        let slot = &mut self.0[rank.index()];
+        *slot = slot.saturating_add(1);
+    }
+
+    /// Remove one card of `rank` (no-op at zero)
+    pub fn remove(&mut self, rank: Rank) {
+        let slot = &mut self.0[rank.index()];
+        *slot = slot.saturating_sub(1);
+    }
+
+    /// Per-rank sum of both counts
+    #[must_use]
+    pub fn plus(&self, other: &Self) -> Self {
+        let mut sum = *self;
+        for (slot, count) in sum.0.iter_mut().zip(other.0) {
+            *slot = slot.saturating_add(count);
+        }
+        sum
+    }
+
+    /// Per-rank difference, or `None` if `other` has more of some rank
+    #[must_use]
+    pub fn checked_minus(&self, other: &Self) -> Option<Self> {
+        let mut difference = *self;
+        for (slot, count) in difference.0.iter_mut().zip(other.0) {
+            *slot = slot.checked_sub(count)?;
+        }
+        Some(difference)
+    }
+
+    /// Returns true if every rank count is at least that of `other`
+    #[must_use]
+    pub fn contains(&self, other: &Self) -> bool {
+        self.0
+            .iter()
+            .zip(other.0)
+            .all(|(held, needed)| *held >= needed)
+    }
+
+    /// Total number of cards
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.0.iter().map(|count| usize::from(*count)).sum()
+    }
+
+    /// Number of ranks with at least one card
+    #[must_use]
+    pub fn distinct_ranks(&self) -> usize {
+        self.0.iter().filter(|count| **count > 0).count()
+    }
+
+    /// Returns true if no cards are counted
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|count| *count == 0)
+    }
+
+    /// Ranks with at least one card and their counts, in ascending rank order
+    pub fn iter(&self) -> impl Iterator<Item = (Rank, u8)> + '_ {
+        Rank::ALL
+            .iter()
+            .zip(self.0)
+            .filter(|(_, count)| *count > 0)
+            .map(|(rank, count)| (*rank, count))
+    }
+
+    /// Rank mask (bit `i` for [`Rank::ALL`]`[i]`) of ranks with at least `min` cards.
+    ///
+    /// Feed it to [`runs`](crate::patterns::runs) for chain lookups.
+    #[must_use]
+    pub fn mask_with_at_least(&self, min: u8) -> u16 {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count >= min.max(1))
+            .fold(0, |mask, (index, _)| mask | 1 << index)
+    }
+
+    /// Ranks held, in ascending rank order
+    #[must_use]
+    pub fn ranks(&self) -> Vec<Rank> {
+        self.iter().map(|(rank, _)| rank).collect()
+    }
+}
+
+impl From<&[Card]> for RankCounts {
+    fn from(cards: &[Card]) -> Self {
+        Self::from_cards(cards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Suit;
+
+    #[test]
+    fn test_counts_and_iteration_order() {
+        let counts = RankCounts::from_cards(&[
+            Card::new(Suit::Spades, Rank::Two),
+            Card::new(Suit::Hearts, Rank::Three),
+            Card::new(Suit::Clubs, Rank::Two),
+        ]);
+
+        assert_eq!(counts.get(Rank::Two), 2);
+        assert_eq!(counts.get(Rank::Three), 1);
+        assert_eq!(counts.get(Rank::Ace), 0);
+        assert_eq!(
+            counts.iter().collect::<Vec<_>>(),
+            vec![(Rank::Three, 1), (Rank::Two, 2)]
+        );
+        assert_eq!(counts.ranks(), vec![Rank::Three, Rank::Two]);
+        assert_eq!(counts.mask_with_at_least(1), 1 | 1 << 12);
+        assert_eq!(counts.mask_with_at_least(2), 1 << 12);
+    }
+
+    #[test]
+    fn test_add_sub_compare() {
+        let mut hand = RankCounts::new();
+        hand.add(Rank::Nine);
+        hand.add(Rank::Nine);
+        hand.add(Rank::Jack);
+
+        let mut play = RankCounts::new();
+        play.add(Rank::Nine);
+
+        assert!(hand.contains(&play));
+        assert!(!play.contains(&hand));
+
+        let rest = hand.checked_minus(&play).unwrap();
+        assert_eq!(rest.get(Rank::Nine), 1);
+        assert_eq!(rest.plus(&play), hand);
+        assert_eq!(play.checked_minus(&hand), None);
+
+        play.remove(Rank::Nine);
+        play.remove(Rank::Nine);
+        assert!(play.is_empty());
+    }
+}
@@ -0,0 +1,59 @@
+//! Configurable rank order for comparisons.
+
+use std::cmp::Ordering;
+
+use super::Rank;
+
+/// Order in which ranks compare, weakest to strongest.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::models::RankOrdering;
+/// use datongzi_rules::Rank;
+/// use std::cmp::Ordering;
+///
+/// // A local variant where Aces outrank 2s
+/// let ace_high = RankOrdering::Custom(vec![Rank::Two, Rank::King, Rank::Ace]);
+/// assert_eq!(ace_high.compare(Rank::Ace, Rank::Two), Ordering::Greater);
+/// // Unlisted ranks sit below listed ones, in natural order
+/// assert_eq!(ace_high.compare(Rank::Queen, Rank::Two), Ordering::Less);
+/// assert_eq!(RankOrdering::Natural.compare(Rank::Ace, Rank::Two), Ordering::Less);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum RankOrdering {
+    /// 3 lowest up to 2 highest
+    #[default]
+    Natural,
+    /// Listed ranks, weakest first, above every unlisted rank
+    Custom(Vec<Rank>),
+}
+
+impl RankOrdering {
+    /// Sort key of `rank`; higher is stronger
+    #[must_use]
+    pub fn key(&self, rank: Rank) -> (usize, u8) {
+        match self {
+            Self::Natural => (0, rank.value()),
+            Self::Custom(order) => match order.iter().position(|r| *r == rank) {
+                Some(index) => (index + 1, 0),
+                None => (0, rank.value()),
+            },
+        }
+    }
+
+    /// Compare two ranks under this ordering
+    #[must_use]
+    pub fn compare(&self, a: Rank, b: Rank) -> Ordering {
+        self.key(a).cmp(&self.key(b))
+    }
+
+    /// Returns true if this is the natural order
+    #[must_use]
+    pub fn is_natural(&self) -> bool {
+        match self {
+            Self::Natural => true,
+            // Listing the strongest ranks in natural order changes nothing
+            Self::Custom(order) => Rank::ALL.ends_with(order),
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Seats, turn order and who leads.
+
+/// Direction play passes around the table.
+///
+/// Seats are numbered clockwise, so clockwise play goes from seat `i` to
+/// seat `i + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    /// Seat `i` → `i + 1`
+    #[default]
+    Clockwise,
+    /// Seat `i` → `i - 1` (逆时针)
+    Counterclockwise,
+}
+
+/// Who leads the first trick of a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FirstLeader {
+    /// The player after the dealer in turn order
+    #[default]
+    LeftOfDealer,
+    /// The winner of the previous game's last trick (falls back to
+    /// [`LeftOfDealer`](Self::LeftOfDealer) in the first game)
+    LastTrickWinner,
+}
+
+/// Seat indices and turn order for one table.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::models::{Rotation, Seating};
+///
+/// let seating = Seating::new(3, Rotation::Counterclockwise, 0);
+/// assert_eq!(seating.next(0), 2);
+/// assert_eq!(seating.turn_order(2), vec![2, 1, 0]);
+/// assert_eq!(seating.next_with_cards(2, &[5, 0, 3]), Some(0)); // seat 1 is out
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Seating {
+    num_seats: usize,
+    rotation: Rotation,
+    dealer: usize,
+}
+
+impl Seating {
+    /// Create a seating for `num_seats` players with `dealer` dealing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_seats` is 0 or `dealer` is not a seat
+    #[must_use]
+    pub fn new(num_seats: usize, rotation: Rotation, dealer: usize) -> Self {
+        assert!(
+            dealer < num_seats,
+            "dealer {dealer} not among {num_seats} seats"
+        );
+        Self {
+            num_seats,
+            rotation,
+            dealer,
+        }
+    }
+
+    /// Create a seating from a configuration's player count and rotation
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dealer` is not a seat
+    #[must_use]
+    pub fn from_config(config: &super::GameConfig, dealer: usize) -> Self {
+        Self::new(usize::from(config.num_players()), config.rotation(), dealer)
+    }
+
+    /// Returns the number of seats
+    #[must_use]
+    pub const fn num_seats(&self) -> usize {
+        self.num_seats
+    }
+
+    /// Returns the rotation
+    #[must_use]
+    pub const fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    /// Returns the dealer's seat
+    #[must_use]
+    pub const fn dealer(&self) -> usize {
+        self.dealer
+    }
+
+    /// Seat that moves after `seat`
+    #[must_use]
+    pub const fn next(&self, seat: usize) -> usize {
+        match self.rotation {
+            Rotation::Clockwise => (seat + 1) % self.num_seats,
+            Rotation::Counterclockwise => (seat + self.num_seats - 1) % self.num_seats,
+        }
+    }
+
+    /// Seat that moved before `seat`
+    #[must_use]
+    pub const fn previous(&self, seat: usize) -> usize {
+        match self.rotation {
+            Rotation::Clockwise => (seat + self.num_seats - 1) % self.num_seats,
+            Rotation::Counterclockwise => (seat + 1) % self.num_seats,
+        }
+    }
+
+    /// Every seat in turn order, starting at `seat`
+    #[must_use]
+    pub fn turn_order(&self, seat: usize) -> Vec<usize> {
+        std::iter::successors(Some(seat), |s| Some(self.next(*s)))
+            .take(self.num_seats)
+            .collect()
+    }
+
+    /// Next seat after `seat` that still holds cards, given each seat's
+    /// remaining card count; `None` if nobody else does
+    #[must_use]
+    pub fn next_with_cards(&self, seat: usize, cards_left: &[usize]) -> Option<usize> {
+        self.turn_order(seat)
+            .into_iter()
+            .skip(1)
+            .find(|s| cards_left.get(*s).is_some_and(|n| *n > 0))
+    }
+
+    /// Seat that leads the first trick.
+    ///
+    /// `last_trick_winner` is the winner of the previous game's last trick,
+    /// if there was a previous game.
+    #[must_use]
+    pub fn first_leader(&self, rule: FirstLeader, last_trick_winner: Option<usize>) -> usize {
+        match (rule, last_trick_winner) {
+            (FirstLeader::LastTrickWinner, Some(winner)) if winner < self.num_seats => winner,
+            _ => self.next(self.dealer),
+        }
+    }
+
+    /// Seating for the next game, with the deal passed to the next seat
+    #[must_use]
+    pub const fn rotate_dealer(&self) -> Self {
+        Self {
+            dealer: self.next(self.dealer),
+            ..*self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::GameConfig;
+
+    #[test]
+    fn test_rotation_directions() {
+        let clockwise = Seating::new(4, Rotation::Clockwise, 3);
+        assert_eq!(clockwise.turn_order(3), vec![3, 0, 1, 2]);
+        assert_eq!(clockwise.previous(0), 3);
+        assert_eq!(clockwise.rotate_dealer().dealer(), 0);
+
+        let counter = Seating::new(4, Rotation::Counterclockwise, 3);
+        assert_eq!(counter.turn_order(3), vec![3, 2, 1, 0]);
+        assert_eq!(counter.previous(0), 1);
+        assert_eq!(counter.rotate_dealer().dealer(), 2);
+    }
+
+    #[test]
+    fn test_first_leader() {
+        let config = GameConfig {
+            rotation: Rotation::Counterclockwise,
+            ..GameConfig::default()
+        };
+        let seating = Seating::from_config(&config, 1);
+        assert_eq!(seating.first_leader(FirstLeader::LeftOfDealer, Some(2)), 0);
+        assert_eq!(
+            seating.first_leader(FirstLeader::LastTrickWinner, Some(2)),
+            2
+        );
+        assert_eq!(seating.first_leader(FirstLeader::LastTrickWinner, None), 0);
+    }
+
+    #[test]
+    fn test_next_with_cards_skips_finished_seats() {
+        let seating = Seating::new(3, Rotation::Clockwise, 0);
+        assert_eq!(seating.next_with_cards(0, &[1, 0, 4]), Some(2));
+        assert_eq!(seating.next_with_cards(0, &[1, 0, 0]), None);
+    }
+}
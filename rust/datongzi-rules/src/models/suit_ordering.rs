@@ -0,0 +1,84 @@
+//! Configurable suit order for equal-rank Tongzi.
+
+use std::cmp::Ordering;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use super::Suit;
+
+/// Order in which equal-rank Tongzi (筒子) compare by suit.
+///
+/// Under the natural order spades always win a tie, so a variant can
+/// shuffle the order once per game instead.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::models::TongziSuitOrder;
+/// use datongzi_rules::Suit;
+/// use std::cmp::Ordering;
+///
+/// let hearts_high = TongziSuitOrder::Fixed([Suit::Diamonds, Suit::Clubs, Suit::Spades, Suit::Hearts]);
+/// assert_eq!(hearts_high.compare(Suit::Hearts, Suit::Spades), Ordering::Greater);
+/// assert_eq!(TongziSuitOrder::Natural.compare(Suit::Hearts, Suit::Spades), Ordering::Less);
+///
+/// // A per-game order is drawn from the game seed
+/// let order = TongziSuitOrder::RandomPerGame.for_game(7);
+/// assert_eq!(order, TongziSuitOrder::RandomPerGame.for_game(7));
+/// assert!(matches!(order, TongziSuitOrder::Fixed(_)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TongziSuitOrder {
+    /// Diamonds lowest up to spades highest
+    #[default]
+    Natural,
+    /// Listed suits, weakest first; unlisted suits sit below them
+    Fixed([Suit; 4]),
+    /// A fresh [`Fixed`](Self::Fixed) order each game, drawn by
+    /// [`for_game`](Self::for_game); compares naturally until drawn
+    RandomPerGame,
+}
+
+impl TongziSuitOrder {
+    /// Sort key of `suit`; higher is stronger
+    #[must_use]
+    pub fn key(&self, suit: Suit) -> u8 {
+        match self {
+            Self::Natural | Self::RandomPerGame => suit.value(),
+            Self::Fixed(order) => order
+                .iter()
+                .position(|s| *s == suit)
+                .map_or(0, |index| index as u8 + 1),
+        }
+    }
+
+    /// Compare two suits under this ordering
+    #[must_use]
+    pub fn compare(&self, a: Suit, b: Suit) -> Ordering {
+        self.key(a).cmp(&self.key(b))
+    }
+
+    /// Returns true if suits compare in natural order
+    #[must_use]
+    pub fn is_natural(&self) -> bool {
+        match self {
+            Self::Natural | Self::RandomPerGame => true,
+            Self::Fixed(order) => *order == Suit::ALL,
+        }
+    }
+
+    /// The order for the game dealt from `seed`: a seeded shuffle for
+    /// [`RandomPerGame`](Self::RandomPerGame), otherwise unchanged
+    #[must_use]
+    pub fn for_game(&self, seed: u64) -> Self {
+        match self {
+            Self::RandomPerGame => {
+                let mut order = Suit::ALL;
+                order.shuffle(&mut StdRng::seed_from_u64(seed));
+                Self::Fixed(order)
+            }
+            other => *other,
+        }
+    }
+}
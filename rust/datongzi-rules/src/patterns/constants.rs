@@ -0,0 +1,88 @@
+//! Size rules shared by every pattern check.
+//!
+//! The recognizer, validator, generators and analyzers read these values
+//! instead of repeating literals, so a variant that changes one of them
+//! changes it everywhere at once.
+
+use crate::models::Suit;
+
+/// Card counts that define the structural patterns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PatternConstants {
+    /// Fewest pairs in a consecutive-pair chain (连对)
+    pub min_pair_chain: usize,
+    /// Fewest triples in an airplane (飞机)
+    pub min_airplane: usize,
+    /// Fewest cards in a bomb (炸弹)
+    pub min_bomb: usize,
+    /// Cards in a tongzi (筒子)
+    pub tongzi: usize,
+    /// Cards in a dizha (地炸)
+    pub dizha: usize,
+}
+
+impl PatternConstants {
+    /// Cards of each suit in a dizha
+    #[must_use]
+    pub const fn dizha_per_suit(&self) -> usize {
+        self.dizha / Suit::ALL.len()
+    }
+}
+
+/// The pattern rules of Da Tong Zi.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::patterns::RULES;
+///
+/// assert_eq!(RULES.min_bomb, 4);
+/// assert_eq!(RULES.dizha_per_suit(), 2);
+/// ```
+pub const RULES: PatternConstants = PatternConstants {
+    min_pair_chain: 2,
+    min_airplane: 2,
+    min_bomb: 4,
+    tongzi: 3,
+    dizha: 8,
+};
+
+// Checked at compile time: a chain needs two links, a dizha splits evenly
+// over the suits, and every trump has more cards than a triple.
+const _: () = {
+    assert!(RULES.min_pair_chain >= 2 && RULES.min_airplane >= 2);
+    assert!(RULES.dizha % Suit::ALL.len() == 0);
+    assert!(RULES.min_bomb > 3 && RULES.tongzi >= 3 && RULES.dizha > RULES.min_bomb);
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Card, Rank};
+    use crate::patterns::{PatternRecognizer, PlayType};
+
+    fn play_type(cards: &[Card]) -> Option<PlayType> {
+        PatternRecognizer::analyze_cards(cards).map(|p| p.play_type)
+    }
+
+    #[test]
+    fn test_recognizer_follows_rules() {
+        let nines = |n: usize| vec![Card::new(Suit::Spades, Rank::Nine); n];
+        assert_eq!(play_type(&nines(RULES.min_bomb)), Some(PlayType::Bomb));
+        assert_ne!(play_type(&nines(RULES.min_bomb - 1)), Some(PlayType::Bomb));
+        assert_eq!(play_type(&nines(RULES.tongzi)), Some(PlayType::Tongzi));
+
+        let dizha: Vec<Card> = Suit::ALL
+            .iter()
+            .flat_map(|s| vec![Card::new(*s, Rank::Nine); RULES.dizha_per_suit()])
+            .collect();
+        assert_eq!(dizha.len(), RULES.dizha);
+        assert_eq!(play_type(&dizha), Some(PlayType::Dizha));
+
+        let pairs: Vec<Card> = Rank::ALL[..RULES.min_pair_chain]
+            .iter()
+            .flat_map(|r| [Card::new(Suit::Spades, *r), Card::new(Suit::Hearts, *r)])
+            .collect();
+        assert_eq!(play_type(&pairs), Some(PlayType::ConsecutivePairs));
+        assert_ne!(play_type(&pairs[..2]), Some(PlayType::ConsecutivePairs));
+    }
+}
@@ -0,0 +1,162 @@
+//! Kicker (带牌) capacity rules.
+//!
+//! Single source of truth for how many extra cards a main group may carry:
+//! - Triple (三张): 0-2 kickers (三张、三带一、三带二)
+//! - Airplane (飞机): each triple carries 0-2 cards, so N triples carry 0..=2N.
+//!   Zero wings is recognized as [`PlayType::Airplane`]; 1..=2N wings as
+//!   [`PlayType::AirplaneWithWings`].
+//! - Every other play type carries no kickers.
+//!
+//! [`GameConfig::forbidden_kicker_ranks`] additionally bars some ranks (for
+//! example 2s or scoring cards) from being carried at all.
+
+use std::ops::RangeInclusive;
+
+use super::{PlayPattern, PlayType};
+use crate::models::{Card, GameConfig};
+use crate::plays::sort_canonical;
+
+/// Maximum kickers each triple group may carry.
+pub const MAX_KICKERS_PER_GROUP: usize = 2;
+
+/// Returns the allowed kicker count for a play type with `group_count` main groups.
+///
+/// `group_count` is the number of triples in the main body (1 for Triple,
+/// N for an N-triple airplane); it is ignored for types without kickers.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::patterns::kicker_rules::capacity_for;
+/// use datongzi_rules::{GameConfig, PlayType};
+///
+/// let config = GameConfig::default();
+/// assert_eq!(capacity_for(PlayType::Triple, 1, &config), 0..=2);
+/// assert_eq!(capacity_for(PlayType::AirplaneWithWings, 3, &config), 1..=6);
+/// assert_eq!(capacity_for(PlayType::Pair, 1, &config), 0..=0);
+/// ```
+#[must_use]
+pub fn capacity_for(
+    play_type: PlayType,
+    group_count: usize,
+    _config: &GameConfig,
+) -> RangeInclusive<usize> {
+    // No variant changes kicker rules yet; the config parameter keeps the
+    // signature stable for when one does.
+    standard_capacity(play_type, group_count)
+}
+
+/// Kicker capacity under the standard rules, used by the recognizer.
+pub(crate) fn standard_capacity(play_type: PlayType, group_count: usize) -> RangeInclusive<usize> {
+    match play_type {
+        PlayType::Triple => 0..=MAX_KICKERS_PER_GROUP,
+        PlayType::Airplane => 0..=0,
+        PlayType::AirplaneWithWings => 1..=MAX_KICKERS_PER_GROUP * group_count,
+        _ => 0..=0,
+    }
+}
+
+/// Splits a recognized play into its main structure and its kickers.
+///
+/// Kickers are the cards beyond three per triple group of a Triple or
+/// airplane; every other play type has no kickers.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::patterns::kicker_rules::split_kickers;
+/// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+///
+/// let cards = vec![
+///     Card::new(Suit::Spades, Rank::Five),
+///     Card::new(Suit::Hearts, Rank::Two),
+///     Card::new(Suit::Hearts, Rank::Five),
+///     Card::new(Suit::Clubs, Rank::Five),
+/// ];
+/// let pattern = PatternRecognizer::analyze_cards(&cards).unwrap();
+/// let (main, kickers) = split_kickers(&cards, &pattern);
+/// assert_eq!(main.len(), 3);
+/// assert_eq!(kickers, vec![Card::new(Suit::Hearts, Rank::Two)]);
+/// ```
+#[must_use]
+pub fn split_kickers(cards: &[Card], pattern: &PlayPattern) -> (Vec<Card>, Vec<Card>) {
+    let main_cards = match pattern.play_type {
+        PlayType::Triple => 3,
+        PlayType::Airplane | PlayType::AirplaneWithWings => 3 * pattern.secondary_ranks.len(),
+        _ => cards.len(),
+    };
+    // Canonical order puts the main structure first and kickers last
+    let mut sorted = cards.to_vec();
+    sort_canonical(&mut sorted, pattern);
+    let kickers = sorted.split_off(main_cards.min(sorted.len()));
+    (sorted, kickers)
+}
+
+/// Returns the first kicker whose rank `config` forbids as a kicker.
+#[must_use]
+pub fn forbidden_kicker(
+    cards: &[Card],
+    pattern: &PlayPattern,
+    config: &GameConfig,
+) -> Option<Card> {
+    if config.forbidden_kicker_ranks().is_empty() {
+        return None;
+    }
+    split_kickers(cards, pattern)
+        .1
+        .into_iter()
+        .find(|card| config.is_kicker_rank_forbidden(card.rank))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+    use crate::patterns::PatternRecognizer;
+
+    #[test]
+    fn test_capacity_for_triple_and_airplanes() {
+        let config = GameConfig::default();
+        assert_eq!(capacity_for(PlayType::Triple, 1, &config), 0..=2);
+        assert_eq!(capacity_for(PlayType::Airplane, 2, &config), 0..=0);
+        assert_eq!(capacity_for(PlayType::AirplaneWithWings, 2, &config), 1..=4);
+    }
+
+    #[test]
+    fn test_capacity_for_types_without_kickers() {
+        let config = GameConfig::default();
+        for play_type in [
+            PlayType::Single,
+            PlayType::Pair,
+            PlayType::ConsecutivePairs,
+            PlayType::Bomb,
+            PlayType::Tongzi,
+            PlayType::Dizha,
+        ] {
+            assert_eq!(capacity_for(play_type, 1, &config), 0..=0);
+        }
+    }
+
+    #[test]
+    fn test_forbidden_kicker() {
+        let cards = vec![
+            Card::new(Suit::Spades, Rank::Two),
+            Card::new(Suit::Hearts, Rank::Two),
+            Card::new(Suit::Clubs, Rank::Two),
+            Card::new(Suit::Spades, Rank::Ten),
+        ];
+        let pattern = PatternRecognizer::analyze_cards(&cards).unwrap();
+        let config = GameConfig {
+            forbidden_kicker_ranks: vec![Rank::Two, Rank::Ten],
+            ..GameConfig::default()
+        };
+
+        // The triple of 2s is the main structure; only the 10 is a kicker
+        assert_eq!(
+            forbidden_kicker(&cards, &pattern, &config),
+            Some(Card::new(Suit::Spades, Rank::Ten))
+        );
+        assert_eq!(
+            forbidden_kicker(&cards, &pattern, &GameConfig::default()),
+            None
+        );
+    }
+}
@@ -1,14 +1,31 @@
 //! Pattern recognition and validation.
 //!
 //! This module contains:
-//! - Pattern types and structures ([`PlayType`], [`PlayPattern`])
-//! - Pattern recognition logic ([`PatternRecognizer`])
-//! - Play validation logic ([`PlayValidator`])
+//! - Pattern types and structures ([`PlayType`], [`PlayPattern`], [`StatKey`])
+//! - Pattern recognition logic ([`PatternRecognizer`], [`AirplaneBody`], [`AirplaneParts`])
+//! - Play validation logic ([`PlayValidator`], [`Evaluation`])
+//! - Pattern size rules ([`RULES`])
+//! - Kicker capacity rules ([`kicker_rules`])
+//! - Precomputed consecutive-run tables ([`runs`])
+//! - Incremental feedback while selecting cards ([`SelectionAnalyzer`])
+//! - Reference comparator for differential tests (`reference`, feature `reference-model`)
 //!
 //! **Status**: Phase 2 - In progress
 
+mod constants;
+pub mod kicker_rules;
 mod pattern;
 mod recognizer;
+#[cfg(feature = "reference-model")]
+pub mod reference;
+pub mod runs;
+mod selection;
 
-pub use pattern::{PlayPattern, PlayType};
-pub use recognizer::{PatternRecognizer, PlayValidator};
+pub use crate::models::PlayType;
+pub use constants::{PatternConstants, RULES};
+pub use pattern::{PlayPattern, StatKey};
+pub(crate) use recognizer::CompareRules;
+pub use recognizer::{
+    AirplaneBody, AirplaneParts, Evaluation, PatternRecognizer, PlayValidator, PARALLEL_BATCH_MIN,
+};
+pub use selection::SelectionAnalyzer;
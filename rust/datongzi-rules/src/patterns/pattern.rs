@@ -1,32 +1,9 @@
 //! Pattern types and structures for card combinations.
 
-use crate::models::{Rank, Suit};
+use std::fmt;
 
-/// Play types in order of strength.
-///
-/// Higher values beat lower values, with special rules for some types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(u8)]
-pub enum PlayType {
-    /// Single card (单牌)
-    Single = 1,
-    /// Pair of cards (对子)
-    Pair = 2,
-    /// Consecutive pairs (连对, 2+ pairs in sequence)
-    ConsecutivePairs = 3,
-    /// Triple with 0-2 kickers (三张，可带0-2张)
-    Triple = 4,
-    /// Airplane - consecutive triples (飞机)
-    Airplane = 6,
-    /// Airplane with wings (飞机带翅膀)
-    AirplaneWithWings = 7,
-    /// Bomb - 4+ same rank (炸弹)
-    Bomb = 8,
-    /// Tongzi - 3 same rank same suit (筒子)
-    Tongzi = 9,
-    /// Dizha - 2 of each suit for same rank (地炸, 8 cards)
-    Dizha = 10,
-}
+use super::recognizer::CompareRules;
+use crate::models::{GameConfig, PlayType, Rank, Suit};
 
 /// Represents a recognized pattern of cards.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -45,6 +22,40 @@ pub struct PlayPattern {
     pub strength: u32,
 }
 
+/// Canonical, suit-agnostic key for aggregating play statistics.
+///
+/// Two plays share a key when they are the same play for statistics:
+/// same type, primary rank, chain length and card count (so 三带一 and
+/// 三带二 stay apart). Suits are dropped except for Tongzi, where the suit
+/// is part of the play. Keys are small, `Copy` and hashable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StatKey {
+    /// Type of play
+    pub play_type: PlayType,
+    /// Main rank of the play
+    pub primary_rank: Rank,
+    /// Suit, for Tongzi only
+    pub suit: Option<Suit>,
+    /// Number of ranks in a chain (0 for non-chain plays)
+    pub chain_length: u8,
+    /// Total number of cards, kickers and wings included
+    pub card_count: u8,
+}
+
+impl fmt::Display for StatKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} ", self.play_type)?;
+        if let Some(suit) = self.suit {
+            write!(f, "{suit}")?;
+        }
+        write!(f, "{}", self.primary_rank)?;
+        if self.chain_length > 0 {
+            write!(f, " x{}", self.chain_length)?;
+        }
+        write!(f, " /{}", self.card_count)
+    }
+}
+
 impl PlayPattern {
     /// Creates a new PlayPattern
     #[must_use]
@@ -101,20 +112,113 @@ impl PlayPattern {
     pub const fn strength(&self) -> u32 {
         self.strength
     }
+
+    /// Canonical statistics key for this play.
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+    ///
+    /// let triple = |suit| {
+    ///     let mut cards = vec![Card::new(suit, Rank::Nine); 3];
+    ///     cards.extend([Card::new(Suit::Hearts, Rank::Five), Card::new(Suit::Clubs, Rank::Six)]);
+    ///     PatternRecognizer::analyze_cards(&cards).unwrap()
+    /// };
+    ///
+    /// // Same 三带二 whichever suits were played
+    /// assert_eq!(triple(Suit::Spades).stat_key(), triple(Suit::Diamonds).stat_key());
+    /// assert_eq!(triple(Suit::Spades).stat_key().to_string(), "Triple 9 /5");
+    /// ```
+    #[must_use]
+    pub fn stat_key(&self) -> StatKey {
+        StatKey {
+            play_type: self.play_type,
+            primary_rank: self.primary_rank,
+            suit: if self.play_type == PlayType::Tongzi {
+                self.primary_suit
+            } else {
+                None
+            },
+            chain_length: u8::try_from(self.secondary_ranks.len()).unwrap_or(u8::MAX),
+            card_count: u8::try_from(self.card_count).unwrap_or(u8::MAX),
+        }
+    }
+
+    /// Maps the pattern to a comparable 0-100 power scale.
+    ///
+    /// `strength` uses a different encoding per play type; this gives UI
+    /// progress bars and AI features one scale. Bands:
+    /// - Normal plays (Single ... AirplaneWithWings): 0-59, by primary rank
+    /// - Bomb: 60-79, by card count then rank
+    /// - Tongzi: 80-94, by rank then suit
+    /// - Dizha: 95-100, by rank
+    ///
+    /// Within a comparability class (patterns that can beat each other), a
+    /// pattern that beats another under `config` never has a lower value, so
+    /// bombs follow [`bomb_rank_order`](GameConfig::bomb_rank_order) and
+    /// Tongzi follow [`tongzi_suit_order`](GameConfig::tongzi_suit_order).
+    /// Ranks removed by `config` are skipped so the scale uses its full width.
+    #[must_use]
+    pub fn normalized_power(&self, config: &GameConfig) -> u8 {
+        let rules = CompareRules::from_config(config);
+        let mut ranks: Vec<Rank> = Rank::ALL
+            .into_iter()
+            .filter(|r| !config.is_rank_removed(*r))
+            .collect();
+        let rank_count = ranks.len().max(1);
+        let position = |ranks: &[Rank]| {
+            ranks
+                .iter()
+                .position(|r| *r == self.primary_rank)
+                .unwrap_or(0)
+        };
+        let rank_index = position(&ranks);
+
+        // Scale `index` in 0..steps to lo..=hi
+        let scale = |index: usize, steps: usize, lo: usize, hi: usize| -> u8 {
+            let span = hi - lo;
+            let value = if steps <= 1 {
+                hi
+            } else {
+                lo + span * index.min(steps - 1) / (steps - 1)
+            };
+            value as u8
+        };
+
+        match self.play_type {
+            PlayType::Dizha => scale(rank_index, rank_count, 95, 100),
+            PlayType::Tongzi => {
+                let mut suits = Suit::ALL;
+                suits.sort_by_key(|s| rules.tongzi_suit_order.key(*s));
+                let suit_index = self
+                    .primary_suit
+                    .and_then(|suit| suits.iter().position(|s| *s == suit))
+                    .unwrap_or(0);
+                scale(rank_index * 4 + suit_index, rank_count * 4, 80, 94)
+            }
+            PlayType::Bomb => {
+                ranks.sort_by_key(|r| rules.bomb_rank_order.key(*r));
+                let rank_index = position(&ranks);
+                // Bomb sizes run from 4 up to every copy of a rank (4 per deck)
+                let max_size = (usize::from(config.num_decks()) * 4).max(4);
+                let size_index = self.card_count.clamp(4, max_size) - 4;
+                let sizes = max_size - 3;
+                scale(
+                    size_index * rank_count + rank_index,
+                    sizes * rank_count,
+                    60,
+                    79,
+                )
+            }
+            _ => scale(rank_index, rank_count, 0, 59),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_play_type_ordering() {
-        assert!(PlayType::Single < PlayType::Pair);
-        assert!(PlayType::Pair < PlayType::Bomb);
-        assert!(PlayType::Bomb < PlayType::Tongzi);
-        assert!(PlayType::Tongzi < PlayType::Dizha);
-    }
-
     #[test]
     fn test_play_pattern_creation() {
         let pattern = PlayPattern::new(
@@ -132,4 +236,164 @@ mod tests {
         assert_eq!(pattern.card_count(), 1);
         assert_eq!(pattern.strength(), 14);
     }
+
+    #[test]
+    fn test_stat_key_keeps_suit_only_for_tongzi() {
+        let pattern = |play_type, suit| PlayPattern::new(play_type, Rank::King, suit, vec![], 3, 0);
+
+        assert_eq!(
+            pattern(PlayType::Triple, Some(Suit::Spades)).stat_key(),
+            pattern(PlayType::Triple, Some(Suit::Hearts)).stat_key()
+        );
+        assert_ne!(
+            pattern(PlayType::Tongzi, Some(Suit::Spades)).stat_key(),
+            pattern(PlayType::Tongzi, Some(Suit::Hearts)).stat_key()
+        );
+        assert_eq!(
+            pattern(PlayType::Tongzi, Some(Suit::Spades))
+                .stat_key()
+                .to_string(),
+            "Tongzi ♠K /3"
+        );
+    }
+
+    #[test]
+    fn test_stat_key_separates_kicker_counts_and_chain_lengths() {
+        let triple = |count| PlayPattern::new(PlayType::Triple, Rank::Nine, None, vec![], count, 0);
+        assert_ne!(triple(4).stat_key(), triple(5).stat_key());
+
+        let pairs = |ranks: Vec<Rank>| {
+            let count = ranks.len() * 2;
+            PlayPattern::new(
+                PlayType::ConsecutivePairs,
+                Rank::Eight,
+                None,
+                ranks,
+                count,
+                0,
+            )
+        };
+        let short = pairs(vec![Rank::Seven, Rank::Eight]);
+        let long = pairs(vec![Rank::Six, Rank::Seven, Rank::Eight]);
+        assert_ne!(short.stat_key(), long.stat_key());
+        assert_eq!(long.stat_key().to_string(), "ConsecutivePairs 8 x3 /6");
+    }
+
+    #[test]
+    fn test_normalized_power_bands() {
+        let config = GameConfig::default();
+        let single = |rank| PlayPattern::new(PlayType::Single, rank, None, vec![], 1, 0);
+
+        assert_eq!(single(Rank::Five).normalized_power(&config), 0);
+        assert_eq!(single(Rank::Two).normalized_power(&config), 59);
+
+        let bomb = |rank, count| PlayPattern::new(PlayType::Bomb, rank, None, vec![], count, 0);
+        let small_bomb = bomb(Rank::Five, 4).normalized_power(&config);
+        let big_bomb = bomb(Rank::Two, 12).normalized_power(&config);
+        assert_eq!(small_bomb, 60);
+        assert_eq!(big_bomb, 79);
+        // More cards beats higher rank
+        assert!(
+            bomb(Rank::Five, 5).normalized_power(&config)
+                > bomb(Rank::Two, 4).normalized_power(&config)
+        );
+
+        let tongzi =
+            |rank, suit| PlayPattern::new(PlayType::Tongzi, rank, Some(suit), vec![], 3, 0);
+        assert_eq!(
+            tongzi(Rank::Five, Suit::Diamonds).normalized_power(&config),
+            80
+        );
+        assert_eq!(
+            tongzi(Rank::Two, Suit::Spades).normalized_power(&config),
+            94
+        );
+        // Non-strict: the band is narrower than the number of Tongzi kinds
+        assert!(
+            tongzi(Rank::King, Suit::Spades).normalized_power(&config)
+                >= tongzi(Rank::King, Suit::Hearts).normalized_power(&config)
+        );
+        assert!(
+            tongzi(Rank::Ace, Suit::Diamonds).normalized_power(&config)
+                > tongzi(Rank::Ten, Suit::Spades).normalized_power(&config)
+        );
+
+        let dizha = |rank| PlayPattern::new(PlayType::Dizha, rank, None, vec![], 8, 0);
+        assert_eq!(dizha(Rank::Five).normalized_power(&config), 95);
+        assert_eq!(dizha(Rank::Two).normalized_power(&config), 100);
+    }
+
+    #[test]
+    fn test_normalized_power_monotone_for_singles() {
+        let config = GameConfig::default();
+        let powers: Vec<u8> = Rank::ALL
+            .into_iter()
+            .filter(|r| !config.is_rank_removed(*r))
+            .map(|r| {
+                PlayPattern::new(PlayType::Single, r, None, vec![], 1, 0).normalized_power(&config)
+            })
+            .collect();
+        assert!(powers.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_normalized_power_agrees_with_config_comparison() {
+        use crate::models::{Card, RankOrdering, TongziSuitOrder};
+        use crate::patterns::{PatternRecognizer, PlayValidator};
+
+        let config = GameConfig {
+            bomb_rank_order: RankOrdering::Custom(vec![Rank::Ace, Rank::Five]),
+            tongzi_suit_order: TongziSuitOrder::Fixed([
+                Suit::Spades,
+                Suit::Hearts,
+                Suit::Clubs,
+                Suit::Diamonds,
+            ]),
+            singles_suit_tiebreak: true,
+            ..GameConfig::default()
+        };
+        let ranks: Vec<Rank> = Rank::ALL
+            .into_iter()
+            .filter(|r| !config.is_rank_removed(*r))
+            .collect();
+
+        let mut plays: Vec<Vec<Card>> = Vec::new();
+        for rank in &ranks {
+            for suit in Suit::ALL {
+                plays.push(vec![Card::new(suit, *rank)]);
+                plays.push(vec![Card::new(suit, *rank); 3]);
+            }
+            for size in 4..=6 {
+                plays.push(
+                    Suit::ALL
+                        .iter()
+                        .cycle()
+                        .take(size)
+                        .map(|s| Card::new(*s, *rank))
+                        .collect(),
+                );
+            }
+            plays.push(
+                Suit::ALL
+                    .iter()
+                    .flat_map(|s| [Card::new(*s, *rank); 2])
+                    .collect(),
+            );
+        }
+        let patterns: Vec<PlayPattern> = plays
+            .iter()
+            .map(|cards| PatternRecognizer::analyze_cards(cards).unwrap())
+            .collect();
+
+        for (cards, pattern) in plays.iter().zip(&patterns) {
+            for other in &patterns {
+                if PlayValidator::can_beat_play_with_config(cards, Some(other), &config) {
+                    assert!(
+                        pattern.normalized_power(&config) >= other.normalized_power(&config),
+                        "{pattern:?} beats {other:?} with less power"
+                    );
+                }
+            }
+        }
+    }
 }
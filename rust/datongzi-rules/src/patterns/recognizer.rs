@@ -1,9 +1,40 @@
 //! Pattern recognition logic for card combinations.
 
-use std::collections::HashMap;
+use super::{kicker_rules, runs, PlayPattern, PlayType, RULES};
+use crate::ai_helpers::{GenerationOptions, PlayGenerator};
+use crate::error::{DatongziError, Result};
+use crate::i18n::Message;
+use crate::invariants;
+use crate::metrics;
+use crate::models::{Card, GameConfig, Rank, RankCounts, RankOrdering, Suit, TongziSuitOrder};
+
+/// Fewest plays [`PatternRecognizer::analyze_batch_parallel`] splits across threads.
+pub const PARALLEL_BATCH_MIN: usize = 256;
+
+/// Which body to pick when several triple runs fit an airplane with wings.
+///
+/// `333444555` plus one card reads either as a three-triple body with one
+/// wing or as `333444` carrying `555` and the extra card as four wings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AirplaneBody {
+    /// Longest legal body (what [`PatternRecognizer::analyze_cards`] uses)
+    #[default]
+    Longest,
+    /// Shortest legal body, leaving as many cards as possible as wings
+    Shortest,
+}
 
-use super::{PlayPattern, PlayType};
-use crate::models::{Card, Rank, Suit};
+/// An airplane with wings split into its body and wings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AirplaneParts {
+    /// Ranks of the consecutive triples, ascending
+    pub body_ranks: Vec<Rank>,
+    /// Three cards of each body rank, in input order
+    pub body: Vec<Card>,
+    /// Every other card, in input order
+    pub wings: Vec<Card>,
+}
 
 /// Recognizes and analyzes card patterns.
 pub struct PatternRecognizer;
@@ -22,63 +53,174 @@ impl PatternRecognizer {
     /// `Some(PlayPattern)` if a valid pattern is recognized, `None` otherwise.
     #[must_use]
     pub fn analyze_cards(cards: &[Card]) -> Option<PlayPattern> {
-        if cards.is_empty() {
-            return None;
+        Self::analyze_counted(cards, &RankCounts::from_cards(cards))
+    }
+
+    /// Analyze many plays at once, e.g. when validating a reconnect replay.
+    ///
+    /// Results are in the order of `plays`. One per-rank count buffer is
+    /// reused across plays instead of being rebuilt per call.
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::{Card, PatternRecognizer, PlayType, Rank, Suit};
+    ///
+    /// let plays = vec![
+    ///     vec![Card::new(Suit::Spades, Rank::Ace)],
+    ///     vec![Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Hearts, Rank::King)],
+    /// ];
+    /// let patterns = PatternRecognizer::analyze_batch(&plays);
+    /// assert_eq!(patterns[0].as_ref().map(|p| p.play_type), Some(PlayType::Single));
+    /// assert!(patterns[1].is_none());
+    /// ```
+    #[must_use]
+    pub fn analyze_batch(plays: &[Vec<Card>]) -> Vec<Option<PlayPattern>> {
+        let mut rank_counts = RankCounts::new();
+        let mut patterns = Vec::with_capacity(plays.len());
+        for cards in plays {
+            rank_counts.0.fill(0);
+            for card in cards {
+                rank_counts.add(card.rank);
+            }
+            patterns.push(Self::analyze_counted(cards, &rank_counts));
         }
+        patterns
+    }
 
-        // Sort cards for easier analysis
-        let mut sorted_cards = cards.to_vec();
-        sorted_cards.sort();
+    /// Like [`analyze_batch`](Self::analyze_batch), split across up to
+    /// `threads` scoped threads.
+    ///
+    /// Falls back to the single-threaded batch for `threads <= 1` or fewer
+    /// than [`PARALLEL_BATCH_MIN`] plays. Work done on helper threads is not
+    /// counted by the caller's [`metrics`](crate::metrics).
+    ///
+    /// # Panics
+    ///
+    /// Propagates a panic from a helper thread
+    #[must_use]
+    pub fn analyze_batch_parallel(plays: &[Vec<Card>], threads: usize) -> Vec<Option<PlayPattern>> {
+        if threads <= 1 || plays.len() < PARALLEL_BATCH_MIN {
+            return Self::analyze_batch(plays);
+        }
 
-        // Count cards by rank
-        let mut rank_counts: HashMap<Rank, usize> = HashMap::new();
-        for card in cards {
-            *rank_counts.entry(card.rank).or_insert(0) += 1;
+        let chunk_size = plays.len().div_ceil(threads);
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = plays
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(|| Self::analyze_batch(chunk)))
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("recognizer thread panicked"))
+                .collect()
+        })
+    }
+
+    /// Like [`analyze_cards`](Self::analyze_cards), choosing the airplane
+    /// body by `body` when the cards read as an airplane with wings in more
+    /// than one way. Every other pattern is recognized as usual.
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::patterns::AirplaneBody;
+    /// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+    ///
+    /// let mut cards = Vec::new();
+    /// for rank in [Rank::Three, Rank::Four, Rank::Five] {
+    ///     for suit in [Suit::Spades, Suit::Hearts, Suit::Clubs] {
+    ///         cards.push(Card::new(suit, rank));
+    ///     }
+    /// }
+    /// cards.push(Card::new(Suit::Spades, Rank::Nine));
+    ///
+    /// let longest = PatternRecognizer::analyze_cards(&cards).unwrap();
+    /// assert_eq!(longest.primary_rank, Rank::Five);
+    /// let shortest = PatternRecognizer::analyze_cards_with_body(&cards, AirplaneBody::Shortest);
+    /// assert_eq!(shortest.unwrap().secondary_ranks, vec![Rank::Three, Rank::Four]);
+    /// ```
+    #[must_use]
+    pub fn analyze_cards_with_body(cards: &[Card], body: AirplaneBody) -> Option<PlayPattern> {
+        let rank_counts = RankCounts::from_cards(cards);
+        let pattern = Self::analyze_counted(cards, &rank_counts)?;
+        if pattern.play_type != PlayType::AirplaneWithWings || body == AirplaneBody::Longest {
+            return Some(pattern);
         }
+        Self::airplane_with_wings(cards, &rank_counts, body)
+    }
 
-        // Count cards by (suit, rank) for special patterns
-        let mut suit_rank_counts: HashMap<(Suit, Rank), usize> = HashMap::new();
-        for card in cards {
-            *suit_rank_counts.entry((card.suit, card.rank)).or_insert(0) += 1;
+    /// Split an airplane with wings into body and wing cards.
+    ///
+    /// Returns `None` unless `cards` are recognized as
+    /// [`PlayType::AirplaneWithWings`]. When a body rank holds a fourth card,
+    /// the first three in input order form the body and the rest are wings.
+    #[must_use]
+    pub fn airplane_parts(cards: &[Card], body: AirplaneBody) -> Option<AirplaneParts> {
+        let pattern = Self::analyze_cards_with_body(cards, body)?;
+        if pattern.play_type != PlayType::AirplaneWithWings {
+            return None;
+        }
+
+        let mut taken = RankCounts::new();
+        let (body_cards, wings) = cards.iter().partition(|card| {
+            let in_body = pattern.secondary_ranks.contains(&card.rank)
+                && usize::from(taken.get(card.rank)) < 3;
+            if in_body {
+                taken.add(card.rank);
+            }
+            in_body
+        });
+        Some(AirplaneParts {
+            body_ranks: pattern.secondary_ranks,
+            body: body_cards,
+            wings,
+        })
+    }
+
+    /// [`analyze_cards`](Self::analyze_cards) with per-rank counts already
+    /// known; `rank_counts` must match `cards`.
+    pub(crate) fn analyze_counted(cards: &[Card], rank_counts: &RankCounts) -> Option<PlayPattern> {
+        metrics::record_recognition();
+        if cards.is_empty() {
+            return None;
         }
 
         // Check for special patterns first (highest priority)
-        if let Some(pattern) = Self::check_dizha(cards, &suit_rank_counts, &rank_counts) {
+        if let Some(pattern) = Self::check_dizha(cards, rank_counts) {
             return Some(pattern);
         }
 
-        if let Some(pattern) = Self::check_tongzi(cards, &suit_rank_counts, &rank_counts) {
+        if let Some(pattern) = Self::check_tongzi(cards, rank_counts) {
             return Some(pattern);
         }
 
-        if let Some(pattern) = Self::check_bomb(cards, &rank_counts) {
+        if let Some(pattern) = Self::check_bomb(cards, rank_counts) {
             return Some(pattern);
         }
 
         // Check for airplane patterns
         // IMPORTANT: Check pure AIRPLANE first, then AIRPLANE_WITH_WINGS
-        if let Some(pattern) = Self::check_airplane(cards, &rank_counts) {
+        if let Some(pattern) = Self::check_airplane(cards, rank_counts) {
             return Some(pattern);
         }
 
-        if let Some(pattern) = Self::check_airplane_with_wings(cards, &rank_counts) {
+        if let Some(pattern) = Self::check_airplane_with_wings(cards, rank_counts) {
             return Some(pattern);
         }
 
         // Check for basic patterns
-        if let Some(pattern) = Self::check_triple(cards, &rank_counts) {
+        if let Some(pattern) = Self::check_triple(cards, rank_counts) {
             return Some(pattern);
         }
 
-        if let Some(pattern) = Self::check_consecutive_pairs(cards, &rank_counts) {
+        if let Some(pattern) = Self::check_consecutive_pairs(cards, rank_counts) {
             return Some(pattern);
         }
 
-        if let Some(pattern) = Self::check_pair(cards, &rank_counts) {
+        if let Some(pattern) = Self::check_pair(cards, rank_counts) {
             return Some(pattern);
         }
 
-        if let Some(pattern) = Self::check_single(cards, &rank_counts) {
+        if let Some(pattern) = Self::check_single(cards, rank_counts) {
             return Some(pattern);
         }
 
@@ -86,7 +228,8 @@ impl PatternRecognizer {
     }
 
     /// Check for single card pattern.
-    fn check_single(cards: &[Card], _rank_counts: &HashMap<Rank, usize>) -> Option<PlayPattern> {
+    fn check_single(cards: &[Card], _rank_counts: &RankCounts) -> Option<PlayPattern> {
+        metrics::record_pattern_check();
         if cards.len() != 1 {
             return None;
         }
@@ -103,12 +246,13 @@ impl PatternRecognizer {
     }
 
     /// Check for pair pattern.
-    fn check_pair(cards: &[Card], rank_counts: &HashMap<Rank, usize>) -> Option<PlayPattern> {
-        if cards.len() != 2 || rank_counts.len() != 1 {
+    fn check_pair(cards: &[Card], rank_counts: &RankCounts) -> Option<PlayPattern> {
+        metrics::record_pattern_check();
+        if cards.len() != 2 || rank_counts.distinct_ranks() != 1 {
             return None;
         }
 
-        let (&rank, &count) = rank_counts.iter().next()?;
+        let (rank, count) = rank_counts.iter().next()?;
         if count != 2 {
             return None;
         }
@@ -124,27 +268,24 @@ impl PatternRecognizer {
     }
 
     /// Check for consecutive pairs pattern (连对).
-    fn check_consecutive_pairs(
-        cards: &[Card],
-        rank_counts: &HashMap<Rank, usize>,
-    ) -> Option<PlayPattern> {
-        if cards.len() < 4 || cards.len() % 2 != 0 {
+    fn check_consecutive_pairs(cards: &[Card], rank_counts: &RankCounts) -> Option<PlayPattern> {
+        metrics::record_pattern_check();
+        if cards.len() < RULES.min_pair_chain * 2 || cards.len() % 2 != 0 {
             return None;
         }
 
         // All ranks must have exactly 2 cards
-        if rank_counts.values().any(|&count| count != 2) {
+        if rank_counts.iter().any(|(_, count)| count != 2) {
             return None;
         }
 
-        let mut ranks: Vec<Rank> = rank_counts.keys().copied().collect();
-        ranks.sort_by_key(|r| r.value());
-
         // Check if ranks are consecutive
-        if !Self::are_consecutive(&ranks) {
+        if !runs::is_consecutive(rank_counts.mask_with_at_least(1)) {
             return None;
         }
 
+        let ranks = rank_counts.ranks();
+
         let highest_rank = *ranks.last()?;
         let ranks_len = ranks.len();
         Some(PlayPattern::new(
@@ -159,7 +300,8 @@ impl PatternRecognizer {
 
     /// Check for triple pattern with optional kickers (0-2 cards).
     /// Supports: 3 cards (bare), 4 cards (with 1), 5 cards (with 2)
-    fn check_triple(cards: &[Card], rank_counts: &HashMap<Rank, usize>) -> Option<PlayPattern> {
+    fn check_triple(cards: &[Card], rank_counts: &RankCounts) -> Option<PlayPattern> {
+        metrics::record_pattern_check();
         // Triple can be 3-5 cards (3 + 0/1/2 kickers)
         if !(3..=5).contains(&cards.len()) {
             return None;
@@ -169,14 +311,14 @@ impl PatternRecognizer {
         let triple_rank =
             rank_counts
                 .iter()
-                .find_map(|(&rank, &count)| if count == 3 { Some(rank) } else { None })?;
+                .find_map(|(rank, count)| if count == 3 { Some(rank) } else { None })?;
 
         // Triple with 0-2 kickers: 3, 4, or 5 cards total
         // All recognized as Triple (三张可带0-2张任意牌)
-        if !(3..=5).contains(&cards.len()) {
+        let play_type = PlayType::Triple;
+        if !kicker_rules::standard_capacity(play_type, 1).contains(&(cards.len() - 3)) {
             return None;
         }
-        let play_type = PlayType::Triple;
 
         Some(PlayPattern::new(
             play_type,
@@ -189,24 +331,24 @@ impl PatternRecognizer {
     }
 
     /// Check for airplane pattern (consecutive triples).
-    fn check_airplane(cards: &[Card], rank_counts: &HashMap<Rank, usize>) -> Option<PlayPattern> {
-        if cards.len() < 6 || cards.len() % 3 != 0 {
+    fn check_airplane(cards: &[Card], rank_counts: &RankCounts) -> Option<PlayPattern> {
+        metrics::record_pattern_check();
+        if cards.len() < RULES.min_airplane * 3 || cards.len() % 3 != 0 {
             return None;
         }
 
         // All ranks must have exactly 3 cards
-        if rank_counts.values().any(|&count| count != 3) {
+        if rank_counts.iter().any(|(_, count)| count != 3) {
             return None;
         }
 
-        let mut ranks: Vec<Rank> = rank_counts.keys().copied().collect();
-        ranks.sort_by_key(|r| r.value());
-
         // Check if ranks are consecutive
-        if !Self::are_consecutive(&ranks) {
+        if !runs::is_consecutive(rank_counts.mask_with_at_least(1)) {
             return None;
         }
 
+        let ranks = rank_counts.ranks();
+
         let highest_rank = *ranks.last()?;
         let ranks_len = ranks.len();
         Some(PlayPattern::new(
@@ -226,38 +368,40 @@ impl PatternRecognizer {
     /// Wings can be any cards (singles, pairs, triples, bombs, etc.)
     ///
     /// Key: Greedily select the LARGEST consecutive triple sequence
-    fn check_airplane_with_wings(
+    fn check_airplane_with_wings(cards: &[Card], rank_counts: &RankCounts) -> Option<PlayPattern> {
+        metrics::record_pattern_check();
+        Self::airplane_with_wings(cards, rank_counts, AirplaneBody::Longest)
+    }
+
+    /// Airplane-with-wings reading of `cards` whose body follows `body`;
+    /// among bodies of equal length the lowest is chosen.
+    fn airplane_with_wings(
         cards: &[Card],
-        rank_counts: &HashMap<Rank, usize>,
+        rank_counts: &RankCounts,
+        body: AirplaneBody,
     ) -> Option<PlayPattern> {
-        if cards.len() < 7 {
+        if cards.len() < RULES.min_airplane * 3 + 1 {
             // Minimum: 2 triples (6) + 1 wing (1)
             // Rule: 每组可以带0-2张，所以最少带1张翅膀
             return None;
         }
 
-        // Find all ranks with at least 3 cards
-        let mut triple_candidates: Vec<Rank> = rank_counts
-            .iter()
-            .filter(|(_, &count)| count >= 3)
-            .map(|(&rank, _)| rank)
-            .collect();
-
-        if triple_candidates.len() < 2 {
-            return None;
-        }
-
-        // Sort candidates by rank value
-        triple_candidates.sort_by_key(|r| r.value());
+        // Maximal runs of ranks with at least 3 cards
+        let triple_runs = runs::maximal_runs(rank_counts.mask_with_at_least(3));
+        let longest = triple_runs.iter().map(|run| run.len).max().unwrap_or(0);
 
         // Strategy: Greedily select the LARGEST consecutive triple sequence
-        // Try all possible consecutive triple combinations, preferring larger airplanes
-        for length in (2..=triple_candidates.len()).rev() {
-            // Start from longest
-            for i in 0..=triple_candidates.len() - length {
-                let candidate_ranks = &triple_candidates[i..i + length];
-
-                if Self::are_consecutive(candidate_ranks) {
+        // Try all possible consecutive triple windows, preferring larger airplanes
+        // unless the caller asked for the shortest body
+        let lengths = RULES.min_airplane..=usize::from(longest);
+        let lengths: Vec<usize> = match body {
+            AirplaneBody::Longest => lengths.rev().collect(),
+            AirplaneBody::Shortest => lengths.collect(),
+        };
+        for length in lengths {
+            for run in triple_runs {
+                for candidate_ranks in run.ranks().windows(length) {
+                    metrics::record_pattern_check();
                     let num_triples = candidate_ranks.len();
                     let triple_cards = num_triples * 3;
                     let wing_cards = cards.len() - triple_cards;
@@ -265,7 +409,9 @@ impl PatternRecognizer {
                     // Check if wing count is valid: 0 < wings <= 2N
                     // Rule: 每组可以带0-2张，所以总翅膀数在1到2N之间
                     // Note: wing_cards > 0 because we're in AirplaneWithWings check
-                    if wing_cards > 0 && wing_cards <= 2 * num_triples {
+                    if kicker_rules::standard_capacity(PlayType::AirplaneWithWings, num_triples)
+                        .contains(&wing_cards)
+                    {
                         let highest_rank = *candidate_ranks.last()?;
                         return Some(PlayPattern::new(
                             PlayType::AirplaneWithWings,
@@ -284,14 +430,15 @@ impl PatternRecognizer {
     }
 
     /// Check for bomb pattern (4+ same rank).
-    fn check_bomb(cards: &[Card], rank_counts: &HashMap<Rank, usize>) -> Option<PlayPattern> {
-        if cards.len() < 4 || rank_counts.len() != 1 {
+    fn check_bomb(cards: &[Card], rank_counts: &RankCounts) -> Option<PlayPattern> {
+        metrics::record_pattern_check();
+        if cards.len() < RULES.min_bomb || rank_counts.distinct_ranks() != 1 {
             return None;
         }
 
-        let (&rank, &count) = rank_counts.iter().next()?;
+        let (rank, count) = rank_counts.iter().next()?;
 
-        if count < 4 {
+        if usize::from(count) < RULES.min_bomb {
             return None;
         }
 
@@ -300,28 +447,21 @@ impl PatternRecognizer {
             rank,
             None,
             vec![],
-            count,
-            u32::from(rank.value()) * 1000 + count as u32,
+            usize::from(count),
+            u32::from(rank.value()) * 1000 + u32::from(count),
         ))
     }
 
     /// Check for tongzi pattern (3 same rank same suit).
-    fn check_tongzi(
-        cards: &[Card],
-        suit_rank_counts: &HashMap<(Suit, Rank), usize>,
-        rank_counts: &HashMap<Rank, usize>,
-    ) -> Option<PlayPattern> {
-        if cards.len() != 3 || rank_counts.len() != 1 {
+    fn check_tongzi(cards: &[Card], rank_counts: &RankCounts) -> Option<PlayPattern> {
+        metrics::record_pattern_check();
+        if cards.len() != RULES.tongzi || rank_counts.distinct_ranks() != 1 {
             return None;
         }
 
-        // Must have exactly one suit-rank combination with 3 cards
-        if suit_rank_counts.len() != 1 {
-            return None;
-        }
-
-        let (&(suit, rank), &count) = suit_rank_counts.iter().next()?;
-        if count != 3 {
+        // All cards must share one suit
+        let Card { suit, rank } = cards[0];
+        if cards.iter().any(|c| c.suit != suit) {
             return None;
         }
 
@@ -330,40 +470,30 @@ impl PatternRecognizer {
             rank,
             Some(suit),
             vec![],
-            3,
+            RULES.tongzi,
             u32::from(rank.value()) * 10000 + u32::from(suit.value()) * 1000,
         ))
     }
 
     /// Check for dizha pattern (2 of each suit for same rank).
-    fn check_dizha(
-        cards: &[Card],
-        suit_rank_counts: &HashMap<(Suit, Rank), usize>,
-        rank_counts: &HashMap<Rank, usize>,
-    ) -> Option<PlayPattern> {
-        if cards.len() != 8 || rank_counts.len() != 1 {
+    fn check_dizha(cards: &[Card], rank_counts: &RankCounts) -> Option<PlayPattern> {
+        metrics::record_pattern_check();
+        if cards.len() != RULES.dizha || rank_counts.distinct_ranks() != 1 {
             return None;
         }
 
-        let &rank = rank_counts.keys().next()?;
-
-        // Must have exactly 2 cards of each suit for this rank
-        let suits_for_rank: Vec<Suit> = suit_rank_counts
-            .keys()
-            .filter(|(_, r)| *r == rank)
-            .map(|(s, _)| *s)
-            .collect();
+        let rank = cards[0].rank;
 
-        if suits_for_rank.len() != 4 {
-            // All 4 suits
-            return None;
+        // Each suit must have the same share of the cards
+        let mut suit_counts = [0u8; Suit::ALL.len()];
+        for card in cards {
+            suit_counts[usize::from(card.suit.value() - 1)] += 1;
         }
-
-        // Each suit must have exactly 2 cards
-        for suit in [Suit::Diamonds, Suit::Clubs, Suit::Hearts, Suit::Spades] {
-            if suit_rank_counts.get(&(suit, rank)) != Some(&2) {
-                return None;
-            }
+        if suit_counts
+            .iter()
+            .any(|&count| usize::from(count) != RULES.dizha_per_suit())
+        {
+            return None;
         }
 
         Some(PlayPattern::new(
@@ -371,43 +501,16 @@ impl PatternRecognizer {
             rank,
             None,
             vec![],
-            8,
+            RULES.dizha,
             u32::from(rank.value()) * 100000,
         ))
     }
-
-    /// Check if ranks are consecutive.
-    ///
-    /// Rule: "2和joker不参与连对和飞机，AA22不能作为连对，AAA222也不能作为飞机"
-    /// Rank::Two (value 15) cannot participate in consecutive sequences.
-    fn are_consecutive(ranks: &[Rank]) -> bool {
-        if ranks.len() <= 1 {
-            return true;
-        }
-
-        // Rule: 2 cannot participate in consecutive pairs or airplane
-        // Check if any rank is Two
-        if ranks.iter().any(|r| *r == Rank::Two) {
-            return false;
-        }
-
-        // Convert to values for comparison
-        let values: Vec<u8> = ranks.iter().map(|r| r.value()).collect();
-
-        // Check normal consecutive sequence
-        for i in 1..values.len() {
-            if values[i] != values[i - 1] + 1 {
-                return false;
-            }
-        }
-
-        true
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Rank;
 
     #[test]
     fn test_single_pattern() {
@@ -530,19 +633,106 @@ mod tests {
         assert_eq!(pattern.primary_rank, Rank::Four);
     }
 
+    #[test]
+    fn test_airplane_parts_by_body_preference() {
+        let mut cards = Vec::new();
+        for rank in [Rank::Three, Rank::Four, Rank::Five] {
+            for suit in [Suit::Spades, Suit::Hearts, Suit::Clubs] {
+                cards.push(Card::new(suit, rank));
+            }
+        }
+        let nine = Card::new(Suit::Spades, Rank::Nine);
+        cards.push(nine);
+
+        let longest = PatternRecognizer::airplane_parts(&cards, AirplaneBody::Longest).unwrap();
+        assert_eq!(
+            longest.body_ranks,
+            vec![Rank::Three, Rank::Four, Rank::Five]
+        );
+        assert_eq!(longest.wings, vec![nine]);
+
+        let shortest = PatternRecognizer::airplane_parts(&cards, AirplaneBody::Shortest).unwrap();
+        assert_eq!(shortest.body_ranks, vec![Rank::Three, Rank::Four]);
+        assert_eq!(shortest.body, cards[..6].to_vec());
+        assert_eq!(shortest.wings, cards[6..].to_vec());
+
+        // A fourth card of a body rank goes to the wings
+        let mut bomb_rank = cards[..6].to_vec();
+        bomb_rank.push(Card::new(Suit::Diamonds, Rank::Three));
+        let parts = PatternRecognizer::airplane_parts(&bomb_rank, AirplaneBody::Longest).unwrap();
+        assert_eq!(parts.body, cards[..6].to_vec());
+        assert_eq!(parts.wings, vec![Card::new(Suit::Diamonds, Rank::Three)]);
+
+        // Not an airplane with wings
+        assert!(PatternRecognizer::airplane_parts(&cards[..6], AirplaneBody::Longest).is_none());
+    }
+
     #[test]
     fn test_are_consecutive() {
-        let ranks = vec![Rank::Three, Rank::Four, Rank::Five];
-        assert!(PatternRecognizer::are_consecutive(&ranks));
+        let are_consecutive = |ranks: &[Rank]| runs::is_consecutive(runs::rank_mask(ranks));
+
+        assert!(are_consecutive(&[Rank::Three, Rank::Four, Rank::Five]));
+        assert!(!are_consecutive(&[Rank::Three, Rank::Five]));
+        assert!(are_consecutive(&[Rank::Ace]));
+
+        // Rule: "2和joker不参与连对和飞机，AA22不能作为连对，AAA222也不能作为飞机"
+        assert!(!are_consecutive(&[Rank::Ace, Rank::Two]));
+    }
+}
+
+static NATURAL_ORDER: RankOrdering = RankOrdering::Natural;
+
+/// Comparison rules a [`GameConfig`] can change.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CompareRules<'a> {
+    /// See [`GameConfig::singles_suit_tiebreak`]
+    pub(crate) singles_suit_tiebreak: bool,
+    /// See [`GameConfig::bomb_rank_order`]
+    pub(crate) bomb_rank_order: &'a RankOrdering,
+    /// See [`GameConfig::tongzi_suit_order`]
+    pub(crate) tongzi_suit_order: &'a TongziSuitOrder,
+}
+
+impl CompareRules<'static> {
+    /// The standard rules
+    pub(crate) fn standard() -> Self {
+        Self {
+            singles_suit_tiebreak: false,
+            bomb_rank_order: &NATURAL_ORDER,
+            tongzi_suit_order: &TongziSuitOrder::Natural,
+        }
+    }
+}
 
-        let ranks = vec![Rank::Three, Rank::Five];
-        assert!(!PatternRecognizer::are_consecutive(&ranks));
+impl<'a> CompareRules<'a> {
+    /// The rules `config` selects
+    pub(crate) fn from_config(config: &'a GameConfig) -> Self {
+        Self {
+            singles_suit_tiebreak: config.singles_suit_tiebreak(),
+            bomb_rank_order: config.bomb_rank_order(),
+            tongzi_suit_order: config.tongzi_suit_order(),
+        }
+    }
 
-        let ranks = vec![Rank::Ace];
-        assert!(PatternRecognizer::are_consecutive(&ranks));
+    /// Whether pattern strength still orders plays under these rules
+    fn is_standard(&self) -> bool {
+        !self.singles_suit_tiebreak
+            && self.bomb_rank_order.is_natural()
+            && self.tongzi_suit_order.is_natural()
     }
 }
 
+/// Result of [`PlayValidator::evaluate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evaluation {
+    /// Recognized pattern (`None` if the cards form no pattern)
+    pub pattern: Option<PlayPattern>,
+    /// Whether the cards may be played
+    pub beats: bool,
+    /// Why the cards may not be played (`None` when `beats`)
+    pub reason: Option<Message>,
+}
+
 /// Validates plays according to Da Tong Zi rules.
 pub struct PlayValidator;
 
@@ -557,25 +747,339 @@ impl PlayValidator {
     /// # Returns
     ///
     /// `true` if new cards can beat current play, `false` otherwise.
+    ///
+    /// This only compares patterns. Configuration rules, including
+    /// [`lead_restrictions`](GameConfig::lead_restrictions) and
+    /// [`finish_restrictions`](GameConfig::finish_restrictions), are enforced
+    /// by [`validate_play_in_hand`](Self::validate_play_in_hand).
     #[must_use]
     pub fn can_beat_play(new_cards: &[Card], current_play: Option<&PlayPattern>) -> bool {
-        if current_play.is_none() {
-            // Starting new round - any valid pattern is allowed
-            return PatternRecognizer::analyze_cards(new_cards).is_some();
+        Self::can_beat_with(new_cards, current_play, CompareRules::standard())
+    }
+
+    /// Like [`can_beat_play`](Self::can_beat_play), honoring comparison
+    /// options in `config`
+    /// ([`singles_suit_tiebreak`](GameConfig::singles_suit_tiebreak),
+    /// [`bomb_rank_order`](GameConfig::bomb_rank_order) and
+    /// [`tongzi_suit_order`](GameConfig::tongzi_suit_order)). Lead and finish
+    /// restrictions depend on the player's hand and are checked by
+    /// [`validate_play_in_hand`](Self::validate_play_in_hand).
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::{Card, GameConfig, PatternRecognizer, PlayValidator, Rank, Suit};
+    ///
+    /// let hearts_nine = PatternRecognizer::analyze_cards(&[Card::new(Suit::Hearts, Rank::Nine)]).unwrap();
+    /// let spades_nine = [Card::new(Suit::Spades, Rank::Nine)];
+    /// let config = GameConfig { singles_suit_tiebreak: true, ..GameConfig::default() };
+    ///
+    /// assert!(!PlayValidator::can_beat_play(&spades_nine, Some(&hearts_nine)));
+    /// assert!(PlayValidator::can_beat_play_with_config(&spades_nine, Some(&hearts_nine), &config));
+    /// ```
+    #[must_use]
+    pub fn can_beat_play_with_config(
+        new_cards: &[Card],
+        current_play: Option<&PlayPattern>,
+        config: &GameConfig,
+    ) -> bool {
+        Self::can_beat_with(new_cards, current_play, CompareRules::from_config(config))
+    }
+
+    /// Shared body of the `can_beat_play*` entry points.
+    pub(crate) fn can_beat_with(
+        new_cards: &[Card],
+        current_play: Option<&PlayPattern>,
+        rules: CompareRules<'_>,
+    ) -> bool {
+        Self::evaluate_with(new_cards, current_play, rules).beats
+    }
+
+    /// Recognize `new_cards` and judge them against `current_play` in one
+    /// call.
+    ///
+    /// Same verdict as [`can_beat_play`](Self::can_beat_play), but keeps the
+    /// analyzed pattern and the rejection reason for logging and events.
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::i18n::Message;
+    /// use datongzi_rules::{Card, PatternRecognizer, PlayType, PlayValidator, Rank, Suit};
+    ///
+    /// let ace = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Ace)]).unwrap();
+    /// let king = [Card::new(Suit::Hearts, Rank::King)];
+    ///
+    /// let evaluation = PlayValidator::evaluate(&king, Some(&ace));
+    /// assert!(!evaluation.beats);
+    /// assert_eq!(evaluation.pattern.unwrap().play_type, PlayType::Single);
+    /// assert!(matches!(evaluation.reason, Some(Message::CannotBeat { .. })));
+    /// ```
+    #[must_use]
+    pub fn evaluate(new_cards: &[Card], current_play: Option<&PlayPattern>) -> Evaluation {
+        Self::evaluate_with(new_cards, current_play, CompareRules::standard())
+    }
+
+    /// Like [`evaluate`](Self::evaluate), honoring comparison options in
+    /// `config`
+    #[must_use]
+    pub fn evaluate_with_config(
+        new_cards: &[Card],
+        current_play: Option<&PlayPattern>,
+        config: &GameConfig,
+    ) -> Evaluation {
+        Self::evaluate_with(new_cards, current_play, CompareRules::from_config(config))
+    }
+
+    fn evaluate_with(
+        new_cards: &[Card],
+        current_play: Option<&PlayPattern>,
+        rules: CompareRules<'_>,
+    ) -> Evaluation {
+        let Some(pattern) = PatternRecognizer::analyze_cards(new_cards) else {
+            return Evaluation {
+                pattern: None,
+                beats: false,
+                reason: Some(Message::InvalidPattern {
+                    cards: new_cards.to_vec(),
+                }),
+            };
+        };
+
+        // Starting new round - any valid pattern is allowed
+        let beats = current_play.map_or(true, |current| {
+            let beats = Self::compare_patterns(&pattern, current, rules);
+            if rules.is_standard() {
+                invariants::assert_strength_monotonic(&pattern, current, beats);
+            }
+            beats
+        });
+        let reason = match current_play {
+            Some(current) if !beats => Some(Message::CannotBeat {
+                play_type: pattern.play_type,
+                cards: new_cards.to_vec(),
+                current: current.play_type,
+            }),
+            _ => None,
+        };
+
+        Evaluation {
+            pattern: Some(pattern),
+            beats,
+            reason,
         }
+    }
 
-        let new_pattern = PatternRecognizer::analyze_cards(new_cards);
-        if new_pattern.is_none() {
-            return false;
+    /// Validate a play against a game configuration and the current play.
+    ///
+    /// Unlike [`can_beat_play`](Self::can_beat_play), this reports *why* a play
+    /// is rejected, and also enforces configuration rules such as removed ranks.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_cards` - Cards being played
+    /// * `current_play` - Current play to beat (None if starting new round)
+    /// * `config` - Game configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns `PlayError` if the cards use a removed rank, do not form a valid
+    /// pattern, fall outside a training drill's play family, carry a kicker of
+    /// a forbidden rank, or cannot beat the current play (with the configured
+    /// comparison options)
+    pub fn validate_play(
+        new_cards: &[Card],
+        current_play: Option<&PlayPattern>,
+        config: &GameConfig,
+    ) -> Result<PlayPattern> {
+        Self::check_ranks_allowed(new_cards, config)?;
+
+        let pattern = PatternRecognizer::analyze_cards(new_cards).ok_or_else(|| {
+            DatongziError::PlayError(Message::InvalidPattern {
+                cards: new_cards.to_vec(),
+            })
+        })?;
+
+        if let Some(drill) = config.training().and_then(|t| t.drill_play_type) {
+            if Self::play_family(pattern.play_type) != Self::play_family(drill) {
+                return Err(DatongziError::PlayError(Message::DrillMismatch {
+                    play_type: pattern.play_type,
+                    drill,
+                }));
+            }
+        }
+
+        if let Some(card) = kicker_rules::forbidden_kicker(new_cards, &pattern, config) {
+            return Err(DatongziError::PlayError(Message::ForbiddenKicker { card }));
+        }
+
+        if let Some(current) = current_play {
+            if !Self::compare_patterns(&pattern, current, CompareRules::from_config(config)) {
+                return Err(DatongziError::PlayError(Message::CannotBeat {
+                    play_type: pattern.play_type,
+                    cards: new_cards.to_vec(),
+                    current: current.play_type,
+                }));
+            }
+        }
+
+        Ok(pattern)
+    }
+
+    /// [`validate_play`](Self::validate_play) for a player holding
+    /// `cards_in_hand` cards, also enforcing
+    /// [`lead_restrictions`](GameConfig::lead_restrictions) when leading and
+    /// [`finish_restrictions`](GameConfig::finish_restrictions) when the play
+    /// empties the hand.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`validate_play`](Self::validate_play) error, or
+    /// `PlayError` if the play opens a trick with a restricted trump or
+    /// finishes the hand in a forbidden way
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::models::LeadRestrictions;
+    /// use datongzi_rules::{Card, GameConfig, PlayValidator, Rank, Suit};
+    ///
+    /// let tongzi = [Card::new(Suit::Spades, Rank::King); 3];
+    /// let config = GameConfig {
+    ///     lead_restrictions: LeadRestrictions { no_tongzi_lead: true, ..LeadRestrictions::default() },
+    ///     ..GameConfig::default()
+    /// };
+    ///
+    /// assert!(PlayValidator::validate_play_in_hand(&tongzi, None, 10, &config).is_err());
+    /// assert!(PlayValidator::validate_play_in_hand(&tongzi, None, 3, &config).is_ok());
+    /// ```
+    pub fn validate_play_in_hand(
+        new_cards: &[Card],
+        current_play: Option<&PlayPattern>,
+        cards_in_hand: usize,
+        config: &GameConfig,
+    ) -> Result<PlayPattern> {
+        let pattern = Self::validate_play(new_cards, current_play, config)?;
+        let cards_left = cards_in_hand.saturating_sub(new_cards.len());
+        if current_play.is_none()
+            && !config
+                .lead_restrictions()
+                .allows(pattern.play_type, cards_left)
+        {
+            return Err(DatongziError::PlayError(Message::LeadRestricted {
+                play_type: pattern.play_type,
+            }));
+        }
+        if cards_left == 0 {
+            Self::check_finish(new_cards, &pattern, config)?;
+        }
+        Ok(pattern)
+    }
+
+    /// Check a hand's last play against
+    /// [`finish_restrictions`](GameConfig::finish_restrictions).
+    fn check_finish(cards: &[Card], pattern: &PlayPattern, config: &GameConfig) -> Result<()> {
+        let restrictions = config.finish_restrictions();
+        if restrictions.no_single_score_card && pattern.play_type == PlayType::Single {
+            if let Some(card) = cards.first().filter(|card| card.is_scoring_card()) {
+                return Err(DatongziError::PlayError(Message::FinishOnScoreCard {
+                    card: *card,
+                }));
+            }
+        }
+        if restrictions.no_kickers && !kicker_rules::split_kickers(cards, pattern).1.is_empty() {
+            return Err(DatongziError::PlayError(Message::FinishWithKickers {
+                play_type: pattern.play_type,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Check that a player holding `hand` may pass on `current_play`.
+    ///
+    /// Passing is always allowed unless the configuration's
+    /// [`cards_left_alert`](GameConfig::cards_left_alert) has `must_beat`
+    /// set, the hand is under the alert, and it holds a play that beats
+    /// `current_play` and would itself pass
+    /// [`validate_play_in_hand`](Self::validate_play_in_hand).
+    ///
+    /// # Errors
+    ///
+    /// Returns `PlayError` if the pass is not allowed
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::models::CardsLeftAlert;
+    /// use datongzi_rules::{Card, GameConfig, PatternRecognizer, PlayValidator, Rank, Suit};
+    ///
+    /// let config = GameConfig {
+    ///     cards_left_alert: Some(CardsLeftAlert { threshold: 3, must_beat: true }),
+    ///     ..GameConfig::default()
+    /// };
+    /// let king = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::King)]).unwrap();
+    ///
+    /// let ace = [Card::new(Suit::Hearts, Rank::Ace)];
+    /// assert!(PlayValidator::validate_pass(&ace, &king, &config).is_err());
+    /// let queen = [Card::new(Suit::Hearts, Rank::Queen)];
+    /// assert!(PlayValidator::validate_pass(&queen, &king, &config).is_ok());
+    /// ```
+    pub fn validate_pass(
+        hand: &[Card],
+        current_play: &PlayPattern,
+        config: &GameConfig,
+    ) -> Result<()> {
+        let bound = config
+            .cards_left_alert()
+            .is_some_and(|alert| alert.must_beat && alert.applies_to(hand.len()));
+        // Only beats the player could legally make count, so a hand whose
+        // every beat breaks another rule (e.g. a finish restriction) may pass
+        if bound
+            && PlayGenerator::generate_beating_plays_with_options(
+                hand,
+                current_play,
+                &GenerationOptions::for_config(config),
+            )
+            .iter()
+            .any(|play| {
+                Self::validate_play_in_hand(play, Some(current_play), hand.len(), config).is_ok()
+            })
+        {
+            return Err(DatongziError::PlayError(Message::MustBeat {
+                cards_left: hand.len(),
+            }));
         }
+        Ok(())
+    }
 
-        Self::compare_patterns(&new_pattern.unwrap(), current_play.unwrap())
+    /// Check that no card uses a rank removed by the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PlayError` naming the first offending card
+    pub fn check_ranks_allowed(cards: &[Card], config: &GameConfig) -> Result<()> {
+        if let Some(card) = cards.iter().find(|c| config.is_rank_removed(c.rank)) {
+            return Err(DatongziError::PlayError(Message::RemovedRank {
+                card: *card,
+            }));
+        }
+        Ok(())
+    }
+
+    /// Play type representing a drill family (airplanes share one family).
+    fn play_family(play_type: PlayType) -> PlayType {
+        match play_type {
+            PlayType::AirplaneWithWings => PlayType::Airplane,
+            other => other,
+        }
     }
 
     /// Compare two patterns to see if new pattern beats current pattern.
     ///
-    /// Returns `true` if new_pattern beats current_pattern.
-    fn compare_patterns(new_pattern: &PlayPattern, current_pattern: &PlayPattern) -> bool {
+    /// Returns `true` if new_pattern beats current_pattern. With
+    /// `singles_suit_tiebreak`, a single beats an equal-rank single of a
+    /// lower suit; equal-size bombs compare by `bomb_rank_order`.
+    fn compare_patterns(
+        new_pattern: &PlayPattern,
+        current_pattern: &PlayPattern,
+        rules: CompareRules<'_>,
+    ) -> bool {
         use std::cmp::Ordering;
 
         // Special case 1: Dizha rules
@@ -611,7 +1115,8 @@ impl PlayValidator {
                             if let (Some(new_suit), Some(current_suit)) =
                                 (new_pattern.primary_suit, current_pattern.primary_suit)
                             {
-                                return new_suit.value() > current_suit.value();
+                                return rules.tongzi_suit_order.compare(new_suit, current_suit)
+                                    == Ordering::Greater;
                             }
                             return false;
                         }
@@ -639,7 +1144,10 @@ impl PlayValidator {
             match new_pattern.card_count.cmp(&current_pattern.card_count) {
                 Ordering::Greater => return true,
                 Ordering::Equal => {
-                    return new_pattern.primary_rank.value() > current_pattern.primary_rank.value()
+                    return rules
+                        .bomb_rank_order
+                        .compare(new_pattern.primary_rank, current_pattern.primary_rank)
+                        == Ordering::Greater
                 }
                 Ordering::Less => return false,
             }
@@ -678,6 +1186,15 @@ impl PlayValidator {
             }
         }
 
+        // Optional single-card tiebreak: equal ranks compare by suit
+        if rules.singles_suit_tiebreak
+            && new_pattern.play_type == PlayType::Single
+            && new_pattern.primary_rank == current_pattern.primary_rank
+        {
+            let suit = |p: &PlayPattern| p.primary_suit.map_or(0, Suit::value);
+            return suit(new_pattern) > suit(current_pattern);
+        }
+
         // Compare by primary_rank for same type patterns
         // For normal patterns (Single, Pair, Triple, Airplane, ConsecutivePairs),
         // we compare by primary_rank directly instead of using strength encoding
@@ -688,6 +1205,7 @@ impl PlayValidator {
 #[cfg(test)]
 mod validator_tests {
     use super::*;
+    use crate::models::{CardsLeftAlert, FinishRestrictions, LeadRestrictions, Rank};
 
     #[test]
     fn test_can_beat_new_round() {
@@ -985,6 +1503,354 @@ mod validator_tests {
         // But for standard single deck, we only test 4-card bombs
     }
 
+    #[test]
+    fn test_validate_play_rejects_removed_ranks() {
+        let config = GameConfig::default(); // removes 3 and 4
+        let cards = vec![
+            Card::new(Suit::Spades, Rank::Three),
+            Card::new(Suit::Hearts, Rank::Three),
+        ];
+
+        let err = PlayValidator::validate_play(&cards, None, &config).unwrap_err();
+        assert!(err.to_string().contains("removed"));
+
+        let allowed = vec![
+            Card::new(Suit::Spades, Rank::Five),
+            Card::new(Suit::Hearts, Rank::Five),
+        ];
+        let pattern = PlayValidator::validate_play(&allowed, None, &config).unwrap();
+        assert_eq!(pattern.play_type, PlayType::Pair);
+    }
+
+    #[test]
+    fn test_validate_play_reports_invalid_and_weaker_plays() {
+        let config = GameConfig::default();
+        let invalid = vec![
+            Card::new(Suit::Spades, Rank::Five),
+            Card::new(Suit::Hearts, Rank::Six),
+        ];
+        assert!(PlayValidator::validate_play(&invalid, None, &config).is_err());
+
+        let current = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Ace)]);
+        let weaker = vec![Card::new(Suit::Spades, Rank::King)];
+        assert!(PlayValidator::validate_play(&weaker, current.as_ref(), &config).is_err());
+    }
+
+    #[test]
+    fn test_singles_suit_tiebreak() {
+        let config = GameConfig {
+            singles_suit_tiebreak: true,
+            ..GameConfig::default()
+        };
+        let hearts_ace = PatternRecognizer::analyze_cards(&[Card::new(Suit::Hearts, Rank::Ace)]);
+        let spades_ace = [Card::new(Suit::Spades, Rank::Ace)];
+        let clubs_ace = [Card::new(Suit::Clubs, Rank::Ace)];
+
+        assert!(PlayValidator::validate_play(&spades_ace, hearts_ace.as_ref(), &config).is_ok());
+        assert!(PlayValidator::validate_play(&clubs_ace, hearts_ace.as_ref(), &config).is_err());
+        assert!(PlayValidator::validate_play(
+            &spades_ace,
+            hearts_ace.as_ref(),
+            &GameConfig::default()
+        )
+        .is_err());
+
+        // Only singles tie-break; pairs still need a higher rank
+        let pair = |suit: Suit| {
+            [
+                Card::new(suit, Rank::Nine),
+                Card::new(Suit::Diamonds, Rank::Nine),
+            ]
+        };
+        let current = PatternRecognizer::analyze_cards(&pair(Suit::Hearts));
+        assert!(!PlayValidator::can_beat_play_with_config(
+            &pair(Suit::Spades),
+            current.as_ref(),
+            &config
+        ));
+    }
+
+    #[test]
+    fn test_tongzi_suit_order() {
+        let config = GameConfig {
+            tongzi_suit_order: TongziSuitOrder::Fixed([
+                Suit::Spades,
+                Suit::Diamonds,
+                Suit::Clubs,
+                Suit::Hearts,
+            ]),
+            ..GameConfig::default()
+        };
+        let tongzi = |suit: Suit| vec![Card::new(suit, Rank::King); 3];
+        let spades = PatternRecognizer::analyze_cards(&tongzi(Suit::Spades));
+        let hearts = PatternRecognizer::analyze_cards(&tongzi(Suit::Hearts));
+
+        assert!(
+            PlayValidator::validate_play(&tongzi(Suit::Diamonds), spades.as_ref(), &config).is_ok()
+        );
+        assert!(
+            PlayValidator::validate_play(&tongzi(Suit::Spades), hearts.as_ref(), &config).is_err()
+        );
+        assert!(!PlayValidator::can_beat_play(
+            &tongzi(Suit::Diamonds),
+            spades.as_ref()
+        ));
+
+        let options = GenerationOptions {
+            tongzi_suit_order: *config.tongzi_suit_order(),
+            ..GenerationOptions::default()
+        };
+        let hand = [tongzi(Suit::Clubs), tongzi(Suit::Spades)].concat();
+        let plays = PlayGenerator::generate_beating_plays_with_options(
+            &hand,
+            spades.as_ref().unwrap(),
+            &options,
+        );
+        assert!(plays.contains(&tongzi(Suit::Clubs)));
+    }
+
+    #[test]
+    fn test_bomb_rank_order() {
+        // Aces above 2s: a local variant
+        let config = GameConfig {
+            bomb_rank_order: RankOrdering::Custom(vec![Rank::Two, Rank::King, Rank::Ace]),
+            ..GameConfig::default()
+        };
+        let bomb = |rank: Rank, size: usize| -> Vec<Card> {
+            Suit::ALL
+                .iter()
+                .cycle()
+                .take(size)
+                .map(|suit| Card::new(*suit, rank))
+                .collect()
+        };
+        let twos = PatternRecognizer::analyze_cards(&bomb(Rank::Two, 4));
+        let kings = PatternRecognizer::analyze_cards(&bomb(Rank::King, 4));
+
+        assert!(PlayValidator::validate_play(&bomb(Rank::Ace, 4), twos.as_ref(), &config).is_ok());
+        assert!(
+            PlayValidator::validate_play(&bomb(Rank::Two, 4), kings.as_ref(), &config).is_err()
+        );
+        assert!(
+            PlayValidator::validate_play(&bomb(Rank::Queen, 4), twos.as_ref(), &config).is_err()
+        );
+        assert!(!PlayValidator::can_beat_play(
+            &bomb(Rank::Ace, 4),
+            twos.as_ref()
+        ));
+
+        // Size still comes first
+        assert!(PlayValidator::can_beat_play_with_config(
+            &bomb(Rank::Six, 5),
+            kings.as_ref(),
+            &config
+        ));
+    }
+
+    #[test]
+    fn test_analyze_batch_matches_single_calls() {
+        let mut plays: Vec<Vec<Card>> = Vec::new();
+        for rank in Rank::ALL {
+            for size in 1..=5 {
+                plays.push(
+                    (0..size)
+                        .map(|j| Card::new(Suit::ALL[(rank.index() + j) % 4], rank))
+                        .collect(),
+                );
+            }
+            plays.push(vec![
+                Card::new(Suit::Spades, rank),
+                Card::new(Suit::Hearts, Rank::Two),
+            ]);
+        }
+        let plays: Vec<Vec<Card>> = plays
+            .iter()
+            .cycle()
+            .take(4 * PARALLEL_BATCH_MIN)
+            .cloned()
+            .collect();
+        let expected: Vec<Option<PlayPattern>> = plays
+            .iter()
+            .map(|p| PatternRecognizer::analyze_cards(p))
+            .collect();
+
+        assert_eq!(PatternRecognizer::analyze_batch(&plays), expected);
+        assert_eq!(
+            PatternRecognizer::analyze_batch_parallel(&plays, 3),
+            expected
+        );
+        assert!(PatternRecognizer::analyze_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_forbidden_kicker_ranks() {
+        let config = GameConfig {
+            forbidden_kicker_ranks: vec![Rank::Two],
+            ..GameConfig::default()
+        };
+        let mut triple: Vec<Card> = [Suit::Spades, Suit::Hearts, Suit::Clubs]
+            .iter()
+            .map(|suit| Card::new(*suit, Rank::Nine))
+            .collect();
+        triple.push(Card::new(Suit::Spades, Rank::Two));
+
+        assert!(matches!(
+            PlayValidator::validate_play(&triple, None, &config),
+            Err(DatongziError::PlayError(Message::ForbiddenKicker { .. }))
+        ));
+        assert!(PlayValidator::validate_play(&triple, None, &GameConfig::default()).is_ok());
+
+        // A triple of the forbidden rank is still a legal main structure
+        let twos: Vec<Card> = [Suit::Spades, Suit::Hearts, Suit::Clubs]
+            .iter()
+            .map(|suit| Card::new(*suit, Rank::Two))
+            .collect();
+        assert!(PlayValidator::validate_play(&twos, None, &config).is_ok());
+    }
+
+    #[test]
+    fn test_lead_restrictions() {
+        let config = GameConfig {
+            lead_restrictions: LeadRestrictions {
+                no_tongzi_lead: true,
+                finishing_allowance: 2,
+                ..LeadRestrictions::default()
+            },
+            ..GameConfig::default()
+        };
+        let tongzi = [Card::new(Suit::Hearts, Rank::Queen); 3];
+
+        assert!(matches!(
+            PlayValidator::validate_play_in_hand(&tongzi, None, 6, &config),
+            Err(DatongziError::PlayError(Message::LeadRestricted {
+                play_type: PlayType::Tongzi
+            }))
+        ));
+        assert!(PlayValidator::validate_play_in_hand(&tongzi, None, 5, &config).is_ok());
+
+        // Answering with the Tongzi and leading other trumps stay legal
+        let ace = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Ace)]);
+        assert!(PlayValidator::validate_play_in_hand(&tongzi, ace.as_ref(), 20, &config).is_ok());
+        let bomb: Vec<Card> = Suit::ALL
+            .iter()
+            .map(|suit| Card::new(*suit, Rank::Nine))
+            .collect();
+        assert!(PlayValidator::validate_play_in_hand(&bomb, None, 20, &config).is_ok());
+    }
+
+    #[test]
+    fn test_validate_pass_under_cards_left_alert() {
+        let alert = |must_beat| GameConfig {
+            cards_left_alert: Some(CardsLeftAlert {
+                threshold: 2,
+                must_beat,
+            }),
+            ..GameConfig::default()
+        };
+        let king =
+            PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::King)]).unwrap();
+        let two_aces = [Card::new(Suit::Hearts, Rank::Ace); 2];
+        let three_aces = [Card::new(Suit::Hearts, Rank::Ace); 3];
+
+        assert!(matches!(
+            PlayValidator::validate_pass(&two_aces, &king, &alert(true)),
+            Err(DatongziError::PlayError(Message::MustBeat {
+                cards_left: 2
+            }))
+        ));
+        // Above the threshold, announcement only, or no alert at all
+        assert!(PlayValidator::validate_pass(&three_aces, &king, &alert(true)).is_ok());
+        assert!(PlayValidator::validate_pass(&two_aces, &king, &alert(false)).is_ok());
+        assert!(PlayValidator::validate_pass(&two_aces, &king, &GameConfig::default()).is_ok());
+    }
+
+    #[test]
+    fn test_finish_restrictions() {
+        let config = GameConfig {
+            finish_restrictions: FinishRestrictions {
+                no_single_score_card: true,
+                no_kickers: true,
+            },
+            ..GameConfig::default()
+        };
+        let ten = [Card::new(Suit::Hearts, Rank::Ten)];
+        assert!(matches!(
+            PlayValidator::validate_play_in_hand(&ten, None, 1, &config),
+            Err(DatongziError::PlayError(Message::FinishOnScoreCard { .. }))
+        ));
+        assert!(PlayValidator::validate_play_in_hand(&ten, None, 2, &config).is_ok());
+        let nine = [Card::new(Suit::Hearts, Rank::Nine)];
+        assert!(PlayValidator::validate_play_in_hand(&nine, None, 1, &config).is_ok());
+
+        let mut triple: Vec<Card> = [Suit::Spades, Suit::Hearts, Suit::Clubs]
+            .iter()
+            .map(|suit| Card::new(*suit, Rank::Eight))
+            .collect();
+        assert!(PlayValidator::validate_play_in_hand(&triple, None, 3, &config).is_ok());
+        triple.push(Card::new(Suit::Spades, Rank::Six));
+        assert!(matches!(
+            PlayValidator::validate_play_in_hand(&triple, None, 4, &config),
+            Err(DatongziError::PlayError(Message::FinishWithKickers {
+                play_type: PlayType::Triple
+            }))
+        ));
+        assert!(
+            PlayValidator::validate_play_in_hand(&triple, None, 4, &GameConfig::default()).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_must_beat_yields_to_finish_restrictions() {
+        let config = GameConfig {
+            cards_left_alert: Some(CardsLeftAlert {
+                threshold: 3,
+                must_beat: true,
+            }),
+            finish_restrictions: FinishRestrictions {
+                no_single_score_card: true,
+                ..FinishRestrictions::default()
+            },
+            ..GameConfig::default()
+        };
+        let queen =
+            PatternRecognizer::analyze_cards(&[Card::new(Suit::Hearts, Rank::Queen)]).unwrap();
+        let king = [Card::new(Suit::Spades, Rank::King)];
+
+        // The K may not finish the hand, so passing must stay legal
+        assert!(PlayValidator::validate_play_in_hand(&king, Some(&queen), 1, &config).is_err());
+        assert!(PlayValidator::validate_pass(&king, &queen, &config).is_ok());
+    }
+
+    #[test]
+    fn test_evaluate_matches_can_beat_play() {
+        let king = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::King)]);
+        let plays = [
+            vec![Card::new(Suit::Hearts, Rank::Ace)],
+            vec![Card::new(Suit::Hearts, Rank::Queen)],
+            vec![
+                Card::new(Suit::Hearts, Rank::Ace),
+                Card::new(Suit::Hearts, Rank::Five),
+            ],
+        ];
+        for current in [None, king.as_ref()] {
+            for play in &plays {
+                let evaluation = PlayValidator::evaluate(play, current);
+                assert_eq!(
+                    evaluation.beats,
+                    PlayValidator::can_beat_play(play, current)
+                );
+                assert_eq!(evaluation.beats, evaluation.reason.is_none());
+                assert_eq!(evaluation.pattern, PatternRecognizer::analyze_cards(play));
+            }
+        }
+
+        let invalid = PlayValidator::evaluate(&plays[2], None);
+        assert!(matches!(
+            invalid.reason,
+            Some(Message::InvalidPattern { .. })
+        ));
+    }
+
     #[test]
     fn test_tongzi_vs_tongzi_comparison() {
         // Tongzi (Spades, Three)
@@ -0,0 +1,110 @@
+//! Reference play comparator (feature `reference-model`).
+//!
+//! A deliberately plain restatement of the beating rules in GAME_RULE.md,
+//! used as the oracle in differential tests against
+//! [`PlayValidator::can_beat_play`](super::PlayValidator::can_beat_play).
+//! It favors obviousness over speed: every (new, current) type pair is
+//! listed in one table, and same-class plays compare by an explicit key.
+
+use super::{PlayPattern, PlayType};
+
+/// How a play of one type relates to a play of another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Relation {
+    /// The new play always beats the current one
+    Beats,
+    /// The new play never beats the current one
+    Loses,
+    /// Same comparison class: compare keys
+    Compare,
+}
+
+use Relation::{Beats, Compare, Loses};
+
+/// Index of each play type in [`RULE_TABLE`].
+const TYPES: [PlayType; 9] = [
+    PlayType::Single,
+    PlayType::Pair,
+    PlayType::ConsecutivePairs,
+    PlayType::Triple,
+    PlayType::Airplane,
+    PlayType::AirplaneWithWings,
+    PlayType::Bomb,
+    PlayType::Tongzi,
+    PlayType::Dizha,
+];
+
+/// `RULE_TABLE[new][current]`, rows and columns ordered like [`TYPES`].
+#[rustfmt::skip]
+const RULE_TABLE: [[Relation; 9]; 9] = [
+    //            Single   Pair     ConsPr   Triple   Plane    PlaneW   Bomb   Tongzi Dizha
+    /* Single */ [Compare, Loses,   Loses,   Loses,   Loses,   Loses,   Loses, Loses, Loses],
+    /* Pair   */ [Loses,   Compare, Loses,   Loses,   Loses,   Loses,   Loses, Loses, Loses],
+    /* ConsPr */ [Loses,   Loses,   Compare, Loses,   Loses,   Loses,   Loses, Loses, Loses],
+    /* Triple */ [Loses,   Loses,   Loses,   Compare, Loses,   Loses,   Loses, Loses, Loses],
+    /* Plane  */ [Loses,   Loses,   Loses,   Loses,   Compare, Compare, Loses, Loses, Loses],
+    /* PlaneW */ [Loses,   Loses,   Loses,   Loses,   Compare, Compare, Loses, Loses, Loses],
+    /* Bomb   */ [Beats,   Beats,   Beats,   Beats,   Beats,   Beats,   Compare, Loses, Loses],
+    /* Tongzi */ [Beats,   Beats,   Beats,   Beats,   Beats,   Beats,   Beats, Compare, Loses],
+    /* Dizha  */ [Beats,   Beats,   Beats,   Beats,   Beats,   Beats,   Beats, Beats, Compare],
+];
+
+/// Returns true if `new_pattern` beats `current_pattern` under the default rules.
+///
+/// Same-class plays compare by:
+/// - Single / Pair / Triple: rank (kickers ignored)
+/// - Consecutive pairs, airplanes (with or without wings): same chain
+///   length required, then rank
+/// - Bomb: card count, then rank
+/// - Tongzi: rank, then suit
+/// - Dizha: rank
+///
+/// # Example
+/// ```
+/// use datongzi_rules::patterns::reference::reference_beats;
+/// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+///
+/// let tongzi = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Five); 3]).unwrap();
+/// let bomb = PatternRecognizer::analyze_cards(&[Card::new(Suit::Hearts, Rank::Ace); 6]).unwrap();
+///
+/// assert!(reference_beats(&tongzi, &bomb));
+/// assert!(!reference_beats(&bomb, &tongzi));
+/// ```
+#[must_use]
+pub fn reference_beats(new_pattern: &PlayPattern, current_pattern: &PlayPattern) -> bool {
+    match RULE_TABLE[type_index(new_pattern.play_type)][type_index(current_pattern.play_type)] {
+        Beats => true,
+        Loses => false,
+        Compare => match (chain_length(new_pattern), chain_length(current_pattern)) {
+            (new_length, current_length) if new_length != current_length => false,
+            _ => compare_key(new_pattern) > compare_key(current_pattern),
+        },
+    }
+}
+
+fn type_index(play_type: PlayType) -> usize {
+    TYPES
+        .iter()
+        .position(|t| *t == play_type)
+        .expect("every play type is listed in TYPES")
+}
+
+/// Number of ranks in the chain, for types that must match chain length.
+fn chain_length(pattern: &PlayPattern) -> Option<usize> {
+    match pattern.play_type {
+        PlayType::ConsecutivePairs | PlayType::Airplane | PlayType::AirplaneWithWings => {
+            Some(pattern.secondary_ranks.len())
+        }
+        _ => None,
+    }
+}
+
+/// Ordering key within a comparison class.
+fn compare_key(pattern: &PlayPattern) -> (usize, u8, u8) {
+    let rank = pattern.primary_rank.value();
+    match pattern.play_type {
+        PlayType::Bomb => (pattern.card_count, rank, 0),
+        PlayType::Tongzi => (0, rank, pattern.primary_suit.map_or(0, |s| s.value())),
+        _ => (0, rank, 0),
+    }
+}
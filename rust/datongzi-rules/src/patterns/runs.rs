@@ -0,0 +1,176 @@
+//! Precomputed consecutive-run tables for rank masks.
+//!
+//! A rank mask is a `u16` with bit `i` set when [`Rank::ALL`]`[i]` is
+//! present (see [`RankCounts::mask_with_at_least`](crate::models::RankCounts::mask_with_at_least)).
+//! The maximal consecutive runs of all 8192 masks are computed at compile
+//! time, so chain checks are a table lookup instead of a sort and scan.
+//!
+//! Run policy: 2 never takes part in a run and ranks do not wrap around,
+//! so AA22 and K-A-2-3 are not chains (连对/飞机 rules). This is the only
+//! policy: [`GameConfig`](crate::GameConfig) has no wrap option, so the
+//! tables are built for
+//! [`ChainWrapPolicy::NoWrap`](crate::variants::ChainWrapPolicy::NoWrap)
+//! alone.
+
+use crate::models::Rank;
+
+/// Number of distinct rank masks.
+pub const MASK_COUNT: usize = 1 << Rank::ALL.len();
+/// Bits of all valid rank masks.
+const MASK_BITS: u16 = (MASK_COUNT - 1) as u16;
+/// Bit of [`Rank::Two`], which never joins a run.
+const TWO_BIT: u16 = 1 << (Rank::ALL.len() - 1);
+/// Ranks that may form runs (3 through A).
+const RUN_RANKS: usize = Rank::ALL.len() - 1;
+/// Most maximal runs a mask can hold (alternating bits over 3..A).
+const MAX_RUNS: usize = RUN_RANKS.div_ceil(2);
+
+/// A maximal run of consecutive ranks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankRun {
+    /// Lowest rank of the run
+    pub low: Rank,
+    /// Number of ranks in the run
+    pub len: u8,
+}
+
+impl RankRun {
+    const EMPTY: Self = Self {
+        low: Rank::Three,
+        len: 0,
+    };
+
+    /// Highest rank of the run
+    #[must_use]
+    pub const fn high(self) -> Rank {
+        Rank::ALL[self.start() + self.len as usize - 1]
+    }
+
+    /// Ranks of the run in ascending order
+    #[must_use]
+    pub fn ranks(self) -> &'static [Rank] {
+        &Rank::ALL[self.start()..self.start() + self.len as usize]
+    }
+
+    const fn start(self) -> usize {
+        self.low.index()
+    }
+}
+
+/// Maximal runs of one mask.
+#[derive(Clone, Copy)]
+struct Runs {
+    count: u8,
+    runs: [RankRun; MAX_RUNS],
+}
+
+static RUN_TABLE: [Runs; MASK_COUNT] = build_table();
+
+const fn build_table() -> [Runs; MASK_COUNT] {
+    let empty = Runs {
+        count: 0,
+        runs: [RankRun::EMPTY; MAX_RUNS],
+    };
+    let mut table = [empty; MASK_COUNT];
+
+    let mut mask = 0;
+    while mask < MASK_COUNT {
+        let bits = mask as u16 & !TWO_BIT;
+        let mut runs = empty;
+        let mut i = 0;
+        while i < RUN_RANKS {
+            if bits & (1 << i) == 0 {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < RUN_RANKS && bits & (1 << i) != 0 {
+                i += 1;
+            }
+            runs.runs[runs.count as usize] = RankRun {
+                low: Rank::ALL[start],
+                len: (i - start) as u8,
+            };
+            runs.count += 1;
+        }
+        table[mask] = runs;
+        mask += 1;
+    }
+
+    table
+}
+
+/// Build the rank mask of `ranks` (duplicates are ignored)
+#[must_use]
+pub fn rank_mask(ranks: &[Rank]) -> u16 {
+    ranks.iter().fold(0, |mask, rank| mask | 1 << rank.index())
+}
+
+/// Maximal runs of `mask` in ascending rank order.
+///
+/// Runs of length 1 are included; 2 is never part of a run.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::patterns::runs::{maximal_runs, rank_mask};
+/// use datongzi_rules::Rank;
+///
+/// let mask = rank_mask(&[Rank::Five, Rank::Six, Rank::Seven, Rank::Ten, Rank::Two]);
+/// let runs = maximal_runs(mask);
+///
+/// assert_eq!(runs.len(), 2);
+/// assert_eq!(runs[0].ranks(), &[Rank::Five, Rank::Six, Rank::Seven]);
+/// assert_eq!(runs[1].ranks(), &[Rank::Ten]);
+/// ```
+#[must_use]
+pub fn maximal_runs(mask: u16) -> &'static [RankRun] {
+    let runs = &RUN_TABLE[usize::from(mask & MASK_BITS)];
+    &runs.runs[..usize::from(runs.count)]
+}
+
+/// Returns true if the ranks in `mask` form one consecutive sequence.
+///
+/// Zero or one rank counts as consecutive; two or more ranks must form a
+/// single run without 2.
+#[must_use]
+pub fn is_consecutive(mask: u16) -> bool {
+    let mask = mask & MASK_BITS;
+    mask.count_ones() <= 1 || (mask & TWO_BIT == 0 && maximal_runs(mask).len() == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runs_match_scan() {
+        for mask in 0..MASK_COUNT as u16 {
+            let ranks: Vec<Rank> = Rank::ALL
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, r)| *r)
+                .collect();
+            assert_eq!(rank_mask(&ranks), mask);
+
+            let expected = ranks.len() <= 1
+                || (!ranks.contains(&Rank::Two)
+                    && ranks.windows(2).all(|w| w[1].value() == w[0].value() + 1));
+            assert_eq!(is_consecutive(mask), expected, "mask {mask:#b}");
+
+            let covered: usize = maximal_runs(mask).iter().map(|r| usize::from(r.len)).sum();
+            let runnable = ranks.iter().filter(|r| **r != Rank::Two).count();
+            assert_eq!(covered, runnable, "mask {mask:#b}");
+        }
+    }
+
+    #[test]
+    fn test_two_breaks_runs() {
+        let runs = maximal_runs(rank_mask(&[Rank::King, Rank::Ace, Rank::Two]));
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].low, Rank::King);
+        assert_eq!(runs[0].high(), Rank::Ace);
+        assert!(!is_consecutive(rank_mask(&[Rank::Ace, Rank::Two])));
+        assert!(is_consecutive(rank_mask(&[Rank::Two])));
+    }
+}
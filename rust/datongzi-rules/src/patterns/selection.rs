@@ -0,0 +1,300 @@
+//! Incremental pattern feedback while a player selects cards.
+
+use super::{kicker_rules, runs, PatternRecognizer, PlayPattern, PlayType};
+use crate::models::{Card, Rank, RankCounts, Suit};
+
+/// Cards per suit for each rank, indexed like [`Rank::ALL`] then suit value.
+type SuitCounts = [[u8; Suit::ALL.len()]; Rank::ALL.len()];
+
+/// Tracks a card selection within a hand, one tap at a time.
+///
+/// Per-rank and per-suit counts are updated on every [`add`](Self::add) /
+/// [`remove`](Self::remove) instead of being rebuilt, so the current
+/// pattern and the types the selection could still grow into are cheap to
+/// refresh on each tap.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::patterns::SelectionAnalyzer;
+/// use datongzi_rules::{Card, PlayType, Rank, Suit};
+///
+/// let hand: Vec<Card> = Suit::ALL.iter().map(|s| Card::new(*s, Rank::Nine)).collect();
+/// let mut selection = SelectionAnalyzer::new(&hand);
+///
+/// assert!(selection.add(hand[0]));
+/// assert!(selection.add(hand[1]));
+///
+/// assert_eq!(selection.current().unwrap().play_type, PlayType::Pair);
+/// assert_eq!(selection.could_become(), vec![PlayType::Triple, PlayType::Bomb]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SelectionAnalyzer {
+    hand_counts: RankCounts,
+    hand_suits: SuitCounts,
+    selected: Vec<Card>,
+    counts: RankCounts,
+    suits: SuitCounts,
+    current: Option<PlayPattern>,
+}
+
+impl SelectionAnalyzer {
+    /// Start with an empty selection from `hand`
+    #[must_use]
+    pub fn new(hand: &[Card]) -> Self {
+        let mut hand_suits = [[0; Suit::ALL.len()]; Rank::ALL.len()];
+        for card in hand {
+            hand_suits[card.rank.index()][card.suit.index()] += 1;
+        }
+        Self {
+            hand_counts: RankCounts::from_cards(hand),
+            hand_suits,
+            selected: Vec::new(),
+            counts: RankCounts::new(),
+            suits: [[0; Suit::ALL.len()]; Rank::ALL.len()],
+            current: None,
+        }
+    }
+
+    /// Select one more `card`.
+    ///
+    /// Returns false (and changes nothing) if every copy of `card` in the
+    /// hand is already selected.
+    pub fn add(&mut self, card: Card) -> bool {
+        let (r, s) = (card.rank.index(), card.suit.index());
+        if self.suits[r][s] >= self.hand_suits[r][s] {
+            return false;
+        }
+        self.suits[r][s] += 1;
+        self.counts.add(card.rank);
+        self.selected.push(card);
+        self.refresh();
+        true
+    }
+
+    /// Deselect one copy of `card`; returns false if it was not selected
+    pub fn remove(&mut self, card: Card) -> bool {
+        let Some(position) = self.selected.iter().position(|c| *c == card) else {
+            return false;
+        };
+        self.selected.swap_remove(position);
+        self.suits[card.rank.index()][card.suit.index()] -= 1;
+        self.counts.remove(card.rank);
+        self.refresh();
+        true
+    }
+
+    /// Deselect everything
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.counts = RankCounts::new();
+        self.suits = [[0; Suit::ALL.len()]; Rank::ALL.len()];
+        self.current = None;
+    }
+
+    /// Currently selected cards, in tap order except after removals
+    #[must_use]
+    pub fn selected(&self) -> &[Card] {
+        &self.selected
+    }
+
+    /// Pattern the selection forms right now, if any
+    #[must_use]
+    pub fn current(&self) -> Option<&PlayPattern> {
+        self.current.as_ref()
+    }
+
+    /// Play types, other than the current one, that adding more cards
+    /// from the hand could form; ordered like [`PlayType`].
+    ///
+    /// Kickers and wings are treated as free slots, so the answer is exact
+    /// for counts and may occasionally be optimistic about which chain the
+    /// recognizer would pick.
+    #[must_use]
+    pub fn could_become(&self) -> Vec<PlayType> {
+        let current = self.current.as_ref().map(|p| p.play_type);
+        [
+            PlayType::Single,
+            PlayType::Pair,
+            PlayType::ConsecutivePairs,
+            PlayType::Triple,
+            PlayType::Airplane,
+            PlayType::AirplaneWithWings,
+            PlayType::Bomb,
+            PlayType::Tongzi,
+            PlayType::Dizha,
+        ]
+        .into_iter()
+        .filter(|play_type| Some(*play_type) != current && self.reachable(*play_type))
+        .collect()
+    }
+
+    fn refresh(&mut self) {
+        self.current = PatternRecognizer::analyze_counted(&self.selected, &self.counts);
+    }
+
+    /// Returns true if some strict superset of the selection within the
+    /// hand forms `play_type`.
+    fn reachable(&self, play_type: PlayType) -> bool {
+        let n = self.counts.total();
+        let hand_total = self.hand_counts.total();
+        let only_rank = |rank: Rank| usize::from(self.counts.get(rank)) == n;
+
+        match play_type {
+            PlayType::Single => n == 0 && hand_total > 0,
+            PlayType::Pair => {
+                n < 2
+                    && Rank::ALL
+                        .iter()
+                        .any(|r| only_rank(*r) && self.hand_counts.get(*r) >= 2)
+            }
+            PlayType::Triple => Rank::ALL.iter().any(|r| {
+                let (held, picked) = (self.hand_counts.get(*r), self.counts.get(*r));
+                if held < 3 || picked > 3 {
+                    return false;
+                }
+                // Kickers: selected cards of other ranks plus any still to add
+                let extra = n - usize::from(picked);
+                let spare = hand_total - usize::from(held) - extra;
+                let needed = if picked < 3 { extra } else { extra + 1 };
+                kicker_rules::standard_capacity(PlayType::Triple, 1).contains(&needed)
+                    && needed - extra <= spare
+            }),
+            PlayType::ConsecutivePairs => self.chain_reachable(2, 0),
+            PlayType::Airplane => self.chain_reachable(3, 0),
+            PlayType::AirplaneWithWings => self.chain_reachable(3, 1),
+            PlayType::Bomb => Rank::ALL
+                .iter()
+                .any(|r| only_rank(*r) && usize::from(self.hand_counts.get(*r)) >= (n + 1).max(4)),
+            PlayType::Tongzi => {
+                n < 3
+                    && Rank::ALL.iter().any(|r| {
+                        Suit::ALL.iter().any(|s| {
+                            let (ri, si) = (r.index(), s.index());
+                            usize::from(self.suits[ri][si]) == n && self.hand_suits[ri][si] >= 3
+                        })
+                    })
+            }
+            PlayType::Dizha => {
+                n < 8
+                    && Rank::ALL.iter().any(|r| {
+                        let ri = r.index();
+                        only_rank(*r)
+                            && (0..Suit::ALL.len())
+                                .all(|si| self.suits[ri][si] <= 2 && self.hand_suits[ri][si] >= 2)
+                    })
+            }
+        }
+    }
+
+    /// Chain of `width`-card groups reachable from the selection, carrying
+    /// at least `min_wings` wing cards (wings only when `min_wings > 0`).
+    fn chain_reachable(&self, width: u8, min_wings: usize) -> bool {
+        let n = self.counts.total();
+        let hand_total = self.hand_counts.total();
+        let play_type = if min_wings > 0 {
+            PlayType::AirplaneWithWings
+        } else if width == 2 {
+            PlayType::ConsecutivePairs
+        } else {
+            PlayType::Airplane
+        };
+
+        runs::maximal_runs(self.hand_counts.mask_with_at_least(width))
+            .iter()
+            .flat_map(|run| {
+                let ranks = run.ranks();
+                (0..ranks.len()).flat_map(move |start| {
+                    (start + 2..=ranks.len()).map(move |end| &ranks[start..end])
+                })
+            })
+            .any(|window| {
+                let core: usize = window
+                    .iter()
+                    .map(|r| usize::from(self.counts.get(*r).min(width)))
+                    .sum();
+                let extra = n - core;
+                let chain_cards = window.len() * usize::from(width);
+                if min_wings == 0 {
+                    return extra == 0 && chain_cards > n;
+                }
+                let capacity = kicker_rules::standard_capacity(play_type, window.len());
+                let wings = extra
+                    .max(min_wings)
+                    .max((n + 1).saturating_sub(chain_cards));
+                capacity.contains(&wings) && chain_cards + wings <= hand_total
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_recognizer_after_each_tap() {
+        let hand = vec![
+            Card::new(Suit::Spades, Rank::Five),
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Spades, Rank::Six),
+            Card::new(Suit::Hearts, Rank::Six),
+            Card::new(Suit::Clubs, Rank::Six),
+        ];
+        let mut selection = SelectionAnalyzer::new(&hand);
+
+        for card in &hand {
+            selection.add(*card);
+            assert_eq!(
+                selection.current(),
+                PatternRecognizer::analyze_cards(selection.selected()).as_ref()
+            );
+        }
+        assert!(selection.remove(hand[4]));
+        assert_eq!(
+            selection.current().unwrap().play_type,
+            PlayType::ConsecutivePairs
+        );
+        assert!(!selection.remove(hand[4]));
+    }
+
+    #[test]
+    fn test_cannot_select_more_copies_than_held() {
+        let hand = vec![Card::new(Suit::Spades, Rank::Ace)];
+        let mut selection = SelectionAnalyzer::new(&hand);
+        assert!(selection.add(hand[0]));
+        assert!(!selection.add(hand[0]));
+        assert!(!selection.add(Card::new(Suit::Hearts, Rank::Ace)));
+    }
+
+    #[test]
+    fn test_could_become() {
+        let mut hand = vec![Card::new(Suit::Spades, Rank::Seven); 3];
+        hand.extend([Card::new(Suit::Hearts, Rank::Eight); 3]);
+        hand.push(Card::new(Suit::Clubs, Rank::Two));
+        let mut selection = SelectionAnalyzer::new(&hand);
+
+        assert!(selection.could_become().contains(&PlayType::Single));
+
+        selection.add(hand[0]);
+        selection.add(hand[1]);
+        assert_eq!(
+            selection.could_become(),
+            vec![
+                PlayType::ConsecutivePairs,
+                PlayType::Triple,
+                PlayType::Airplane,
+                PlayType::AirplaneWithWings,
+                PlayType::Tongzi,
+            ]
+        );
+
+        selection.add(hand[6]);
+        assert_eq!(
+            selection.could_become(),
+            vec![PlayType::Triple, PlayType::AirplaneWithWings]
+        );
+
+        selection.clear();
+        assert!(selection.current().is_none());
+        assert!(selection.selected().is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+//! Canonical card ordering for plays.
+
+use std::cmp::Reverse;
+
+use crate::models::{Card, Rank, RankCounts};
+use crate::patterns::{PlayPattern, PlayType};
+
+/// Sort the cards of a play into canonical order.
+///
+/// Order:
+/// 1. Main-group cards first, then kickers (带牌/翅膀)
+/// 2. Within each part, descending rank, then descending suit (♠ > ♥ > ♣ > ♦)
+///
+/// For Triple and airplanes, the main group is three cards of each triple
+/// rank; any further copies of that rank count as kickers. For every other
+/// play type all cards belong to the main group. The result depends only on
+/// the multiset of cards, so every client renders (and hashes) a play the
+/// same way regardless of selection order.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+/// use datongzi_rules::plays::sort_canonical;
+///
+/// let mut cards = vec![
+///     Card::new(Suit::Hearts, Rank::Ace),
+///     Card::new(Suit::Spades, Rank::Five),
+///     Card::new(Suit::Hearts, Rank::Five),
+///     Card::new(Suit::Clubs, Rank::Five),
+/// ];
+/// let pattern = PatternRecognizer::analyze_cards(&cards).unwrap();
+/// sort_canonical(&mut cards, &pattern);
+/// assert_eq!(cards[3], Card::new(Suit::Hearts, Rank::Ace)); // kicker last
+/// ```
+pub fn sort_canonical(cards: &mut [Card], pattern: &PlayPattern) {
+    cards.sort_by_key(|card| Reverse(*card));
+
+    let main_ranks: &[Rank] = match pattern.play_type {
+        PlayType::Triple => std::slice::from_ref(&pattern.primary_rank),
+        PlayType::Airplane | PlayType::AirplaneWithWings => &pattern.secondary_ranks,
+        _ => return,
+    };
+    let mut main_slots = RankCounts::new();
+    for rank in main_ranks {
+        main_slots.0[rank.index()] = 3;
+    }
+
+    let is_kicker: Vec<bool> = cards
+        .iter()
+        .map(|card| {
+            let main = main_slots.get(card.rank) > 0;
+            main_slots.remove(card.rank);
+            !main
+        })
+        .collect();
+
+    let mut tagged: Vec<(bool, Card)> = is_kicker.into_iter().zip(cards.iter().copied()).collect();
+    // Stable sort keeps the descending order inside each part
+    tagged.sort_by_key(|(kicker, _)| *kicker);
+    for (slot, (_, card)) in cards.iter_mut().zip(tagged) {
+        *slot = card;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Suit;
+    use crate::patterns::PatternRecognizer;
+
+    #[test]
+    fn test_sort_canonical_is_selection_order_independent() {
+        let cards = vec![
+            Card::new(Suit::Diamonds, Rank::Seven),
+            Card::new(Suit::Spades, Rank::Eight),
+            Card::new(Suit::Hearts, Rank::Seven),
+            Card::new(Suit::Hearts, Rank::Eight),
+        ];
+        let pattern = PatternRecognizer::analyze_cards(&cards).unwrap();
+
+        let mut a = cards.clone();
+        let mut b: Vec<Card> = cards.into_iter().rev().collect();
+        sort_canonical(&mut a, &pattern);
+        sort_canonical(&mut b, &pattern);
+
+        assert_eq!(a, b);
+        assert_eq!(a[0], Card::new(Suit::Spades, Rank::Eight));
+        assert_eq!(a[3], Card::new(Suit::Diamonds, Rank::Seven));
+    }
+
+    #[test]
+    fn test_sort_canonical_airplane_extra_copy_is_kicker() {
+        // JJJ QQQQ + 3: the fourth Q is a wing
+        let mut cards = vec![
+            Card::new(Suit::Diamonds, Rank::Three),
+            Card::new(Suit::Spades, Rank::Jack),
+            Card::new(Suit::Hearts, Rank::Jack),
+            Card::new(Suit::Clubs, Rank::Jack),
+            Card::new(Suit::Spades, Rank::Queen),
+            Card::new(Suit::Hearts, Rank::Queen),
+            Card::new(Suit::Clubs, Rank::Queen),
+            Card::new(Suit::Diamonds, Rank::Queen),
+        ];
+        let pattern = PatternRecognizer::analyze_cards(&cards).unwrap();
+        assert_eq!(pattern.play_type, PlayType::AirplaneWithWings);
+
+        sort_canonical(&mut cards, &pattern);
+
+        let ranks: Vec<Rank> = cards.iter().map(|c| c.rank).collect();
+        assert_eq!(
+            ranks,
+            vec![
+                Rank::Queen,
+                Rank::Queen,
+                Rank::Queen,
+                Rank::Jack,
+                Rank::Jack,
+                Rank::Jack,
+                Rank::Queen,
+                Rank::Three,
+            ]
+        );
+        assert_eq!(cards[6], Card::new(Suit::Diamonds, Rank::Queen));
+    }
+}
@@ -0,0 +1,7 @@
+//! Helpers for working with the cards of a single play.
+//!
+//! - [`sort_canonical`]: Deterministic display order for a play
+
+mod canonical;
+
+pub use canonical::sort_canonical;
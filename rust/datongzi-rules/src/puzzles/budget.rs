@@ -0,0 +1,119 @@
+//! Cooperative node and time budgets for search.
+
+use std::time::{Duration, Instant};
+
+/// Limits how long a search may run.
+///
+/// Searches call [`tick`](Self::tick) once per node and stop as soon as it
+/// returns false, so they return within the budget plus one node. A node
+/// budget is deterministic and works on every target. A time budget reads
+/// [`Instant`], which `wasm32-unknown-unknown` does not provide, so use node
+/// budgets there. The clock starts at the first tick.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::puzzles::TimeBudget;
+///
+/// let mut budget = TimeBudget::nodes(2);
+/// assert!(budget.tick());
+/// assert!(budget.tick());
+/// assert!(!budget.tick());
+/// assert!(budget.is_exhausted());
+/// assert!(TimeBudget::unlimited().tick());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TimeBudget {
+    max_nodes: Option<u64>,
+    time_limit: Option<Duration>,
+    started: Option<Instant>,
+    nodes: u64,
+    exhausted: bool,
+}
+
+impl TimeBudget {
+    /// A budget that never runs out
+    #[must_use]
+    pub const fn unlimited() -> Self {
+        Self {
+            max_nodes: None,
+            time_limit: None,
+            started: None,
+            nodes: 0,
+            exhausted: false,
+        }
+    }
+
+    /// A budget of `max_nodes` search nodes
+    #[must_use]
+    pub const fn nodes(max_nodes: u64) -> Self {
+        Self::unlimited().with_nodes(max_nodes)
+    }
+
+    /// A budget of `limit` wall-clock time
+    #[must_use]
+    pub const fn time(limit: Duration) -> Self {
+        Self::unlimited().with_time(limit)
+    }
+
+    /// Also cap the search at `max_nodes` nodes
+    #[must_use]
+    pub const fn with_nodes(mut self, max_nodes: u64) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+
+    /// Also cap the search at `limit` wall-clock time
+    #[must_use]
+    pub const fn with_time(mut self, limit: Duration) -> Self {
+        self.time_limit = Some(limit);
+        self
+    }
+
+    /// Account for one node; returns false once the budget is spent
+    pub fn tick(&mut self) -> bool {
+        if self.exhausted {
+            return false;
+        }
+        if self.max_nodes.is_some_and(|max| self.nodes >= max) {
+            self.exhausted = true;
+            return false;
+        }
+        if let Some(limit) = self.time_limit {
+            let started = *self.started.get_or_insert_with(Instant::now);
+            if started.elapsed() >= limit {
+                self.exhausted = true;
+                return false;
+            }
+        }
+        self.nodes += 1;
+        true
+    }
+
+    /// Returns the number of nodes accounted so far
+    #[must_use]
+    pub const fn nodes_used(&self) -> u64 {
+        self.nodes
+    }
+
+    /// Returns true once a [`tick`](Self::tick) has been refused
+    #[must_use]
+    pub const fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_budget_runs_out() {
+        let mut budget = TimeBudget::time(Duration::ZERO).with_nodes(10);
+        assert!(!budget.tick());
+        assert_eq!(budget.nodes_used(), 0);
+
+        let mut budget = TimeBudget::time(Duration::from_secs(3600));
+        assert!((0..1000).all(|_| budget.tick()));
+        assert_eq!(budget.nodes_used(), 1000);
+    }
+}
@@ -0,0 +1,270 @@
+//! Seeded daily deal generation.
+
+use crate::ai_helpers::HandPatternAnalyzer;
+use crate::error::{DatongziError, Result};
+use crate::fairness::ShuffleAudit;
+use crate::i18n::Message;
+use crate::models::{Card, Deck, GameConfig};
+
+/// Multiplier used to derive per-attempt seeds from the daily seed.
+const ATTEMPT_SEED_STEP: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Constraints a daily deal must satisfy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DealCriteria {
+    /// Minimum number of Dizha across all hands
+    pub min_dizha: usize,
+    /// Minimum number of Tongzi across all hands
+    pub min_tongzi: usize,
+    /// Maximum difference in score-card points (5/10/K) between any two hands
+    pub max_score_imbalance: Option<i32>,
+    /// Number of seeded deals to try before giving up
+    pub max_attempts: usize,
+}
+
+impl Default for DealCriteria {
+    fn default() -> Self {
+        Self {
+            min_dizha: 1,
+            min_tongzi: 0,
+            max_score_imbalance: Some(60),
+            max_attempts: 1000,
+        }
+    }
+}
+
+/// Measured statistics of a deal, used to prove the criteria were met.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DealProof {
+    /// Number of Dizha across all hands
+    pub dizha_count: usize,
+    /// Number of Tongzi across all hands
+    pub tongzi_count: usize,
+    /// Score-card points held by each hand (seat order)
+    pub score_points: Vec<i32>,
+}
+
+impl DealProof {
+    /// Measures a set of dealt hands
+    #[must_use]
+    pub fn measure(hands: &[Vec<Card>]) -> Self {
+        let mut dizha_count = 0;
+        let mut tongzi_count = 0;
+        let mut score_points = Vec::with_capacity(hands.len());
+
+        for hand in hands {
+            let patterns = HandPatternAnalyzer::analyze_patterns(hand);
+            dizha_count += patterns.dizha.len();
+            tongzi_count += patterns.tongzi.len();
+            score_points.push(hand.iter().map(Card::score_value).sum());
+        }
+
+        Self {
+            dizha_count,
+            tongzi_count,
+            score_points,
+        }
+    }
+
+    /// Returns the largest score-point difference between two hands
+    #[must_use]
+    pub fn score_imbalance(&self) -> i32 {
+        let max = self.score_points.iter().copied().max().unwrap_or(0);
+        let min = self.score_points.iter().copied().min().unwrap_or(0);
+        max - min
+    }
+
+    /// Returns true if these statistics satisfy `criteria`
+    #[must_use]
+    pub fn satisfies(&self, criteria: &DealCriteria) -> bool {
+        self.dizha_count >= criteria.min_dizha
+            && self.tongzi_count >= criteria.min_tongzi
+            && criteria
+                .max_score_imbalance
+                .map_or(true, |max| self.score_imbalance() <= max)
+    }
+}
+
+/// A deal selected by [`generate_daily_deal`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DailyDeal {
+    /// The daily seed the search started from
+    pub seed: u64,
+    /// Seed that reproduces this exact deal via [`deal_from_seed`]
+    pub deal_seed: u64,
+    /// Number of deals tried before this one was accepted (0-based)
+    pub attempt: usize,
+    /// Hands in seat order
+    pub hands: Vec<Vec<Card>>,
+    /// Cards dealt aside
+    pub aside: Vec<Card>,
+    /// Measured statistics
+    pub proof: DealProof,
+}
+
+impl DailyDeal {
+    /// Re-deals from `deal_seed` and re-measures, confirming that the deal is
+    /// authentic and satisfies `criteria`.
+    #[must_use]
+    pub fn verify(&self, config: &GameConfig, criteria: &DealCriteria) -> bool {
+        let Ok((hands, aside)) = deal_from_seed(config, self.deal_seed) else {
+            return false;
+        };
+        let proof = DealProof::measure(&hands);
+
+        hands == self.hands
+            && aside == self.aside
+            && proof == self.proof
+            && proof.satisfies(criteria)
+    }
+}
+
+/// Deal hands and aside cards from a seeded shuffle.
+///
+/// The deck honors `config.removed_ranks`. Uses `StdRng`, so deals are
+/// reproducible for a given version of the `rand` crate.
+///
+/// # Errors
+///
+/// Returns an error if the deck is too small for the configured deal
+pub fn deal_from_seed(config: &GameConfig, seed: u64) -> Result<(Vec<Vec<Card>>, Vec<Card>)> {
+    deal_from_seed_audited(config, seed).map(|(hands, aside, _)| (hands, aside))
+}
+
+/// Like [`deal_from_seed`], also returning the seed and permutation digest
+/// for the game record (see [`GameRecord::shuffle`](crate::scoring::GameRecord::shuffle)).
+///
+/// # Errors
+///
+/// Returns an error if the deck is too small for the configured deal
+pub fn deal_from_seed_audited(
+    config: &GameConfig,
+    seed: u64,
+) -> Result<(Vec<Vec<Card>>, Vec<Card>, ShuffleAudit)> {
+    let mut deck = Deck::from_config(config);
+    let required =
+        config.cards_per_player() * usize::from(config.num_players()) + config.cards_dealt_aside();
+    if deck.len() < required {
+        return Err(DatongziError::ConfigError(Message::NotEnoughCards {
+            need: required,
+            have: deck.len(),
+        }));
+    }
+
+    let audit = ShuffleAudit::shuffle(&mut deck, seed);
+
+    let hands = (0..config.num_players())
+        .map(|_| deck.deal_cards(config.cards_per_player()))
+        .collect();
+    let aside = deck.deal_cards(config.cards_dealt_aside());
+
+    Ok((hands, aside, audit))
+}
+
+/// Search seeded deals until one satisfies `criteria`.
+///
+/// Attempt `i` uses `seed + i * 0x9E3779B97F4A7C15` (wrapping), so the same
+/// daily seed always yields the same deal.
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be dealt, or if no deal
+/// satisfies the criteria within `criteria.max_attempts`
+///
+/// # Example
+/// ```
+/// use datongzi_rules::ConfigFactory;
+/// use datongzi_rules::puzzles::{generate_daily_deal, DealCriteria};
+///
+/// let config = ConfigFactory::create_standard_3deck_3player();
+/// let criteria = DealCriteria {
+///     min_dizha: 0,
+///     min_tongzi: 1,
+///     max_score_imbalance: None,
+///     max_attempts: 100,
+/// };
+///
+/// let deal = generate_daily_deal(&config, 20261016, &criteria).unwrap();
+/// assert!(deal.verify(&config, &criteria));
+/// ```
+pub fn generate_daily_deal(
+    config: &GameConfig,
+    seed: u64,
+    criteria: &DealCriteria,
+) -> Result<DailyDeal> {
+    for attempt in 0..criteria.max_attempts {
+        let deal_seed = seed.wrapping_add((attempt as u64).wrapping_mul(ATTEMPT_SEED_STEP));
+        let (hands, aside) = deal_from_seed(config, deal_seed)?;
+        let proof = DealProof::measure(&hands);
+
+        if proof.satisfies(criteria) {
+            return Ok(DailyDeal {
+                seed,
+                deal_seed,
+                attempt,
+                hands,
+                aside,
+                proof,
+            });
+        }
+    }
+
+    Err(DatongziError::InvalidInput(Message::NoDealFound {
+        attempts: criteria.max_attempts,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deal_from_seed_is_reproducible() {
+        let config = GameConfig::default();
+        let first = deal_from_seed(&config, 7).unwrap();
+        let second = deal_from_seed(&config, 7).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first.0.len(), 3);
+        assert!(first.0.iter().all(|h| h.len() == 41));
+        assert_eq!(first.1.len(), 9);
+    }
+
+    #[test]
+    fn test_generate_daily_deal_meets_criteria() {
+        let config = GameConfig::default();
+        let criteria = DealCriteria::default();
+
+        let deal = generate_daily_deal(&config, 1, &criteria).unwrap();
+        assert!(deal.proof.dizha_count >= 1);
+        assert!(deal.proof.score_imbalance() <= 60);
+        assert!(deal.verify(&config, &criteria));
+
+        // Same seed, same deal
+        assert_eq!(generate_daily_deal(&config, 1, &criteria).unwrap(), deal);
+    }
+
+    #[test]
+    fn test_generate_daily_deal_impossible_criteria() {
+        let config = GameConfig::default();
+        let criteria = DealCriteria {
+            min_dizha: 100,
+            max_attempts: 3,
+            ..DealCriteria::default()
+        };
+        assert!(generate_daily_deal(&config, 1, &criteria).is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_deal() {
+        let config = GameConfig::default();
+        let criteria = DealCriteria {
+            min_dizha: 0,
+            max_score_imbalance: None,
+            ..DealCriteria::default()
+        };
+        let mut deal = generate_daily_deal(&config, 5, &criteria).unwrap();
+        deal.hands[0].swap_remove(0);
+        assert!(!deal.verify(&config, &criteria));
+    }
+}
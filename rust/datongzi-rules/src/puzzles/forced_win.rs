@@ -0,0 +1,380 @@
+//! Forced-win verification for endgame puzzles.
+
+use std::collections::HashMap;
+
+use super::TimeBudget;
+use crate::ai_helpers::PlayGenerator;
+use crate::invariants;
+use crate::models::{Card, Rank, Suit};
+use crate::patterns::{PatternRecognizer, PlayPattern, PlayType};
+
+/// Cap on generated plays per position; puzzle hands are small.
+const MAX_PLAYS_PER_POSITION: usize = 1000;
+
+/// Proof that a puzzle position is a forced win.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForcedWin {
+    /// Fewest plays the solver needs to empty its hand against any defence
+    pub moves: usize,
+    /// A winning first action (`None` = pass)
+    pub first_play: Option<Vec<Card>>,
+}
+
+/// Result of [`verify_forced_win_within`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveOutcome {
+    /// The position is a forced win
+    Proved(ForcedWin),
+    /// No forced win exists within the move limit
+    Disproved {
+        /// Legal action to play anyway (`None` = pass)
+        fallback: Option<Vec<Card>>,
+    },
+    /// The budget ran out before the search finished
+    Exhausted {
+        /// Legal action to play anyway (`None` = pass)
+        fallback: Option<Vec<Card>>,
+    },
+}
+
+impl SolveOutcome {
+    /// The action to take: the winning play if proved, else the fallback
+    /// (`None` = pass)
+    #[must_use]
+    pub fn play(&self) -> Option<&[Card]> {
+        match self {
+            Self::Proved(win) => win.first_play.as_deref(),
+            Self::Disproved { fallback } | Self::Exhausted { fallback } => fallback.as_deref(),
+        }
+    }
+}
+
+/// Marker for a search stopped by its [`TimeBudget`].
+struct OutOfBudget;
+
+/// Hashable form of a [`PlayPattern`] for the transposition table.
+type PatternKey = (PlayType, Rank, Option<Suit>, Vec<Rank>, usize);
+
+fn pattern_key(pattern: &PlayPattern) -> PatternKey {
+    (
+        pattern.play_type,
+        pattern.primary_rank,
+        pattern.primary_suit,
+        pattern.secondary_ranks.clone(),
+        pattern.card_count,
+    )
+}
+
+/// Search position. Player 0 is the solver; play passes in index order.
+#[derive(Clone)]
+struct Position {
+    hands: Vec<Vec<Card>>,
+    turn: usize,
+    leader: usize,
+    pattern: Option<PlayPattern>,
+    passes: usize,
+}
+
+type PositionKey = (
+    Vec<Vec<Card>>,
+    usize,
+    usize,
+    Option<PatternKey>,
+    usize,
+    usize,
+);
+
+impl Position {
+    fn key(&self, budget: usize) -> PositionKey {
+        (
+            self.hands.clone(),
+            self.turn,
+            self.leader,
+            self.pattern.as_ref().map(pattern_key),
+            self.passes,
+            budget,
+        )
+    }
+
+    /// Legal actions for the player to move (`None` = pass).
+    fn actions(&self) -> Vec<Option<Vec<Card>>> {
+        let hand = &self.hands[self.turn];
+        match &self.pattern {
+            None => PlayGenerator::generate_all_plays_adaptive(hand, MAX_PLAYS_PER_POSITION)
+                .into_iter()
+                .map(Some)
+                .collect(),
+            Some(pattern) => {
+                let mut actions: Vec<Option<Vec<Card>>> =
+                    PlayGenerator::generate_beating_plays_with_same_type_or_trump(hand, pattern)
+                        .into_iter()
+                        .map(Some)
+                        .collect();
+                actions.push(None);
+                actions
+            }
+        }
+    }
+
+    /// Position after the player to move takes `action`.
+    fn apply(&self, action: Option<&[Card]>) -> Self {
+        let players = self.hands.len();
+        let mut next = self.clone();
+        match action {
+            Some(cards) => {
+                let hand = &mut next.hands[self.turn];
+                for card in cards {
+                    if let Some(position) = hand.iter().position(|c| c == card) {
+                        hand.remove(position);
+                    }
+                }
+                if invariants::ENABLED {
+                    invariants::assert_conservation(&self.hands[self.turn], hand, cards);
+                }
+                next.pattern = PatternRecognizer::analyze_cards(cards);
+                next.leader = self.turn;
+                next.passes = 0;
+                next.turn = (self.turn + 1) % players;
+            }
+            None => {
+                next.passes += 1;
+                if next.passes + 1 >= players {
+                    // Everyone else passed: the leader starts a new trick
+                    next.pattern = None;
+                    next.passes = 0;
+                    next.turn = self.leader;
+                } else {
+                    next.turn = (self.turn + 1) % players;
+                }
+            }
+        }
+        next
+    }
+}
+
+/// Certify that `hand` goes out first within `max_moves` plays.
+///
+/// Player 0 holds `hand`; `opponents_hands` follow in turn order, all fully
+/// known (puzzle positions are open-handed). `current_pattern` is the play
+/// to beat, made by the last opponent; `None` means `hand` leads. A win must
+/// hold against every opponent reply, and passing is always allowed.
+///
+/// Returns the shortest forced win, or `None` if some defence stops it.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::puzzles::verify_forced_win;
+/// use datongzi_rules::{Card, Rank, Suit};
+///
+/// let ace = Card::new(Suit::Spades, Rank::Ace);
+/// let two = Card::new(Suit::Hearts, Rank::Two);
+/// let opponent = vec![Card::new(Suit::Clubs, Rank::Two), Card::new(Suit::Clubs, Rank::King)];
+///
+/// // Lead the 2 (a 2 cannot beat it), then the ace. Leading the ace first
+/// // loses: the opponent takes it with the ♣2 and goes out with the K.
+/// let win = verify_forced_win(&[two, ace], &[opponent.clone()], None, 2).unwrap();
+/// assert_eq!(win.moves, 2);
+/// assert_eq!(win.first_play, Some(vec![two]));
+///
+/// assert!(verify_forced_win(&[two, ace], &[opponent], None, 1).is_none());
+/// ```
+#[must_use]
+pub fn verify_forced_win(
+    hand: &[Card],
+    opponents_hands: &[Vec<Card>],
+    current_pattern: Option<&PlayPattern>,
+    max_moves: usize,
+) -> Option<ForcedWin> {
+    let mut budget = TimeBudget::unlimited();
+    match verify_forced_win_within(
+        hand,
+        opponents_hands,
+        current_pattern,
+        max_moves,
+        &mut budget,
+    ) {
+        SolveOutcome::Proved(win) => Some(win),
+        SolveOutcome::Disproved { .. } | SolveOutcome::Exhausted { .. } => None,
+    }
+}
+
+/// [`verify_forced_win`] that stops when `budget` runs out.
+///
+/// Every outcome carries an action to play, so a bot can call this with a
+/// small budget on slow devices and still move in time. The fallback is the
+/// first legal action (a play when leading).
+///
+/// # Example
+/// ```
+/// use datongzi_rules::puzzles::{verify_forced_win_within, SolveOutcome, TimeBudget};
+/// use datongzi_rules::{Card, Rank, Suit};
+///
+/// let ace = Card::new(Suit::Spades, Rank::Ace);
+/// let two = Card::new(Suit::Hearts, Rank::Two);
+/// let opponent = vec![Card::new(Suit::Clubs, Rank::Two), Card::new(Suit::Clubs, Rank::King)];
+///
+/// let mut budget = TimeBudget::nodes(1);
+/// let outcome = verify_forced_win_within(&[two, ace], &[opponent], None, 2, &mut budget);
+/// assert!(matches!(outcome, SolveOutcome::Exhausted { .. }));
+/// assert!(outcome.play().is_some());
+/// ```
+#[must_use]
+pub fn verify_forced_win_within(
+    hand: &[Card],
+    opponents_hands: &[Vec<Card>],
+    current_pattern: Option<&PlayPattern>,
+    max_moves: usize,
+    budget: &mut TimeBudget,
+) -> SolveOutcome {
+    let sorted = |cards: &[Card]| {
+        let mut cards = cards.to_vec();
+        cards.sort_by_key(|c| (c.rank.value(), c.suit.value()));
+        cards
+    };
+    let hands: Vec<Vec<Card>> = std::iter::once(sorted(hand))
+        .chain(opponents_hands.iter().map(|h| sorted(h)))
+        .collect();
+    let start = Position {
+        leader: hands.len() - 1,
+        hands,
+        turn: 0,
+        pattern: current_pattern.cloned(),
+        passes: 0,
+    };
+
+    let actions = start.actions();
+    let fallback = actions.first().cloned().flatten();
+    let mut memo = HashMap::new();
+    for moves in 0..=max_moves {
+        for action in &actions {
+            match action_wins(&start, action.as_deref(), moves, &mut memo, budget) {
+                Ok(Some(true)) => {
+                    return SolveOutcome::Proved(ForcedWin {
+                        moves,
+                        first_play: action.clone(),
+                    })
+                }
+                Ok(_) => {}
+                Err(OutOfBudget) => return SolveOutcome::Exhausted { fallback },
+            }
+        }
+    }
+    SolveOutcome::Disproved { fallback }
+}
+
+/// Returns true if player 0 forces going out first with at most `budget` plays.
+fn solver_wins(
+    position: &Position,
+    budget: usize,
+    memo: &mut HashMap<PositionKey, bool>,
+    search: &mut TimeBudget,
+) -> Result<bool, OutOfBudget> {
+    let key = position.key(budget);
+    if let Some(&known) = memo.get(&key) {
+        return Ok(known);
+    }
+    if !search.tick() {
+        return Err(OutOfBudget);
+    }
+
+    // The solver needs one winning action; an opponent needs one refutation
+    let solver_to_move = position.turn == 0;
+    let mut outcome = !solver_to_move;
+    for action in position.actions() {
+        if action_wins(position, action.as_deref(), budget, memo, search)? == Some(solver_to_move) {
+            outcome = solver_to_move;
+            break;
+        }
+    }
+
+    memo.insert(key, outcome);
+    Ok(outcome)
+}
+
+/// Outcome for player 0 after the player to move takes `action`, or `None`
+/// if the solver has no plays left in `budget`.
+fn action_wins(
+    position: &Position,
+    action: Option<&[Card]>,
+    budget: usize,
+    memo: &mut HashMap<PositionKey, bool>,
+    search: &mut TimeBudget,
+) -> Result<Option<bool>, OutOfBudget> {
+    let solver_to_move = position.turn == 0;
+    let budget = match action {
+        Some(_) if solver_to_move => match budget.checked_sub(1) {
+            Some(budget) => budget,
+            None => return Ok(None),
+        },
+        _ => budget,
+    };
+    match action {
+        // Going out ends the game
+        Some(cards) if cards.len() == position.hands[position.turn].len() => {
+            Ok(Some(solver_to_move))
+        }
+        _ => solver_wins(&position.apply(action), budget, memo, search).map(Some),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opponent_trump_breaks_the_win() {
+        let king = Card::new(Suit::Spades, Rank::King);
+        let five = Card::new(Suit::Hearts, Rank::Five);
+        let bomb = vec![Card::new(Suit::Clubs, Rank::Nine); 4];
+
+        // Opponent can bomb whatever is led, then go out
+        assert!(verify_forced_win(&[king, five], &[bomb], None, 5).is_none());
+    }
+
+    #[test]
+    fn test_pass_can_be_the_winning_move() {
+        let ace = Card::new(Suit::Spades, Rank::Ace);
+        let seven = Card::new(Suit::Hearts, Rank::Seven);
+        let hand = vec![ace, ace, seven];
+
+        // The opponent led a single 2 that cannot be beaten
+        let two = PatternRecognizer::analyze_cards(&[Card::new(Suit::Clubs, Rank::Two)]).unwrap();
+        let opponent = vec![
+            Card::new(Suit::Clubs, Rank::Five),
+            Card::new(Suit::Clubs, Rank::Six),
+        ];
+
+        // Pass, beat the opponent's lead with the 7, then lead the aces
+        let win = verify_forced_win(&hand, &[opponent], Some(&two), 3).unwrap();
+        assert_eq!(win.moves, 2);
+        assert_eq!(win.first_play, None);
+    }
+
+    #[test]
+    fn test_budget_does_not_change_the_answer() {
+        let ace = Card::new(Suit::Spades, Rank::Ace);
+        let two = Card::new(Suit::Hearts, Rank::Two);
+        let opponent = vec![
+            Card::new(Suit::Clubs, Rank::Two),
+            Card::new(Suit::Clubs, Rank::King),
+        ];
+
+        let mut budget = TimeBudget::nodes(10_000);
+        let outcome = verify_forced_win_within(
+            &[two, ace],
+            std::slice::from_ref(&opponent),
+            None,
+            2,
+            &mut budget,
+        );
+        assert_eq!(outcome.play(), Some(&[two][..]));
+        assert!(!budget.is_exhausted());
+
+        let mut budget = TimeBudget::nodes(10_000);
+        let outcome = verify_forced_win_within(&[two, ace], &[opponent], None, 1, &mut budget);
+        assert!(matches!(
+            outcome,
+            SolveOutcome::Disproved { fallback: Some(_) }
+        ));
+    }
+}
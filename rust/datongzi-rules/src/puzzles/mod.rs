@@ -0,0 +1,20 @@
+//! Puzzle and daily-challenge support.
+//!
+//! This module builds puzzle features on top of the rules engine:
+//! - [`PlayTemplate`]: Target play type plus constraints
+//! - [`find_plays_matching`]: Find plays in a hand that satisfy a template
+//! - [`generate_daily_deal`]: Seeded deal search with verifiable criteria
+//! - [`verify_forced_win`]: Certify that a puzzle position is a forced win
+//! - [`TimeBudget`]: Node and time limits for budgeted search
+
+mod budget;
+mod daily_deal;
+mod forced_win;
+mod play_template;
+
+pub use budget::TimeBudget;
+pub use daily_deal::{
+    deal_from_seed, deal_from_seed_audited, generate_daily_deal, DailyDeal, DealCriteria, DealProof,
+};
+pub use forced_win::{verify_forced_win, verify_forced_win_within, ForcedWin, SolveOutcome};
+pub use play_template::{find_plays_matching, PlayTemplate};
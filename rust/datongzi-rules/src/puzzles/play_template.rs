@@ -0,0 +1,282 @@
+//! Pattern templates for puzzle mode.
+
+use crate::ai_helpers::{GenerationOptions, PlayGenerator};
+use crate::models::{Card, Rank};
+use crate::patterns::{PatternRecognizer, PlayPattern, PlayType};
+
+/// A target play shape with constraints, e.g. "an airplane of length ≥3 containing ♥Q".
+///
+/// ## Length
+/// `min_length`/`max_length` mean:
+/// - Chain length for ConsecutivePairs / Airplane / AirplaneWithWings (number of groups)
+/// - Card count for Bomb
+/// - Always 1 for other play types
+///
+/// # Example
+/// ```
+/// use datongzi_rules::{Card, PlayType, Rank, Suit};
+/// use datongzi_rules::puzzles::PlayTemplate;
+///
+/// let template = PlayTemplate::new(PlayType::Airplane)
+///     .with_min_length(3)
+///     .containing(Card::new(Suit::Hearts, Rank::Queen));
+/// assert_eq!(template.min_length, Some(3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayTemplate {
+    /// Required play type
+    pub play_type: PlayType,
+    /// Minimum length (see type docs)
+    pub min_length: Option<usize>,
+    /// Maximum length (see type docs)
+    pub max_length: Option<usize>,
+    /// Minimum primary rank
+    pub min_rank: Option<Rank>,
+    /// Cards the play must contain (multiset)
+    pub required_cards: Vec<Card>,
+}
+
+impl PlayTemplate {
+    /// Creates a template matching any play of the given type
+    #[must_use]
+    pub fn new(play_type: PlayType) -> Self {
+        Self {
+            play_type,
+            min_length: None,
+            max_length: None,
+            min_rank: None,
+            required_cards: Vec::new(),
+        }
+    }
+
+    /// Requires a minimum length
+    #[must_use]
+    pub fn with_min_length(mut self, min_length: usize) -> Self {
+        self.min_length = Some(min_length);
+        self
+    }
+
+    /// Requires a maximum length
+    #[must_use]
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Requires the primary rank to be at least `rank`
+    #[must_use]
+    pub fn with_min_rank(mut self, rank: Rank) -> Self {
+        self.min_rank = Some(rank);
+        self
+    }
+
+    /// Requires the play to contain `card` (may be called repeatedly)
+    #[must_use]
+    pub fn containing(mut self, card: Card) -> Self {
+        self.required_cards.push(card);
+        self
+    }
+
+    /// Returns the template length of a pattern (see type docs)
+    #[must_use]
+    pub fn pattern_length(pattern: &PlayPattern) -> usize {
+        match pattern.play_type {
+            PlayType::ConsecutivePairs | PlayType::Airplane | PlayType::AirplaneWithWings => {
+                pattern.secondary_ranks.len()
+            }
+            PlayType::Bomb => pattern.card_count,
+            _ => 1,
+        }
+    }
+
+    /// Checks whether a set of cards satisfies this template.
+    #[must_use]
+    pub fn matches(&self, cards: &[Card]) -> bool {
+        let Some(pattern) = PatternRecognizer::analyze_cards(cards) else {
+            return false;
+        };
+
+        if pattern.play_type != self.play_type {
+            return false;
+        }
+
+        let length = Self::pattern_length(&pattern);
+        if self.min_length.map_or(false, |min| length < min)
+            || self.max_length.map_or(false, |max| length > max)
+        {
+            return false;
+        }
+
+        if self
+            .min_rank
+            .map_or(false, |min| pattern.primary_rank.value() < min.value())
+        {
+            return false;
+        }
+
+        let mut remaining = cards.to_vec();
+        self.required_cards.iter().all(|required| {
+            if let Some(pos) = remaining.iter().position(|c| c == required) {
+                remaining.remove(pos);
+                true
+            } else {
+                false
+            }
+        })
+    }
+}
+
+/// Find all plays in `hand` that satisfy `template`.
+///
+/// Candidates come from [`PlayGenerator`]; when a candidate misses a required
+/// card, a same-rank card of the candidate is swapped for it if the hand holds
+/// a spare copy. Results are sorted, deduplicated card lists.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::{Card, PlayType, Rank, Suit};
+/// use datongzi_rules::puzzles::{find_plays_matching, PlayTemplate};
+///
+/// let hand = vec![
+///     Card::new(Suit::Spades, Rank::Queen),
+///     Card::new(Suit::Hearts, Rank::Queen),
+///     Card::new(Suit::Clubs, Rank::Queen),
+/// ];
+/// let template = PlayTemplate::new(PlayType::Pair).containing(Card::new(Suit::Hearts, Rank::Queen));
+///
+/// let plays = find_plays_matching(&hand, &template);
+/// assert_eq!(plays.len(), 2); // ♥Q with ♠Q or ♣Q
+/// ```
+#[must_use]
+pub fn find_plays_matching(hand: &[Card], template: &PlayTemplate) -> Vec<Vec<Card>> {
+    let mut results: Vec<Vec<Card>> = Vec::new();
+
+    for candidate in PlayGenerator::generate_plays_of_type(
+        hand,
+        template.play_type,
+        &GenerationOptions::default(),
+    ) {
+        let Some(mut play) = realize_required_cards(hand, candidate, &template.required_cards)
+        else {
+            continue;
+        };
+
+        if template.matches(&play) {
+            play.sort();
+            if !results.contains(&play) {
+                results.push(play);
+            }
+        }
+    }
+
+    results.sort();
+    results
+}
+
+/// Swap same-rank cards so that `play` contains every required card.
+///
+/// Returns `None` if a required card cannot be placed.
+fn realize_required_cards(
+    hand: &[Card],
+    mut play: Vec<Card>,
+    required_cards: &[Card],
+) -> Option<Vec<Card>> {
+    // Cards in hand not used by the play
+    let mut spare = hand.to_vec();
+    for card in &play {
+        let pos = spare.iter().position(|c| c == card)?;
+        spare.remove(pos);
+    }
+
+    // Positions already holding a required card must not be swapped out
+    let mut locked = vec![false; play.len()];
+
+    for required in required_cards {
+        if let Some(pos) = (0..play.len()).find(|&i| !locked[i] && play[i] == *required) {
+            locked[pos] = true;
+            continue;
+        }
+
+        let spare_pos = spare.iter().position(|c| c == required)?;
+        let swap_pos = (0..play.len()).find(|&i| !locked[i] && play[i].rank == required.rank)?;
+
+        spare[spare_pos] = play[swap_pos];
+        play[swap_pos] = *required;
+        locked[swap_pos] = true;
+    }
+
+    Some(play)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Suit;
+
+    fn triple(rank: Rank) -> Vec<Card> {
+        vec![
+            Card::new(Suit::Spades, rank),
+            Card::new(Suit::Clubs, rank),
+            Card::new(Suit::Diamonds, rank),
+        ]
+    }
+
+    #[test]
+    fn test_airplane_length_and_required_card() {
+        let mut hand = triple(Rank::Ten);
+        hand.extend(triple(Rank::Jack));
+        hand.extend(triple(Rank::Queen));
+        hand.push(Card::new(Suit::Hearts, Rank::Queen));
+
+        let template = PlayTemplate::new(PlayType::Airplane)
+            .with_min_length(3)
+            .containing(Card::new(Suit::Hearts, Rank::Queen));
+        let plays = find_plays_matching(&hand, &template);
+
+        assert!(!plays.is_empty());
+        for play in &plays {
+            assert_eq!(play.len(), 9);
+            assert!(play.contains(&Card::new(Suit::Hearts, Rank::Queen)));
+        }
+
+        // Length 4 is impossible
+        let too_long = PlayTemplate::new(PlayType::Airplane).with_min_length(4);
+        assert!(find_plays_matching(&hand, &too_long).is_empty());
+    }
+
+    #[test]
+    fn test_required_card_missing_from_hand() {
+        let hand = triple(Rank::Five);
+        let template =
+            PlayTemplate::new(PlayType::Pair).containing(Card::new(Suit::Hearts, Rank::Five));
+        assert!(find_plays_matching(&hand, &template).is_empty());
+    }
+
+    #[test]
+    fn test_min_rank_filter() {
+        let hand = vec![
+            Card::new(Suit::Spades, Rank::Six),
+            Card::new(Suit::Hearts, Rank::Six),
+            Card::new(Suit::Spades, Rank::Ace),
+            Card::new(Suit::Hearts, Rank::Ace),
+        ];
+        let template = PlayTemplate::new(PlayType::Pair).with_min_rank(Rank::King);
+        let plays = find_plays_matching(&hand, &template);
+
+        assert_eq!(plays.len(), 1);
+        assert_eq!(plays[0][0].rank, Rank::Ace);
+    }
+
+    #[test]
+    fn test_bomb_length_is_card_count() {
+        let mut hand = triple(Rank::Nine);
+        hand.push(Card::new(Suit::Hearts, Rank::Nine));
+        hand.push(Card::new(Suit::Hearts, Rank::Nine));
+
+        let template = PlayTemplate::new(PlayType::Bomb).with_min_length(5);
+        let plays = find_plays_matching(&hand, &template);
+
+        assert_eq!(plays.len(), 1);
+        assert_eq!(plays[0].len(), 5);
+    }
+}
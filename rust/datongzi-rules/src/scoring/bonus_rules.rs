@@ -0,0 +1,139 @@
+//! Pluggable bonus rules.
+//!
+//! [`ScoreComputation`](super::ScoreComputation) asks each installed
+//! [`BonusRule`] for events when a trick is won and when the game ends. The
+//! standard Tongzi, Dizha and finish-position bonuses are built-in rules;
+//! house bonuses are added with
+//! [`add_bonus_rule`](super::ScoreComputation::add_bonus_rule).
+
+use std::fmt;
+
+use super::{BonusType, ScoringEvent};
+use crate::i18n::Message;
+use crate::models::{GameConfig, Rank};
+use crate::patterns::{PlayPattern, PlayType};
+
+/// What a rule sees when a trick is won.
+#[derive(Debug, Clone, Copy)]
+pub struct TrickContext<'a> {
+    /// Scoring configuration
+    pub config: &'a GameConfig,
+    /// Player who won the trick
+    pub player_id: &'a str,
+    /// The play that won the trick
+    pub winning_pattern: &'a PlayPattern,
+    /// Round number of the trick
+    pub round_number: usize,
+}
+
+/// What a rule sees when the game ends.
+#[derive(Debug, Clone, Copy)]
+pub struct GameEndContext<'a> {
+    /// Scoring configuration
+    pub config: &'a GameConfig,
+    /// Player IDs in finish order (上游 first)
+    pub finish_order: &'a [String],
+    /// Events recorded so far
+    pub events: &'a [ScoringEvent],
+}
+
+/// A source of bonus events.
+///
+/// Both hooks default to no events, so a rule implements only what it needs.
+pub trait BonusRule: fmt::Debug + Send + Sync {
+    /// Events for the player who won a trick
+    fn on_trick_won(&self, _ctx: &TrickContext<'_>) -> Vec<ScoringEvent> {
+        Vec::new()
+    }
+
+    /// Events awarded once at game end
+    fn on_game_end(&self, _ctx: &GameEndContext<'_>) -> Vec<ScoringEvent> {
+        Vec::new()
+    }
+}
+
+/// K/A/2 Tongzi bonus for the trick-winning play.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TongziBonus;
+
+impl BonusRule for TongziBonus {
+    fn on_trick_won(&self, ctx: &TrickContext<'_>) -> Vec<ScoringEvent> {
+        let pattern = ctx.winning_pattern;
+        if pattern.play_type != PlayType::Tongzi {
+            return Vec::new();
+        }
+
+        let (points, bonus_type) = match pattern.primary_rank {
+            Rank::King => (ctx.config.k_tongzi_bonus(), BonusType::KTongzi),
+            Rank::Ace => (ctx.config.a_tongzi_bonus(), BonusType::ATongzi),
+            Rank::Two => (ctx.config.two_tongzi_bonus(), BonusType::TwoTongzi),
+            _ => return Vec::new(),
+        };
+
+        vec![ScoringEvent::new(
+            ctx.player_id.to_string(),
+            bonus_type,
+            points,
+            Message::TongziBonus {
+                rank: pattern.primary_rank,
+                round: ctx.round_number,
+            },
+            Some(ctx.round_number),
+            Vec::new(),
+        )]
+    }
+}
+
+/// Dizha bonus for the trick-winning play.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DizhaBonus;
+
+impl BonusRule for DizhaBonus {
+    fn on_trick_won(&self, ctx: &TrickContext<'_>) -> Vec<ScoringEvent> {
+        let pattern = ctx.winning_pattern;
+        if pattern.play_type != PlayType::Dizha {
+            return Vec::new();
+        }
+
+        vec![ScoringEvent::new(
+            ctx.player_id.to_string(),
+            BonusType::Dizha,
+            ctx.config.dizha_bonus(),
+            Message::DizhaBonus {
+                rank: pattern.primary_rank,
+                round: ctx.round_number,
+            },
+            Some(ctx.round_number),
+            Vec::new(),
+        )]
+    }
+}
+
+/// Finish position bonuses (上游, 二游, 三游).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FinishBonus;
+
+impl BonusRule for FinishBonus {
+    fn on_game_end(&self, ctx: &GameEndContext<'_>) -> Vec<ScoringEvent> {
+        ctx.finish_order
+            .iter()
+            .zip(ctx.config.finish_bonus())
+            .enumerate()
+            .map(|(i, (player_id, &points))| {
+                let bonus_type = match i {
+                    0 => BonusType::FinishFirst,
+                    1 => BonusType::FinishSecond,
+                    _ => BonusType::FinishThird,
+                };
+                ScoringEvent::new(
+                    player_id.clone(),
+                    bonus_type,
+                    points,
+                    Message::FinishPosition { position: i + 1 },
+                    None,
+                    Vec::new(),
+                )
+            })
+            .collect()
+    }
+}
@@ -1,11 +1,18 @@
 //! Scoring rules and calculations for Da Tong Zi game.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
-use crate::models::{Card, GameConfig, Rank};
-use crate::patterns::{PlayPattern, PlayType};
+use super::bonus_rules::{
+    BonusRule, DizhaBonus, FinishBonus, GameEndContext, TongziBonus, TrickContext,
+};
+use crate::i18n::{Locale, Message};
+use crate::models::{Card, GameConfig, LastTrickScoring, Rank};
+use crate::patterns::PlayPattern;
+use crate::variants::check_hot_reload;
 
 /// Types of bonus scoring in the game.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BonusType {
     /// Round win bonus (base score from 5/10/K)
@@ -18,15 +25,59 @@ pub enum BonusType {
     TwoTongzi,
     /// Dizha bonus (400 points default)
     Dizha,
+    /// Final-trick extra points (see [`GameConfig::last_trick_scoring`])
+    LastTrick,
     /// Finish first (上游, +100 default)
     FinishFirst,
     /// Finish second (二游, -40 default)
     FinishSecond,
     /// Finish third (三游, -60 default)
     FinishThird,
+    /// Revealed-hand stake adjustment (明牌, multiplies the player's total)
+    RevealMultiplier,
+}
+
+/// Machine-readable code for a [`ScoringEvent`]'s reason.
+///
+/// Together with the event's parameters ([`ScoringEvent::round_number`],
+/// [`ScoringEvent::cards_involved`], [`ScoringEvent::rank`]) this is all a
+/// client needs to word the settlement screen in its own catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReasonCode {
+    /// Round won with scoring cards
+    RoundWin,
+    /// K/A/2 Tongzi bonus
+    TongziBonus,
+    /// Dizha bonus
+    DizhaBonus,
+    /// Final-trick extra points
+    LastTrick,
+    /// Finish-position bonus
+    FinishPosition,
+    /// Revealed-hand stake adjustment
+    RevealMultiplier,
+    /// House-rule text, or a message that is not a scoring reason
+    Custom,
+}
+
+impl ReasonCode {
+    /// Catalog key, matching [`Message::key`]
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::RoundWin => "scoring.round_win",
+            Self::TongziBonus => "scoring.tongzi_bonus",
+            Self::DizhaBonus => "scoring.dizha_bonus",
+            Self::LastTrick => "scoring.last_trick",
+            Self::FinishPosition => "scoring.finish_position",
+            Self::RevealMultiplier => "scoring.reveal_multiplier",
+            Self::Custom => "custom",
+        }
+    }
 }
 
 /// Represents a single scoring event.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ScoringEvent {
     /// Player ID
@@ -35,14 +86,68 @@ pub struct ScoringEvent {
     pub bonus_type: BonusType,
     /// Points awarded (can be negative)
     pub points: i32,
-    /// Human-readable reason
-    pub reason: String,
+    /// Why the points were awarded; render with [`Message::render`]
+    pub reason: Message,
     /// Round number (if applicable)
     pub round_number: Option<usize>,
     /// Cards involved in scoring (display format)
     pub cards_involved: Vec<String>,
 }
 
+/// A player's declaration to reveal their hand (明牌) for multiplied stakes.
+///
+/// The revealed cards are public information: the game engine should expose
+/// them to every player for the rest of the game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RevealDeclaration {
+    /// Player ID
+    pub player_id: String,
+    /// Cards shown to all players at declaration time
+    pub revealed_cards: Vec<Card>,
+    /// Round number the declaration was made in (None = before the first round)
+    pub round_number: Option<usize>,
+}
+
+impl RevealDeclaration {
+    /// Creates a new reveal declaration
+    #[must_use]
+    pub fn new(player_id: String, revealed_cards: Vec<Card>, round_number: Option<usize>) -> Self {
+        Self {
+            player_id,
+            revealed_cards,
+            round_number,
+        }
+    }
+}
+
+/// How a player handled plays to beat, for post-game statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PressureStats {
+    /// Plays made, leads included
+    pub plays: usize,
+    /// Cards in those plays
+    pub cards_played: usize,
+    /// Times the player beat another player's play
+    pub beats: usize,
+    /// Times the player passed on another player's play
+    pub passes: usize,
+    /// Times another player beat this player's play (被压制)
+    pub times_beaten: usize,
+}
+
+impl PressureStats {
+    /// Average cards per play (0 with no plays)
+    #[must_use]
+    pub fn average_play_size(&self) -> f64 {
+        if self.plays == 0 {
+            0.0
+        } else {
+            self.cards_played as f64 / self.plays as f64
+        }
+    }
+}
+
 /// Game scoring summary
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GameSummary {
@@ -52,6 +157,10 @@ pub struct GameSummary {
     pub winner_id: Option<String>,
     /// Total number of scoring events
     pub total_events: usize,
+    /// Beat/pass statistics for each player (all zero unless actions were
+    /// recorded with [`ScoreComputation::record_play`] and
+    /// [`ScoreComputation::record_pass`])
+    pub pressure: HashMap<String, PressureStats>,
 }
 
 impl ScoringEvent {
@@ -61,7 +170,7 @@ impl ScoringEvent {
         player_id: String,
         bonus_type: BonusType,
         points: i32,
-        reason: String,
+        reason: Message,
         round_number: Option<usize>,
         cards_involved: Vec<String>,
     ) -> Self {
@@ -74,6 +183,50 @@ impl ScoringEvent {
             cards_involved,
         }
     }
+
+    /// Code for why the points were awarded
+    #[must_use]
+    pub const fn reason_code(&self) -> ReasonCode {
+        match self.reason {
+            Message::RoundWin { .. } => ReasonCode::RoundWin,
+            Message::TongziBonus { .. } => ReasonCode::TongziBonus,
+            Message::DizhaBonus { .. } => ReasonCode::DizhaBonus,
+            Message::LastTrick { .. } => ReasonCode::LastTrick,
+            Message::FinishPosition { .. } => ReasonCode::FinishPosition,
+            Message::RevealMultiplier { .. } => ReasonCode::RevealMultiplier,
+            _ => ReasonCode::Custom,
+        }
+    }
+
+    /// Rank of the Tongzi or Dizha behind a bonus
+    #[must_use]
+    pub const fn rank(&self) -> Option<Rank> {
+        match self.reason {
+            Message::TongziBonus { rank, .. } | Message::DizhaBonus { rank, .. } => Some(rank),
+            _ => None,
+        }
+    }
+
+    /// The reason worded for `locale`.
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::i18n::Locale;
+    /// use datongzi_rules::scoring::ReasonCode;
+    /// use datongzi_rules::{Card, GameConfig, Rank, ScoreComputation, Suit};
+    ///
+    /// let mut engine = ScoreComputation::new(GameConfig::default());
+    /// let event = engine
+    ///     .create_round_win_event("p1".to_string(), &[Card::new(Suit::Spades, Rank::King)], 2)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(event.reason_code(), ReasonCode::RoundWin);
+    /// assert_eq!(event.render(Locale::ZhCn), "第2轮获胜，得分牌10分");
+    /// ```
+    #[must_use]
+    pub fn render(&self, locale: Locale) -> String {
+        self.reason.render(locale)
+    }
 }
 
 /// Handles all scoring calculations and bonus awards.
@@ -84,18 +237,78 @@ impl ScoringEvent {
 pub struct ScoreComputation {
     config: GameConfig,
     scoring_events: Vec<ScoringEvent>,
+    reveal_declarations: Vec<RevealDeclaration>,
+    bonus_rules: Vec<Arc<dyn BonusRule>>,
+    captured: HashMap<String, Vec<Card>>,
+    pressure: HashMap<String, PressureStats>,
 }
 
 impl ScoreComputation {
     /// Creates a new scoring computation engine with game configuration
+    ///
+    /// The built-in Tongzi, Dizha and finish-position bonus rules are installed.
     #[must_use]
     pub fn new(config: GameConfig) -> Self {
         Self {
             config,
             scoring_events: Vec::new(),
+            reveal_declarations: Vec::new(),
+            bonus_rules: vec![
+                Arc::new(TongziBonus),
+                Arc::new(DizhaBonus),
+                Arc::new(FinishBonus),
+            ],
+            captured: HashMap::new(),
+            pressure: HashMap::new(),
         }
     }
 
+    /// Installs an additional bonus rule, consulted after the existing ones
+    pub fn add_bonus_rule(&mut self, rule: impl BonusRule + 'static) {
+        self.bonus_rules.push(Arc::new(rule));
+    }
+
+    /// Removes every bonus rule, including the built-ins
+    pub fn clear_bonus_rules(&mut self) {
+        self.bonus_rules.clear();
+    }
+
+    /// Returns the configuration in force
+    #[must_use]
+    pub const fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    /// Switch to `updated` rules mid-game, if that is safe.
+    ///
+    /// Events already created keep their values; later events use the new
+    /// bonus values. Changes that would alter play legality are refused (see
+    /// [`hot_reload_conflicts`](crate::variants::hot_reload_conflicts)).
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from
+    /// [`check_hot_reload`](crate::variants::check_hot_reload); the current
+    /// configuration is kept
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::{GameConfig, ScoreComputation};
+    ///
+    /// let mut engine = ScoreComputation::new(GameConfig::default());
+    /// let tweak = GameConfig { dizha_bonus: 500, ..GameConfig::default() };
+    /// assert!(engine.apply_config(tweak).is_ok());
+    /// assert_eq!(engine.config().dizha_bonus(), 500);
+    ///
+    /// let more_decks = GameConfig { num_decks: 4, ..GameConfig::default() };
+    /// assert!(engine.apply_config(more_decks).is_err());
+    /// ```
+    pub fn apply_config(&mut self, updated: GameConfig) -> crate::Result<()> {
+        check_hot_reload(&self.config, &updated)?;
+        self.config = updated;
+        Ok(())
+    }
+
     /// Calculates base score from cards in a round.
     ///
     /// # Arguments
@@ -116,6 +329,9 @@ impl ScoreComputation {
 
     /// Creates scoring event for round winner.
     ///
+    /// Every card in `round_cards` is added to the winner's
+    /// [`captured`](Self::captured) pile, scoring or not.
+    ///
     /// # Arguments
     ///
     /// * `player_id` - ID of the player who won
@@ -132,6 +348,10 @@ impl ScoreComputation {
         round_number: usize,
     ) -> Option<ScoringEvent> {
         let base_score = self.calculate_round_base_score(round_cards);
+        self.captured
+            .entry(player_id.clone())
+            .or_default()
+            .extend_from_slice(round_cards);
 
         if base_score > 0 {
             let scoring_cards: Vec<String> = round_cards
@@ -144,7 +364,10 @@ impl ScoreComputation {
                 player_id,
                 BonusType::RoundWin,
                 base_score,
-                format!("Round {round_number} win with {base_score} scoring cards"),
+                Message::RoundWin {
+                    round: round_number,
+                    points: base_score,
+                },
                 Some(round_number),
                 scoring_cards,
             );
@@ -156,8 +379,79 @@ impl ScoreComputation {
         None
     }
 
+    /// Creates the extra event for the game's final trick, per
+    /// [`GameConfig::last_trick_scoring`].
+    ///
+    /// Call alongside [`create_round_win_event`](Self::create_round_win_event)
+    /// for the last round. Under [`LastTrickScoring::TakeAside`] the `aside`
+    /// pile joins the winner's [`captured`](Self::captured) cards; the other
+    /// options ignore it.
+    ///
+    /// # Returns
+    ///
+    /// `Some(ScoringEvent)` if the option awards any points, `None` otherwise
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::models::LastTrickScoring;
+    /// use datongzi_rules::{Card, GameConfig, Rank, ScoreComputation, Suit};
+    ///
+    /// let config = GameConfig { last_trick_scoring: LastTrickScoring::Double, ..GameConfig::default() };
+    /// let mut engine = ScoreComputation::new(config);
+    /// let trick = [Card::new(Suit::Spades, Rank::Ten)];
+    ///
+    /// engine.create_round_win_event("p1".to_string(), &trick, 7);
+    /// let event = engine.create_last_trick_event("p1".to_string(), &trick, &[], 7).unwrap();
+    /// assert_eq!(event.points, 10);
+    /// assert_eq!(engine.calculate_total_score_for_player("p1"), 20);
+    /// ```
+    pub fn create_last_trick_event(
+        &mut self,
+        player_id: String,
+        round_cards: &[Card],
+        aside: &[Card],
+        round_number: usize,
+    ) -> Option<ScoringEvent> {
+        let extra_cards = match self.config.last_trick_scoring() {
+            LastTrickScoring::Standard => return None,
+            LastTrickScoring::Double => round_cards,
+            LastTrickScoring::TakeAside => {
+                self.captured
+                    .entry(player_id.clone())
+                    .or_default()
+                    .extend_from_slice(aside);
+                aside
+            }
+        };
+
+        let points = self.calculate_round_base_score(extra_cards);
+        if points == 0 {
+            return None;
+        }
+        let scoring_cards: Vec<String> = extra_cards
+            .iter()
+            .filter(|c| c.is_scoring_card())
+            .map(|c| c.to_string())
+            .collect();
+        let event = ScoringEvent::new(
+            player_id,
+            BonusType::LastTrick,
+            points,
+            Message::LastTrick {
+                round: round_number,
+                points,
+            },
+            Some(round_number),
+            scoring_cards,
+        );
+        self.scoring_events.push(event.clone());
+        Some(event)
+    }
+
     /// Creates scoring events for special bonuses (Tongzi, Dizha).
     ///
+    /// Each installed [`BonusRule`] contributes through `on_trick_won`.
+    ///
     /// IMPORTANT: According to game rules, only the FINAL winning play of a round
     /// receives special bonuses. If player A plays K Tongzi and player B beats it
     /// with A Tongzi, only player B gets the bonus (200 points), player A gets nothing.
@@ -181,57 +475,32 @@ impl ScoreComputation {
         round_number: usize,
         is_round_winning_play: bool,
     ) -> Vec<ScoringEvent> {
-        let mut events = Vec::new();
-
         // Only award special bonuses if this is the round winning play
         if !is_round_winning_play {
-            return events;
+            return Vec::new();
         }
 
-        match winning_pattern.play_type {
-            PlayType::Tongzi => {
-                if let Some((bonus_points, bonus_type)) =
-                    self.get_tongzi_bonus(winning_pattern.primary_rank)
-                {
-                    let event = ScoringEvent::new(
-                        player_id,
-                        bonus_type,
-                        bonus_points,
-                        format!(
-                            "{:?} Tongzi in round {round_number}",
-                            winning_pattern.primary_rank
-                        ),
-                        Some(round_number),
-                        Vec::new(),
-                    );
-                    events.push(event.clone());
-                    self.scoring_events.push(event);
-                }
-            }
-            PlayType::Dizha => {
-                let bonus_points = self.config.dizha_bonus();
-                let event = ScoringEvent::new(
-                    player_id,
-                    BonusType::Dizha,
-                    bonus_points,
-                    format!(
-                        "{:?} Dizha in round {round_number}",
-                        winning_pattern.primary_rank
-                    ),
-                    Some(round_number),
-                    Vec::new(),
-                );
-                events.push(event.clone());
-                self.scoring_events.push(event);
-            }
-            _ => {}
-        }
+        let ctx = TrickContext {
+            config: &self.config,
+            player_id: &player_id,
+            winning_pattern,
+            round_number,
+        };
+        let events: Vec<ScoringEvent> = self
+            .bonus_rules
+            .iter()
+            .flat_map(|rule| rule.on_trick_won(&ctx))
+            .collect();
 
+        self.scoring_events.extend(events.iter().cloned());
         events
     }
 
     /// Creates finish position bonus events (上游, 二游, 三游).
     ///
+    /// Each installed [`BonusRule`] contributes through `on_game_end`, so
+    /// house game-end bonuses are emitted here too.
+    ///
     /// # Arguments
     ///
     /// * `player_ids_in_finish_order` - Player IDs sorted by finish order
@@ -243,31 +512,101 @@ impl ScoreComputation {
         &mut self,
         player_ids_in_finish_order: &[String],
     ) -> Vec<ScoringEvent> {
+        let ctx = GameEndContext {
+            config: &self.config,
+            finish_order: player_ids_in_finish_order,
+            events: &self.scoring_events,
+        };
+        let events: Vec<ScoringEvent> = self
+            .bonus_rules
+            .iter()
+            .flat_map(|rule| rule.on_game_end(&ctx))
+            .collect();
+
+        self.scoring_events.extend(events.iter().cloned());
+        events
+    }
+
+    /// Records a reveal declaration (明牌).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration does not allow revealing, or if
+    /// the player has already revealed their hand
+    pub fn declare_reveal(&mut self, declaration: RevealDeclaration) -> crate::Result<()> {
+        if !self.config.allow_reveal() {
+            return Err(crate::DatongziError::ConfigError(Message::RevealNotAllowed));
+        }
+
+        if self.is_revealed(&declaration.player_id) {
+            return Err(crate::DatongziError::PlayError(Message::AlreadyRevealed {
+                player_id: declaration.player_id.clone(),
+            }));
+        }
+
+        self.reveal_declarations.push(declaration);
+        Ok(())
+    }
+
+    /// Returns true if the player has revealed their hand
+    #[must_use]
+    pub fn is_revealed(&self, player_id: &str) -> bool {
+        self.reveal_declarations
+            .iter()
+            .any(|d| d.player_id == player_id)
+    }
+
+    /// Returns all reveal declarations in declaration order
+    #[must_use]
+    pub fn reveal_declarations(&self) -> &[RevealDeclaration] {
+        &self.reveal_declarations
+    }
+
+    /// Creates stake adjustment events for players who revealed their hand.
+    ///
+    /// Call at game end, after all round and finish events have been
+    /// created. Each revealed player's total (win or loss) is multiplied by
+    /// `reveal_multiplier`; the difference is emitted as a single
+    /// `RevealMultiplier` event so that event totals stay authoritative.
+    ///
+    /// Reveal events from an earlier call are replaced, so calling this again
+    /// never compounds the multiplier.
+    ///
+    /// # Returns
+    ///
+    /// List of `ScoringEvent`s (players with a zero total get no event)
+    pub fn create_reveal_multiplier_events(&mut self) -> Vec<ScoringEvent> {
+        self.scoring_events
+            .retain(|e| e.bonus_type != BonusType::RevealMultiplier);
+
         let mut events = Vec::new();
+        let extra_factor = self.config.reveal_multiplier() - 1;
 
-        for (i, player_id) in player_ids_in_finish_order.iter().enumerate() {
-            if i < self.config.finish_bonus().len() {
-                let bonus_points = self.config.finish_bonus()[i];
-
-                let (bonus_type, position_name) = match i {
-                    0 => (BonusType::FinishFirst, "上游"),
-                    1 => (BonusType::FinishSecond, "二游"),
-                    _ => (BonusType::FinishThird, "三游"),
-                };
-
-                let event = ScoringEvent::new(
-                    player_id.clone(),
-                    bonus_type,
-                    bonus_points,
-                    format!("Finished in position {} ({position_name})", i + 1),
-                    None,
-                    Vec::new(),
-                );
-                events.push(event.clone());
-                self.scoring_events.push(event);
+        for declaration in &self.reveal_declarations {
+            let total = self.calculate_total_score_for_player(&declaration.player_id);
+            let adjustment = total * extra_factor;
+            if adjustment == 0 {
+                continue;
             }
+
+            events.push(ScoringEvent::new(
+                declaration.player_id.clone(),
+                BonusType::RevealMultiplier,
+                adjustment,
+                Message::RevealMultiplier {
+                    total,
+                    multiplier: self.config.reveal_multiplier(),
+                },
+                None,
+                declaration
+                    .revealed_cards
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect(),
+            ));
         }
 
+        self.scoring_events.extend(events.iter().cloned());
         events
     }
 
@@ -289,6 +628,28 @@ impl ScoreComputation {
             .sum()
     }
 
+    /// Projects player totals as if `additional_events` had been recorded.
+    ///
+    /// Does not modify the recorded events, so UI "what-if" displays and AI
+    /// score-delta evaluation can call it freely.
+    ///
+    /// # Arguments
+    ///
+    /// * `additional_events` - Hypothetical events to add on top of recorded ones
+    ///
+    /// # Returns
+    ///
+    /// Map of player_id -> projected total, covering every player that appears
+    /// in recorded or additional events
+    #[must_use]
+    pub fn project(&self, additional_events: &[ScoringEvent]) -> HashMap<String, i32> {
+        let mut totals: HashMap<String, i32> = HashMap::new();
+        for event in self.scoring_events.iter().chain(additional_events) {
+            *totals.entry(event.player_id.clone()).or_insert(0) += event.points;
+        }
+        totals
+    }
+
     /// Validates that provided scores match recorded events.
     ///
     /// # Arguments
@@ -315,6 +676,77 @@ impl ScoreComputation {
         &self.scoring_events
     }
 
+    /// Returns every card `player_id` has captured by winning rounds, in
+    /// the order won
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::{Card, GameConfig, Rank, ScoreComputation, Suit};
+    ///
+    /// let mut engine = ScoreComputation::new(GameConfig::default());
+    /// let trick = [Card::new(Suit::Spades, Rank::King), Card::new(Suit::Hearts, Rank::Six)];
+    /// engine.create_round_win_event("p1".to_string(), &trick, 1);
+    ///
+    /// assert_eq!(engine.captured("p1"), &trick);
+    /// assert_eq!(engine.captured_points("p1"), 10);
+    /// assert!(engine.captured("p2").is_empty());
+    /// ```
+    #[must_use]
+    pub fn captured(&self, player_id: &str) -> &[Card] {
+        self.captured.get(player_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the points (5/10/K) in `player_id`'s captured cards
+    #[must_use]
+    pub fn captured_points(&self, player_id: &str) -> i32 {
+        self.calculate_round_base_score(self.captured(player_id))
+    }
+
+    /// Record a play for the pressure statistics.
+    ///
+    /// `beaten` is the player whose play this one beat (`None` when
+    /// leading).
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::{GameConfig, ScoreComputation};
+    ///
+    /// let mut engine = ScoreComputation::new(GameConfig::default());
+    /// engine.record_play("p1", 2, None);
+    /// engine.record_play("p2", 2, Some("p1"));
+    /// engine.record_pass("p1");
+    ///
+    /// let stats = engine.pressure("p1");
+    /// assert_eq!((stats.times_beaten, stats.passes), (1, 1));
+    /// assert_eq!(engine.pressure("p2").beats, 1);
+    /// ```
+    pub fn record_play(&mut self, player_id: &str, card_count: usize, beaten: Option<&str>) {
+        let stats = self.pressure.entry(player_id.to_string()).or_default();
+        stats.plays += 1;
+        stats.cards_played += card_count;
+        if let Some(beaten) = beaten.filter(|beaten| *beaten != player_id) {
+            stats.beats += 1;
+            self.pressure
+                .entry(beaten.to_string())
+                .or_default()
+                .times_beaten += 1;
+        }
+    }
+
+    /// Record a pass on another player's play for the pressure statistics
+    pub fn record_pass(&mut self, player_id: &str) {
+        self.pressure
+            .entry(player_id.to_string())
+            .or_default()
+            .passes += 1;
+    }
+
+    /// Returns `player_id`'s pressure statistics so far
+    #[must_use]
+    pub fn pressure(&self, player_id: &str) -> PressureStats {
+        self.pressure.get(player_id).copied().unwrap_or_default()
+    }
+
     /// Generates a comprehensive game scoring summary.
     ///
     /// # Arguments
@@ -343,17 +775,10 @@ impl ScoreComputation {
             final_scores,
             winner_id,
             total_events: self.scoring_events.len(),
-        }
-    }
-
-    // Private helper methods
-
-    fn get_tongzi_bonus(&self, rank: Rank) -> Option<(i32, BonusType)> {
-        match rank {
-            Rank::King => Some((self.config.k_tongzi_bonus(), BonusType::KTongzi)),
-            Rank::Ace => Some((self.config.a_tongzi_bonus(), BonusType::ATongzi)),
-            Rank::Two => Some((self.config.two_tongzi_bonus(), BonusType::TwoTongzi)),
-            _ => None,
+            pressure: player_ids
+                .iter()
+                .map(|id| (id.clone(), self.pressure(id)))
+                .collect(),
         }
     }
 }
@@ -361,7 +786,8 @@ impl ScoreComputation {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::Suit;
+    use crate::models::{Rank, Suit};
+    use crate::patterns::PlayType;
 
     #[test]
     fn test_calculate_round_base_score() {
@@ -425,6 +851,27 @@ mod tests {
         let event = engine.create_round_win_event("player1".to_string(), &cards, 1);
 
         assert!(event.is_none());
+        assert_eq!(engine.captured("player1"), cards.as_slice());
+    }
+
+    #[test]
+    fn test_captured_points_match_round_events() {
+        let mut engine = ScoreComputation::new(GameConfig::default());
+        let five = Card::new(Suit::Spades, Rank::Five);
+        let ten = Card::new(Suit::Hearts, Rank::Ten);
+        let six = Card::new(Suit::Clubs, Rank::Six);
+
+        engine.create_round_win_event("p1".to_string(), &[five, six], 1);
+        engine.create_round_win_event("p2".to_string(), &[ten], 2);
+        engine.create_round_win_event("p1".to_string(), &[ten, five], 3);
+
+        assert_eq!(engine.captured("p1"), &[five, six, ten, five]);
+        for player in ["p1", "p2"] {
+            assert_eq!(
+                engine.captured_points(player),
+                engine.calculate_total_score_for_player(player)
+            );
+        }
     }
 
     #[test]
@@ -480,6 +927,9 @@ mod tests {
         assert_eq!(events.len(), 1);
         assert_eq!(events[0].bonus_type, BonusType::Dizha);
         assert_eq!(events[0].points, 400);
+        assert_eq!(events[0].reason_code(), ReasonCode::DizhaBonus);
+        assert_eq!(events[0].rank(), Some(Rank::Ten));
+        assert_eq!(events[0].render(Locale::EnUs), "Ten Dizha in round 1");
     }
 
     #[test]
@@ -511,6 +961,11 @@ mod tests {
         assert_eq!(events[2].player_id, "player3");
         assert_eq!(events[2].bonus_type, BonusType::FinishThird);
         assert_eq!(events[2].points, -60);
+
+        assert!(events
+            .iter()
+            .all(|e| e.reason_code() == ReasonCode::FinishPosition && e.rank().is_none()));
+        assert_eq!(events[0].render(Locale::ZhCn), "第1名完成（上游）");
     }
 
     #[test]
@@ -523,7 +978,7 @@ mod tests {
             "player1".to_string(),
             BonusType::RoundWin,
             15,
-            "Round 1".to_string(),
+            Message::Custom("Round 1".to_string()),
             Some(1),
             vec![],
         ));
@@ -531,7 +986,7 @@ mod tests {
             "player1".to_string(),
             BonusType::KTongzi,
             100,
-            "K Tongzi".to_string(),
+            Message::Custom("K Tongzi".to_string()),
             Some(1),
             vec![],
         ));
@@ -539,7 +994,7 @@ mod tests {
             "player2".to_string(),
             BonusType::RoundWin,
             25,
-            "Round 2".to_string(),
+            Message::Custom("Round 2".to_string()),
             Some(2),
             vec![],
         ));
@@ -547,7 +1002,7 @@ mod tests {
             "player1".to_string(),
             BonusType::FinishFirst,
             100,
-            "First place".to_string(),
+            Message::Custom("First place".to_string()),
             None,
             vec![],
         ));
@@ -592,7 +1047,7 @@ mod tests {
             "player1".to_string(),
             BonusType::RoundWin,
             15,
-            "Round 1".to_string(),
+            Message::Custom("Round 1".to_string()),
             Some(1),
             vec![],
         ));
@@ -600,7 +1055,7 @@ mod tests {
             "player2".to_string(),
             BonusType::RoundWin,
             25,
-            "Round 2".to_string(),
+            Message::Custom("Round 2".to_string()),
             Some(2),
             vec![],
         ));
@@ -616,6 +1071,108 @@ mod tests {
         assert!(!engine.validate_scores(&incorrect_scores));
     }
 
+    #[test]
+    fn test_project_does_not_mutate() {
+        let config = GameConfig::default();
+        let mut engine = ScoreComputation::new(config);
+        engine.create_round_win_event(
+            "player1".to_string(),
+            &[Card::new(Suit::Spades, Rank::Ten)],
+            1,
+        );
+
+        let hypothetical = vec![ScoringEvent::new(
+            "player2".to_string(),
+            BonusType::RoundWin,
+            25,
+            Message::Custom("What if".to_string()),
+            Some(2),
+            vec![],
+        )];
+        let projected = engine.project(&hypothetical);
+
+        assert_eq!(projected["player1"], 10);
+        assert_eq!(projected["player2"], 25);
+        assert_eq!(engine.scoring_events().len(), 1);
+        assert_eq!(engine.calculate_total_score_for_player("player2"), 0);
+    }
+
+    #[test]
+    fn test_reveal_requires_config() {
+        let mut engine = ScoreComputation::new(GameConfig::default());
+        let result =
+            engine.declare_reveal(RevealDeclaration::new("player1".to_string(), vec![], None));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reveal_multiplier_events() {
+        let config = GameConfig {
+            allow_reveal: true,
+            ..GameConfig::default()
+        };
+        let mut engine = ScoreComputation::new(config);
+
+        let hand = vec![Card::new(Suit::Spades, Rank::Two)];
+        engine
+            .declare_reveal(RevealDeclaration::new("player1".to_string(), hand, None))
+            .unwrap();
+        assert!(engine.is_revealed("player1"));
+        assert!(engine
+            .declare_reveal(RevealDeclaration::new("player1".to_string(), vec![], None))
+            .is_err());
+
+        engine.create_round_win_event(
+            "player1".to_string(),
+            &[Card::new(Suit::Hearts, Rank::King)],
+            1,
+        );
+        engine.create_finish_bonus_events(&[
+            "player2".to_string(),
+            "player1".to_string(),
+            "player3".to_string(),
+        ]);
+
+        // player1: 10 (round) - 40 (二游) = -30, doubled to -60
+        let events = engine.create_reveal_multiplier_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].bonus_type, BonusType::RevealMultiplier);
+        assert_eq!(events[0].points, -30);
+        assert_eq!(events[0].cards_involved.len(), 1);
+        assert_eq!(engine.calculate_total_score_for_player("player1"), -60);
+        assert_eq!(engine.calculate_total_score_for_player("player2"), 100);
+    }
+
+    #[test]
+    fn test_reveal_multiplier_events_are_idempotent() {
+        let config = GameConfig {
+            allow_reveal: true,
+            ..GameConfig::default()
+        };
+        let mut engine = ScoreComputation::new(config);
+        engine
+            .declare_reveal(RevealDeclaration::new("player1".to_string(), vec![], None))
+            .unwrap();
+        engine.create_finish_bonus_events(&[
+            "player1".to_string(),
+            "player2".to_string(),
+            "player3".to_string(),
+        ]);
+
+        let first = engine.create_reveal_multiplier_events();
+        let second = engine.create_reveal_multiplier_events();
+        assert_eq!(first, second);
+        assert_eq!(engine.calculate_total_score_for_player("player1"), 200);
+        assert_eq!(
+            engine
+                .scoring_events()
+                .iter()
+                .filter(|e| e.bonus_type == BonusType::RevealMultiplier)
+                .count(),
+            1
+        );
+    }
+
     #[test]
     fn test_custom_config_bonuses() {
         let config = GameConfig::new(3, 3, 41, 9, vec![200, -50, -150], 150, 250, 350, 500);
@@ -0,0 +1,351 @@
+//! Human-readable hand history text for game records.
+//!
+//! A hand history lists the seats, the dealt hands and then one numbered
+//! line per round:
+//!
+//! ```text
+//! Players: alice bob carol
+//! Hand alice: 5♠ 5♥ K♣
+//! Hand bob: 7♠ 7♥ 9♦
+//! Hand carol: 8♣ 8♦ 10♥
+//! Aside: -
+//!
+//! 1. alice: 5♠ 5♥ | bob: 7♠ 7♥ | carol: 8♣ 8♦ | alice: pass | bob: pass
+//! 2. carol: 10♥ | alice: K♣
+//! ```
+//!
+//! Cards use their `Display` form and `-` stands for no cards. Rounds break
+//! where [`score_record`](super::score_record) closes them (every other
+//! player still holding cards has passed), but the parser only reads the
+//! actions in order, so line breaks never change the record. Blank lines and
+//! `#` comments are skipped.
+
+use super::{GameRecord, RecordedAction};
+use crate::error::{DatongziError, Result};
+use crate::i18n::Message;
+use crate::models::Card;
+
+fn write_cards(cards: &[Card]) -> String {
+    if cards.is_empty() {
+        return "-".to_string();
+    }
+    let tokens: Vec<String> = cards.iter().map(Card::to_string).collect();
+    tokens.join(" ")
+}
+
+fn parse_cards(text: &str) -> Option<Vec<Card>> {
+    if text.trim() == "-" {
+        return Some(Vec::new());
+    }
+    text.split_whitespace().map(Card::from_display).collect()
+}
+
+fn is_writable_id(player_id: &str) -> bool {
+    !player_id.is_empty()
+        && !player_id
+            .chars()
+            .any(|c| c.is_whitespace() || c == ':' || c == '|')
+}
+
+impl GameRecord {
+    /// Write the seats, hands, aside and actions as a hand history.
+    ///
+    /// Reveals, the shuffle audit and annotations are not part of the
+    /// format and are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidInput` with [`Message::HandHistoryPlayerId`] for a
+    /// player ID that is empty or contains whitespace, `:` or `|`
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::scoring::{GameRecord, RecordedAction};
+    /// use datongzi_rules::{Card, Rank, Suit};
+    ///
+    /// let king = Card::new(Suit::Spades, Rank::King);
+    /// let record = GameRecord {
+    ///     player_ids: vec!["a".to_string(), "b".to_string()],
+    ///     hands: vec![vec![king], vec![Card::new(Suit::Hearts, Rank::Five)]],
+    ///     actions: vec![RecordedAction::Play { player_id: "a".to_string(), cards: vec![king] }],
+    ///     ..GameRecord::default()
+    /// };
+    ///
+    /// let text = record.to_hand_history().unwrap();
+    /// assert!(text.ends_with("1. a: K♠\n"));
+    /// assert_eq!(GameRecord::from_hand_history(&text).unwrap(), record);
+    /// ```
+    pub fn to_hand_history(&self) -> Result<String> {
+        let player_ids = self.player_ids.iter();
+        let actors = self.actions.iter().map(|action| match action {
+            RecordedAction::Play { player_id, .. } | RecordedAction::Pass { player_id } => {
+                player_id
+            }
+        });
+        if let Some(player_id) = player_ids.chain(actors).find(|id| !is_writable_id(id)) {
+            return Err(DatongziError::InvalidInput(Message::HandHistoryPlayerId {
+                player_id: player_id.clone(),
+            }));
+        }
+
+        let mut text = format!("Players: {}\n", self.player_ids.join(" "));
+        for (player_id, hand) in self.player_ids.iter().zip(&self.hands) {
+            text.push_str(&format!("Hand {player_id}: {}\n", write_cards(hand)));
+        }
+        text.push_str(&format!("Aside: {}\n", write_cards(&self.aside)));
+
+        let mut cards_left: Vec<usize> = self.hands.iter().map(Vec::len).collect();
+        let seat = |player_id: &str| self.player_ids.iter().position(|id| id == player_id);
+        // Leader and passes of the open round, as in `score_record`
+        let mut open: Option<(Option<usize>, usize)> = None;
+        let mut rounds: Vec<Vec<String>> = Vec::new();
+        let mut round: Vec<String> = Vec::new();
+        for action in &self.actions {
+            match action {
+                RecordedAction::Play { player_id, cards } => {
+                    round.push(format!("{player_id}: {}", write_cards(cards)));
+                    let leader = seat(player_id);
+                    if let Some(left) = leader.and_then(|s| cards_left.get_mut(s)) {
+                        *left = left.saturating_sub(cards.len());
+                    }
+                    open = Some((leader, 0));
+                }
+                RecordedAction::Pass { player_id } => {
+                    round.push(format!("{player_id}: pass"));
+                    if let Some((_, passes)) = open.as_mut() {
+                        *passes += 1;
+                    }
+                }
+            }
+
+            let round_over = open.is_some_and(|(leader, passes)| {
+                let others = (0..cards_left.len())
+                    .filter(|p| Some(*p) != leader && cards_left[*p] > 0)
+                    .count();
+                passes >= others
+            });
+            if round_over {
+                rounds.push(std::mem::take(&mut round));
+                open = None;
+            }
+        }
+        if !round.is_empty() {
+            rounds.push(round);
+        }
+
+        if !rounds.is_empty() {
+            text.push('\n');
+        }
+        for (index, round) in rounds.iter().enumerate() {
+            text.push_str(&format!("{}. {}\n", index + 1, round.join(" | ")));
+        }
+        Ok(text)
+    }
+
+    /// Parse a hand history written by [`to_hand_history`](Self::to_hand_history).
+    ///
+    /// The result has no reveals, shuffle audit or annotations. Hands not
+    /// listed are empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidInput` with [`Message::HandHistoryLine`] for the first
+    /// malformed line: an unknown header, an unreadable card, a player not
+    /// listed in `Players`, or any line before `Players`
+    pub fn from_hand_history(text: &str) -> Result<Self> {
+        let mut record = Self::default();
+        let mut has_players = false;
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let malformed = || {
+                DatongziError::InvalidInput(Message::HandHistoryLine {
+                    line: index + 1,
+                    text: line.to_string(),
+                })
+            };
+
+            if let Some(players) = line.strip_prefix("Players:") {
+                if has_players {
+                    return Err(malformed());
+                }
+                record.player_ids = players.split_whitespace().map(str::to_string).collect();
+                record.hands = vec![Vec::new(); record.player_ids.len()];
+                has_players = true;
+                continue;
+            }
+            if !has_players {
+                return Err(malformed());
+            }
+            let seat = |player_id: &str| record.player_ids.iter().position(|id| id == player_id);
+
+            if let Some(hand) = line.strip_prefix("Hand ") {
+                let (player_id, cards) = hand.split_once(':').ok_or_else(malformed)?;
+                let seat = seat(player_id.trim()).ok_or_else(malformed)?;
+                record.hands[seat] = parse_cards(cards).ok_or_else(malformed)?;
+            } else if let Some(aside) = line.strip_prefix("Aside:") {
+                record.aside = parse_cards(aside).ok_or_else(malformed)?;
+            } else {
+                let (number, actions) = line.split_once(". ").ok_or_else(malformed)?;
+                if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(malformed());
+                }
+                for entry in actions.split('|') {
+                    let (player_id, cards) = entry.split_once(':').ok_or_else(malformed)?;
+                    let player_id = player_id.trim();
+                    seat(player_id).ok_or_else(malformed)?;
+                    let player_id = player_id.to_string();
+                    record.actions.push(if cards.trim() == "pass" {
+                        RecordedAction::Pass { player_id }
+                    } else {
+                        RecordedAction::Play {
+                            player_id,
+                            cards: parse_cards(cards).ok_or_else(malformed)?,
+                        }
+                    });
+                }
+            }
+        }
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{GameConfig, Rank, Suit};
+    use crate::scoring::score_record;
+
+    fn card(suit: Suit, rank: Rank) -> Card {
+        Card::new(suit, rank)
+    }
+
+    fn sample_record() -> GameRecord {
+        let play = |player_id: &str, cards: Vec<Card>| RecordedAction::Play {
+            player_id: player_id.to_string(),
+            cards,
+        };
+        let pass = |player_id: &str| RecordedAction::Pass {
+            player_id: player_id.to_string(),
+        };
+        GameRecord {
+            player_ids: vec!["alice".to_string(), "bob".to_string(), "carol".to_string()],
+            hands: vec![
+                vec![
+                    card(Suit::Spades, Rank::Five),
+                    card(Suit::Hearts, Rank::Five),
+                    card(Suit::Clubs, Rank::King),
+                ],
+                vec![
+                    card(Suit::Spades, Rank::Seven),
+                    card(Suit::Hearts, Rank::Seven),
+                    card(Suit::Diamonds, Rank::Nine),
+                ],
+                vec![
+                    card(Suit::Clubs, Rank::Eight),
+                    card(Suit::Diamonds, Rank::Eight),
+                    card(Suit::Hearts, Rank::Ten),
+                ],
+            ],
+            actions: vec![
+                play(
+                    "alice",
+                    vec![
+                        card(Suit::Spades, Rank::Five),
+                        card(Suit::Hearts, Rank::Five),
+                    ],
+                ),
+                play(
+                    "bob",
+                    vec![
+                        card(Suit::Spades, Rank::Seven),
+                        card(Suit::Hearts, Rank::Seven),
+                    ],
+                ),
+                play(
+                    "carol",
+                    vec![
+                        card(Suit::Clubs, Rank::Eight),
+                        card(Suit::Diamonds, Rank::Eight),
+                    ],
+                ),
+                pass("alice"),
+                pass("bob"),
+                play("carol", vec![card(Suit::Hearts, Rank::Ten)]),
+                play("alice", vec![card(Suit::Clubs, Rank::King)]),
+            ],
+            aside: vec![card(Suit::Spades, Rank::Two)],
+            ..GameRecord::default()
+        }
+    }
+
+    #[test]
+    fn test_writes_one_line_per_round() {
+        let text = sample_record().to_hand_history().unwrap();
+        assert_eq!(
+            text,
+            "Players: alice bob carol\n\
+             Hand alice: 5♠ 5♥ K♣\n\
+             Hand bob: 7♠ 7♥ 9♦\n\
+             Hand carol: 8♣ 8♦ 10♥\n\
+             Aside: 2♠\n\
+             \n\
+             1. alice: 5♠ 5♥ | bob: 7♠ 7♥ | carol: 8♣ 8♦ | alice: pass | bob: pass\n\
+             2. carol: 10♥ | alice: K♣\n"
+        );
+    }
+
+    #[test]
+    fn test_round_trip_keeps_scoring() {
+        let record = sample_record();
+        let parsed = GameRecord::from_hand_history(&record.to_hand_history().unwrap()).unwrap();
+        assert_eq!(parsed, record);
+
+        let config = GameConfig::default();
+        assert_eq!(
+            score_record(&parsed, &config).unwrap().final_scores,
+            score_record(&record, &config).unwrap().final_scores
+        );
+    }
+
+    #[test]
+    fn test_parse_ignores_layout_and_comments() {
+        let text = "# shared from a friendly game\n\
+                    Players: a b\n\
+                    Hand a: K♠\n\
+                    1. a: K♠\n\
+                    2. b: pass";
+        let record = GameRecord::from_hand_history(text).unwrap();
+        assert_eq!(
+            record.hands,
+            vec![vec![card(Suit::Spades, Rank::King)], Vec::new()]
+        );
+        assert_eq!(record.actions.len(), 2);
+    }
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        let line_error = |text: &str| match GameRecord::from_hand_history(text) {
+            Err(DatongziError::InvalidInput(Message::HandHistoryLine { line, .. })) => line,
+            other => panic!("expected a line error, got {other:?}"),
+        };
+        assert_eq!(line_error("Hand a: K♠"), 1);
+        assert_eq!(line_error("Players: a\nHand a: K♠ Z♠"), 2);
+        assert_eq!(line_error("Players: a\n1. b: pass"), 2);
+        assert_eq!(line_error("Players: a\nfirst. a: pass"), 2);
+
+        let record = GameRecord {
+            player_ids: vec!["alice smith".to_string()],
+            hands: vec![Vec::new()],
+            ..GameRecord::default()
+        };
+        assert!(matches!(
+            record.to_hand_history(),
+            Err(DatongziError::InvalidInput(
+                Message::HandHistoryPlayerId { .. }
+            ))
+        ));
+    }
+}
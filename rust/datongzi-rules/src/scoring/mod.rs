@@ -3,7 +3,21 @@
 //! This module provides the scoring engine for calculating points, bonuses,
 //! and game results. It is a pure calculation engine that does not manage
 //! game state - that is the responsibility of the upper layer (game engine).
+//! [`score_record`] recomputes a finished game's scoring from its replay record.
+//! [`GameRecord::to_hand_history`] and [`GameRecord::from_hand_history`]
+//! convert records to and from a shareable text format.
 
+mod bonus_rules;
 mod computation;
+mod hand_history;
+mod record;
 
-pub use computation::{BonusType, GameSummary, ScoreComputation, ScoringEvent};
+pub use bonus_rules::{
+    BonusRule, DizhaBonus, FinishBonus, GameEndContext, TongziBonus, TrickContext,
+};
+pub use computation::{
+    BonusType, GameSummary, PressureStats, ReasonCode, RevealDeclaration, ScoreComputation,
+    ScoringEvent,
+};
+pub(crate) use record::{replay, ReplayStep};
+pub use record::{score_record, GameRecord, PlayAnnotation, RecordedAction};
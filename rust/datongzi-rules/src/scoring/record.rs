@@ -0,0 +1,652 @@
+//! Scoring a finished game from its replay record.
+
+use std::collections::BTreeMap;
+
+use super::{GameSummary, RevealDeclaration, ScoreComputation};
+use crate::error::{DatongziError, Result};
+use crate::fairness::ShuffleAudit;
+use crate::i18n::Message;
+use crate::invariants;
+use crate::models::{Card, GameConfig};
+use crate::patterns::{PlayPattern, PlayValidator};
+
+/// One turn in a game record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RecordedAction {
+    /// `player_id` played `cards`
+    Play {
+        /// Player who played
+        player_id: String,
+        /// Cards played
+        cards: Vec<Card>,
+    },
+    /// `player_id` passed
+    Pass {
+        /// Player who passed
+        player_id: String,
+    },
+}
+
+/// Client-side context attached to one recorded action.
+///
+/// Annotations never affect validation or scoring; they ride along with the
+/// record so analytics can relate behavior to outcomes.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayAnnotation {
+    /// Milliseconds between the turn starting and the client submitting
+    pub latency_ms: Option<u32>,
+    /// Whether the player asked for a hint before acting
+    pub hint_used: bool,
+    /// Emoji reaction sent with the action
+    pub reaction: Option<String>,
+}
+
+/// Everything needed to replay a game's scoring.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameRecord {
+    /// Player IDs in seat order
+    pub player_ids: Vec<String>,
+    /// Dealt hands, indexed like `player_ids`
+    pub hands: Vec<Vec<Card>>,
+    /// Plays and passes in turn order
+    pub actions: Vec<RecordedAction>,
+    /// Reveal declarations (明牌), in declaration order
+    pub reveals: Vec<RevealDeclaration>,
+    /// Seed and permutation digest when the deal came from the built-in
+    /// shuffle ([`deal_from_seed_audited`](crate::puzzles::deal_from_seed_audited))
+    pub shuffle: Option<ShuffleAudit>,
+    /// Annotations keyed by index into `actions`; most actions have none
+    pub annotations: BTreeMap<usize, PlayAnnotation>,
+    /// Cards left out of play (the aside after any exchange); only scored
+    /// under [`LastTrickScoring::TakeAside`](crate::models::LastTrickScoring::TakeAside)
+    pub aside: Vec<Card>,
+}
+
+impl GameRecord {
+    /// Returns the annotation of action `turn`, if any
+    #[must_use]
+    pub fn annotation(&self, turn: usize) -> Option<&PlayAnnotation> {
+        self.annotations.get(&turn)
+    }
+}
+
+/// The trick in progress while replaying.
+pub(crate) struct OpenRound {
+    /// Cards played so far this round
+    pub(crate) cards: Vec<Card>,
+    /// Seat of the last player
+    pub(crate) leader: usize,
+    /// Pattern of the last play
+    pub(crate) pattern: PlayPattern,
+    passes: usize,
+}
+
+/// A step reported by [`replay`].
+pub(crate) enum ReplayStep<'a> {
+    /// An action is about to be applied
+    Action {
+        /// Action index
+        turn: usize,
+        /// 1-based round number
+        round_number: usize,
+        /// Seat of the acting player
+        seat: usize,
+        /// Hands before the action
+        hands: &'a [Vec<Card>],
+        /// The round being answered (`None` when leading)
+        open: Option<&'a OpenRound>,
+        /// The action itself
+        action: &'a RecordedAction,
+        /// Client annotation of the action
+        annotation: Option<&'a PlayAnnotation>,
+    },
+    /// A round was won by its last player
+    RoundClosed {
+        /// The finished round
+        round: OpenRound,
+        /// 1-based round number
+        round_number: usize,
+    },
+}
+
+/// Report a rule broken at `turn` as a record error.
+///
+/// Unrecognized and non-beating plays keep their dedicated messages; every
+/// other play error is wrapped in [`Message::RecordRuleViolation`].
+fn record_error(
+    turn: usize,
+    player_id: &str,
+    cards: &[Card],
+    error: DatongziError,
+) -> DatongziError {
+    let DatongziError::PlayError(reason) = error else {
+        return error;
+    };
+    DatongziError::InvalidInput(match reason {
+        Message::InvalidPattern { .. } => Message::RecordUnrecognizedPlay { turn },
+        Message::CannotBeat { .. } => Message::RecordIllegalPlay {
+            turn,
+            player_id: player_id.to_string(),
+            cards: cards.to_vec(),
+        },
+        reason => Message::RecordRuleViolation {
+            turn,
+            player_id: player_id.to_string(),
+            reason: Box::new(reason),
+        },
+    })
+}
+
+/// Walk a record's actions against its dealt hands, validating each one
+/// under `config` (see [`PlayValidator::validate_play_in_hand`] and
+/// [`PlayValidator::validate_pass`]).
+///
+/// Returns the finish order (player IDs in the order hands emptied).
+pub(crate) fn replay(
+    record: &GameRecord,
+    config: &GameConfig,
+    mut visit: impl FnMut(ReplayStep<'_>),
+) -> Result<Vec<String>> {
+    if record.hands.len() != record.player_ids.len() {
+        return Err(DatongziError::InvalidInput(Message::RecordHandCount {
+            hands: record.hands.len(),
+            players: record.player_ids.len(),
+        }));
+    }
+
+    let mut hands = record.hands.clone();
+    let mut finish_order: Vec<String> = Vec::new();
+    let mut open: Option<OpenRound> = None;
+    let mut round_number = 1;
+
+    let seat = |player_id: &str| {
+        record
+            .player_ids
+            .iter()
+            .position(|id| id == player_id)
+            .ok_or_else(|| {
+                DatongziError::InvalidInput(Message::UnknownPlayer {
+                    player_id: player_id.to_string(),
+                })
+            })
+    };
+
+    for (turn, action) in record.actions.iter().enumerate() {
+        match action {
+            RecordedAction::Play { player_id, cards } => {
+                let player = seat(player_id)?;
+                let current = open.as_ref().map(|round| &round.pattern);
+                if let Some(card) = cards.iter().find(|card| {
+                    cards.iter().filter(|c| c == card).count()
+                        > hands[player].iter().filter(|c| c == card).count()
+                }) {
+                    return Err(DatongziError::InvalidInput(Message::RecordCardNotHeld {
+                        turn,
+                        player_id: player_id.clone(),
+                        card: *card,
+                    }));
+                }
+                let pattern = PlayValidator::validate_play_in_hand(
+                    cards,
+                    current,
+                    hands[player].len(),
+                    config,
+                )
+                .map_err(|error| record_error(turn, player_id, cards, error))?;
+
+                visit(ReplayStep::Action {
+                    turn,
+                    round_number,
+                    seat: player,
+                    hands: &hands,
+                    open: open.as_ref(),
+                    action,
+                    annotation: record.annotation(turn),
+                });
+
+                let before = invariants::ENABLED.then(|| hands[player].clone());
+                for card in cards {
+                    let hand = &mut hands[player];
+                    if let Some(position) = hand.iter().position(|c| c == card) {
+                        hand.swap_remove(position);
+                    }
+                }
+                if let Some(before) = before {
+                    invariants::assert_conservation(&before, &hands[player], cards);
+                }
+                if hands[player].is_empty() {
+                    finish_order.push(player_id.clone());
+                }
+
+                let mut round_cards = open.take().map(|round| round.cards).unwrap_or_default();
+                round_cards.extend_from_slice(cards);
+                open = Some(OpenRound {
+                    cards: round_cards,
+                    leader: player,
+                    pattern,
+                    passes: 0,
+                });
+            }
+            RecordedAction::Pass { player_id } => {
+                let player = seat(player_id)?;
+                let Some(current) = open.as_ref().map(|round| &round.pattern) else {
+                    return Err(DatongziError::InvalidInput(
+                        Message::RecordPassWithoutPlay {
+                            turn,
+                            player_id: player_id.clone(),
+                        },
+                    ));
+                };
+                PlayValidator::validate_pass(&hands[player], current, config)
+                    .map_err(|error| record_error(turn, player_id, &[], error))?;
+                visit(ReplayStep::Action {
+                    turn,
+                    round_number,
+                    seat: player,
+                    hands: &hands,
+                    open: open.as_ref(),
+                    action,
+                    annotation: record.annotation(turn),
+                });
+                if let Some(round) = open.as_mut() {
+                    round.passes += 1;
+                }
+            }
+        }
+
+        let round_over = open.as_ref().is_some_and(|round| {
+            let others = (0..hands.len())
+                .filter(|p| *p != round.leader && !hands[*p].is_empty())
+                .count();
+            round.passes >= others
+        });
+        if round_over {
+            if let Some(round) = open.take() {
+                visit(ReplayStep::RoundClosed {
+                    round,
+                    round_number,
+                });
+                round_number += 1;
+            }
+        }
+    }
+
+    if let Some(round) = open.take() {
+        visit(ReplayStep::RoundClosed {
+            round,
+            round_number,
+        });
+    }
+    Ok(finish_order)
+}
+
+/// Recompute a game's scoring purely from its record.
+///
+/// Replays the actions against the dealt hands to derive every event:
+/// round wins and Tongzi/Dizha bonuses for each round's last play, finish
+/// bonuses in the order hands empty, and reveal multipliers. A round closes
+/// once every other player still holding cards has passed; a round still
+/// open when the record ends goes to its last player. The final round also
+/// gets the configured [`last_trick_scoring`](GameConfig::last_trick_scoring)
+/// event. Every play and pass
+/// also feeds the summary's [`pressure`](GameSummary::pressure) statistics.
+///
+/// # Errors
+///
+/// Returns [`DatongziError::InvalidInput`] if the record is inconsistent
+/// (unknown player, card not in hand, unrecognized or non-beating play,
+/// pass with nothing to beat), or a reveal error from
+/// [`ScoreComputation::declare_reveal`].
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use datongzi_rules::scoring::{score_record, GameRecord, RecordedAction};
+/// use datongzi_rules::{Card, GameConfig, Rank, Suit};
+///
+/// let king = Card::new(Suit::Spades, Rank::King);
+/// let three = Card::new(Suit::Hearts, Rank::Three);
+/// let record = GameRecord {
+///     player_ids: vec!["a".to_string(), "b".to_string()],
+///     hands: vec![vec![king], vec![three, three]],
+///     actions: vec![RecordedAction::Play { player_id: "a".to_string(), cards: vec![king] }],
+///     reveals: Vec::new(),
+///     shuffle: None,
+///     annotations: BTreeMap::new(),
+///     aside: Vec::new(),
+/// };
+///
+/// let summary = score_record(&record, &GameConfig::default()).unwrap();
+/// // K is worth 10, plus the 上游 finish bonus
+/// assert_eq!(summary.final_scores["a"], 10 + GameConfig::default().finish_bonus()[0]);
+/// ```
+pub fn score_record(record: &GameRecord, config: &GameConfig) -> Result<GameSummary> {
+    let mut engine = ScoreComputation::new(config.clone());
+    // Each round is closed once the next one ends, so the last is known
+    let mut last_round: Option<(OpenRound, usize)> = None;
+    let finish_order = replay(record, config, |step| match step {
+        ReplayStep::Action {
+            open,
+            action: RecordedAction::Play { player_id, cards },
+            ..
+        } => {
+            let beaten = open.map(|round| record.player_ids[round.leader].as_str());
+            engine.record_play(player_id, cards.len(), beaten);
+        }
+        ReplayStep::Action {
+            action: RecordedAction::Pass { player_id },
+            ..
+        } => engine.record_pass(player_id),
+        ReplayStep::RoundClosed {
+            round,
+            round_number,
+        } => {
+            if let Some((round, round_number)) = last_round.replace((round, round_number)) {
+                close_round(&mut engine, record, &round, round_number, false);
+            }
+        }
+    })?;
+    if let Some((round, round_number)) = last_round {
+        close_round(&mut engine, record, &round, round_number, true);
+    }
+
+    engine.create_finish_bonus_events(&finish_order);
+    if !record.reveals.is_empty() {
+        for declaration in &record.reveals {
+            engine.declare_reveal(declaration.clone())?;
+        }
+        engine.create_reveal_multiplier_events();
+    }
+
+    Ok(engine.get_game_summary(&record.player_ids))
+}
+
+/// Award a finished round to its last player.
+fn close_round(
+    engine: &mut ScoreComputation,
+    record: &GameRecord,
+    round: &OpenRound,
+    round_number: usize,
+    is_last: bool,
+) {
+    let winner = &record.player_ids[round.leader];
+    engine.create_round_win_event(winner.clone(), &round.cards, round_number);
+    if is_last {
+        engine.create_last_trick_event(winner.clone(), &round.cards, &record.aside, round_number);
+    }
+    engine.create_special_bonus_events(winner.clone(), &round.pattern, round_number, true);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        CardsLeftAlert, FinishRestrictions, LastTrickScoring, LeadRestrictions, Rank, Suit,
+    };
+
+    fn play(player_id: &str, cards: &[Card]) -> RecordedAction {
+        RecordedAction::Play {
+            player_id: player_id.to_string(),
+            cards: cards.to_vec(),
+        }
+    }
+
+    fn pass(player_id: &str) -> RecordedAction {
+        RecordedAction::Pass {
+            player_id: player_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_rounds_close_after_all_others_pass() {
+        let five = Card::new(Suit::Spades, Rank::Five);
+        let ten = Card::new(Suit::Hearts, Rank::Ten);
+        let six = Card::new(Suit::Clubs, Rank::Six);
+        let seven = Card::new(Suit::Clubs, Rank::Seven);
+        let record = GameRecord {
+            player_ids: vec!["a".into(), "b".into(), "c".into()],
+            hands: vec![vec![five, six], vec![ten], vec![seven, seven]],
+            actions: vec![
+                play("a", &[five]),
+                play("b", &[ten]), // b is out; a and c must pass
+                pass("c"),
+                pass("a"),
+                play("c", &[seven]),
+                pass("a"),
+                play("c", &[seven]),
+            ],
+            reveals: Vec::new(),
+            shuffle: None,
+            annotations: BTreeMap::new(),
+            aside: Vec::new(),
+        };
+
+        let config = GameConfig::default();
+        let summary = score_record(&record, &config).unwrap();
+        let finish = config.finish_bonus();
+        assert_eq!(summary.final_scores["b"], 15 + finish[0]);
+        assert_eq!(summary.final_scores["c"], finish[1]);
+        assert_eq!(summary.final_scores["a"], 0);
+
+        let pressure = &summary.pressure;
+        assert_eq!(pressure["a"].times_beaten, 1);
+        assert_eq!(pressure["a"].passes, 2);
+        assert_eq!(pressure["b"].beats, 1);
+        assert_eq!(pressure["c"].plays, 2);
+        assert!((pressure["c"].average_play_size() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_last_trick_scoring_conserves_points() {
+        let five = Card::new(Suit::Spades, Rank::Five);
+        let ten = Card::new(Suit::Hearts, Rank::Ten);
+        let king = Card::new(Suit::Clubs, Rank::King);
+        let record = GameRecord {
+            player_ids: vec!["a".into(), "b".into()],
+            hands: vec![vec![five, king], vec![ten]],
+            actions: vec![
+                play("a", &[five]),
+                play("b", &[ten]),
+                pass("a"),
+                play("a", &[king]),
+            ],
+            aside: vec![Card::new(Suit::Diamonds, Rank::Five), ten],
+            ..GameRecord::default()
+        };
+        let card_points = |cards: &[Card]| -> i32 {
+            cards
+                .iter()
+                .filter(|c| c.is_scoring_card())
+                .map(|c| c.score_value())
+                .sum()
+        };
+        let dealt: i32 = record.hands.iter().map(|hand| card_points(hand)).sum();
+        let aside = card_points(&record.aside);
+
+        for (scoring, expected) in [
+            (LastTrickScoring::Standard, dealt),
+            (LastTrickScoring::Double, dealt + 10),
+            (LastTrickScoring::TakeAside, dealt + aside),
+        ] {
+            let config = GameConfig {
+                last_trick_scoring: scoring,
+                ..GameConfig::default()
+            };
+            let summary = score_record(&record, &config).unwrap();
+            let finish = config.finish_bonus();
+            // Only the final trick (a's K) is affected
+            assert_eq!(summary.final_scores["b"], 15 + finish[0]);
+            let awarded: i32 = summary.final_scores.values().sum::<i32>() - finish[0] - finish[1];
+            assert_eq!(awarded, expected, "{scoring:?}");
+        }
+    }
+
+    #[test]
+    fn test_replay_enforces_config_rules() {
+        let record = |a: Vec<Card>, b: Vec<Card>, actions: Vec<RecordedAction>| GameRecord {
+            player_ids: vec!["a".into(), "b".into()],
+            hands: vec![a, b],
+            actions,
+            ..GameRecord::default()
+        };
+        let violation =
+            |record: &GameRecord, config: &GameConfig| match score_record(record, config) {
+                Err(DatongziError::InvalidInput(Message::RecordRuleViolation {
+                    reason, ..
+                })) => Some(*reason),
+                _ => None,
+            };
+        let nine = |suit| Card::new(suit, Rank::Nine);
+        let king = Card::new(Suit::Spades, Rank::King);
+        let six = Card::new(Suit::Hearts, Rank::Six);
+
+        // Removed ranks
+        let three = Card::new(Suit::Spades, Rank::Three);
+        let threes = record(vec![three, six], vec![six], vec![play("a", &[three])]);
+        assert!(matches!(
+            violation(&threes, &GameConfig::default()),
+            Some(Message::RemovedRank { .. })
+        ));
+        let no_removed = GameConfig {
+            removed_ranks: Vec::new(),
+            ..GameConfig::default()
+        };
+        assert!(score_record(&threes, &no_removed).is_ok());
+
+        // Comparison order: an equal-rank single of a higher suit
+        let tiebreak = record(
+            vec![nine(Suit::Hearts), six],
+            vec![nine(Suit::Spades), six],
+            vec![
+                play("a", &[nine(Suit::Hearts)]),
+                play("b", &[nine(Suit::Spades)]),
+            ],
+        );
+        assert!(matches!(
+            score_record(&tiebreak, &GameConfig::default()),
+            Err(DatongziError::InvalidInput(Message::RecordIllegalPlay {
+                turn: 1,
+                ..
+            }))
+        ));
+        let suits_break_ties = GameConfig {
+            singles_suit_tiebreak: true,
+            ..GameConfig::default()
+        };
+        assert!(score_record(&tiebreak, &suits_break_ties).is_ok());
+
+        // Forbidden kickers
+        let mut triple: Vec<Card> = [Suit::Spades, Suit::Hearts, Suit::Clubs]
+            .iter()
+            .map(|suit| nine(*suit))
+            .collect();
+        triple.push(Card::new(Suit::Clubs, Rank::Two));
+        let kicker = record(triple.clone(), vec![six], vec![play("a", &triple)]);
+        let no_two_kickers = GameConfig {
+            forbidden_kicker_ranks: vec![Rank::Two],
+            ..GameConfig::default()
+        };
+        assert!(matches!(
+            violation(&kicker, &no_two_kickers),
+            Some(Message::ForbiddenKicker { .. })
+        ));
+
+        // Lead restrictions
+        let tongzi = vec![nine(Suit::Spades); 3];
+        let mut hand = tongzi.clone();
+        hand.push(six);
+        let lead = record(hand, vec![six], vec![play("a", &tongzi)]);
+        let no_tongzi_lead = GameConfig {
+            lead_restrictions: LeadRestrictions {
+                no_tongzi_lead: true,
+                ..LeadRestrictions::default()
+            },
+            ..GameConfig::default()
+        };
+        assert!(matches!(
+            violation(&lead, &no_tongzi_lead),
+            Some(Message::LeadRestricted { .. })
+        ));
+
+        // Finish restrictions
+        let finish = record(vec![king], vec![six], vec![play("a", &[king])]);
+        let no_score_finish = GameConfig {
+            finish_restrictions: FinishRestrictions {
+                no_single_score_card: true,
+                ..FinishRestrictions::default()
+            },
+            ..GameConfig::default()
+        };
+        assert!(matches!(
+            violation(&finish, &no_score_finish),
+            Some(Message::FinishOnScoreCard { .. })
+        ));
+
+        // Must beat under the cards-left alert
+        let ace = Card::new(Suit::Hearts, Rank::Ace);
+        let must_beat = record(
+            vec![king, six],
+            vec![ace, six],
+            vec![play("a", &[king]), pass("b")],
+        );
+        let alert = GameConfig {
+            cards_left_alert: Some(CardsLeftAlert {
+                threshold: 2,
+                must_beat: true,
+            }),
+            ..GameConfig::default()
+        };
+        assert!(matches!(
+            violation(&must_beat, &alert),
+            Some(Message::MustBeat { cards_left: 2 })
+        ));
+        assert!(score_record(&must_beat, &GameConfig::default()).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_inconsistent_records() {
+        let ace = Card::new(Suit::Spades, Rank::Ace);
+        let mut record = GameRecord {
+            player_ids: vec!["a".into(), "b".into()],
+            hands: vec![vec![ace], vec![]],
+            actions: vec![pass("a")],
+            reveals: Vec::new(),
+            shuffle: None,
+            annotations: BTreeMap::new(),
+            aside: Vec::new(),
+        };
+        assert!(score_record(&record, &GameConfig::default()).is_err());
+
+        record.actions = vec![play("b", &[ace])];
+        assert!(score_record(&record, &GameConfig::default()).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_annotations_survive_serde() {
+        let five = Card::new(Suit::Spades, Rank::Five);
+        let record = GameRecord {
+            player_ids: vec!["a".into()],
+            hands: vec![vec![five]],
+            actions: vec![play("a", &[five])],
+            annotations: BTreeMap::from([(
+                0,
+                PlayAnnotation {
+                    latency_ms: Some(1200),
+                    hint_used: false,
+                    reaction: Some("🎉".into()),
+                },
+            )]),
+            ..GameRecord::default()
+        };
+
+        let json = serde_json::to_string(&record).unwrap();
+        let restored: GameRecord = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, record);
+        assert_eq!(restored.annotation(0).unwrap().latency_ms, Some(1200));
+    }
+}
@@ -0,0 +1,323 @@
+//! Per-seat deal statistics across many seeds.
+
+use crate::ai_helpers::HandPatternAnalyzer;
+use crate::error::{DatongziError, Result};
+use crate::i18n::Message;
+use crate::models::{Card, GameConfig};
+use crate::puzzles::deal_from_seed;
+
+/// Running mean and variance (Welford), resumable from its fields.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RunningStats {
+    /// Values seen
+    pub count: u64,
+    /// Mean of the values
+    pub mean: f64,
+    /// Sum of squared deviations from the mean
+    pub m2: f64,
+}
+
+impl RunningStats {
+    /// Add one value
+    pub fn add(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Sample standard deviation (0 for fewer than two values)
+    #[must_use]
+    pub fn std_dev(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            (self.m2 / (self.count - 1) as f64).sqrt()
+        }
+    }
+}
+
+/// What one seat received on average.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeatStats {
+    /// Seat index in deal order
+    pub seat: usize,
+    /// Mean trumps (bombs, Tongzi, Dizha) per hand
+    pub mean_trumps: f64,
+    /// Standard deviation of trumps per hand
+    pub std_dev_trumps: f64,
+    /// Mean score-card points (5/10/K) per hand
+    pub mean_score_points: f64,
+    /// Standard deviation of score-card points per hand
+    pub std_dev_score_points: f64,
+}
+
+/// Progress of a [`deal_fairness_report_checkpointed`] run.
+///
+/// All fields are plain data, so callers can persist a checkpoint in any
+/// format and rebuild it to resume.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DealFairnessCheckpoint {
+    /// Seeds `0..completed` are done
+    pub completed: u64,
+    /// Trumps per hand, one entry per seat
+    pub trumps: Vec<RunningStats>,
+    /// Score-card points per hand, one entry per seat
+    pub points: Vec<RunningStats>,
+}
+
+impl DealFairnessCheckpoint {
+    fn new(seats: usize) -> Self {
+        Self {
+            completed: 0,
+            trumps: vec![RunningStats::default(); seats],
+            points: vec![RunningStats::default(); seats],
+        }
+    }
+
+    fn report(&self) -> DealFairnessReport {
+        DealFairnessReport {
+            deals: self.completed,
+            seats: (0..self.trumps.len())
+                .map(|seat| SeatStats {
+                    seat,
+                    mean_trumps: self.trumps[seat].mean,
+                    std_dev_trumps: self.trumps[seat].std_dev(),
+                    mean_score_points: self.points[seat].mean,
+                    std_dev_score_points: self.points[seat].std_dev(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Summary of per-seat deal statistics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DealFairnessReport {
+    /// Deals sampled
+    pub deals: u64,
+    /// One entry per seat
+    pub seats: Vec<SeatStats>,
+}
+
+impl DealFairnessReport {
+    /// Largest difference between two seats' mean trumps
+    #[must_use]
+    pub fn trump_gap(&self) -> f64 {
+        Self::gap(self.seats.iter().map(|s| s.mean_trumps))
+    }
+
+    /// Largest difference between two seats' mean score points
+    #[must_use]
+    pub fn score_gap(&self) -> f64 {
+        Self::gap(self.seats.iter().map(|s| s.mean_score_points))
+    }
+
+    /// [`score_gap`](Self::score_gap) in standard errors of a difference of
+    /// two seat means.
+    ///
+    /// Under a fair shuffle this rarely exceeds 3-4; larger values point at
+    /// dealing-order bias.
+    #[must_use]
+    pub fn score_gap_z(&self) -> f64 {
+        let n = self.seats.len().max(1) as f64;
+        let pooled = self
+            .seats
+            .iter()
+            .map(|s| s.std_dev_score_points.powi(2))
+            .sum::<f64>()
+            / n;
+        let standard_error = (2.0 * pooled / self.deals.max(1) as f64).sqrt();
+        if standard_error == 0.0 {
+            0.0
+        } else {
+            self.score_gap() / standard_error
+        }
+    }
+
+    fn gap(values: impl Iterator<Item = f64> + Clone) -> f64 {
+        let max = values.clone().fold(f64::MIN, f64::max);
+        let min = values.fold(f64::MAX, f64::min);
+        if max < min {
+            0.0
+        } else {
+            max - min
+        }
+    }
+}
+
+/// Deal `n_seeds` games with [`deal_from_seed`] (seeds `0..n_seeds`) and
+/// report per-seat trump and score-card statistics.
+///
+/// # Errors
+///
+/// Returns an error if `n_seeds` is 0 or the configuration cannot be dealt
+///
+/// # Example
+/// ```
+/// use datongzi_rules::simulation::deal_fairness_report;
+/// use datongzi_rules::GameConfig;
+///
+/// let report = deal_fairness_report(&GameConfig::default(), 50).unwrap();
+/// assert_eq!(report.seats.len(), 3);
+/// assert!(report.score_gap_z() < 6.0);
+/// ```
+pub fn deal_fairness_report(config: &GameConfig, n_seeds: u64) -> Result<DealFairnessReport> {
+    deal_fairness_report_with(config, n_seeds, |seed| {
+        deal_from_seed(config, seed).map(|(hands, _)| hands)
+    })
+}
+
+/// Like [`deal_fairness_report`], with hands from `deal(seed)`.
+///
+/// Use this to validate a new shuffle implementation before deploying it.
+///
+/// # Errors
+///
+/// Returns an error if `n_seeds` is 0, or the first error from `deal`
+pub fn deal_fairness_report_with(
+    config: &GameConfig,
+    n_seeds: u64,
+    deal: impl FnMut(u64) -> Result<Vec<Vec<Card>>>,
+) -> Result<DealFairnessReport> {
+    run(config, n_seeds, deal, None, u64::MAX, |_| Ok(()))
+}
+
+/// Like [`deal_fairness_report`], saving progress to `sink` every `every`
+/// seeds and once at the end, and continuing from `resume` if given.
+///
+/// Seeds are dealt in order, so a resumed run gives the same report as an
+/// uninterrupted one.
+///
+/// # Errors
+///
+/// Returns an error if `n_seeds` is 0, the configuration cannot be dealt,
+/// `resume` has a different seat count, or `sink` fails
+///
+/// # Example
+/// ```
+/// use datongzi_rules::simulation::{deal_fairness_report, deal_fairness_report_checkpointed};
+/// use datongzi_rules::GameConfig;
+///
+/// let config = GameConfig::default();
+/// let mut saved = Vec::new();
+/// deal_fairness_report_checkpointed(&config, 20, None, 10, |c| {
+///     saved.push(c.clone());
+///     Ok(())
+/// })
+/// .unwrap();
+///
+/// // Resume from the first checkpoint as if the run had stopped there
+/// let resumed =
+///     deal_fairness_report_checkpointed(&config, 20, Some(saved[0].clone()), 10, |_| Ok(())).unwrap();
+/// assert_eq!(resumed, deal_fairness_report(&config, 20).unwrap());
+/// ```
+pub fn deal_fairness_report_checkpointed(
+    config: &GameConfig,
+    n_seeds: u64,
+    resume: Option<DealFairnessCheckpoint>,
+    every: u64,
+    sink: impl FnMut(&DealFairnessCheckpoint) -> Result<()>,
+) -> Result<DealFairnessReport> {
+    run(
+        config,
+        n_seeds,
+        |seed| deal_from_seed(config, seed).map(|(hands, _)| hands),
+        resume,
+        every.max(1),
+        sink,
+    )
+}
+
+fn run(
+    config: &GameConfig,
+    n_seeds: u64,
+    mut deal: impl FnMut(u64) -> Result<Vec<Vec<Card>>>,
+    resume: Option<DealFairnessCheckpoint>,
+    every: u64,
+    mut sink: impl FnMut(&DealFairnessCheckpoint) -> Result<()>,
+) -> Result<DealFairnessReport> {
+    if n_seeds == 0 {
+        return Err(DatongziError::InvalidInput(Message::NoSeeds));
+    }
+
+    let seats = usize::from(config.num_players());
+    let mut progress = resume.unwrap_or_else(|| DealFairnessCheckpoint::new(seats));
+    if progress.trumps.len() != seats || progress.points.len() != seats {
+        return Err(DatongziError::InvalidInput(Message::CheckpointSeats {
+            seats: progress.trumps.len(),
+            expected: seats,
+        }));
+    }
+
+    while progress.completed < n_seeds {
+        for (seat, hand) in deal(progress.completed)?.iter().enumerate().take(seats) {
+            let patterns = HandPatternAnalyzer::analyze_patterns(hand);
+            progress.trumps[seat].add(patterns.trump_count as f64);
+            progress.points[seat].add(f64::from(hand.iter().map(Card::score_value).sum::<i32>()));
+        }
+        progress.completed += 1;
+        if progress.completed % every == 0 || progress.completed == n_seeds {
+            sink(&progress)?;
+        }
+    }
+
+    Ok(progress.report())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+
+    #[test]
+    fn test_detects_a_biased_dealer() {
+        let config = GameConfig::default();
+        let king = Card::new(Suit::Spades, Rank::King);
+        let three = Card::new(Suit::Spades, Rank::Three);
+
+        // Seat 0 always gets more kings, with some noise so variance is nonzero
+        let report = deal_fairness_report_with(&config, 200, |seed| {
+            let extra = usize::try_from(seed % 3).unwrap_or(0);
+            Ok(vec![
+                vec![king; 4 + extra],
+                vec![king, three, three],
+                vec![three; 3],
+            ])
+        })
+        .unwrap();
+
+        assert!((report.seats[0].mean_score_points - 50.0).abs() < 1.0);
+        assert!(report.score_gap() > 40.0);
+        assert!(report.score_gap_z() > 10.0);
+    }
+
+    #[test]
+    fn test_resume_rejects_wrong_seat_count() {
+        let checkpoint = DealFairnessCheckpoint::new(4);
+        let result = deal_fairness_report_checkpointed(
+            &GameConfig::default(),
+            5,
+            Some(checkpoint),
+            1,
+            |_| Ok(()),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_checkpoints_follow_interval() {
+        let mut completed = Vec::new();
+        deal_fairness_report_checkpointed(&GameConfig::default(), 7, None, 3, |c| {
+            completed.push(c.completed);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(completed, vec![3, 6, 7]);
+    }
+
+    #[test]
+    fn test_rejects_zero_seeds() {
+        assert!(deal_fairness_report(&GameConfig::default(), 0).is_err());
+    }
+}
@@ -0,0 +1,18 @@
+//! Monte Carlo checks over many seeded deals.
+//!
+//! This module contains:
+//! - [`deal_fairness_report`]: Per-seat trump and score-card statistics, to
+//!   catch dealing-order bias in a shuffle implementation
+//! - [`deal_fairness_report_checkpointed`]: The same, saving resumable
+//!   [`DealFairnessCheckpoint`]s for long runs
+//! - [`tongzi_parity_report`]: Which suit wins same-rank Tongzi contests,
+//!   to judge whether a variant should randomize the Tongzi suit order
+
+mod deal_fairness;
+mod tongzi_parity;
+
+pub use deal_fairness::{
+    deal_fairness_report, deal_fairness_report_checkpointed, deal_fairness_report_with,
+    DealFairnessCheckpoint, DealFairnessReport, RunningStats, SeatStats,
+};
+pub use tongzi_parity::{tongzi_parity_report, TongziParityReport};
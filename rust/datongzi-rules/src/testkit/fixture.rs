@@ -0,0 +1,234 @@
+//! One-line text fixtures for recognition and comparison calls.
+
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+use crate::error::{DatongziError, Result};
+use crate::i18n::Message;
+use crate::models::Card;
+use crate::patterns::{PatternRecognizer, PlayType, PlayValidator};
+
+const PLAY_TYPES: [PlayType; 9] = [
+    PlayType::Single,
+    PlayType::Pair,
+    PlayType::ConsecutivePairs,
+    PlayType::Triple,
+    PlayType::Airplane,
+    PlayType::AirplaneWithWings,
+    PlayType::Bomb,
+    PlayType::Tongzi,
+    PlayType::Dizha,
+];
+
+/// A recorded call and the output it produced.
+///
+/// Written one per line, with cards in display form and `-` for no cards:
+///
+/// ```text
+/// recognize 5♠ 5♥ => Pair
+/// recognize 5♠ 6♥ => none
+/// beats 6♠ 6♥ | 5♠ 5♥ => true
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FixtureCase {
+    /// [`PatternRecognizer::analyze_cards`]
+    Recognize {
+        /// Cards analyzed
+        cards: Vec<Card>,
+        /// Recognized play type; `None` if the cards form no pattern
+        play_type: Option<PlayType>,
+    },
+    /// [`PlayValidator::can_beat_play`]
+    Beats {
+        /// Cards played
+        cards: Vec<Card>,
+        /// Play being answered; empty when leading
+        current: Vec<Card>,
+        /// Whether `cards` beat `current`
+        beats: bool,
+    },
+}
+
+impl FixtureCase {
+    /// Record the current recognizer output for `cards`
+    #[must_use]
+    pub fn recognize(cards: &[Card]) -> Self {
+        Self::Recognize {
+            cards: cards.to_vec(),
+            play_type: PatternRecognizer::analyze_cards(cards).map(|p| p.play_type),
+        }
+    }
+
+    /// Record whether `cards` currently beat `current`
+    #[must_use]
+    pub fn beats(cards: &[Card], current: &[Card]) -> Self {
+        let current_pattern = if current.is_empty() {
+            None
+        } else {
+            PatternRecognizer::analyze_cards(current)
+        };
+        Self::Beats {
+            cards: cards.to_vec(),
+            current: current.to_vec(),
+            beats: PlayValidator::can_beat_play(cards, current_pattern.as_ref()),
+        }
+    }
+
+    /// Re-run the call; true if it still produces the recorded output
+    #[must_use]
+    pub fn still_holds(&self) -> bool {
+        let rerun = match self {
+            Self::Recognize { cards, .. } => Self::recognize(cards),
+            Self::Beats { cards, current, .. } => Self::beats(cards, current),
+        };
+        rerun == *self
+    }
+
+    /// Parse one fixture line
+    #[must_use]
+    pub fn parse(line: &str) -> Option<Self> {
+        let (call, output) = line.trim().split_once(" => ")?;
+        if let Some(cards) = call.strip_prefix("recognize ") {
+            let play_type = match output {
+                "none" => None,
+                name => Some(*PLAY_TYPES.iter().find(|t| format!("{t:?}") == name)?),
+            };
+            return Some(Self::Recognize {
+                cards: parse_cards(cards)?,
+                play_type,
+            });
+        }
+
+        let (cards, current) = call.strip_prefix("beats ")?.split_once(" | ")?;
+        Some(Self::Beats {
+            cards: parse_cards(cards)?,
+            current: parse_cards(current)?,
+            beats: output.parse().ok()?,
+        })
+    }
+}
+
+fn parse_cards(text: &str) -> Option<Vec<Card>> {
+    if text.trim() == "-" {
+        return Some(Vec::new());
+    }
+    text.split_whitespace().map(Card::from_display).collect()
+}
+
+fn write_cards(f: &mut fmt::Formatter<'_>, cards: &[Card]) -> fmt::Result {
+    if cards.is_empty() {
+        return write!(f, "-");
+    }
+    let tokens: Vec<String> = cards.iter().map(Card::to_string).collect();
+    write!(f, "{}", tokens.join(" "))
+}
+
+impl fmt::Display for FixtureCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Recognize { cards, play_type } => {
+                write!(f, "recognize ")?;
+                write_cards(f, cards)?;
+                match play_type {
+                    Some(play_type) => write!(f, " => {play_type:?}"),
+                    None => write!(f, " => none"),
+                }
+            }
+            Self::Beats {
+                cards,
+                current,
+                beats,
+            } => {
+                write!(f, "beats ")?;
+                write_cards(f, cards)?;
+                write!(f, " | ")?;
+                write_cards(f, current)?;
+                write!(f, " => {beats}")
+            }
+        }
+    }
+}
+
+/// Append `case` as one line to the fixture file at `path`, creating it if needed.
+///
+/// # Errors
+///
+/// Returns the I/O error if the file cannot be opened or written
+pub fn append_case(path: impl AsRef<Path>, case: &FixtureCase) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{case}")
+}
+
+/// Parse a fixture file's text; blank lines and `#` comments are skipped.
+///
+/// # Errors
+///
+/// Returns `InvalidInput` with [`Message::FixtureLine`] for the first
+/// malformed line
+pub fn parse_cases(text: &str) -> Result<Vec<FixtureCase>> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(index, line)| {
+            FixtureCase::parse(line).ok_or_else(|| {
+                DatongziError::InvalidInput(Message::FixtureLine {
+                    line: index + 1,
+                    text: line.to_string(),
+                })
+            })
+        })
+        .collect()
+}
+
+/// Cases in a fixture file's text whose recorded output no longer holds.
+///
+/// A regression test asserts this is empty.
+///
+/// # Errors
+///
+/// Same as [`parse_cases`]
+pub fn failing_cases(text: &str) -> Result<Vec<FixtureCase>> {
+    Ok(parse_cases(text)?
+        .into_iter()
+        .filter(|case| !case.still_holds())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+
+    #[test]
+    fn test_line_round_trip() {
+        let ten = Card::new(Suit::Hearts, Rank::Ten);
+        let cases = [
+            FixtureCase::recognize(&[ten, ten]),
+            FixtureCase::recognize(&[ten, Card::new(Suit::Spades, Rank::Two)]),
+            FixtureCase::beats(&[ten], &[]),
+        ];
+        for case in &cases {
+            assert_eq!(FixtureCase::parse(&case.to_string()).as_ref(), Some(case));
+            assert!(case.still_holds());
+        }
+        assert_eq!(cases[2].to_string(), "beats 10♥ | - => true");
+    }
+
+    #[test]
+    fn test_failing_cases_reports_changed_outputs() {
+        let text = "# disputed at table 7\nrecognize 5♠ 5♥ => Pair\n\nbeats 5♠ | 6♠ => true\n";
+        let failing = failing_cases(text).unwrap();
+        assert_eq!(failing.len(), 1);
+        assert!(matches!(failing[0], FixtureCase::Beats { beats: true, .. }));
+
+        assert_eq!(
+            parse_cases("recognize 5♠ => Quad"),
+            Err(DatongziError::InvalidInput(Message::FixtureLine {
+                line: 1,
+                text: "recognize 5♠ => Quad".to_string()
+            }))
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Test helpers for turning exploratory checks into regression fixtures
+//! (requires the `testkit` feature).
+//!
+//! This module contains:
+//! - [`record_case!`](crate::record_case): Run a recognition or comparison
+//!   call and append its inputs and output to a fixture file
+//! - [`FixtureCase`]: One recorded call, in a one-line text format
+//! - [`failing_cases`]: Recorded cases whose output has since changed
+
+mod fixture;
+
+pub use fixture::{append_case, failing_cases, parse_cases, FixtureCase};
+
+/// Run a recognition or comparison call and record it to a fixture file.
+///
+/// `recognize(cards)` evaluates to
+/// [`PatternRecognizer::analyze_cards`](crate::PatternRecognizer::analyze_cards);
+/// `beats(cards, current)` evaluates to
+/// [`PlayValidator::can_beat_play`](crate::PlayValidator::can_beat_play)
+/// against the pattern of `current` (empty `current` means leading). Either
+/// way one [`FixtureCase`] line is appended to the file at `path`.
+///
+/// # Panics
+///
+/// Panics if the fixture file cannot be appended to
+///
+/// # Example
+/// ```
+/// use datongzi_rules::testkit::failing_cases;
+/// use datongzi_rules::{record_case, Card, Rank, Suit};
+///
+/// let path = std::env::temp_dir().join("datongzi-record-case-doc.txt");
+/// let _ = std::fs::remove_file(&path);
+///
+/// let fives = [Card::new(Suit::Spades, Rank::Five), Card::new(Suit::Hearts, Rank::Five)];
+/// let sixes = [Card::new(Suit::Spades, Rank::Six), Card::new(Suit::Hearts, Rank::Six)];
+/// assert!(record_case!(&path, recognize(&fives)).is_some());
+/// assert!(record_case!(&path, beats(&sixes, &fives)));
+///
+/// let fixture = std::fs::read_to_string(&path).unwrap();
+/// assert_eq!(fixture.lines().count(), 2);
+/// assert!(failing_cases(&fixture).unwrap().is_empty());
+/// ```
+#[macro_export]
+macro_rules! record_case {
+    ($path:expr, recognize($cards:expr)) => {{
+        let cards: &[$crate::Card] = $cards;
+        let case = $crate::testkit::FixtureCase::recognize(cards);
+        $crate::testkit::append_case($path, &case).expect("cannot append to fixture file");
+        $crate::PatternRecognizer::analyze_cards(cards)
+    }};
+    ($path:expr, beats($cards:expr, $current:expr)) => {{
+        let cards: &[$crate::Card] = $cards;
+        let current: &[$crate::Card] = $current;
+        let case = $crate::testkit::FixtureCase::beats(cards, current);
+        $crate::testkit::append_case($path, &case).expect("cannot append to fixture file");
+        matches!(
+            case,
+            $crate::testkit::FixtureCase::Beats { beats: true, .. }
+        )
+    }};
+}
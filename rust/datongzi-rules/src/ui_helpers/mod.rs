@@ -0,0 +1,9 @@
+//! Helpers for card-selection user interfaces.
+//!
+//! This module contains:
+//! - [`nearest_valid_plays`]: Minimal add/remove edits that turn an invalid
+//!   selection into a valid play
+
+mod repair;
+
+pub use repair::{nearest_valid_plays, PlayRepair};
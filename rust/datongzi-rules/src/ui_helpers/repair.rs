@@ -0,0 +1,210 @@
+//! Repair suggestions for invalid selections.
+
+use std::collections::HashSet;
+
+use crate::models::Card;
+use crate::patterns::{PatternRecognizer, PlayPattern};
+use crate::plays::sort_canonical;
+
+/// Largest number of single-card edits a suggestion may need.
+const MAX_EDIT_DISTANCE: usize = 2;
+
+/// Most suggestions returned; a touch UI can show only a few.
+const MAX_SUGGESTIONS: usize = 8;
+
+/// A valid play close to a selection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayRepair {
+    /// The resulting play, in canonical order
+    pub cards: Vec<Card>,
+    /// Its pattern
+    pub pattern: PlayPattern,
+    /// Selected cards to deselect
+    pub removed: Vec<Card>,
+    /// Hand cards to select
+    pub added: Vec<Card>,
+}
+
+impl PlayRepair {
+    /// Number of single-card edits
+    #[must_use]
+    pub fn distance(&self) -> usize {
+        self.removed.len() + self.added.len()
+    }
+}
+
+/// Suggest valid plays reachable from `selection` by deselecting and/or
+/// selecting at most two cards.
+///
+/// Suggestions are ranked by edit distance, then removals before
+/// additions (a stray tap is the usual mistake), then weakest pattern
+/// first. A valid selection is returned as its own zero-edit suggestion.
+/// Returns nothing if `selection` is not part of `hand`.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::ui_helpers::nearest_valid_plays;
+/// use datongzi_rules::{Card, PlayType, Rank, Suit};
+///
+/// let nine = Card::new(Suit::Spades, Rank::Nine);
+/// let nine2 = Card::new(Suit::Hearts, Rank::Nine);
+/// let jack = Card::new(Suit::Clubs, Rank::Jack);
+/// let hand = vec![nine, nine2, jack];
+///
+/// // Tapped J by mistake while selecting a pair of 9s
+/// let repairs = nearest_valid_plays(&[nine, nine2, jack], &hand);
+/// assert_eq!(repairs[0].removed, vec![jack]);
+/// assert_eq!(repairs[0].pattern.play_type, PlayType::Pair);
+/// ```
+#[must_use]
+pub fn nearest_valid_plays(selection: &[Card], hand: &[Card]) -> Vec<PlayRepair> {
+    let mut unselected = hand.to_vec();
+    for card in selection {
+        match unselected.iter().position(|c| c == card) {
+            Some(position) => {
+                unselected.swap_remove(position);
+            }
+            None => return Vec::new(),
+        }
+    }
+    let mut removable = selection.to_vec();
+    removable.sort();
+    removable.dedup();
+    unselected.sort();
+
+    let mut seen = HashSet::new();
+    let mut repairs = Vec::new();
+    let mut frontier = vec![(selection.to_vec(), Vec::new(), Vec::new())];
+    for distance in 0..=MAX_EDIT_DISTANCE {
+        let mut next = Vec::new();
+        for (cards, removed, added) in frontier {
+            let mut key = cards.clone();
+            key.sort();
+            if !seen.insert(key) {
+                continue;
+            }
+            if let Some(pattern) = PatternRecognizer::analyze_cards(&cards) {
+                let mut cards = cards.clone();
+                sort_canonical(&mut cards, &pattern);
+                repairs.push(PlayRepair {
+                    cards,
+                    pattern,
+                    removed: removed.clone(),
+                    added: added.clone(),
+                });
+            }
+            if distance == MAX_EDIT_DISTANCE {
+                continue;
+            }
+            next.extend(removals(&cards, &removable, &removed, &added));
+            next.extend(additions(&cards, &unselected, &removed, &added));
+        }
+        frontier = next;
+    }
+
+    repairs.sort_by(|a, b| {
+        (a.distance(), a.added.len(), a.pattern.strength, &a.cards).cmp(&(
+            b.distance(),
+            b.added.len(),
+            b.pattern.strength,
+            &b.cards,
+        ))
+    });
+    repairs.truncate(MAX_SUGGESTIONS);
+    repairs
+}
+
+type Edit = (Vec<Card>, Vec<Card>, Vec<Card>);
+
+/// Deselect one more selected card (never one just added).
+fn removals<'a>(
+    cards: &'a [Card],
+    removable: &'a [Card],
+    removed: &'a [Card],
+    added: &'a [Card],
+) -> impl Iterator<Item = Edit> + 'a {
+    removable
+        .iter()
+        .filter(|card| cards.contains(card) && !added.contains(card))
+        .map(move |card| {
+            let mut cards = cards.to_vec();
+            if let Some(position) = cards.iter().position(|c| c == card) {
+                cards.remove(position);
+            }
+            let mut removed = removed.to_vec();
+            removed.push(*card);
+            (cards, removed, added.to_vec())
+        })
+}
+
+/// Select one more unselected hand card (never one just removed).
+fn additions<'a>(
+    cards: &'a [Card],
+    unselected: &'a [Card],
+    removed: &'a [Card],
+    added: &'a [Card],
+) -> impl Iterator<Item = Edit> + 'a {
+    unselected
+        .iter()
+        .enumerate()
+        .filter(move |(i, card)| {
+            // One candidate per distinct card still available
+            let copies_added = added.iter().filter(|c| c == card).count();
+            let first_free = unselected[..*i].iter().filter(|c| c == card).count() == copies_added;
+            first_free && !removed.contains(card)
+        })
+        .map(move |(_, card)| {
+            let mut cards = cards.to_vec();
+            cards.push(*card);
+            let mut added = added.to_vec();
+            added.push(*card);
+            (cards, removed.to_vec(), added)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Rank, Suit};
+    use crate::patterns::PlayType;
+
+    #[test]
+    fn test_valid_selection_needs_no_edit() {
+        let pair = [
+            Card::new(Suit::Spades, Rank::Five),
+            Card::new(Suit::Hearts, Rank::Five),
+        ];
+        let repairs = nearest_valid_plays(&pair, &pair);
+        assert_eq!(repairs[0].distance(), 0);
+        assert_eq!(repairs[0].pattern.play_type, PlayType::Pair);
+    }
+
+    #[test]
+    fn test_suggests_additions() {
+        let hand = vec![
+            Card::new(Suit::Spades, Rank::Five),
+            Card::new(Suit::Hearts, Rank::Five),
+            Card::new(Suit::Spades, Rank::Six),
+            Card::new(Suit::Hearts, Rank::Six),
+        ];
+        // Three of the four cards of 5566
+        let repairs = nearest_valid_plays(&hand[..3], &hand);
+        assert!(repairs.iter().all(|r| r.distance() <= MAX_EDIT_DISTANCE));
+        assert!(repairs
+            .windows(2)
+            .all(|w| w[0].distance() <= w[1].distance()));
+        let chain = repairs
+            .iter()
+            .find(|r| r.pattern.play_type == PlayType::ConsecutivePairs)
+            .unwrap();
+        assert_eq!(chain.added, vec![hand[3]]);
+        assert!(chain.removed.is_empty());
+    }
+
+    #[test]
+    fn test_selection_outside_hand() {
+        let five = Card::new(Suit::Spades, Rank::Five);
+        let six = Card::new(Suit::Spades, Rank::Six);
+        assert!(nearest_valid_plays(&[five], &[six]).is_empty());
+    }
+}
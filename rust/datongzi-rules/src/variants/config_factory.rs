@@ -4,8 +4,10 @@
 //! - Different deck counts (2-4 decks)
 //! - Different player counts (2-4 players)
 //! - Regional rule variations
+//! - Training presets for practice and tutorials
 
-use crate::models::GameConfig;
+use crate::models::{Card, GameConfig, Rank, Suit, TrainingMode};
+use crate::patterns::{kicker_rules, PlayType};
 
 /// Factory for creating game configurations with different rule variants.
 pub struct ConfigFactory;
@@ -189,6 +191,60 @@ impl ConfigFactory {
         )
     }
 
+    /// Create a practice configuration with no scoring.
+    ///
+    /// - Standard 3-deck, 3-player deal
+    /// - Every bonus (finish, Tongzi, Dizha) is zero
+    /// - Training mode with unlimited undo
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::ConfigFactory;
+    ///
+    /// let config = ConfigFactory::create_training_no_scoring();
+    /// assert!(config.training().unwrap().unlimited_undo);
+    /// assert_eq!(config.dizha_bonus(), 0);
+    /// assert_eq!(config.finish_bonus(), &[0, 0, 0]);
+    /// ```
+    #[must_use]
+    pub fn create_training_no_scoring() -> GameConfig {
+        GameConfig {
+            training: Some(TrainingMode {
+                unlimited_undo: true,
+                drill_play_type: None,
+            }),
+            ..GameConfig::new(3, 3, 41, 9, vec![0, 0, 0], 0, 0, 0, 0)
+        }
+    }
+
+    /// Create a tutorial configuration that drills one pattern family.
+    ///
+    /// Same as [`create_training_no_scoring`](Self::create_training_no_scoring),
+    /// but [`PlayValidator::validate_play`](crate::PlayValidator::validate_play)
+    /// only accepts plays of `play_type`'s family.
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::{Card, ConfigFactory, PlayType, PlayValidator, Rank, Suit};
+    ///
+    /// let config = ConfigFactory::create_pattern_drill(PlayType::Pair);
+    /// let pair = [Card::new(Suit::Spades, Rank::Nine), Card::new(Suit::Hearts, Rank::Nine)];
+    /// let single = [Card::new(Suit::Spades, Rank::Ace)];
+    ///
+    /// assert!(PlayValidator::validate_play(&pair, None, &config).is_ok());
+    /// assert!(PlayValidator::validate_play(&single, None, &config).is_err());
+    /// ```
+    #[must_use]
+    pub fn create_pattern_drill(play_type: PlayType) -> GameConfig {
+        GameConfig {
+            training: Some(TrainingMode {
+                unlimited_undo: true,
+                drill_play_type: Some(play_type),
+            }),
+            ..Self::create_training_no_scoring()
+        }
+    }
+
     /// Create custom configuration with specified parameters.
     ///
     /// # Arguments
@@ -250,7 +306,7 @@ impl ConfigFactory {
     ///
     /// Uses a heuristic where first place gets +100, and others share -100
     /// proportionally to maintain zero-sum fairness.
-    fn calculate_default_finish_bonus(num_players: u8) -> Vec<i32> {
+    pub(super) fn calculate_default_finish_bonus(num_players: u8) -> Vec<i32> {
         if num_players == 0 {
             return vec![];
         }
@@ -276,6 +332,71 @@ impl ConfigFactory {
     }
 }
 
+/// Corrected values suggested by [`VariantValidator::suggest_fix`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigFix {
+    /// Suggested cards dealt to each player
+    pub cards_per_player: usize,
+    /// Suggested cards set aside
+    pub cards_dealt_aside: usize,
+    /// Suggested finish bonuses (unchanged unless the length was wrong)
+    pub finish_bonus: Vec<i32>,
+}
+
+impl ConfigFix {
+    /// Returns a copy of `config` with the suggested values applied
+    #[must_use]
+    pub fn apply(&self, config: &GameConfig) -> GameConfig {
+        GameConfig {
+            cards_per_player: self.cards_per_player,
+            cards_dealt_aside: self.cards_dealt_aside,
+            finish_bonus: self.finish_bonus.clone(),
+            ..config.clone()
+        }
+    }
+}
+
+/// Whether a play type can occur under a configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PatternFeasibility {
+    /// Occurs in normal play
+    Possible,
+    /// Possible only if one hand gets every copy it needs; effectively never
+    Degenerate,
+    /// Cannot occur
+    Impossible,
+}
+
+/// Per-play-type feasibility report from [`VariantValidator::audit_patterns`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternAudit {
+    /// Feasibility and explanation for every play type, weakest first
+    pub entries: Vec<(PlayType, PatternFeasibility, String)>,
+    /// Largest bomb a hand can hold (0 if bombs are impossible)
+    pub max_bomb_size: usize,
+}
+
+impl PatternAudit {
+    /// Feasibility of `play_type`
+    #[must_use]
+    pub fn feasibility(&self, play_type: PlayType) -> PatternFeasibility {
+        self.entries
+            .iter()
+            .find(|(t, _, _)| *t == play_type)
+            .map_or(PatternFeasibility::Impossible, |(_, f, _)| *f)
+    }
+
+    /// Play types a UI should offer (neither impossible nor degenerate)
+    #[must_use]
+    pub fn reachable(&self) -> Vec<PlayType> {
+        self.entries
+            .iter()
+            .filter(|(_, f, _)| *f == PatternFeasibility::Possible)
+            .map(|(t, _, _)| *t)
+            .collect()
+    }
+}
+
 /// Validate game configuration variants for playability.
 pub struct VariantValidator;
 
@@ -288,6 +409,9 @@ impl VariantValidator {
     /// 3. Finish bonus length matches player count
     /// 4. Finish bonuses sum to ≤0 (zero-sum fairness)
     ///
+    /// Training configurations ([`GameConfig::is_training`]) skip checks 1
+    /// and 2, since tutorial deals may be small or uneven.
+    ///
     /// # Returns
     /// Tuple of (is_valid, list_of_warnings)
     ///
@@ -305,12 +429,22 @@ impl VariantValidator {
         let mut warnings = Vec::new();
 
         // Calculate total cards in deck
-        let total_cards = usize::from(config.num_decks()) * 52;
-        let total_available = total_cards - config.cards_dealt_aside();
+        let total_cards = config.deck_size();
+        let total_available = total_cards.saturating_sub(config.cards_dealt_aside());
         let required = usize::from(config.num_players()) * 10; // Minimum 10 cards per player
 
+        // Check the deal fits the deck (the case suggest_fix repairs)
+        let dealt = config.cards_per_player() * usize::from(config.num_players())
+            + config.cards_dealt_aside();
+        if dealt > total_cards {
+            warnings.push(format!(
+                "Deal needs {} cards but the deck has {}",
+                dealt, total_cards
+            ));
+        }
+
         // Check if enough cards for all players
-        if total_available < required {
+        if total_available < required && !config.is_training() {
             warnings.push(format!(
                 "Too few cards: {} available, need at least {} for {} players",
                 total_available,
@@ -320,7 +454,7 @@ impl VariantValidator {
         }
 
         // Check for unbalanced distribution
-        if total_available % usize::from(config.num_players()) != 0 {
+        if total_available % usize::from(config.num_players()) != 0 && !config.is_training() {
             warnings.push(format!(
                 "Uneven distribution: {} cards cannot be evenly divided among {} players",
                 total_available,
@@ -350,6 +484,200 @@ impl VariantValidator {
 
         (is_valid, warnings)
     }
+
+    /// Report which play types the configuration makes impossible or
+    /// degenerate, so UIs can hide unreachable patterns.
+    ///
+    /// Considers the deck composition (copies of each card), hand size, and
+    /// removed ranks (chains need two adjacent ranks below 2). For example
+    /// Tongzi needs 3 copies of one card (3+ decks), and Dizha needs 2 of
+    /// each suit (2+ decks; with exactly 2, one hand must get all 8).
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::variants::PatternFeasibility;
+    /// use datongzi_rules::{GameConfig, PlayType, VariantValidator};
+    ///
+    /// let config = GameConfig {
+    ///     num_decks: 1,
+    ///     num_players: 4,
+    ///     cards_per_player: 13,
+    ///     ..GameConfig::default()
+    /// };
+    /// let audit = VariantValidator::audit_patterns(&config);
+    /// assert_eq!(audit.feasibility(PlayType::Tongzi), PatternFeasibility::Impossible);
+    /// assert_eq!(audit.feasibility(PlayType::Dizha), PatternFeasibility::Impossible);
+    /// assert_eq!(audit.max_bomb_size, 4);
+    /// ```
+    #[must_use]
+    pub fn audit_patterns(config: &GameConfig) -> PatternAudit {
+        use PatternFeasibility::{Degenerate, Impossible, Possible};
+
+        // Most copies of one card, of one rank, and of every suit of one rank
+        let max_card_copies = Rank::ALL
+            .iter()
+            .flat_map(|rank| Suit::ALL.map(|suit| config.copies_of(Card::new(suit, *rank))))
+            .max()
+            .unwrap_or(0);
+        let max_rank_copies = Rank::ALL
+            .iter()
+            .map(|rank| config.copies_of_rank(*rank))
+            .max()
+            .unwrap_or(0);
+        let decks = Rank::ALL
+            .iter()
+            .filter_map(|rank| {
+                Suit::ALL
+                    .iter()
+                    .map(|suit| config.copies_of(Card::new(*suit, *rank)))
+                    .min()
+            })
+            .max()
+            .unwrap_or(0);
+        let hand = config.cards_per_player();
+        let any_rank = Rank::ALL.iter().any(|r| !config.is_rank_removed(*r));
+        let chains = Rank::ALL.windows(2).any(|pair| {
+            pair.iter()
+                .all(|r| *r != Rank::Two && !config.is_rank_removed(*r))
+        });
+        let max_bomb_size = if any_rank && hand >= 4 && max_rank_copies >= 4 {
+            max_rank_copies.min(hand)
+        } else {
+            0
+        };
+
+        let needs_cards = |play_type: PlayType, cards: usize| {
+            if !any_rank || hand < cards {
+                (
+                    Impossible,
+                    format!("{play_type:?} needs {cards} cards in hand"),
+                )
+            } else {
+                (Possible, String::new())
+            }
+        };
+        let needs_chain = |play_type: PlayType, cards: usize| {
+            if chains {
+                needs_cards(play_type, cards)
+            } else {
+                (
+                    Impossible,
+                    "no two adjacent ranks below 2 remain".to_string(),
+                )
+            }
+        };
+        let min_wings = *kicker_rules::standard_capacity(PlayType::AirplaneWithWings, 2).start();
+
+        let tongzi = if max_card_copies < 3 {
+            (
+                Impossible,
+                format!("needs 3 copies of one card, at most {max_card_copies} per card"),
+            )
+        } else {
+            needs_cards(PlayType::Tongzi, 3)
+        };
+        let dizha = match decks {
+            0 | 1 => (
+                Impossible,
+                format!("needs 2 cards of each suit, at most {decks} per suit"),
+            ),
+            2 if hand >= 8 => (Degenerate, "one hand must hold all 8 copies".to_string()),
+            _ => needs_cards(PlayType::Dizha, 8),
+        };
+        let bomb = if max_bomb_size > 0 {
+            (Possible, format!("4 to {max_bomb_size} cards"))
+        } else {
+            needs_cards(PlayType::Bomb, 4)
+        };
+
+        let entries = [
+            (PlayType::Single, needs_cards(PlayType::Single, 1)),
+            (PlayType::Pair, needs_cards(PlayType::Pair, 2)),
+            (
+                PlayType::ConsecutivePairs,
+                needs_chain(PlayType::ConsecutivePairs, 4),
+            ),
+            (PlayType::Triple, needs_cards(PlayType::Triple, 3)),
+            (PlayType::Airplane, needs_chain(PlayType::Airplane, 6)),
+            (
+                PlayType::AirplaneWithWings,
+                needs_chain(PlayType::AirplaneWithWings, 6 + min_wings),
+            ),
+            (PlayType::Bomb, bomb),
+            (PlayType::Tongzi, tongzi),
+            (PlayType::Dizha, dizha),
+        ]
+        .into_iter()
+        .map(|(play_type, (feasibility, note))| (play_type, feasibility, note))
+        .collect();
+
+        PatternAudit {
+            entries,
+            max_bomb_size,
+        }
+    }
+
+    /// Suggest corrected values for a configuration that cannot be dealt.
+    ///
+    /// Fixes:
+    /// 1. Card split: if players × cards_per_player + aside exceeds the deck
+    ///    (after removed ranks), deal the whole deck keeping the aside pile as
+    ///    close to the original as possible; leftovers go to the aside pile
+    /// 2. Finish bonus length: replaced by the default bonus for the player count
+    ///
+    /// # Returns
+    /// `None` if the configuration needs no fix, or if the player/deck count
+    /// itself is invalid (no split can fix that).
+    ///
+    /// # Example
+    /// ```
+    /// use datongzi_rules::{GameConfig, VariantValidator};
+    ///
+    /// let config = GameConfig {
+    ///     cards_per_player: 50,
+    ///     ..GameConfig::default()
+    /// };
+    /// let fix = VariantValidator::suggest_fix(&config).unwrap();
+    /// assert_eq!(fix.cards_per_player, 41);
+    /// assert_eq!(fix.cards_dealt_aside, 9);
+    /// assert!(fix.apply(&config).validate().is_ok());
+    /// ```
+    #[must_use]
+    pub fn suggest_fix(config: &GameConfig) -> Option<ConfigFix> {
+        let num_players = config.num_players();
+        if !(2..=4).contains(&num_players) || config.num_decks() == 0 {
+            return None;
+        }
+
+        let players = usize::from(num_players);
+        let deck_size = config.deck_size();
+        let required = config.cards_per_player() * players + config.cards_dealt_aside();
+        let bonus_ok = config.finish_bonus().len() == players;
+
+        if required <= deck_size && bonus_ok {
+            return None;
+        }
+
+        let (cards_per_player, cards_dealt_aside) = if required <= deck_size {
+            (config.cards_per_player(), config.cards_dealt_aside())
+        } else {
+            let aside = config.cards_dealt_aside().min(deck_size);
+            let per_player = (deck_size - aside) / players;
+            (per_player, deck_size - per_player * players)
+        };
+
+        let finish_bonus = if bonus_ok {
+            config.finish_bonus().to_vec()
+        } else {
+            ConfigFactory::calculate_default_finish_bonus(num_players)
+        };
+
+        Some(ConfigFix {
+            cards_per_player,
+            cards_dealt_aside,
+            finish_bonus,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -415,6 +743,46 @@ mod tests {
         assert_eq!(config.num_players(), 3);
     }
 
+    #[test]
+    fn test_validate_config_agrees_with_suggest_fix() {
+        // Removing 3-6 leaves 108 cards for a 132-card deal
+        let config = GameConfig {
+            removed_ranks: vec![Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            ..GameConfig::default()
+        };
+        let (is_valid, warnings) = VariantValidator::validate_config(&config);
+        assert!(!is_valid);
+        assert!(warnings.iter().any(|w| w.contains("108")), "{warnings:?}");
+
+        let fixed = VariantValidator::suggest_fix(&config)
+            .unwrap()
+            .apply(&config);
+        assert!(VariantValidator::validate_config(&fixed).0);
+    }
+
+    #[test]
+    fn test_suggest_fix() {
+        assert!(VariantValidator::suggest_fix(&GameConfig::default()).is_none());
+
+        // 4 players × 40 + 9 > 132: deal everything, remainder aside
+        let config = GameConfig {
+            num_players: 4,
+            cards_per_player: 40,
+            ..GameConfig::default()
+        };
+        let fix = VariantValidator::suggest_fix(&config).unwrap();
+        assert_eq!(fix.cards_per_player, 30);
+        assert_eq!(fix.cards_dealt_aside, 12);
+        assert_eq!(fix.finish_bonus.len(), 4);
+        assert!(fix.apply(&config).validate().is_ok());
+
+        let bad_players = GameConfig {
+            num_players: 5,
+            ..GameConfig::default()
+        };
+        assert!(VariantValidator::suggest_fix(&bad_players).is_none());
+    }
+
     #[test]
     fn test_create_custom() {
         let config = ConfigFactory::create_custom(
@@ -458,11 +826,11 @@ mod tests {
     #[test]
     fn test_validate_uneven_distribution() {
         // Create config with uneven distribution
-        // 2 decks = 104 cards, 2 aside = 102 available, not divisible by 3 (102 % 3 = 0, so use different config)
-        // 2 decks = 104 cards, 1 aside = 103 available, not divisible by 3 (103 % 3 = 1)
+        // 2 decks without 3 and 4 = 88 cards, 1 aside = 87 available, divisible by 3
+        // 2 decks without 3 and 4 = 88 cards, 2 aside = 86 available, not divisible by 3 (86 % 3 = 2)
         let config = ConfigFactory::create_custom(
-            2, 3, 34, // cards_per_player (just a number, validation checks distribution)
-            1,  // 104 - 1 = 103, not divisible by 3
+            2, 3, 28, // cards_per_player (28 * 3 + 2 = 86, the deal fits)
+            2,  // 88 - 2 = 86, not divisible by 3
             100, 200, 300, 400,
         );
 
@@ -531,6 +899,40 @@ mod tests {
         assert_eq!(config.num_players(), 3);
     }
 
+    #[test]
+    fn test_audit_honors_card_copies() {
+        // Only two copies of each 2 and A: no 2/A Tongzi, other ranks unaffected
+        let config = GameConfig {
+            card_copies: [Rank::Two, Rank::Ace]
+                .iter()
+                .flat_map(|rank| Suit::ALL.map(|suit| (Card::new(suit, *rank), 2)))
+                .collect(),
+            ..GameConfig::default()
+        };
+        let audit = VariantValidator::audit_patterns(&config);
+        assert_eq!(
+            audit.feasibility(PlayType::Tongzi),
+            PatternFeasibility::Possible
+        );
+        assert_eq!(audit.max_bomb_size, 12);
+
+        let single_copies = GameConfig {
+            num_decks: 1,
+            num_players: 4,
+            cards_per_player: 10,
+            cards_dealt_aside: 3,
+            finish_bonus: vec![100, 0, -40, -60],
+            card_copies: vec![(Card::new(Suit::Spades, Rank::Ace), 0)],
+            ..GameConfig::default()
+        };
+        assert!(VariantValidator::validate_config(&single_copies).0);
+        let audit = VariantValidator::audit_patterns(&single_copies);
+        assert_eq!(
+            audit.feasibility(PlayType::Tongzi),
+            PatternFeasibility::Impossible
+        );
+    }
+
     #[test]
     fn test_finish_bonus_sum() {
         let config = ConfigFactory::create_standard_3deck_3player();
@@ -539,4 +941,68 @@ mod tests {
         // Should be zero or negative for fairness
         assert_eq!(bonus_sum, 0);
     }
+
+    #[test]
+    fn test_audit_patterns_by_deck_count() {
+        use PatternFeasibility::{Degenerate, Impossible, Possible};
+
+        let standard = VariantValidator::audit_patterns(&GameConfig::default());
+        assert_eq!(standard.reachable().len(), 9);
+        assert_eq!(standard.max_bomb_size, 12);
+
+        let two_decks = GameConfig {
+            num_decks: 2,
+            cards_per_player: 34,
+            cards_dealt_aside: 2,
+            ..GameConfig::default()
+        };
+        let audit = VariantValidator::audit_patterns(&two_decks);
+        assert_eq!(audit.feasibility(PlayType::Tongzi), Impossible);
+        assert_eq!(audit.feasibility(PlayType::Dizha), Degenerate);
+        assert_eq!(audit.feasibility(PlayType::Bomb), Possible);
+        assert_eq!(audit.max_bomb_size, 8);
+        assert!(!audit.reachable().contains(&PlayType::Dizha));
+    }
+
+    #[test]
+    fn test_audit_patterns_without_chains() {
+        let config = GameConfig {
+            removed_ranks: vec![
+                Rank::Four,
+                Rank::Six,
+                Rank::Eight,
+                Rank::Ten,
+                Rank::Queen,
+                Rank::Ace,
+            ],
+            ..GameConfig::default()
+        };
+        let audit = VariantValidator::audit_patterns(&config);
+        assert_eq!(
+            audit.feasibility(PlayType::ConsecutivePairs),
+            PatternFeasibility::Impossible
+        );
+        assert_eq!(
+            audit.feasibility(PlayType::Triple),
+            PatternFeasibility::Possible
+        );
+    }
+
+    #[test]
+    fn test_training_relaxes_distribution_checks() {
+        let uneven = ConfigFactory::create_custom(2, 3, 28, 2, 100, 200, 300, 400);
+        let training = GameConfig {
+            training: Some(TrainingMode::default()),
+            finish_bonus: vec![0, 0, 0],
+            ..uneven
+        };
+
+        let (is_valid, warnings) = VariantValidator::validate_config(&training);
+        assert!(is_valid, "{warnings:?}");
+
+        let drill = ConfigFactory::create_pattern_drill(PlayType::Airplane);
+        assert!(drill.is_training());
+        assert!(drill.validate().is_ok());
+        assert!(VariantValidator::validate_config(&drill).0);
+    }
 }
@@ -0,0 +1,160 @@
+//! Applying rule updates to a game in progress.
+
+use crate::error::{DatongziError, Result};
+use crate::i18n::Message;
+use crate::models::GameConfig;
+
+/// Configuration fields an update may not change mid-game, in field order.
+///
+/// Deal, comparison, kicker, turn-order and drill settings decide which
+/// plays are legal, so changing them would invalidate plays already made.
+/// Bonus values, the reveal multiplier, discard scoring and last-trick
+/// scoring only affect scores still to be awarded.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::variants::hot_reload_conflicts;
+/// use datongzi_rules::GameConfig;
+///
+/// let current = GameConfig::default();
+/// let bonus_tweak = GameConfig { k_tongzi_bonus: 150, ..current.clone() };
+/// let new_deck = GameConfig { num_decks: 4, singles_suit_tiebreak: true, ..current.clone() };
+///
+/// assert!(hot_reload_conflicts(&current, &bonus_tweak).is_empty());
+/// assert_eq!(hot_reload_conflicts(&current, &new_deck), ["num_decks", "singles_suit_tiebreak"]);
+/// ```
+#[must_use]
+pub fn hot_reload_conflicts(current: &GameConfig, updated: &GameConfig) -> Vec<&'static str> {
+    // Destructured so a new field must be classified here before it compiles
+    let GameConfig {
+        num_decks,
+        num_players,
+        cards_per_player,
+        cards_dealt_aside,
+        removed_ranks,
+        card_copies,
+        finish_bonus: _,
+        k_tongzi_bonus: _,
+        a_tongzi_bonus: _,
+        two_tongzi_bonus: _,
+        dizha_bonus: _,
+        allow_reveal,
+        reveal_multiplier: _,
+        allow_bidding,
+        discard_scoring: _,
+        last_trick_scoring: _,
+        training,
+        rotation,
+        first_leader,
+        singles_suit_tiebreak,
+        bomb_rank_order,
+        tongzi_suit_order,
+        forbidden_kicker_ranks,
+        lead_restrictions,
+        cards_left_alert,
+        finish_restrictions,
+    } = updated;
+
+    [
+        ("num_decks", *num_decks != current.num_decks),
+        ("num_players", *num_players != current.num_players),
+        (
+            "cards_per_player",
+            *cards_per_player != current.cards_per_player,
+        ),
+        (
+            "cards_dealt_aside",
+            *cards_dealt_aside != current.cards_dealt_aside,
+        ),
+        ("removed_ranks", *removed_ranks != current.removed_ranks),
+        ("card_copies", *card_copies != current.card_copies),
+        ("allow_reveal", *allow_reveal != current.allow_reveal),
+        ("allow_bidding", *allow_bidding != current.allow_bidding),
+        ("training", *training != current.training),
+        ("rotation", *rotation != current.rotation),
+        ("first_leader", *first_leader != current.first_leader),
+        (
+            "singles_suit_tiebreak",
+            *singles_suit_tiebreak != current.singles_suit_tiebreak,
+        ),
+        (
+            "bomb_rank_order",
+            *bomb_rank_order != current.bomb_rank_order,
+        ),
+        (
+            "tongzi_suit_order",
+            *tongzi_suit_order != current.tongzi_suit_order,
+        ),
+        (
+            "forbidden_kicker_ranks",
+            *forbidden_kicker_ranks != current.forbidden_kicker_ranks,
+        ),
+        (
+            "lead_restrictions",
+            *lead_restrictions != current.lead_restrictions,
+        ),
+        (
+            "cards_left_alert",
+            *cards_left_alert != current.cards_left_alert,
+        ),
+        (
+            "finish_restrictions",
+            *finish_restrictions != current.finish_restrictions,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(field, changed)| changed.then_some(field))
+    .collect()
+}
+
+/// Check that `updated` is a valid configuration that may replace `current`
+/// in a game in progress.
+///
+/// # Errors
+///
+/// Returns `ConfigError` with [`Message::RulesChangeNotReloadable`] listing
+/// every [`hot_reload_conflicts`] field, or the error from
+/// [`GameConfig::validate`]
+pub fn check_hot_reload(current: &GameConfig, updated: &GameConfig) -> Result<()> {
+    let conflicts = hot_reload_conflicts(current, updated);
+    if !conflicts.is_empty() {
+        return Err(DatongziError::ConfigError(
+            Message::RulesChangeNotReloadable {
+                fields: conflicts.into_iter().map(str::to_string).collect(),
+            },
+        ));
+    }
+    updated.validate()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Rank;
+
+    #[test]
+    fn test_check_hot_reload() {
+        let current = GameConfig::default();
+        let kickers = GameConfig {
+            forbidden_kicker_ranks: vec![Rank::Two],
+            dizha_bonus: 500,
+            ..current.clone()
+        };
+        assert_eq!(
+            check_hot_reload(&current, &kickers),
+            Err(DatongziError::ConfigError(
+                Message::RulesChangeNotReloadable {
+                    fields: vec!["forbidden_kicker_ranks".to_string()],
+                }
+            ))
+        );
+
+        // Safe fields must still form a valid configuration
+        let short_bonus = GameConfig {
+            finish_bonus: vec![100, -100],
+            ..current.clone()
+        };
+        assert!(hot_reload_conflicts(&current, &short_bonus).is_empty());
+        assert!(check_hot_reload(&current, &short_bonus).is_err());
+    }
+}
@@ -0,0 +1,308 @@
+//! Extension points for house rules.
+//!
+//! A [`HouseRule`] hooks into three moments a game engine goes through:
+//! validating a play, finishing a trick, and applying scores. Engines keep a
+//! [`HouseRules`] set and call its hooks at those moments, so deployments
+//! can add odd local rules without forking the standard ones.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::error::Result;
+use crate::models::{Card, GameConfig};
+use crate::patterns::{PlayPattern, PlayValidator};
+use crate::scoring::{ScoringEvent, TrickContext};
+
+/// What a rule sees before a play is validated.
+#[derive(Debug, Clone, Copy)]
+pub struct PlayContext<'a> {
+    /// Game configuration
+    pub config: &'a GameConfig,
+    /// Player making the play
+    pub player_id: &'a str,
+    /// Cards being played
+    pub cards: &'a [Card],
+    /// Play to beat (`None` when leading)
+    pub current_play: Option<&'a PlayPattern>,
+    /// 1-based trick number
+    pub trick_number: usize,
+    /// Cards the player holds before the play
+    pub cards_in_hand: usize,
+}
+
+/// A house rule. Every hook defaults to "no change".
+pub trait HouseRule: fmt::Debug + Send + Sync {
+    /// Reject a play before the standard validation runs
+    ///
+    /// # Errors
+    ///
+    /// Returns the error to report to the player
+    fn before_play_validation(&self, _ctx: &PlayContext<'_>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Extra events once a trick is won
+    fn after_trick(&self, _ctx: &TrickContext<'_>) -> Vec<ScoringEvent> {
+        Vec::new()
+    }
+
+    /// Adjust the game's events before they are applied to players
+    fn before_score_apply(&self, _events: &mut Vec<ScoringEvent>) {}
+}
+
+type PlayCheck = dyn Fn(&PlayContext<'_>) -> Result<()> + Send + Sync;
+type TrickHook = dyn Fn(&TrickContext<'_>) -> Vec<ScoringEvent> + Send + Sync;
+type ScoreHook = dyn Fn(&mut Vec<ScoringEvent>) + Send + Sync;
+
+/// A house rule built from one closure.
+enum ClosureRule {
+    BeforePlayValidation(Box<PlayCheck>),
+    AfterTrick(Box<TrickHook>),
+    BeforeScoreApply(Box<ScoreHook>),
+}
+
+impl fmt::Debug for ClosureRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hook = match self {
+            Self::BeforePlayValidation(_) => "before_play_validation",
+            Self::AfterTrick(_) => "after_trick",
+            Self::BeforeScoreApply(_) => "before_score_apply",
+        };
+        write!(f, "ClosureRule({hook})")
+    }
+}
+
+impl HouseRule for ClosureRule {
+    fn before_play_validation(&self, ctx: &PlayContext<'_>) -> Result<()> {
+        match self {
+            Self::BeforePlayValidation(check) => check(ctx),
+            _ => Ok(()),
+        }
+    }
+
+    fn after_trick(&self, ctx: &TrickContext<'_>) -> Vec<ScoringEvent> {
+        match self {
+            Self::AfterTrick(hook) => hook(ctx),
+            _ => Vec::new(),
+        }
+    }
+
+    fn before_score_apply(&self, events: &mut Vec<ScoringEvent>) {
+        if let Self::BeforeScoreApply(hook) = self {
+            hook(events);
+        }
+    }
+}
+
+/// The house rules in force for a game, run in the order they were added.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::i18n::Message;
+/// use datongzi_rules::variants::{HouseRules, PlayContext};
+/// use datongzi_rules::{Card, DatongziError, GameConfig, Rank, Suit};
+///
+/// // "The first trick must include a 5"
+/// let mut rules = HouseRules::new();
+/// rules.on_before_play_validation(|ctx: &PlayContext<'_>| {
+///     if ctx.trick_number == 1 && ctx.cards.iter().all(|c| c.rank != Rank::Five) {
+///         return Err(DatongziError::PlayError(Message::Custom(
+///             "the first trick must include a 5".to_string(),
+///         )));
+///     }
+///     Ok(())
+/// });
+///
+/// let config = GameConfig::default();
+/// let nine = [Card::new(Suit::Spades, Rank::Nine)];
+/// let mut ctx = PlayContext {
+///     config: &config,
+///     player_id: "p1",
+///     cards: &nine,
+///     current_play: None,
+///     trick_number: 1,
+///     cards_in_hand: 20,
+/// };
+/// assert!(rules.validate_play(&ctx).is_err());
+///
+/// ctx.trick_number = 2;
+/// assert!(rules.validate_play(&ctx).is_ok());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HouseRules {
+    rules: Vec<Arc<dyn HouseRule>>,
+}
+
+impl HouseRules {
+    /// No house rules
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a rule
+    pub fn add(&mut self, rule: Arc<dyn HouseRule>) {
+        self.rules.push(rule);
+    }
+
+    /// Add a closure run before play validation
+    pub fn on_before_play_validation(
+        &mut self,
+        check: impl Fn(&PlayContext<'_>) -> Result<()> + Send + Sync + 'static,
+    ) {
+        self.add(Arc::new(ClosureRule::BeforePlayValidation(Box::new(check))));
+    }
+
+    /// Add a closure run after each trick
+    pub fn on_after_trick(
+        &mut self,
+        hook: impl Fn(&TrickContext<'_>) -> Vec<ScoringEvent> + Send + Sync + 'static,
+    ) {
+        self.add(Arc::new(ClosureRule::AfterTrick(Box::new(hook))));
+    }
+
+    /// Add a closure run before scores are applied
+    pub fn on_before_score_apply(
+        &mut self,
+        hook: impl Fn(&mut Vec<ScoringEvent>) + Send + Sync + 'static,
+    ) {
+        self.add(Arc::new(ClosureRule::BeforeScoreApply(Box::new(hook))));
+    }
+
+    /// Number of rules
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns true if no rules are installed
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Run every `before_play_validation` hook.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first rule's error
+    pub fn before_play_validation(&self, ctx: &PlayContext<'_>) -> Result<()> {
+        self.rules
+            .iter()
+            .try_for_each(|rule| rule.before_play_validation(ctx))
+    }
+
+    /// House-rule checks followed by [`PlayValidator::validate_play_in_hand`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first house-rule error, or the standard validation error
+    pub fn validate_play(&self, ctx: &PlayContext<'_>) -> Result<PlayPattern> {
+        self.before_play_validation(ctx)?;
+        PlayValidator::validate_play_in_hand(
+            ctx.cards,
+            ctx.current_play,
+            ctx.cards_in_hand,
+            ctx.config,
+        )
+    }
+
+    /// Events from every `after_trick` hook, in rule order
+    #[must_use]
+    pub fn after_trick(&self, ctx: &TrickContext<'_>) -> Vec<ScoringEvent> {
+        self.rules
+            .iter()
+            .flat_map(|rule| rule.after_trick(ctx))
+            .collect()
+    }
+
+    /// Run every `before_score_apply` hook on `events`
+    pub fn before_score_apply(&self, events: &mut Vec<ScoringEvent>) {
+        for rule in &self.rules {
+            rule.before_score_apply(events);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Message;
+    use crate::models::{Rank, Suit};
+    use crate::patterns::PatternRecognizer;
+    use crate::scoring::BonusType;
+
+    #[derive(Debug)]
+    struct DoubleNegatives;
+
+    impl HouseRule for DoubleNegatives {
+        fn before_score_apply(&self, events: &mut Vec<ScoringEvent>) {
+            for event in events.iter_mut().filter(|e| e.points < 0) {
+                event.points *= 2;
+            }
+        }
+    }
+
+    fn event(points: i32) -> ScoringEvent {
+        ScoringEvent::new(
+            "p1".to_string(),
+            BonusType::FinishThird,
+            points,
+            Message::Custom("test".to_string()),
+            None,
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn test_hooks_run_in_order() {
+        let mut rules = HouseRules::new();
+        rules.add(Arc::new(DoubleNegatives));
+        rules.on_before_score_apply(|events| events.retain(|e| e.points != 0));
+        assert_eq!(rules.len(), 2);
+
+        let mut events = vec![event(-30), event(0), event(50)];
+        rules.before_score_apply(&mut events);
+        let points: Vec<i32> = events.iter().map(|e| e.points).collect();
+        assert_eq!(points, vec![-60, 50]);
+    }
+
+    #[test]
+    fn test_after_trick_collects_events() {
+        let mut rules = HouseRules::new();
+        rules.on_after_trick(|ctx| {
+            if ctx.winning_pattern.primary_rank == Rank::Three {
+                vec![event(5)]
+            } else {
+                Vec::new()
+            }
+        });
+
+        let config = GameConfig::default();
+        let threes =
+            PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Three)]).unwrap();
+        let ctx = TrickContext {
+            config: &config,
+            player_id: "p1",
+            winning_pattern: &threes,
+            round_number: 1,
+        };
+        assert_eq!(rules.after_trick(&ctx).len(), 1);
+    }
+
+    #[test]
+    fn test_standard_validation_still_applies() {
+        let rules = HouseRules::new();
+        let config = GameConfig::default();
+        let king = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::King)]);
+        let ctx = PlayContext {
+            config: &config,
+            player_id: "p1",
+            cards: &[Card::new(Suit::Hearts, Rank::Ten)],
+            current_play: king.as_ref(),
+            trick_number: 3,
+            cards_in_hand: 10,
+        };
+        assert!(rules.validate_play(&ctx).is_err());
+    }
+}
@@ -0,0 +1,669 @@
+//! Rule-variant inference from imported game records.
+
+use std::collections::HashMap;
+
+use super::ConfigFactory;
+use crate::error::DatongziError;
+use crate::i18n::Message;
+use crate::models::{Card, GameConfig, Rank, RankCounts, Suit};
+use crate::patterns::PatternRecognizer;
+use crate::scoring::{score_record, GameRecord, RecordedAction};
+
+/// Configuration deduced by [`infer_config`], with everything that did not fit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigInference {
+    /// Configuration consistent with the bulk of the records
+    pub config: GameConfig,
+    /// Chain wrap policy of the source app
+    pub wrap_policy: ChainWrapPolicy,
+    /// Observations the inferred configuration cannot explain
+    pub contradictions: Vec<Contradiction>,
+}
+
+/// How chains (consecutive pairs, airplanes) treat the top of the rank cycle.
+///
+/// Reported by inference only; [`GameConfig`] cannot select a wrap policy
+/// and the rules engine always plays [`NoWrap`](Self::NoWrap).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ChainWrapPolicy {
+    /// 2 never joins a chain and ranks do not wrap (this crate's run policy);
+    /// also reported when no record shows otherwise
+    #[default]
+    NoWrap,
+    /// Chains run through 2 and wrap back to 3 (A-2-3). The rules engine
+    /// cannot replay such chains, so each one is also reported as a
+    /// [`Contradiction::WrappedChain`]
+    WrapAround,
+}
+
+/// An observation that contradicts the inferred configuration.
+///
+/// `record` indexes the slice passed to [`infer_config`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Contradiction {
+    /// The record seats a different number of players than most records
+    PlayerCount {
+        /// Record index
+        record: usize,
+        /// Players seated
+        players: usize,
+    },
+    /// A dealt hand differs in size from the inferred cards per player
+    HandSize {
+        /// Record index
+        record: usize,
+        /// Seat of the hand
+        seat: usize,
+        /// Cards dealt
+        cards: usize,
+    },
+    /// A chain through 2 or wrapping past it (A-2-3); runs here never
+    /// include 2, so the source app uses a different wrap policy
+    WrappedChain {
+        /// Record index
+        record: usize,
+        /// Action index of the chain
+        turn: usize,
+    },
+    /// The record does not replay under the inferred configuration
+    Replay {
+        /// Record index
+        record: usize,
+        /// Replay error
+        error: DatongziError,
+    },
+    /// A final score the fitted scoring values do not reproduce
+    Score {
+        /// Record index
+        record: usize,
+        /// Seat of the player
+        seat: usize,
+        /// Score under the inferred configuration
+        expected: i32,
+        /// Score reported by the source app
+        observed: i32,
+    },
+}
+
+/// Smallest pivot treated as nonzero when fitting scoring values.
+const PIVOT_EPSILON: f64 = 1e-9;
+
+/// Most common value, preferring the larger on ties.
+fn mode(values: impl Iterator<Item = usize>) -> Option<usize> {
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    for value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    counts
+        .into_iter()
+        .max_by_key(|(value, count)| (*count, *value))
+        .map(|(value, _)| value)
+}
+
+/// Returns true for equal-size groups (pairs or triples) of at least two
+/// ranks, including 2, that are consecutive once ranks wrap from 2 to 3.
+fn is_wrapped_chain(cards: &[Card]) -> bool {
+    let counts = RankCounts::from_cards(cards);
+    let group = counts.iter().next().map_or(0, |(_, count)| count);
+    if counts.distinct_ranks() < 2
+        || !(2..=3).contains(&group)
+        || counts.iter().any(|(_, count)| count != group)
+        || counts.get(Rank::Two) == 0
+    {
+        return false;
+    }
+
+    let indices: Vec<usize> = counts.iter().map(|(rank, _)| rank.index()).collect();
+    // Contiguous on the rank cycle means exactly one gap around the circle
+    let gaps = indices
+        .iter()
+        .zip(indices.iter().cycle().skip(1))
+        .filter(|(a, b)| (**b + Rank::ALL.len() - **a) % Rank::ALL.len() != 1)
+        .count();
+    gaps == 1
+}
+
+/// Deduce a configuration from games recorded by another app.
+///
+/// Infers the player count and cards per player (most common values), the
+/// deck count (most copies of one card in a deal, raised until the deal
+/// fits), removed ranks (ranks never dealt) and the aside pile (the rest of
+/// the deck). Every record is then replayed under the inferred configuration;
+/// failures, including chains that only a wrapping run policy allows, are
+/// reported as [`Contradiction`]s.
+///
+/// Records carry no final scores, so scoring values stay at the defaults
+/// (finish bonuses are sized for the inferred player count); use
+/// [`infer_config_with_scores`] to fit them. Any wrapped chain sets
+/// [`ChainWrapPolicy::WrapAround`]. With no records the default configuration
+/// is returned.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::puzzles::deal_from_seed;
+/// use datongzi_rules::scoring::GameRecord;
+/// use datongzi_rules::variants::{infer_config, ChainWrapPolicy};
+/// use datongzi_rules::{ConfigFactory, Rank};
+///
+/// let config = ConfigFactory::create_standard_3deck_3player();
+/// let records: Vec<GameRecord> = (0..3)
+///     .map(|seed| GameRecord {
+///         player_ids: vec!["a".into(), "b".into(), "c".into()],
+///         hands: deal_from_seed(&config, seed).unwrap().0,
+///         ..GameRecord::default()
+///     })
+///     .collect();
+///
+/// let inference = infer_config(&records);
+/// assert!(inference.contradictions.is_empty());
+/// assert_eq!(inference.wrap_policy, ChainWrapPolicy::NoWrap);
+/// assert_eq!(inference.config.num_decks(), 3);
+/// assert_eq!(inference.config.removed_ranks(), &[Rank::Three, Rank::Four]);
+/// assert_eq!(inference.config.cards_dealt_aside(), 9);
+/// ```
+#[must_use]
+pub fn infer_config(records: &[GameRecord]) -> ConfigInference {
+    let mut contradictions = Vec::new();
+    let Some(players) = mode(records.iter().map(|r| r.player_ids.len())) else {
+        return ConfigInference {
+            config: GameConfig::default(),
+            wrap_policy: ChainWrapPolicy::NoWrap,
+            contradictions,
+        };
+    };
+
+    let seated: Vec<usize> = (0..records.len())
+        .filter(|i| {
+            let count = records[*i].player_ids.len();
+            if count != players {
+                contradictions.push(Contradiction::PlayerCount {
+                    record: *i,
+                    players: count,
+                });
+            }
+            count == players
+        })
+        .collect();
+
+    let cards_per_player = mode(
+        seated
+            .iter()
+            .flat_map(|i| records[*i].hands.iter().map(Vec::len)),
+    )
+    .unwrap_or(0);
+    for i in &seated {
+        for (seat, hand) in records[*i].hands.iter().enumerate() {
+            if hand.len() != cards_per_player {
+                contradictions.push(Contradiction::HandSize {
+                    record: *i,
+                    seat,
+                    cards: hand.len(),
+                });
+            }
+        }
+    }
+
+    let mut copies: Vec<usize> = Vec::new();
+    let mut seen: Vec<Rank> = Vec::new();
+    for i in &seated {
+        let mut counts: HashMap<Card, usize> = HashMap::new();
+        for card in records[*i].hands.iter().flatten() {
+            *counts.entry(*card).or_insert(0) += 1;
+            if !seen.contains(&card.rank) {
+                seen.push(card.rank);
+            }
+        }
+        copies.extend(counts.into_values());
+    }
+    let removed_ranks: Vec<Rank> = Rank::ALL
+        .iter()
+        .copied()
+        .filter(|rank| !seen.contains(rank))
+        .collect();
+
+    let dealt = players * cards_per_player;
+    let deck_per_copy = Suit::ALL.len() * (Rank::ALL.len() - removed_ranks.len());
+    let mut num_decks = copies.into_iter().max().unwrap_or(1).max(1);
+    while num_decks * deck_per_copy < dealt && num_decks < usize::from(u8::MAX) {
+        num_decks += 1;
+    }
+    let num_decks = u8::try_from(num_decks).unwrap_or(u8::MAX);
+    let num_players = u8::try_from(players).unwrap_or(u8::MAX);
+
+    let defaults = GameConfig::default();
+    let finish_bonus = if usize::from(num_players) == defaults.finish_bonus().len() {
+        defaults.finish_bonus().to_vec()
+    } else {
+        ConfigFactory::calculate_default_finish_bonus(num_players)
+    };
+    let config = GameConfig {
+        num_decks,
+        num_players,
+        cards_per_player,
+        cards_dealt_aside: (usize::from(num_decks) * deck_per_copy).saturating_sub(dealt),
+        removed_ranks,
+        finish_bonus,
+        ..defaults
+    };
+
+    for i in seated {
+        let Err(error) = score_record(&records[i], &config) else {
+            continue;
+        };
+        let turn = match &error {
+            DatongziError::InvalidInput(
+                Message::RecordIllegalPlay { turn, .. } | Message::RecordUnrecognizedPlay { turn },
+            ) => Some(*turn),
+            _ => None,
+        };
+        let wrapped = turn.filter(|turn| {
+            matches!(
+                records[i].actions.get(*turn),
+                Some(RecordedAction::Play { cards, .. })
+                    if PatternRecognizer::analyze_cards(cards).is_none()
+                        && is_wrapped_chain(cards)
+            )
+        });
+        contradictions.push(match wrapped {
+            Some(turn) => Contradiction::WrappedChain { record: i, turn },
+            None => Contradiction::Replay { record: i, error },
+        });
+    }
+
+    let wrap_policy = if contradictions
+        .iter()
+        .any(|c| matches!(c, Contradiction::WrappedChain { .. }))
+    {
+        ChainWrapPolicy::WrapAround
+    } else {
+        ChainWrapPolicy::NoWrap
+    };
+    ConfigInference {
+        config,
+        wrap_policy,
+        contradictions,
+    }
+}
+
+/// Deduce a configuration and fit its scoring values to reported scores.
+///
+/// `final_scores[i]` holds the final score of each seat of `records[i]`, in
+/// `player_ids` order. Everything else is inferred as in [`infer_config`].
+/// The finish bonuses and the K/A/2 tongzi and dizha bonuses are then fitted
+/// by least squares over every record that replays cleanly; scoring is
+/// linear in these values, so consistent scores are reproduced exactly.
+/// Values no record constrains (say, a dizha bonus when no dizha ever won a
+/// trick) keep their defaults. Each seat whose score the fitted values do
+/// not reproduce is reported as a [`Contradiction::Score`].
+///
+/// Records without a score list, or whose list does not match their seats,
+/// are not fitted.
+///
+/// # Example
+/// ```
+/// use datongzi_rules::scoring::{score_record, GameRecord, RecordedAction};
+/// use datongzi_rules::variants::{infer_config, infer_config_with_scores};
+/// use datongzi_rules::{Card, GameConfig, Rank, Suit};
+///
+/// let card = |suit, rank| Card::new(suit, rank);
+/// let record = |a: Vec<Card>, b: Vec<Card>| GameRecord {
+///     player_ids: vec!["a".into(), "b".into()],
+///     actions: vec![
+///         RecordedAction::Play { player_id: "a".into(), cards: a.clone() },
+///         RecordedAction::Play { player_id: "b".into(), cards: b.clone() },
+///     ],
+///     hands: vec![a, b],
+///     ..GameRecord::default()
+/// };
+/// let records = vec![
+///     record(vec![card(Suit::Spades, Rank::Five)], vec![card(Suit::Hearts, Rank::Ten)]),
+///     record(vec![card(Suit::Spades, Rank::Six)], vec![card(Suit::Hearts, Rank::Seven)]),
+/// ];
+///
+/// // The source app pays [150, -150] for finishing
+/// let source = GameConfig {
+///     finish_bonus: vec![150, -150],
+///     ..infer_config(&records).config
+/// };
+/// let scores: Vec<Vec<i32>> = records
+///     .iter()
+///     .map(|r| {
+///         let summary = score_record(r, &source).unwrap();
+///         r.player_ids.iter().map(|id| summary.final_scores[id]).collect()
+///     })
+///     .collect();
+///
+/// let inference = infer_config_with_scores(&records, &scores);
+/// assert!(inference.contradictions.is_empty());
+/// assert_eq!(inference.config.finish_bonus(), &[150, -150]);
+/// ```
+#[must_use]
+pub fn infer_config_with_scores(
+    records: &[GameRecord],
+    final_scores: &[Vec<i32>],
+) -> ConfigInference {
+    let mut inference = infer_config(records);
+    let config = &inference.config;
+    let finishers = config.finish_bonus().len();
+
+    // Scoring values being fitted, in this order
+    let defaults: Vec<i32> = config
+        .finish_bonus()
+        .iter()
+        .copied()
+        .chain([
+            config.k_tongzi_bonus(),
+            config.a_tongzi_bonus(),
+            config.two_tongzi_bonus(),
+            config.dizha_bonus(),
+        ])
+        .collect();
+    let with_values = |values: &[i32]| GameConfig {
+        finish_bonus: values[..finishers].to_vec(),
+        k_tongzi_bonus: values[finishers],
+        a_tongzi_bonus: values[finishers + 1],
+        two_tongzi_bonus: values[finishers + 2],
+        dizha_bonus: values[finishers + 3],
+        ..config.clone()
+    };
+    let zeroed = vec![0; defaults.len()];
+    let units: Vec<GameConfig> = (0..defaults.len())
+        .map(|k| {
+            let mut values = zeroed.clone();
+            values[k] = 1;
+            with_values(&values)
+        })
+        .collect();
+    let zeroed = with_values(&zeroed);
+
+    // One row per seat: coefficient of each value, base score, observed score
+    let mut rows: Vec<(usize, usize, Vec<i32>, i32, i32)> = Vec::new();
+    for (i, record) in records.iter().enumerate() {
+        let Some(observed) = final_scores.get(i) else {
+            continue;
+        };
+        let fails_replay = inference.contradictions.iter().any(|c| {
+            matches!(c,
+                Contradiction::PlayerCount { record, .. }
+                | Contradiction::WrappedChain { record, .. }
+                | Contradiction::Replay { record, .. } if *record == i)
+        });
+        if fails_replay || observed.len() != record.player_ids.len() {
+            continue;
+        }
+        let seat_scores = |config: &GameConfig| -> Option<Vec<i32>> {
+            let summary = score_record(record, config).ok()?;
+            Some(
+                record
+                    .player_ids
+                    .iter()
+                    .map(|id| summary.final_scores.get(id).copied().unwrap_or(0))
+                    .collect(),
+            )
+        };
+        let Some(base) = seat_scores(&zeroed) else {
+            continue;
+        };
+        let Some(unit_scores) = units.iter().map(seat_scores).collect::<Option<Vec<_>>>() else {
+            continue;
+        };
+        for (seat, &observed) in observed.iter().enumerate() {
+            let coefficients = unit_scores
+                .iter()
+                .map(|s| s[seat].saturating_sub(base[seat]))
+                .collect();
+            rows.push((i, seat, coefficients, base[seat], observed));
+        }
+    }
+    if rows.is_empty() {
+        return inference;
+    }
+
+    let fitted = fit_values(
+        rows.iter().map(|(_, _, coefficients, base, observed)| {
+            (
+                coefficients.as_slice(),
+                f64::from(observed.saturating_sub(*base)),
+            )
+        }),
+        &defaults,
+    );
+    for (record, seat, coefficients, base, observed) in rows {
+        // Saturate so absurd fitted values surface as contradictions, not overflow
+        let expected = coefficients
+            .iter()
+            .zip(&fitted)
+            .map(|(c, v)| c.saturating_mul(*v))
+            .fold(base, i32::saturating_add);
+        if expected != observed {
+            inference.contradictions.push(Contradiction::Score {
+                record,
+                seat,
+                expected,
+                observed,
+            });
+        }
+    }
+    inference.config = with_values(&fitted);
+    inference
+}
+
+/// Least-squares fit of `values` to `rows` of (coefficients, target), rounded
+/// to whole points.
+///
+/// Solves the normal equations in reduced row echelon form; values without a
+/// pivot are not determined by the rows and keep their `defaults`.
+fn fit_values<'a>(rows: impl Iterator<Item = (&'a [i32], f64)>, defaults: &[i32]) -> Vec<i32> {
+    let width = defaults.len();
+    // Augmented normal matrix [AᵀA | Aᵀb]
+    let mut normal = vec![vec![0.0; width + 1]; width];
+    for (coefficients, target) in rows {
+        for (row, &a) in normal.iter_mut().zip(coefficients) {
+            for (cell, &b) in row.iter_mut().zip(coefficients) {
+                *cell += f64::from(a) * f64::from(b);
+            }
+            row[width] += f64::from(a) * target;
+        }
+    }
+
+    let mut pivots: Vec<(usize, usize)> = Vec::new();
+    for column in 0..width {
+        let row = pivots.len();
+        let Some(best) = (row..width).max_by(|a, b| {
+            normal[*a][column]
+                .abs()
+                .total_cmp(&normal[*b][column].abs())
+        }) else {
+            break;
+        };
+        if normal[best][column].abs() < PIVOT_EPSILON {
+            continue;
+        }
+        normal.swap(row, best);
+        let pivot = normal[row][column];
+        for cell in &mut normal[row] {
+            *cell /= pivot;
+        }
+        let pivot_row = normal[row].clone();
+        for (other, cells) in normal.iter_mut().enumerate() {
+            let factor = cells[column];
+            if other == row || factor.abs() < PIVOT_EPSILON {
+                continue;
+            }
+            for (cell, p) in cells.iter_mut().zip(&pivot_row) {
+                *cell -= factor * p;
+            }
+        }
+        pivots.push((row, column));
+    }
+
+    let mut values: Vec<f64> = defaults.iter().map(|d| f64::from(*d)).collect();
+    let free: Vec<usize> = (0..width)
+        .filter(|column| pivots.iter().all(|(_, c)| c != column))
+        .collect();
+    for (row, column) in &pivots {
+        values[*column] = normal[*row][width]
+            - free
+                .iter()
+                .map(|f| normal[*row][*f] * values[*f])
+                .sum::<f64>();
+    }
+    values.iter().map(|v| v.round() as i32).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(ranks: &[Rank]) -> Vec<Card> {
+        ranks
+            .iter()
+            .flat_map(|r| [Card::new(Suit::Spades, *r), Card::new(Suit::Hearts, *r)])
+            .collect()
+    }
+
+    #[test]
+    fn test_flags_wrapped_chains_and_odd_records() {
+        let chain = pairs(&[Rank::Ace, Rank::Two, Rank::Three]);
+        let mut hand_b = pairs(&[Rank::Five, Rank::Six, Rank::Seven]);
+        hand_b.truncate(6);
+        let wrapped = GameRecord {
+            player_ids: vec!["a".into(), "b".into()],
+            hands: vec![chain.clone(), hand_b.clone()],
+            actions: vec![RecordedAction::Play {
+                player_id: "a".into(),
+                cards: chain.clone(),
+            }],
+            ..GameRecord::default()
+        };
+        let clean = GameRecord {
+            actions: Vec::new(),
+            ..wrapped.clone()
+        };
+        let three_seats = GameRecord {
+            player_ids: vec!["a".into(), "b".into(), "c".into()],
+            hands: vec![chain.clone(), hand_b.clone(), hand_b],
+            ..GameRecord::default()
+        };
+
+        let inference = infer_config(&[wrapped, clean.clone(), clean, three_seats]);
+        assert_eq!(inference.config.num_players(), 2);
+        assert_eq!(inference.config.cards_per_player(), 6);
+        assert_eq!(inference.config.num_decks(), 1);
+        assert_eq!(inference.config.finish_bonus().len(), 2);
+        assert_eq!(inference.wrap_policy, ChainWrapPolicy::WrapAround);
+        assert_eq!(
+            inference.contradictions,
+            vec![
+                Contradiction::PlayerCount {
+                    record: 3,
+                    players: 3
+                },
+                Contradiction::WrappedChain { record: 0, turn: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fits_scoring_values_to_final_scores() {
+        let three = |suit, rank| vec![Card::new(suit, rank); 3];
+        let game = |a: Vec<Card>, b: Vec<Card>, b_answers: bool| {
+            let mut actions = vec![RecordedAction::Play {
+                player_id: "a".into(),
+                cards: a.clone(),
+            }];
+            if b_answers {
+                actions.push(RecordedAction::Play {
+                    player_id: "b".into(),
+                    cards: b.clone(),
+                });
+            }
+            GameRecord {
+                player_ids: vec!["a".into(), "b".into()],
+                hands: vec![a, b],
+                actions,
+                ..GameRecord::default()
+            }
+        };
+        let odd = pairs(&[Rank::Three, Rank::Four])[..3].to_vec();
+        let records = vec![
+            // a wins with a K tongzi and finishes first
+            game(three(Suit::Spades, Rank::King), odd.clone(), false),
+            // a wins with an A tongzi
+            game(three(Suit::Hearts, Rank::Ace), odd, false),
+            // b answers 555 with a 2 tongzi; both finish
+            game(
+                three(Suit::Clubs, Rank::Five),
+                three(Suit::Spades, Rank::Two),
+                true,
+            ),
+            // 555 then 666: second place is pinned down
+            game(
+                three(Suit::Clubs, Rank::Five),
+                three(Suit::Hearts, Rank::Six),
+                true,
+            ),
+        ];
+        let source = GameConfig {
+            finish_bonus: vec![80, -80],
+            k_tongzi_bonus: 150,
+            a_tongzi_bonus: 250,
+            two_tongzi_bonus: 350,
+            ..infer_config(&records).config
+        };
+        let mut scores: Vec<Vec<i32>> = records
+            .iter()
+            .map(|record| {
+                let summary = score_record(record, &source).unwrap();
+                record
+                    .player_ids
+                    .iter()
+                    .map(|id| summary.final_scores[id])
+                    .collect()
+            })
+            .collect();
+
+        let inference = infer_config_with_scores(&records, &scores);
+        assert!(inference.contradictions.is_empty());
+        assert_eq!(inference.config.finish_bonus(), &[80, -80]);
+        assert_eq!(inference.config.k_tongzi_bonus(), 150);
+        assert_eq!(inference.config.a_tongzi_bonus(), 250);
+        assert_eq!(inference.config.two_tongzi_bonus(), 350);
+        // No dizha ever won a trick
+        assert_eq!(
+            inference.config.dizha_bonus(),
+            GameConfig::default().dizha_bonus()
+        );
+
+        // A score the other records cannot explain is flagged
+        scores.push(scores[3].clone());
+        scores.push(scores[3].clone());
+        scores[3][1] += 90;
+        let mut tampered = records.clone();
+        tampered.push(records[3].clone());
+        tampered.push(records[3].clone());
+        let inference = infer_config_with_scores(&tampered, &scores);
+        assert!(inference.contradictions.contains(&Contradiction::Score {
+            record: 3,
+            seat: 1,
+            expected: scores[3][1] - 60,
+            observed: scores[3][1],
+        }));
+    }
+
+    #[test]
+    fn test_is_wrapped_chain() {
+        assert!(is_wrapped_chain(&pairs(&[
+            Rank::Ace,
+            Rank::Two,
+            Rank::Three
+        ])));
+        assert!(is_wrapped_chain(&pairs(&[
+            Rank::King,
+            Rank::Ace,
+            Rank::Two
+        ])));
+        assert!(!is_wrapped_chain(&pairs(&[Rank::Five, Rank::Six])));
+        assert!(!is_wrapped_chain(&pairs(&[Rank::Two, Rank::Four])));
+    }
+}
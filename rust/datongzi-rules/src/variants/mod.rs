@@ -3,7 +3,21 @@
 //! This module provides pre-configured game setups and validation tools:
 //! - `ConfigFactory`: Factory for creating common game configurations
 //! - `VariantValidator`: Validator for checking configuration playability
+//! - `HouseRules`: Engine hooks for house-rule plugins
+//! - `infer_config`: Deduce a configuration from imported game records
+//! - `infer_config_with_scores`: Also fit scoring values to the records' final scores
+//! - `check_hot_reload`: Whether updated rules may replace those of a game in progress
 
 mod config_factory;
+mod hot_reload;
+mod house_rules;
+mod inference;
 
-pub use config_factory::{ConfigFactory, VariantValidator};
+pub use config_factory::{
+    ConfigFactory, ConfigFix, PatternAudit, PatternFeasibility, VariantValidator,
+};
+pub use hot_reload::{check_hot_reload, hot_reload_conflicts};
+pub use house_rules::{HouseRule, HouseRules, PlayContext};
+pub use inference::{
+    infer_config, infer_config_with_scores, ChainWrapPolicy, ConfigInference, Contradiction,
+};
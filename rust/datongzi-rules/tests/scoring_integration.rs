@@ -1,5 +1,7 @@
 //! Integration tests for scoring system
 
+use datongzi_rules::i18n::Message;
+use datongzi_rules::scoring::{BonusRule, GameEndContext, ScoringEvent, TrickContext};
 use datongzi_rules::{
     BonusType, Card, GameConfig, PlayPattern, PlayType, Rank, ScoreComputation, Suit,
 };
@@ -160,3 +162,66 @@ fn test_zero_score_round() {
     // No events should be recorded
     assert_eq!(engine.scoring_events().len(), 0);
 }
+
+/// House rule: 50 points for winning a trick with a bomb of 6+ cards,
+/// and a 20-point consolation for the last finisher.
+#[derive(Debug)]
+struct HouseRule;
+
+impl BonusRule for HouseRule {
+    fn on_trick_won(&self, ctx: &TrickContext<'_>) -> Vec<ScoringEvent> {
+        let pattern = ctx.winning_pattern;
+        if pattern.play_type != PlayType::Bomb || pattern.card_count < 6 {
+            return Vec::new();
+        }
+        vec![ScoringEvent::new(
+            ctx.player_id.to_string(),
+            BonusType::RoundWin,
+            50,
+            Message::Custom("Big bomb".to_string()),
+            Some(ctx.round_number),
+            Vec::new(),
+        )]
+    }
+
+    fn on_game_end(&self, ctx: &GameEndContext<'_>) -> Vec<ScoringEvent> {
+        ctx.finish_order
+            .last()
+            .map(|last| {
+                ScoringEvent::new(
+                    last.clone(),
+                    BonusType::RoundWin,
+                    20,
+                    Message::Custom("Consolation".to_string()),
+                    None,
+                    Vec::new(),
+                )
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+#[test]
+fn test_custom_bonus_rule() {
+    let mut engine = ScoreComputation::new(GameConfig::default());
+    engine.add_bonus_rule(HouseRule);
+
+    let bomb = PlayPattern::new(PlayType::Bomb, Rank::Nine, None, vec![], 6, 0);
+    let events = engine.create_special_bonus_events("p1".to_string(), &bomb, 1, true);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].points, 50);
+
+    let finish_order = vec!["p1".to_string(), "p2".to_string(), "p3".to_string()];
+    let events = engine.create_finish_bonus_events(&finish_order);
+    assert_eq!(events.len(), 4);
+    assert_eq!(engine.calculate_total_score_for_player("p3"), -60 + 20);
+
+    // Without any rules, no bonuses are produced
+    let mut bare = ScoreComputation::new(GameConfig::default());
+    bare.clear_bonus_rules();
+    let dizha = PlayPattern::new(PlayType::Dizha, Rank::Ace, None, vec![], 8, 0);
+    assert!(bare
+        .create_special_bonus_events("p1".to_string(), &dizha, 1, true)
+        .is_empty());
+}
@@ -0,0 +1,129 @@
+//! Differential test: PlayValidator::can_beat_play vs the reference comparator.
+//!
+//! Run with `cargo test --features reference-model --test test_compare_reference`.
+
+#![cfg(feature = "reference-model")]
+
+use datongzi_rules::patterns::reference::reference_beats;
+use datongzi_rules::{Card, PatternRecognizer, PlayPattern, PlayValidator, Rank, Suit};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Ranks that may appear in chains (3 through A).
+const CHAIN_RANKS: &[Rank] = &[
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+    Rank::Jack,
+    Rank::Queen,
+    Rank::King,
+    Rank::Ace,
+];
+
+fn random_suit(rng: &mut StdRng) -> Suit {
+    *Suit::ALL.choose(rng).unwrap()
+}
+
+fn random_rank(rng: &mut StdRng) -> Rank {
+    *Rank::ALL.choose(rng).unwrap()
+}
+
+/// `count` cards of `rank` in random suits
+fn of_rank(rng: &mut StdRng, rank: Rank, count: usize) -> Vec<Card> {
+    (0..count)
+        .map(|_| Card::new(random_suit(rng), rank))
+        .collect()
+}
+
+/// `width` cards of each rank in a random chain of `length` ranks
+fn chain(rng: &mut StdRng, length: usize, width: usize) -> Vec<Card> {
+    let start = rng.gen_range(0..=CHAIN_RANKS.len() - length);
+    CHAIN_RANKS[start..start + length]
+        .iter()
+        .flat_map(|rank| of_rank(rng, *rank, width))
+        .collect()
+}
+
+/// Random cards shaped like one of the play types; may not form a valid play
+fn random_play(rng: &mut StdRng) -> Vec<Card> {
+    let rank = random_rank(rng);
+    match rng.gen_range(0..9) {
+        0 => of_rank(rng, rank, 1),
+        1 => of_rank(rng, rank, 2),
+        2 => {
+            let length = rng.gen_range(2..=5);
+            chain(rng, length, 2)
+        }
+        3 => {
+            let mut cards = of_rank(rng, rank, 3);
+            let kickers = rng.gen_range(0..=2);
+            cards.extend((0..kickers).map(|_| Card::new(random_suit(rng), random_rank(rng))));
+            cards
+        }
+        4 => {
+            let length = rng.gen_range(2..=4);
+            chain(rng, length, 3)
+        }
+        5 => {
+            let length = rng.gen_range(2..=3);
+            let mut cards = chain(rng, length, 3);
+            let wings = rng.gen_range(1..=2 * length);
+            cards.extend((0..wings).map(|_| Card::new(random_suit(rng), random_rank(rng))));
+            cards
+        }
+        6 => {
+            let count = rng.gen_range(4..=8);
+            of_rank(rng, rank, count)
+        }
+        7 => vec![Card::new(random_suit(rng), rank); 3],
+        _ => Suit::ALL
+            .iter()
+            .flat_map(|suit| [Card::new(*suit, rank); 2])
+            .collect(),
+    }
+}
+
+fn random_pattern(rng: &mut StdRng) -> (Vec<Card>, PlayPattern) {
+    loop {
+        let cards = random_play(rng);
+        if let Some(pattern) = PatternRecognizer::analyze_cards(&cards) {
+            return (cards, pattern);
+        }
+    }
+}
+
+#[test]
+fn test_can_beat_play_matches_reference() {
+    let mut rng = StdRng::seed_from_u64(0x5eed);
+
+    for _ in 0..50_000 {
+        let (new_cards, new_pattern) = random_pattern(&mut rng);
+        let (_, current_pattern) = random_pattern(&mut rng);
+
+        assert_eq!(
+            PlayValidator::can_beat_play(&new_cards, Some(&current_pattern)),
+            reference_beats(&new_pattern, &current_pattern),
+            "{new_pattern:?} vs {current_pattern:?}"
+        );
+    }
+}
+
+#[test]
+fn test_every_type_pair_is_exercised() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut seen = std::collections::HashSet::new();
+
+    for _ in 0..50_000 {
+        let (_, a) = random_pattern(&mut rng);
+        let (_, b) = random_pattern(&mut rng);
+        seen.insert((a.play_type, b.play_type));
+    }
+
+    assert_eq!(seen.len(), 81);
+}
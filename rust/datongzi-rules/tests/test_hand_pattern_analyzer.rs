@@ -1,6 +1,7 @@
 //! Unit tests for HandPatternAnalyzer.
 
-use datongzi_rules::{Card, HandPatternAnalyzer, Rank, Suit};
+use datongzi_rules::ai_helpers::{AnalyzeOptions, ConsumeError, HandPatterns, QuadTreatment};
+use datongzi_rules::{Card, HandPatternAnalyzer, PatternRecognizer, PlayType, Rank, Suit};
 
 #[test]
 fn test_analyze_empty_hand() {
@@ -317,3 +318,249 @@ fn test_triple_vs_consecutive_pairs_priority() {
     assert_eq!(patterns.consecutive_pair_chains.len(), 0);
     assert_eq!(patterns.triples.len(), 0);
 }
+
+#[test]
+fn test_optimize_for_fewest_plays_beats_greedy() {
+    // Greedy: 777 + 55 + 66 + 88 + 99 = 5 plays
+    // Optimal: 5566778899 + single 7 = 2 plays
+    let mut hand = Vec::new();
+    for rank in [Rank::Five, Rank::Six, Rank::Eight, Rank::Nine] {
+        hand.push(Card::new(Suit::Spades, rank));
+        hand.push(Card::new(Suit::Hearts, rank));
+    }
+    hand.push(Card::new(Suit::Spades, Rank::Seven));
+    hand.push(Card::new(Suit::Hearts, Rank::Seven));
+    hand.push(Card::new(Suit::Clubs, Rank::Seven));
+
+    let greedy = HandPatternAnalyzer::analyze_patterns(&hand);
+    let optimized = HandPatternAnalyzer::optimize_for_fewest_plays(&hand);
+
+    assert_eq!(optimized.play_count(), 2);
+    assert!(optimized.play_count() < greedy.play_count());
+    assert_eq!(optimized.consecutive_pair_chains.len(), 1);
+    assert_eq!(optimized.consecutive_pair_chains[0].len(), 10);
+    assert_eq!(optimized.singles.len(), 1);
+    assert_eq!(optimized.singles[0].rank, Rank::Seven);
+}
+
+#[test]
+fn test_optimize_for_fewest_plays_keeps_trumps_and_cards() {
+    let mut hand = vec![
+        Card::new(Suit::Spades, Rank::King),
+        Card::new(Suit::Hearts, Rank::King),
+        Card::new(Suit::Clubs, Rank::King),
+        Card::new(Suit::Diamonds, Rank::King),
+        Card::new(Suit::Hearts, Rank::Two),
+        Card::new(Suit::Spades, Rank::Two),
+    ];
+    hand.push(Card::new(Suit::Hearts, Rank::Ace));
+
+    let optimized = HandPatternAnalyzer::optimize_for_fewest_plays(&hand);
+
+    assert_eq!(optimized.bombs.len(), 1);
+    assert_eq!(optimized.trump_count, 1);
+    assert_eq!(optimized.pairs.len(), 1);
+    assert_eq!(optimized.singles.len(), 1);
+
+    let total: usize = optimized.bombs.iter().map(Vec::len).sum::<usize>()
+        + optimized.pairs.iter().map(Vec::len).sum::<usize>()
+        + optimized.singles.len();
+    assert_eq!(total, hand.len());
+}
+
+#[test]
+fn test_optimize_for_fewest_plays_absorbs_kickers() {
+    // 999 + 55: triple-with-two (1 play) beats triple + pair (2 plays)
+    let hand = vec![
+        Card::new(Suit::Spades, Rank::Nine),
+        Card::new(Suit::Hearts, Rank::Nine),
+        Card::new(Suit::Clubs, Rank::Nine),
+        Card::new(Suit::Spades, Rank::Five),
+        Card::new(Suit::Hearts, Rank::Five),
+    ];
+
+    let optimized = HandPatternAnalyzer::optimize_for_fewest_plays(&hand);
+    assert_eq!(optimized.play_count(), 1);
+    assert_eq!(optimized.triples.len(), 1);
+    assert_eq!(optimized.triples[0].len(), 5);
+    assert!(optimized.pairs.is_empty());
+    assert_eq!(
+        PatternRecognizer::analyze_cards(&optimized.triples[0]).map(|p| p.play_type),
+        Some(PlayType::Triple)
+    );
+
+    // 555666 + 9 + J + K: an airplane carries up to four wings
+    let mut hand: Vec<Card> = [Rank::Five, Rank::Six]
+        .iter()
+        .flat_map(|r| [Suit::Spades, Suit::Hearts, Suit::Clubs].map(|s| Card::new(s, *r)))
+        .collect();
+    hand.extend([Rank::Nine, Rank::Jack, Rank::King].map(|r| Card::new(Suit::Clubs, r)));
+
+    let optimized = HandPatternAnalyzer::optimize_for_fewest_plays(&hand);
+    assert_eq!(optimized.play_count(), 1);
+    assert_eq!(
+        PatternRecognizer::analyze_cards(&optimized.airplane_chains[0]).map(|p| p.play_type),
+        Some(PlayType::AirplaneWithWings)
+    );
+}
+
+fn quad(rank: Rank) -> Vec<Card> {
+    Suit::ALL
+        .iter()
+        .map(|suit| Card::new(*suit, rank))
+        .collect()
+}
+
+#[test]
+fn test_quads_split_when_bombs_not_locked() {
+    let mut hand = quad(Rank::Eight);
+    hand.extend(quad(Rank::Nine).into_iter().take(3));
+
+    let bomb = HandPatternAnalyzer::optimize_for_fewest_plays(&hand);
+    assert_eq!(bomb.bombs.len(), 1);
+
+    let options = AnalyzeOptions {
+        treat_quads_as: QuadTreatment::TriplePlusSingle,
+    };
+    let split = HandPatternAnalyzer::optimize_for_fewest_plays_with_options(&hand, &options);
+    assert!(split.bombs.is_empty());
+    assert_eq!(split.airplane_chains.len(), 1);
+
+    let cards = |p: &HandPatterns| -> usize {
+        p.airplane_chains.iter().map(Vec::len).sum::<usize>()
+            + p.triples.iter().map(Vec::len).sum::<usize>()
+            + p.pairs.iter().map(Vec::len).sum::<usize>()
+            + p.singles.len()
+    };
+    assert_eq!(cards(&split), hand.len());
+}
+
+#[test]
+fn test_quads_both_prefers_fewer_plays() {
+    // 8888 + 99: bomb + pair (2 plays) vs triple + single + pair (3 plays)
+    let mut hand = quad(Rank::Eight);
+    hand.extend(quad(Rank::Nine).into_iter().take(2));
+
+    let options = AnalyzeOptions {
+        treat_quads_as: QuadTreatment::Both,
+    };
+    let both = HandPatternAnalyzer::optimize_for_fewest_plays_with_options(&hand, &options);
+    assert_eq!(both.bombs.len(), 1);
+    assert_eq!(both.play_count(), 2);
+
+    let analyzed = HandPatternAnalyzer::analyze_patterns_with_options(&hand, &options);
+    assert_eq!(analyzed.bombs.len(), 1);
+}
+
+#[test]
+fn test_larger_bombs_always_kept() {
+    let mut hand = quad(Rank::Ten);
+    hand.push(Card::new(Suit::Spades, Rank::Ten));
+
+    let options = AnalyzeOptions {
+        treat_quads_as: QuadTreatment::TriplePlusSingle,
+    };
+    let patterns = HandPatternAnalyzer::analyze_patterns_with_options(&hand, &options);
+    assert_eq!(patterns.bombs.len(), 1);
+    assert_eq!(patterns.bombs[0].len(), 5);
+}
+
+#[test]
+fn test_near_trumps() {
+    // Two spade kings: one short of a Tongzi
+    let mut hand = vec![Card::new(Suit::Spades, Rank::King); 2];
+    // Seven fives, one diamond short of a Dizha; each pair is also a near-Tongzi
+    for suit in [Suit::Spades, Suit::Hearts, Suit::Clubs] {
+        hand.extend([Card::new(suit, Rank::Five); 2]);
+    }
+    hand.push(Card::new(Suit::Diamonds, Rank::Five));
+
+    let near = HandPatternAnalyzer::analyze_patterns(&hand).near_trumps;
+
+    assert_eq!(near[0].play_type, PlayType::Dizha);
+    assert_eq!(near[0].cards.len(), 7);
+    assert_eq!(near[0].missing, Card::new(Suit::Diamonds, Rank::Five));
+
+    let tongzi: Vec<Card> = near
+        .iter()
+        .filter(|n| n.play_type == PlayType::Tongzi)
+        .map(|n| n.missing)
+        .collect();
+    assert_eq!(tongzi[0], Card::new(Suit::Spades, Rank::King));
+    assert_eq!(tongzi.len(), 4);
+
+    let optimized = HandPatternAnalyzer::optimize_for_fewest_plays(&hand);
+    assert_eq!(optimized.near_trumps, near);
+}
+
+#[test]
+fn test_complete_trumps_are_not_near() {
+    let hand = vec![Card::new(Suit::Hearts, Rank::Ace); 3];
+    assert!(HandPatternAnalyzer::analyze_patterns(&hand)
+        .near_trumps
+        .is_empty());
+}
+
+#[test]
+fn test_consume_whole_group_keeps_other_groups() {
+    let hand = vec![
+        Card::new(Suit::Spades, Rank::Five),
+        Card::new(Suit::Hearts, Rank::Five),
+        Card::new(Suit::Spades, Rank::Six),
+        Card::new(Suit::Hearts, Rank::Six),
+        Card::new(Suit::Spades, Rank::Jack),
+        Card::new(Suit::Hearts, Rank::Jack),
+        Card::new(Suit::Clubs, Rank::Jack),
+        Card::new(Suit::Spades, Rank::Ace),
+    ];
+    let patterns = HandPatternAnalyzer::analyze_patterns(&hand);
+    let triple = patterns.triples[0].clone();
+
+    let rest = patterns.consume(&triple).unwrap();
+    assert!(rest.triples.is_empty());
+    assert_eq!(
+        rest.consecutive_pair_chains,
+        patterns.consecutive_pair_chains
+    );
+    assert_eq!(rest.singles, patterns.singles);
+    assert_eq!(rest.total_cards, 5);
+    assert_eq!(rest.play_count(), patterns.play_count() - 1);
+}
+
+#[test]
+fn test_consume_breaking_a_bomb_reflows_it() {
+    let mut hand: Vec<Card> = Suit::ALL
+        .iter()
+        .map(|s| Card::new(*s, Rank::Eight))
+        .collect();
+    hand.push(Card::new(Suit::Spades, Rank::King));
+    let patterns = HandPatternAnalyzer::analyze_patterns(&hand);
+    assert_eq!(patterns.trump_count, 1);
+
+    // A single 8 comes out of the bomb, leaving a triple
+    let rest = patterns
+        .consume(&[Card::new(Suit::Hearts, Rank::Eight)])
+        .unwrap();
+    assert_eq!(rest.trump_count, 0);
+    assert_eq!(rest.triples.len(), 1);
+    assert_eq!(rest.singles, vec![Card::new(Suit::Spades, Rank::King)]);
+}
+
+#[test]
+fn test_consume_rejects_unheld_or_invalid_plays() {
+    let hand = vec![
+        Card::new(Suit::Spades, Rank::Nine),
+        Card::new(Suit::Hearts, Rank::Ten),
+    ];
+    let patterns = HandPatternAnalyzer::analyze_patterns(&hand);
+
+    assert_eq!(
+        patterns.consume(&hand).unwrap_err(),
+        ConsumeError::InvalidPattern
+    );
+    let ace = Card::new(Suit::Spades, Rank::Ace);
+    assert_eq!(
+        patterns.consume(&[ace]).unwrap_err(),
+        ConsumeError::CardNotHeld(ace)
+    );
+}
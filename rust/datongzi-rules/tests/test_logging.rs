@@ -0,0 +1,74 @@
+//! Tests for the pluggable Logger hooks.
+
+use std::sync::{Arc, Mutex};
+
+use datongzi_rules::logging::{self, Level, Logger};
+use datongzi_rules::{Card, HandPatternAnalyzer, PlayGenerator, Rank, Suit};
+
+/// Records (level, module, message) for levels up to `max_level`.
+struct Recorder {
+    max_level: Level,
+    records: Mutex<Vec<(Level, String, String)>>,
+}
+
+impl Logger for Recorder {
+    fn log(&self, level: Level, module: &str, msg: &dyn Fn() -> String) {
+        self.records
+            .lock()
+            .unwrap()
+            .push((level, module.to_string(), msg()));
+    }
+
+    fn enabled(&self, level: Level) -> bool {
+        level <= self.max_level
+    }
+}
+
+// One test function: the logger is process-global
+#[test]
+fn test_logger_receives_generator_and_solver_records() {
+    let recorder = Arc::new(Recorder {
+        max_level: Level::Trace,
+        records: Mutex::new(Vec::new()),
+    });
+    logging::set_logger(recorder.clone());
+
+    let big_hand: Vec<Card> = Rank::ALL
+        .iter()
+        .flat_map(|rank| {
+            [
+                Card::new(Suit::Spades, *rank),
+                Card::new(Suit::Hearts, *rank),
+            ]
+        })
+        .collect();
+    assert!(PlayGenerator::generate_all_plays(&big_hand, 10).is_err());
+    let _ = HandPatternAnalyzer::optimize_for_fewest_plays(&big_hand);
+
+    let records = recorder.records.lock().unwrap().clone();
+    assert!(records
+        .iter()
+        .any(|(level, module, msg)| *level == Level::Warn
+            && module.ends_with("play_generator")
+            && msg.contains("26 cards")));
+    assert!(records
+        .iter()
+        .any(|(level, module, _)| *level == Level::Debug && module.ends_with("play_generator")));
+    assert!(records
+        .iter()
+        .any(|(level, module, _)| *level == Level::Trace
+            && module.ends_with("hand_pattern_analyzer")));
+
+    // Disabled levels are skipped; cleared loggers receive nothing
+    let quiet = Arc::new(Recorder {
+        max_level: Level::Error,
+        records: Mutex::new(Vec::new()),
+    });
+    logging::set_logger(quiet.clone());
+    let _ = PlayGenerator::generate_all_plays(&big_hand, 10);
+    assert!(quiet.records.lock().unwrap().is_empty());
+
+    logging::clear_logger();
+    let _ = PlayGenerator::generate_all_plays(&big_hand, 10);
+    assert_eq!(recorder.records.lock().unwrap().len(), records.len());
+}
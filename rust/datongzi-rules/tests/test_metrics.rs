@@ -0,0 +1,47 @@
+//! Tests for RulesMetrics work counters.
+
+use datongzi_rules::metrics::RulesMetrics;
+use datongzi_rules::{Card, HandPatternAnalyzer, PatternRecognizer, PlayGenerator, Rank, Suit};
+
+#[test]
+fn test_generation_counts_plays_and_recognitions() {
+    let hand = vec![
+        Card::new(Suit::Spades, Rank::Seven),
+        Card::new(Suit::Hearts, Rank::Seven),
+        Card::new(Suit::Clubs, Rank::King),
+    ];
+    let single_five =
+        PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Five)]).unwrap();
+
+    let (plays, metrics) = RulesMetrics::measure(|| {
+        PlayGenerator::generate_beating_plays_with_same_type_or_trump(&hand, &single_five)
+    });
+
+    assert_eq!(metrics.plays_generated, plays.len() as u64);
+    // Every candidate is validated through the recognizer
+    assert!(metrics.recognitions >= plays.len() as u64);
+    assert_eq!(metrics.solver_nodes, 0);
+}
+
+#[test]
+fn test_solver_counts_nodes_and_cache_hits() {
+    let hand: Vec<Card> = [Rank::Five, Rank::Six, Rank::Seven, Rank::Eight]
+        .iter()
+        .flat_map(|rank| {
+            [Suit::Spades, Suit::Hearts, Suit::Clubs]
+                .iter()
+                .map(move |suit| Card::new(*suit, *rank))
+        })
+        .collect();
+
+    let (_, metrics) =
+        RulesMetrics::measure(|| HandPatternAnalyzer::optimize_for_fewest_plays(&hand));
+
+    assert!(metrics.solver_nodes > 0);
+    assert!(metrics.cache_hits > 0);
+
+    // Counters are per call: a second run costs the same again
+    let (_, again) =
+        RulesMetrics::measure(|| HandPatternAnalyzer::optimize_for_fewest_plays(&hand));
+    assert_eq!(again, metrics);
+}
@@ -308,7 +308,7 @@ fn test_game_config_custom_valid() {
     let config2p = GameConfig::new(
         2,             // 2副牌
         2,             // 2人
-        47,            // 每人47张
+        39,            // 每人39张（去掉3和4后共88张）
         10,            // 铺底10张
         vec![50, -50], // 完成奖励
         150,
@@ -322,7 +322,7 @@ fn test_game_config_custom_valid() {
     let config4p = GameConfig::new(
         4,                        // 4副牌
         4,                        // 4人
-        46,                       // 每人46张
+        40,                       // 每人40张（去掉3和4后共176张）
         16,                       // 铺底16张
         vec![100, -30, -50, -70], // 完成奖励
         100,
@@ -448,15 +448,15 @@ fn test_game_config_finish_bonus_length_mismatch() {
 
 #[test]
 fn test_game_config_edge_cases() {
-    // 最小有效配置：2人，1副牌，每人26张，0张铺底
-    let min_config = GameConfig::new(1, 2, 26, 0, vec![50, -50], 100, 200, 300, 400);
+    // 最小有效配置：2人，1副牌（去掉3和4后44张），每人22张，0张铺底
+    let min_config = GameConfig::new(1, 2, 22, 0, vec![50, -50], 100, 200, 300, 400);
     assert!(min_config.validate().is_ok());
 
     // 大型游戏：4副牌，4人（最多4人）
     let large_config = GameConfig::new(
         4,
         4, // 最多4人
-        48,
+        40,
         16,
         vec![100, -20, -40, -80],
         100,
@@ -470,14 +470,14 @@ fn test_game_config_edge_cases() {
 #[test]
 fn test_game_config_zero_bonuses() {
     // 所有奖励分为0（有效，但不常见）
-    let config = GameConfig::new(3, 3, 44, 9, vec![0, 0, 0], 0, 0, 0, 0);
+    let config = GameConfig::new(3, 3, 41, 9, vec![0, 0, 0], 0, 0, 0, 0);
     assert!(config.validate().is_ok());
 }
 
 #[test]
 fn test_game_config_negative_bonuses() {
     // 所有完成奖励为负（罕见但有效）
-    let config = GameConfig::new(3, 3, 44, 9, vec![-100, -200, -300], 100, 200, 300, 400);
+    let config = GameConfig::new(3, 3, 41, 9, vec![-100, -200, -300], 100, 200, 300, 400);
     assert!(config.validate().is_ok());
 }
 
@@ -0,0 +1,88 @@
+//! Operation-count guards for hot paths.
+//!
+//! Budgets are counted with `RulesMetrics`, not timed, so they are stable
+//! across machines. They are generous (several times the current cost) and
+//! only trip on algorithmic blowups.
+//!
+//! Run with `cargo test --features perf-guards --test test_perf_guards`.
+
+#![cfg(feature = "perf-guards")]
+
+use datongzi_rules::metrics::RulesMetrics;
+use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+
+/// `copies` cards of each rank in `ranks`, cycling through suits.
+fn cards(ranks: &[Rank], copies: usize) -> Vec<Card> {
+    ranks
+        .iter()
+        .flat_map(|rank| (0..copies).map(move |i| Card::new(Suit::ALL[i % 4], *rank)))
+        .collect()
+}
+
+/// Assert that recognizing `cards` stays within `budget` pattern checks.
+fn assert_within_budget(name: &str, cards: &[Card], budget: u64) {
+    let (_, metrics) = RulesMetrics::measure(|| PatternRecognizer::analyze_cards(cards));
+    assert_eq!(metrics.recognitions, 1, "{name}: recognizer re-entered");
+    assert!(
+        metrics.pattern_checks <= budget,
+        "{name}: {} pattern checks, budget {budget}",
+        metrics.pattern_checks
+    );
+}
+
+#[test]
+fn test_simple_plays() {
+    assert_within_budget("single", &cards(&[Rank::Nine], 1), 30);
+    assert_within_budget("pair", &cards(&[Rank::Nine], 2), 30);
+    assert_within_budget("bomb", &cards(&[Rank::Nine], 6), 30);
+    assert_within_budget(
+        "triple with kickers",
+        &cards(&[Rank::Nine, Rank::Five], 3)[..5],
+        30,
+    );
+}
+
+#[test]
+fn test_chains() {
+    let run = [
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+        Rank::Ace,
+    ];
+    assert_within_budget("long consecutive pairs", &cards(&run, 2), 30);
+    assert_within_budget("long airplane", &cards(&run, 3), 30);
+
+    let mut wings = cards(&run[..5], 3);
+    wings.extend(cards(&[Rank::Two], 4));
+    assert_within_budget("airplane with wings", &wings, 30);
+}
+
+#[test]
+fn test_full_hands() {
+    // Every rank tripled: one long airplane with wings
+    assert_within_budget("tripled ranks", &cards(&Rank::ALL, 3), 60);
+
+    // Short triple runs with too many wings: every window is tried and fails
+    let mut hand = cards(
+        &[
+            Rank::Five,
+            Rank::Six,
+            Rank::Eight,
+            Rank::Nine,
+            Rank::Jack,
+            Rank::Queen,
+        ],
+        3,
+    );
+    hand.extend(cards(&[Rank::Two], 12));
+    hand.extend(cards(&[Rank::Three, Rank::Ten], 4));
+    assert!(PatternRecognizer::analyze_cards(&hand).is_none());
+    assert_within_budget("fragmented triples", &hand, 60);
+}
@@ -1,6 +1,15 @@
 //! Unit tests for PlayGenerator.
 
-use datongzi_rules::{Card, PatternRecognizer, PlayGenerator, PlayType, Rank, Suit};
+use datongzi_rules::ai_helpers::{
+    DecisionTrace, GenerationOptions, MoveWeights, PlayBuffer, RejectReason, WingPolicy,
+};
+use datongzi_rules::models::{LeadRestrictions, RankOrdering};
+use datongzi_rules::{
+    Card, ConfigFactory, DatongziError, GameConfig, PatternRecognizer, PlayGenerator, PlayType,
+    PlayValidator, Rank, Suit,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 #[test]
 fn test_generate_singles() {
@@ -274,8 +283,28 @@ fn test_generate_all_plays_exceeds_limit() {
     // Use a very low limit to trigger error
     let result = PlayGenerator::generate_all_plays(&hand, 10);
 
-    assert!(result.is_err());
-    assert!(result.unwrap_err().contains("exceeds limit"));
+    assert!(matches!(
+        result,
+        Err(DatongziError::TooManyCombinations { max: 10, .. })
+    ));
+    assert!(PlayGenerator::estimate_play_count(&hand) > 10);
+}
+
+#[test]
+fn test_estimate_play_count_is_upper_bound() {
+    let mut hand = Vec::new();
+    for rank in [Rank::Five, Rank::Six, Rank::Seven] {
+        for suit in [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Spades] {
+            hand.push(Card::new(suit, rank));
+        }
+    }
+    hand.push(Card::new(Suit::Diamonds, Rank::King));
+
+    let actual = PlayGenerator::generate_all_plays(&hand, 100_000)
+        .unwrap()
+        .len();
+    assert!(PlayGenerator::estimate_play_count(&hand) >= actual);
+    assert_eq!(PlayGenerator::estimate_play_count(&[]), 0);
 }
 
 #[test]
@@ -485,3 +514,585 @@ fn test_generate_beating_bare_triple_with_kickers() {
         }
     }
 }
+
+#[test]
+fn test_sample_legal_move_passes_when_nothing_beats() {
+    let hand = vec![Card::new(Suit::Spades, Rank::Five)];
+    let current_pattern =
+        PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::King)]).unwrap();
+    let mut rng = StdRng::seed_from_u64(1);
+
+    let play = PlayGenerator::sample_legal_move(
+        &hand,
+        Some(&current_pattern),
+        &MoveWeights::default(),
+        &mut rng,
+    );
+
+    assert!(play.is_none());
+}
+
+#[test]
+fn test_sample_legal_move_only_returns_beating_plays() {
+    let hand = vec![
+        Card::new(Suit::Spades, Rank::Three),
+        Card::new(Suit::Hearts, Rank::Ace),
+        Card::new(Suit::Clubs, Rank::Two),
+        Card::new(Suit::Spades, Rank::Nine),
+        Card::new(Suit::Hearts, Rank::Nine),
+        Card::new(Suit::Clubs, Rank::Nine),
+        Card::new(Suit::Diamonds, Rank::Nine),
+    ];
+    let current_pattern =
+        PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::King)]).unwrap();
+    let mut rng = StdRng::seed_from_u64(42);
+
+    for _ in 0..50 {
+        let play = PlayGenerator::sample_legal_move(
+            &hand,
+            Some(&current_pattern),
+            &MoveWeights::default(),
+            &mut rng,
+        )
+        .unwrap();
+        assert!(PlayValidator::can_beat_play(&play, Some(&current_pattern)));
+    }
+}
+
+#[test]
+fn test_sample_legal_move_greedy_prefers_low_cards() {
+    let hand = vec![
+        Card::new(Suit::Spades, Rank::Three),
+        Card::new(Suit::Hearts, Rank::Ace),
+    ];
+    let weights = MoveWeights {
+        temperature: 0.0,
+        ..MoveWeights::default()
+    };
+    let mut rng = StdRng::seed_from_u64(0);
+
+    let play = PlayGenerator::sample_legal_move(&hand, None, &weights, &mut rng).unwrap();
+
+    assert_eq!(play, vec![Card::new(Suit::Spades, Rank::Three)]);
+}
+
+#[test]
+fn test_generate_all_plays_adaptive_falls_back_to_canonical() {
+    // 12 nines (3 decks) explode into thousands of bomb subsets
+    let mut hand = Vec::new();
+    for _ in 0..3 {
+        for suit in [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds] {
+            hand.push(Card::new(suit, Rank::Nine));
+        }
+    }
+    hand.push(Card::new(Suit::Hearts, Rank::Three));
+
+    let complexity = PlayGenerator::analyze_complexity(&hand);
+    assert_eq!(complexity.bomb_groups, 1);
+    assert!(complexity.estimated_plays > 200);
+
+    let plays = PlayGenerator::generate_all_plays_adaptive(&hand, 200);
+    assert!(plays.len() <= 200);
+
+    // Every bomb size is still represented, plus dizha
+    for size in 4..=12 {
+        assert!(plays.iter().any(|p| p.len() == size
+            && PatternRecognizer::analyze_cards(p).map(|pat| pat.play_type)
+                == Some(PlayType::Bomb)));
+    }
+    assert!(plays.iter().any(|p| {
+        PatternRecognizer::analyze_cards(p).map(|pat| pat.play_type) == Some(PlayType::Dizha)
+    }));
+}
+
+#[test]
+fn test_generate_all_plays_adaptive_matches_naive_for_small_hands() {
+    let hand = vec![
+        Card::new(Suit::Spades, Rank::Five),
+        Card::new(Suit::Hearts, Rank::Five),
+        Card::new(Suit::Clubs, Rank::Six),
+    ];
+
+    assert_eq!(
+        PlayGenerator::generate_all_plays_adaptive(&hand, 1000),
+        PlayGenerator::generate_all_plays(&hand, 1000).unwrap()
+    );
+}
+
+#[test]
+fn test_protect_trump_structures_keeps_tongzi_intact() {
+    // ♠8♠8♠8 (Tongzi) + ♥8: the ♥8 single and the Tongzi are safe, ♠8 singles/pairs are not
+    let mut hand = vec![Card::new(Suit::Spades, Rank::Eight); 3];
+    hand.push(Card::new(Suit::Hearts, Rank::Eight));
+    let single_five =
+        PatternRecognizer::analyze_cards(&[Card::new(Suit::Clubs, Rank::Five)]).unwrap();
+
+    let unprotected = PlayGenerator::generate_beating_plays_with_options(
+        &hand,
+        &single_five,
+        &GenerationOptions::default(),
+    );
+    assert_eq!(
+        unprotected,
+        PlayGenerator::generate_beating_plays_with_same_type_or_trump(&hand, &single_five)
+    );
+    assert!(unprotected.contains(&vec![Card::new(Suit::Spades, Rank::Eight)]));
+
+    let options = GenerationOptions {
+        protect_trump_structures: true,
+        ..GenerationOptions::default()
+    };
+    let protected =
+        PlayGenerator::generate_beating_plays_with_options(&hand, &single_five, &options);
+    assert!(protected.contains(&vec![Card::new(Suit::Hearts, Rank::Eight)]));
+    assert!(!protected.contains(&vec![Card::new(Suit::Spades, Rank::Eight)]));
+    // The bomb of four 8s uses the Tongzi cards and is dropped; the Tongzi itself stays
+    assert!(protected.iter().all(|p| p.len() != 4));
+    assert!(protected.iter().any(|p| {
+        PatternRecognizer::analyze_cards(p).map(|pat| pat.play_type) == Some(PlayType::Tongzi)
+    }));
+}
+
+#[test]
+fn test_protect_trump_structures_keeps_dizha_intact() {
+    let mut hand = Vec::new();
+    for suit in [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds] {
+        hand.push(Card::new(suit, Rank::Jack));
+        hand.push(Card::new(suit, Rank::Jack));
+    }
+    let pair_of_fives = PatternRecognizer::analyze_cards(&[
+        Card::new(Suit::Spades, Rank::Five),
+        Card::new(Suit::Hearts, Rank::Five),
+    ])
+    .unwrap();
+    let options = GenerationOptions {
+        protect_trump_structures: true,
+        ..GenerationOptions::default()
+    };
+
+    let plays = PlayGenerator::generate_beating_plays_with_options(&hand, &pair_of_fives, &options);
+    let types: Vec<PlayType> = plays
+        .iter()
+        .filter_map(|p| PatternRecognizer::analyze_cards(p).map(|pat| pat.play_type))
+        .collect();
+    assert_eq!(types, vec![PlayType::Dizha]);
+}
+
+#[test]
+fn test_generate_all_plays_with_options_matches_legacy() {
+    let hand = vec![
+        Card::new(Suit::Spades, Rank::Five),
+        Card::new(Suit::Hearts, Rank::Five),
+        Card::new(Suit::Clubs, Rank::Five),
+        Card::new(Suit::Spades, Rank::Six),
+        Card::new(Suit::Hearts, Rank::Six),
+    ];
+    let options = GenerationOptions {
+        canonical_dedup: true,
+        ..GenerationOptions::default()
+    };
+
+    assert_eq!(
+        PlayGenerator::generate_all_plays_with_options(&hand, &options).unwrap(),
+        PlayGenerator::generate_all_plays(&hand, 1000).unwrap()
+    );
+
+    // Without dedup, every suit variant of the 5s pairs is kept
+    let raw = PlayGenerator::generate_all_plays_with_options(&hand, &GenerationOptions::default())
+        .unwrap();
+    let pairs_of_fives = |plays: &[Vec<Card>]| {
+        plays
+            .iter()
+            .filter(|p| p.len() == 2 && p.iter().all(|c| c.rank == Rank::Five))
+            .count()
+    };
+    assert_eq!(pairs_of_fives(&raw), 3);
+    assert_eq!(
+        pairs_of_fives(&PlayGenerator::generate_all_plays(&hand, 1000).unwrap()),
+        1
+    );
+}
+
+#[test]
+fn test_generation_options_allowed_play_types() {
+    let hand = vec![
+        Card::new(Suit::Spades, Rank::Nine),
+        Card::new(Suit::Hearts, Rank::Nine),
+        Card::new(Suit::Clubs, Rank::Nine),
+        Card::new(Suit::Diamonds, Rank::Nine),
+        Card::new(Suit::Spades, Rank::Ten),
+    ];
+    let options = GenerationOptions {
+        allowed_play_types: Some(vec![PlayType::Bomb]),
+        ..GenerationOptions::default()
+    };
+
+    let plays = PlayGenerator::generate_all_plays_with_options(&hand, &options).unwrap();
+    assert_eq!(plays.len(), 1);
+    assert_eq!(plays[0].len(), 4);
+
+    let single_five =
+        PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Five)]).unwrap();
+    let beating = PlayGenerator::generate_beating_plays_with_options(&hand, &single_five, &options);
+    assert_eq!(beating, plays);
+
+    let adaptive = PlayGenerator::generate_all_plays_adaptive_with_options(&hand, &options);
+    assert_eq!(adaptive, plays);
+}
+
+#[test]
+fn test_generation_options_max_combinations() {
+    let hand = vec![
+        Card::new(Suit::Spades, Rank::Five),
+        Card::new(Suit::Hearts, Rank::Six),
+        Card::new(Suit::Clubs, Rank::Seven),
+    ];
+    let options = GenerationOptions {
+        max_combinations: 2,
+        ..GenerationOptions::default()
+    };
+
+    assert!(matches!(
+        PlayGenerator::generate_all_plays_with_options(&hand, &options),
+        Err(DatongziError::TooManyCombinations { found: 3, max: 2 })
+    ));
+}
+
+#[test]
+fn test_generation_options_wing_policy() {
+    let mut hand = Vec::new();
+    for rank in [Rank::Seven, Rank::Eight] {
+        for suit in [Suit::Spades, Suit::Hearts, Suit::Clubs] {
+            hand.push(Card::new(suit, rank));
+        }
+    }
+    for rank in [Rank::Three, Rank::Four, Rank::Five] {
+        hand.push(Card::new(Suit::Spades, rank));
+        hand.push(Card::new(Suit::Hearts, rank));
+    }
+    let wings_only = |wing_policy| GenerationOptions {
+        allowed_play_types: Some(vec![PlayType::AirplaneWithWings]),
+        wing_policy,
+        ..GenerationOptions::default()
+    };
+
+    // Three pair ranks, two needed: C(3, 2) wing sets
+    let pairs =
+        PlayGenerator::generate_all_plays_with_options(&hand, &wings_only(WingPolicy::Pairs))
+            .unwrap();
+    assert_eq!(pairs.len(), 3);
+
+    let selected = PlayGenerator::generate_all_plays_with_options(
+        &hand,
+        &wings_only(WingPolicy::SelectedKickers),
+    )
+    .unwrap();
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected[0].len(), 10);
+
+    let none =
+        PlayGenerator::generate_all_plays_with_options(&hand, &wings_only(WingPolicy::NoWings))
+            .unwrap();
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_generate_into_matches_vec_apis() {
+    let hand = vec![
+        Card::new(Suit::Spades, Rank::Eight),
+        Card::new(Suit::Hearts, Rank::Eight),
+        Card::new(Suit::Clubs, Rank::Eight),
+        Card::new(Suit::Diamonds, Rank::Eight),
+        Card::new(Suit::Spades, Rank::King),
+    ];
+    let pair_of_fives = PatternRecognizer::analyze_cards(&[
+        Card::new(Suit::Spades, Rank::Five),
+        Card::new(Suit::Hearts, Rank::Five),
+    ])
+    .unwrap();
+    let options = GenerationOptions::default();
+    let mut buffer = PlayBuffer::new();
+
+    PlayGenerator::generate_into(&mut buffer, &hand, Some(&pair_of_fives), &options);
+    assert_eq!(
+        buffer.to_vecs(),
+        PlayGenerator::generate_beating_plays_with_options(&hand, &pair_of_fives, &options)
+    );
+
+    // Reuse across turns
+    buffer.clear();
+    PlayGenerator::generate_into(&mut buffer, &hand, None, &options);
+    assert_eq!(
+        buffer.to_vecs(),
+        PlayGenerator::generate_all_plays_adaptive_with_options(&hand, &options)
+    );
+}
+
+#[test]
+fn test_sample_legal_move_traced_explains_choice() {
+    // Why would the bot throw its Dizha on a single 6? The trace shows the odds.
+    let mut hand = vec![Card::new(Suit::Spades, Rank::Seven)];
+    for suit in [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds] {
+        hand.push(Card::new(suit, Rank::Jack));
+        hand.push(Card::new(suit, Rank::Jack));
+    }
+    let single_six =
+        PatternRecognizer::analyze_cards(&[Card::new(Suit::Clubs, Rank::Six)]).unwrap();
+    let weights = MoveWeights::default();
+
+    for seed in 0..20 {
+        let (play, trace) = PlayGenerator::sample_legal_move_traced(
+            &hand,
+            Some(&single_six),
+            &weights,
+            &mut StdRng::seed_from_u64(seed),
+        );
+        let legacy = PlayGenerator::sample_legal_move(
+            &hand,
+            Some(&single_six),
+            &weights,
+            &mut StdRng::seed_from_u64(seed),
+        );
+        assert_eq!(play, legacy);
+
+        let chosen = &trace.candidates[trace.chosen.unwrap()];
+        assert_eq!(Some(&chosen.cards), play.as_ref());
+        assert!(chosen.rejected.is_none());
+        assert!(trace
+            .candidates
+            .iter()
+            .enumerate()
+            .all(|(i, c)| Some(i) == trace.chosen || c.rejected == Some(RejectReason::NotSampled)));
+
+        let total: f64 = trace.candidates.iter().map(|c| c.probability).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    let (_, trace) = PlayGenerator::sample_legal_move_traced(
+        &hand,
+        Some(&single_six),
+        &weights,
+        &mut StdRng::seed_from_u64(0),
+    );
+    let dizha = trace
+        .candidates
+        .iter()
+        .find(|c| c.play_type == Some(PlayType::Dizha))
+        .unwrap();
+    let seven = trace
+        .candidates
+        .iter()
+        .find(|c| c.cards == vec![Card::new(Suit::Spades, Rank::Seven)])
+        .unwrap();
+    assert!(dizha.score < seven.score);
+    assert!(dizha.probability > 0.0);
+}
+
+#[test]
+fn test_sample_legal_move_traced_pass() {
+    let hand = vec![Card::new(Suit::Spades, Rank::Five)];
+    let king = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::King)]).unwrap();
+
+    let (play, trace) = PlayGenerator::sample_legal_move_traced(
+        &hand,
+        Some(&king),
+        &MoveWeights::default(),
+        &mut StdRng::seed_from_u64(1),
+    );
+    assert!(play.is_none());
+    assert_eq!(trace, DecisionTrace::default());
+}
+
+#[test]
+fn test_singles_suit_tiebreak_generation() {
+    let hand = vec![
+        Card::new(Suit::Diamonds, Rank::Nine),
+        Card::new(Suit::Spades, Rank::Nine),
+        Card::new(Suit::Hearts, Rank::Eight),
+    ];
+    let hearts_nine =
+        PatternRecognizer::analyze_cards(&[Card::new(Suit::Hearts, Rank::Nine)]).unwrap();
+
+    let default =
+        PlayGenerator::generate_beating_plays_with_same_type_or_trump(&hand, &hearts_nine);
+    assert!(default.is_empty());
+
+    for canonical_dedup in [false, true] {
+        let options = GenerationOptions {
+            singles_suit_tiebreak: true,
+            canonical_dedup,
+            ..GenerationOptions::default()
+        };
+        let plays =
+            PlayGenerator::generate_beating_plays_with_options(&hand, &hearts_nine, &options);
+        assert_eq!(plays, vec![vec![Card::new(Suit::Spades, Rank::Nine)]]);
+    }
+}
+
+#[test]
+fn test_bomb_rank_order_generation() {
+    let aces: Vec<Card> = Suit::ALL.iter().map(|s| Card::new(*s, Rank::Ace)).collect();
+    let twos: Vec<Card> = Suit::ALL.iter().map(|s| Card::new(*s, Rank::Two)).collect();
+    let current = PatternRecognizer::analyze_cards(&twos).unwrap();
+
+    assert!(
+        PlayGenerator::generate_beating_plays_with_same_type_or_trump(&aces, &current).is_empty()
+    );
+
+    let options = GenerationOptions {
+        bomb_rank_order: RankOrdering::Custom(vec![Rank::Two, Rank::Ace]),
+        ..GenerationOptions::default()
+    };
+    let plays = PlayGenerator::generate_beating_plays_with_options(&aces, &current, &options);
+    assert_eq!(plays.len(), 1);
+    assert_eq!(plays[0].len(), 4);
+}
+
+#[test]
+fn test_generate_leading_candidates_bounded() {
+    let mut hand = Vec::new();
+    for rank in [Rank::Five, Rank::Six, Rank::Seven, Rank::Eight, Rank::King] {
+        for suit in Suit::ALL {
+            hand.push(Card::new(suit, rank));
+            hand.push(Card::new(suit, rank));
+        }
+    }
+    hand.push(Card::new(Suit::Spades, Rank::Ten));
+
+    let leads = PlayGenerator::generate_leading_candidates(&hand, &GenerationOptions::default());
+    let types: Vec<PlayType> = leads
+        .iter()
+        .map(|play| PatternRecognizer::analyze_cards(play).unwrap().play_type)
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            PlayType::Single,
+            PlayType::Pair,
+            PlayType::ConsecutivePairs,
+            PlayType::Airplane,
+            PlayType::Triple,
+            PlayType::Triple,
+            PlayType::Triple,
+            PlayType::Triple,
+            PlayType::Triple,
+            PlayType::Bomb,
+            PlayType::Dizha,
+        ]
+    );
+    // 5-6-7-8 chains, lowest single and pair
+    assert_eq!(leads[0], vec![Card::new(Suit::Diamonds, Rank::Five)]);
+    assert_eq!(leads[2].len(), 8);
+    assert_eq!(leads[3].len(), 12);
+
+    let pairs_only = GenerationOptions {
+        allowed_play_types: Some(vec![PlayType::Pair]),
+        ..GenerationOptions::default()
+    };
+    let leads = PlayGenerator::generate_leading_candidates(&hand, &pairs_only);
+    assert_eq!(leads.len(), 1);
+}
+
+#[test]
+fn test_forbidden_kicker_ranks_generation() {
+    let mut hand: Vec<Card> = [Suit::Spades, Suit::Hearts, Suit::Clubs]
+        .iter()
+        .flat_map(|suit| [Card::new(*suit, Rank::Nine), Card::new(*suit, Rank::Ten)])
+        .collect();
+    hand.push(Card::new(Suit::Spades, Rank::Two));
+    hand.push(Card::new(Suit::Hearts, Rank::Two));
+    hand.push(Card::new(Suit::Spades, Rank::Six));
+
+    let options = GenerationOptions {
+        forbidden_kicker_ranks: vec![Rank::Two],
+        ..GenerationOptions::default()
+    };
+    let plays = PlayGenerator::generate_all_plays_with_options(&hand, &options).unwrap();
+    let carries_two = |play: &Vec<Card>| {
+        let pattern = PatternRecognizer::analyze_cards(play).unwrap();
+        matches!(
+            pattern.play_type,
+            PlayType::Triple | PlayType::AirplaneWithWings
+        ) && play.iter().any(|c| c.rank == Rank::Two)
+    };
+
+    assert!(!plays.iter().any(carries_two));
+    // Kickers of other ranks are still offered
+    assert!(plays
+        .iter()
+        .any(|play| play.len() == 4 && play.contains(&Card::new(Suit::Spades, Rank::Six))));
+    // The 2s themselves remain playable
+    assert!(plays
+        .iter()
+        .any(|play| play.len() == 2 && play.iter().all(|c| c.rank == Rank::Two)));
+
+    let all = PlayGenerator::generate_all_plays_with_options(&hand, &GenerationOptions::default())
+        .unwrap();
+    assert!(all.iter().any(carries_two));
+}
+
+#[test]
+fn test_for_config_filters_restricted_leads() {
+    let mut hand = vec![Card::new(Suit::Spades, Rank::King); 3];
+    hand.extend([
+        Card::new(Suit::Hearts, Rank::Six),
+        Card::new(Suit::Clubs, Rank::Nine),
+    ]);
+    let config = GameConfig {
+        lead_restrictions: LeadRestrictions {
+            no_tongzi_lead: true,
+            ..LeadRestrictions::default()
+        },
+        ..GameConfig::default()
+    };
+    let options = GenerationOptions::for_config(&config);
+    let is_tongzi = |play: &Vec<Card>| {
+        PatternRecognizer::analyze_cards(play).map(|p| p.play_type) == Some(PlayType::Tongzi)
+    };
+
+    let unrestricted =
+        PlayGenerator::generate_leading_candidates(&hand, &GenerationOptions::default());
+    assert!(unrestricted.iter().any(is_tongzi));
+
+    let leads = PlayGenerator::generate_leading_candidates(&hand, &options);
+    assert!(!leads.is_empty());
+    assert!(!leads.iter().any(is_tongzi));
+    for lead in &leads {
+        assert!(PlayValidator::validate_play_in_hand(lead, None, hand.len(), &config).is_ok());
+    }
+
+    let mut buffer = PlayBuffer::new();
+    PlayGenerator::generate_into(&mut buffer, &hand, None, &options);
+    assert!(!buffer.iter().any(|play| is_tongzi(&play.to_vec())));
+
+    // The Tongzi may still answer a play
+    let queen = PatternRecognizer::analyze_cards(&[Card::new(Suit::Hearts, Rank::Queen)]).unwrap();
+    buffer.clear();
+    PlayGenerator::generate_into(&mut buffer, &hand, Some(&queen), &options);
+    assert!(buffer.iter().any(|play| is_tongzi(&play.to_vec())));
+}
+
+#[test]
+fn test_for_config_honors_removed_ranks_and_drills() {
+    let hand = vec![
+        Card::new(Suit::Spades, Rank::Three),
+        Card::new(Suit::Hearts, Rank::Five),
+        Card::new(Suit::Clubs, Rank::Five),
+    ];
+    let config = ConfigFactory::create_pattern_drill(PlayType::Pair);
+    let plays = PlayGenerator::generate_all_plays_with_options(
+        &hand,
+        &GenerationOptions::for_config(&config),
+    )
+    .unwrap();
+    assert_eq!(plays.len(), 1);
+    assert!(PlayValidator::validate_play(&plays[0], None, &config).is_ok());
+
+    let leads = PlayGenerator::generate_leading_candidates(
+        &hand,
+        &GenerationOptions::for_config(&GameConfig::default()),
+    );
+    assert!(leads
+        .iter()
+        .all(|play| play.iter().all(|c| c.rank != Rank::Three)));
+}
@@ -575,9 +575,9 @@ fn test_variant_validator_finish_bonus_mismatch() {
 fn test_variant_validator_edge_case_valid() {
     // 边界但有效的配置
     let edge = GameConfig::new(
-        1,  // 1副牌
+        1,  // 1副牌（去掉3和4后44张）
         2,  // 2人
-        26, // 每人26张
+        22, // 每人22张
         0,  // 无铺底
         vec![50, -50],
         100,
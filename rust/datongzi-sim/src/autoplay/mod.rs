@@ -0,0 +1,15 @@
+//! Automatic play on a player's behalf.
+//!
+//! This module contains:
+//! - [`Strategy`]: Chooses a move for one seat
+//! - [`ProfileBot`]: Heuristic player tuned by a [`BotProfile`]
+//! - [`TrusteePlayer`]: Conservative, rule-compliant stand-in for a
+//!   disconnected player (托管)
+
+mod profile;
+mod strategy;
+mod trustee;
+
+pub use profile::{BotProfile, ProfileBot};
+pub use strategy::Strategy;
+pub use trustee::TrusteePlayer;
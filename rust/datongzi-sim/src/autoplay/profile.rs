@@ -0,0 +1,258 @@
+//! Heuristic bots with tunable personalities.
+
+use datongzi_rules::ai_helpers::{GenerationOptions, PlayGenerator};
+use datongzi_rules::models::{Card, GameConfig, Rank, Suit};
+use datongzi_rules::patterns::{PlayPattern, PlayValidator};
+
+use super::trustee::{breaks_bomb, is_trump};
+use super::{Strategy, TrusteePlayer};
+
+/// Personality knobs for a [`ProfileBot`], each from 0.0 to 1.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BotProfile {
+    /// Highest cost the bot spends to take the lead; a non-trump answer
+    /// costs its rank's place from 3 (0.0) to 2 (1.0)
+    pub aggression: f64,
+    /// Cost of answering with a bomb, Tongzi or Dizha
+    pub trump_thrift: f64,
+    /// Added to `aggression` when the play to beat is built on score cards
+    /// (5, 10, K)
+    pub score_greed: f64,
+}
+
+impl BotProfile {
+    /// Middle of every range
+    pub const BALANCED: Self = Self {
+        aggression: 0.5,
+        trump_thrift: 0.5,
+        score_greed: 0.5,
+    };
+    /// Contests most plays and spends trumps freely
+    pub const AGGRESSIVE: Self = Self {
+        aggression: 0.9,
+        trump_thrift: 0.2,
+        score_greed: 0.3,
+    };
+    /// Lets most plays go and hoards trumps
+    pub const CAUTIOUS: Self = Self {
+        aggression: 0.3,
+        trump_thrift: 0.9,
+        score_greed: 0.2,
+    };
+}
+
+impl Default for BotProfile {
+    fn default() -> Self {
+        Self::BALANCED
+    }
+}
+
+/// Heuristic player whose answers follow a [`BotProfile`].
+///
+/// - Answering: the cheapest legal play that does not break a bomb, Tongzi
+///   or Dizha (see [`BotProfile`] for costs), played when its cost is at
+///   most the bot's aggression plus any score greed. Otherwise the bot
+///   passes, unless a cards-left alert obliges it to beat.
+/// - Leading: as [`TrusteePlayer`], or the first legal lead when the
+///   configuration restricts that one.
+///
+/// # Example
+/// ```
+/// use datongzi_sim::autoplay::{BotProfile, ProfileBot, Strategy};
+/// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+///
+/// let hand = vec![Card::new(Suit::Hearts, Rank::Jack)];
+/// let nine = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Nine)]).unwrap();
+///
+/// let mut aggressive = ProfileBot::new(BotProfile::AGGRESSIVE);
+/// let mut cautious = ProfileBot::new(BotProfile::CAUTIOUS);
+/// assert_eq!(aggressive.choose_play(&hand, Some(&nine)), Some(hand.clone()));
+/// assert_eq!(cautious.choose_play(&hand, Some(&nine)), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProfileBot {
+    profile: BotProfile,
+    config: GameConfig,
+}
+
+impl ProfileBot {
+    /// Create a bot playing under the default configuration
+    #[must_use]
+    pub fn new(profile: BotProfile) -> Self {
+        Self::with_config(profile, GameConfig::default())
+    }
+
+    /// Create a bot playing under the rules of `config`
+    #[must_use]
+    pub fn with_config(profile: BotProfile, config: GameConfig) -> Self {
+        Self { profile, config }
+    }
+
+    /// The bot's personality
+    #[must_use]
+    pub const fn profile(&self) -> BotProfile {
+        self.profile
+    }
+
+    fn cost(&self, pattern: &PlayPattern) -> f64 {
+        if is_trump(pattern.play_type) {
+            return self.profile.trump_thrift;
+        }
+        let span = Rank::Two.value() - Rank::Three.value();
+        f64::from(pattern.primary_rank.value() - Rank::Three.value()) / f64::from(span)
+    }
+
+    fn answer(&self, hand: &[Card], current_pattern: &PlayPattern) -> Option<Vec<Card>> {
+        let options = GenerationOptions {
+            protect_trump_structures: true,
+            ..GenerationOptions::for_config(&self.config)
+        };
+        let (play, cost) =
+            PlayGenerator::generate_beating_plays_with_options(hand, current_pattern, &options)
+                .into_iter()
+                .filter(|play| !breaks_bomb(hand, play))
+                .filter_map(|play| {
+                    PlayValidator::validate_play_in_hand(
+                        &play,
+                        Some(current_pattern),
+                        hand.len(),
+                        &self.config,
+                    )
+                    .ok()
+                    .map(|pattern| (play, self.cost(&pattern)))
+                })
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))?;
+
+        // Whether a card scores depends on its rank only
+        let on_score_cards =
+            Card::new(Suit::Spades, current_pattern.primary_rank).is_scoring_card();
+        let mut budget = self.profile.aggression;
+        if on_score_cards {
+            budget += self.profile.score_greed;
+        }
+        let obliged = PlayValidator::validate_pass(hand, current_pattern, &self.config).is_err();
+        (cost <= budget || obliged).then_some(play)
+    }
+
+    fn lead(&self, hand: &[Card]) -> Option<Vec<Card>> {
+        let is_legal = |play: &Vec<Card>| {
+            PlayValidator::validate_play_in_hand(play, None, hand.len(), &self.config).is_ok()
+        };
+        TrusteePlayer::lead(hand).filter(is_legal).or_else(|| {
+            PlayGenerator::generate_leading_candidates(
+                hand,
+                &GenerationOptions::for_config(&self.config),
+            )
+            .into_iter()
+            .find(is_legal)
+        })
+    }
+}
+
+impl Strategy for ProfileBot {
+    fn choose_play(
+        &mut self,
+        hand: &[Card],
+        current_pattern: Option<&PlayPattern>,
+    ) -> Option<Vec<Card>> {
+        match current_pattern {
+            Some(pattern) => self.answer(hand, pattern),
+            None => self.lead(hand),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::tournament::{Pairing, Tournament};
+    use datongzi_rules::patterns::PatternRecognizer;
+
+    fn single(rank: Rank) -> PlayPattern {
+        PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, rank)]).unwrap()
+    }
+
+    #[test]
+    fn test_trump_thrift_guards_bombs() {
+        let mut hand: Vec<Card> = Suit::ALL
+            .iter()
+            .map(|s| Card::new(*s, Rank::Nine))
+            .collect();
+        hand.push(Card::new(Suit::Hearts, Rank::Six));
+        let ace = single(Rank::Ace);
+
+        let spender = ProfileBot::new(BotProfile::AGGRESSIVE).answer(&hand, &ace);
+        assert_eq!(spender, Some(hand[..4].to_vec()));
+        assert_eq!(
+            ProfileBot::new(BotProfile::CAUTIOUS).answer(&hand, &ace),
+            None
+        );
+    }
+
+    #[test]
+    fn test_score_greed_contests_score_cards() {
+        let hand = vec![Card::new(Suit::Hearts, Rank::Ace)];
+        let greedy = ProfileBot::new(BotProfile::BALANCED);
+        assert_eq!(
+            greedy.answer(&hand, &single(Rank::King)),
+            Some(hand.clone())
+        );
+        assert_eq!(greedy.answer(&hand, &single(Rank::Queen)), None);
+    }
+
+    /// Counts how often the wrapped bot passes when it could answer.
+    struct Counted {
+        bot: ProfileBot,
+        answers: Rc<Cell<(u32, u32)>>,
+    }
+
+    impl Strategy for Counted {
+        fn choose_play(
+            &mut self,
+            hand: &[Card],
+            current_pattern: Option<&PlayPattern>,
+        ) -> Option<Vec<Card>> {
+            let play = self.bot.choose_play(hand, current_pattern);
+            if current_pattern.is_some() {
+                let (passes, turns) = self.answers.get();
+                self.answers
+                    .set((passes + u32::from(play.is_none()), turns + 1));
+            }
+            play
+        }
+    }
+
+    fn pass_rate(answers: &Cell<(u32, u32)>) -> f64 {
+        let (passes, turns) = answers.get();
+        f64::from(passes) / f64::from(turns)
+    }
+
+    #[test]
+    fn test_profiles_change_play_in_simulation() {
+        let aggressive = Rc::new(Cell::new((0, 0)));
+        let cautious = Rc::new(Cell::new((0, 0)));
+        let mut tournament = Tournament::new(GameConfig::default(), 11, 4);
+        let counter = Rc::clone(&aggressive);
+        tournament.register("aggressive", move || {
+            Box::new(Counted {
+                bot: ProfileBot::new(BotProfile::AGGRESSIVE),
+                answers: Rc::clone(&counter),
+            })
+        });
+        let counter = Rc::clone(&cautious);
+        tournament.register("cautious", move || {
+            Box::new(Counted {
+                bot: ProfileBot::new(BotProfile::CAUTIOUS),
+                answers: Rc::clone(&counter),
+            })
+        });
+
+        let table = tournament.run(Pairing::RoundRobin).unwrap();
+        assert!(pass_rate(&aggressive) < pass_rate(&cautious));
+        // Unlike identical strategies, the two profiles do not always draw
+        assert!(table.results[0][1].unwrap().draws < 4);
+    }
+}
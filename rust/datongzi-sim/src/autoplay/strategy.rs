@@ -0,0 +1,17 @@
+//! The move-selection trait shared by automatic players.
+
+use datongzi_rules::models::Card;
+use datongzi_rules::patterns::PlayPattern;
+
+/// Chooses a move for one seat.
+pub trait Strategy {
+    /// Cards to play from `hand`, or `None` to pass.
+    ///
+    /// `current_pattern` is the play to beat (`None` when leading). When
+    /// leading with a non-empty hand, implementations must return a play.
+    fn choose_play(
+        &mut self,
+        hand: &[Card],
+        current_pattern: Option<&PlayPattern>,
+    ) -> Option<Vec<Card>>;
+}
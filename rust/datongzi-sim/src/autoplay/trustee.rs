@@ -0,0 +1,151 @@
+//! Conservative autoplay for disconnected players.
+
+use datongzi_rules::ai_helpers::{GenerationOptions, HandPatternAnalyzer, PlayGenerator};
+use datongzi_rules::models::{Card, Rank};
+use datongzi_rules::patterns::{PatternRecognizer, PlayPattern, PlayType, RULES};
+
+use super::Strategy;
+
+/// Stand-in for a disconnected player (托管).
+///
+/// Deliberately simple and predictable, unlike a competitive bot:
+/// - Answering: the cheapest legal play that does not break a bomb,
+///   Tongzi or Dizha; a normal play is always preferred to a trump. Passes
+///   when no such play exists.
+/// - Leading: the lowest-ranked non-trump group of the hand's decomposition
+///   (larger groups first on ties), or the weakest trump if only trumps
+///   remain.
+///
+/// # Example
+/// ```
+/// use datongzi_sim::autoplay::{Strategy, TrusteePlayer};
+/// use datongzi_rules::{Card, PatternRecognizer, Rank, Suit};
+///
+/// let hand = vec![
+///     Card::new(Suit::Spades, Rank::Nine),
+///     Card::new(Suit::Hearts, Rank::Queen),
+///     Card::new(Suit::Clubs, Rank::Two),
+/// ];
+/// let current = PatternRecognizer::analyze_cards(&[Card::new(Suit::Spades, Rank::Ten)]).unwrap();
+///
+/// let mut trustee = TrusteePlayer::new();
+/// assert_eq!(trustee.choose_play(&hand, Some(&current)), Some(vec![hand[1]]));
+/// assert_eq!(trustee.choose_play(&hand, None), Some(vec![hand[0]]));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrusteePlayer;
+
+impl TrusteePlayer {
+    /// Create a trustee player
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn answer(hand: &[Card], current_pattern: &PlayPattern) -> Option<Vec<Card>> {
+        let options = GenerationOptions {
+            protect_trump_structures: true,
+            ..GenerationOptions::default()
+        };
+        PlayGenerator::generate_beating_plays_with_options(hand, current_pattern, &options)
+            .into_iter()
+            .filter(|play| !breaks_bomb(hand, play))
+            .filter_map(|play| PatternRecognizer::analyze_cards(&play).map(|p| (play, p)))
+            .min_by_key(|(_, p)| (is_trump(p.play_type), p.card_count, p.strength))
+            .map(|(play, _)| play)
+    }
+
+    pub(super) fn lead(hand: &[Card]) -> Option<Vec<Card>> {
+        let patterns = HandPatternAnalyzer::analyze_patterns(hand);
+        let normal = patterns
+            .airplane_chains
+            .iter()
+            .chain(&patterns.consecutive_pair_chains)
+            .chain(&patterns.triples)
+            .chain(&patterns.pairs)
+            .cloned()
+            .chain(patterns.singles.iter().map(|c| vec![*c]))
+            .min_by_key(|group| {
+                let low = group.iter().map(|c| c.rank.value()).min().unwrap_or(0);
+                (low, std::cmp::Reverse(group.len()))
+            });
+        normal.or_else(|| {
+            patterns
+                .bombs
+                .iter()
+                .chain(&patterns.tongzi)
+                .chain(&patterns.dizha)
+                .min_by_key(|group| PatternRecognizer::analyze_cards(group).map(|p| p.strength))
+                .cloned()
+        })
+    }
+}
+
+impl Strategy for TrusteePlayer {
+    fn choose_play(
+        &mut self,
+        hand: &[Card],
+        current_pattern: Option<&PlayPattern>,
+    ) -> Option<Vec<Card>> {
+        match current_pattern {
+            Some(pattern) => Self::answer(hand, pattern),
+            None => Self::lead(hand),
+        }
+    }
+}
+
+pub(super) fn is_trump(play_type: PlayType) -> bool {
+    matches!(
+        play_type,
+        PlayType::Bomb | PlayType::Tongzi | PlayType::Dizha
+    )
+}
+
+/// True if a non-bomb `play` takes cards from a rank held as a bomb
+pub(super) fn breaks_bomb(hand: &[Card], play: &[Card]) -> bool {
+    let count = |cards: &[Card], rank: Rank| cards.iter().filter(|c| c.rank == rank).count();
+    let is_bomb = PatternRecognizer::analyze_cards(play).is_some_and(|p| is_trump(p.play_type));
+    !is_bomb && play.iter().any(|c| count(hand, c.rank) >= RULES.min_bomb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datongzi_rules::models::Suit;
+
+    fn pattern(cards: &[Card]) -> PlayPattern {
+        PatternRecognizer::analyze_cards(cards).unwrap()
+    }
+
+    #[test]
+    fn test_never_breaks_trumps() {
+        let nines: Vec<Card> = Suit::ALL
+            .iter()
+            .map(|s| Card::new(*s, Rank::Nine))
+            .collect();
+        let mut hand = nines.clone();
+        hand.push(Card::new(Suit::Hearts, Rank::Six));
+        let mut trustee = TrusteePlayer::new();
+
+        // A single 9 would break the bomb; the bomb itself is the only answer
+        let eight = pattern(&[Card::new(Suit::Spades, Rank::Eight)]);
+        assert_eq!(
+            trustee.choose_play(&hand, Some(&eight)),
+            Some(nines.clone())
+        );
+
+        // Leading sheds the 6, not part of the bomb
+        assert_eq!(
+            trustee.choose_play(&hand, None),
+            Some(vec![Card::new(Suit::Hearts, Rank::Six)])
+        );
+        assert_eq!(trustee.choose_play(&nines, None), Some(nines.clone()));
+    }
+
+    #[test]
+    fn test_passes_without_an_answer() {
+        let hand = vec![Card::new(Suit::Hearts, Rank::Six)];
+        let ace = pattern(&[Card::new(Suit::Spades, Rank::Ace)]);
+        assert_eq!(TrusteePlayer::new().choose_play(&hand, Some(&ace)), None);
+    }
+}
@@ -0,0 +1,232 @@
+//! Elo-style ratings from seeded round-robin play.
+
+use datongzi_rules::error::Result;
+use datongzi_rules::models::GameConfig;
+
+use crate::autoplay::Strategy;
+use crate::tournament::{CrossTable, Pairing, Tournament};
+
+/// Rating of a field's average entrant.
+pub const BASE_RATING: f64 = 1500.0;
+
+/// Normal quantile of the 95% confidence interval.
+const Z_95: f64 = 1.96;
+
+/// Fitting stops once no rating factor moves by more than this fraction.
+const TOLERANCE: f64 = 1e-10;
+
+/// Upper bound on fitting iterations.
+const MAX_ITERATIONS: usize = 10_000;
+
+/// One entrant's rating.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rating {
+    /// Entrant name
+    pub name: String,
+    /// Elo-scale rating; the field's geometric mean is [`BASE_RATING`]
+    pub elo: f64,
+    /// Lower end of the 95% confidence interval
+    pub low: f64,
+    /// Upper end of the 95% confidence interval
+    pub high: f64,
+    /// Deals played
+    pub deals: u32,
+}
+
+/// Rates strategies by playing every pair over seeded duplicate deals.
+///
+/// The games are a round-robin [`Tournament`]; each deal counts as a win,
+/// loss or draw. Ratings are the maximum-likelihood Bradley-Terry fit on
+/// the Elo scale (400 points = 10:1 odds), with one virtual draw added per
+/// pairing so an unbeaten entrant still gets a finite rating. Intervals use
+/// the fit's standard error.
+///
+/// # Example
+/// ```
+/// use datongzi_sim::autoplay::{BotProfile, ProfileBot, TrusteePlayer};
+/// use datongzi_sim::ladder::Ladder;
+/// use datongzi_rules::GameConfig;
+///
+/// let mut ladder = Ladder::new(GameConfig::default(), 3, 2);
+/// ladder
+///     .register("trustee", || Box::new(TrusteePlayer::new()))
+///     .register("cautious", || Box::new(ProfileBot::new(BotProfile::CAUTIOUS)));
+///
+/// let ratings = ladder.run().unwrap();
+/// assert_eq!(ratings.len(), 2);
+/// assert!(ratings[0].elo >= ratings[1].elo);
+/// assert!(ratings[0].low < ratings[0].elo && ratings[0].elo < ratings[0].high);
+/// ```
+pub struct Ladder {
+    tournament: Tournament,
+}
+
+impl Ladder {
+    /// Create an empty ladder playing `deals_per_pairing` deals per pair
+    #[must_use]
+    pub fn new(config: GameConfig, seed: u64, deals_per_pairing: u64) -> Self {
+        Self {
+            tournament: Tournament::new(config, seed, deals_per_pairing),
+        }
+    }
+
+    /// Register an entrant; `factory` is called once per game
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        factory: impl Fn() -> Box<dyn Strategy> + 'static,
+    ) -> &mut Self {
+        self.tournament.register(name, factory);
+        self
+    }
+
+    /// Play the round robin and rate every entrant, best first.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Tournament::run`]
+    pub fn run(&self) -> Result<Vec<Rating>> {
+        let table = self.tournament.run(Pairing::RoundRobin)?;
+        Ok(Self::rate(&table))
+    }
+
+    /// Rate the entrants of a finished cross-table, best first
+    #[must_use]
+    pub fn rate(table: &CrossTable) -> Vec<Rating> {
+        let n = table.names.len();
+        // Games and points of i against j, with the virtual draw
+        let mut games = vec![vec![0.0; n]; n];
+        let mut points = vec![0.0; n];
+        for (i, row) in table.results.iter().enumerate() {
+            for (j, result) in row.iter().enumerate() {
+                if let Some(result) = result {
+                    let played = result.wins + result.losses + result.draws;
+                    games[i][j] = f64::from(played) + 1.0;
+                    points[i] += f64::from(result.half_points()) / 2.0 + 0.5;
+                }
+            }
+        }
+
+        // Minorization-maximization for the Bradley-Terry strengths
+        let mut strength = vec![1.0_f64; n];
+        for _ in 0..MAX_ITERATIONS {
+            let mut next: Vec<f64> = (0..n)
+                .map(|i| {
+                    let exposure: f64 = (0..n)
+                        .filter(|j| games[i][*j] > 0.0)
+                        .map(|j| games[i][j] / (strength[i] + strength[j]))
+                        .sum();
+                    if exposure > 0.0 {
+                        points[i] / exposure
+                    } else {
+                        strength[i]
+                    }
+                })
+                .collect();
+            let log_mean = next.iter().map(|s| s.ln()).sum::<f64>() / n as f64;
+            for s in &mut next {
+                *s /= log_mean.exp();
+            }
+            let moved = next
+                .iter()
+                .zip(&strength)
+                .map(|(a, b)| (a / b - 1.0).abs())
+                .fold(0.0, f64::max);
+            strength = next;
+            if moved < TOLERANCE {
+                break;
+            }
+        }
+
+        let scale = 400.0 / std::f64::consts::LN_10;
+        let mut ratings: Vec<Rating> = (0..n)
+            .map(|i| {
+                let information: f64 = (0..n)
+                    .map(|j| {
+                        let p = strength[i] / (strength[i] + strength[j]);
+                        games[i][j] * p * (1.0 - p)
+                    })
+                    .sum();
+                let elo = BASE_RATING + scale * strength[i].ln();
+                let margin = Z_95 * scale / information.sqrt();
+                let deals = table.results[i]
+                    .iter()
+                    .flatten()
+                    .map(|r| r.wins + r.losses + r.draws)
+                    .sum();
+                Rating {
+                    name: table.names[i].clone(),
+                    elo,
+                    low: elo - margin,
+                    high: elo + margin,
+                    deals,
+                }
+            })
+            .collect();
+        ratings.sort_by(|a, b| b.elo.total_cmp(&a.elo));
+        ratings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::autoplay::TrusteePlayer;
+    use crate::tournament::MatchResult;
+
+    fn two_entrants(wins: u32, losses: u32) -> CrossTable {
+        let result = MatchResult {
+            wins,
+            losses,
+            ..MatchResult::default()
+        };
+        let mirrored = MatchResult {
+            wins: losses,
+            losses: wins,
+            ..MatchResult::default()
+        };
+        CrossTable {
+            names: vec!["a".to_string(), "b".to_string()],
+            results: vec![vec![None, Some(result)], vec![Some(mirrored), None]],
+        }
+    }
+
+    #[test]
+    fn test_rating_gap_matches_elo_odds() {
+        let ratings = Ladder::rate(&two_entrants(3, 1));
+        assert_eq!(ratings[0].name, "a");
+        // 3.5 : 1.5 points with the virtual draw
+        let gap = ratings[0].elo - ratings[1].elo;
+        assert!((gap - 400.0 * (3.5_f64 / 1.5).log10()).abs() < 1e-6);
+        assert!((ratings[0].elo + ratings[1].elo - 2.0 * BASE_RATING).abs() < 1e-6);
+        assert_eq!(ratings[0].deals, 4);
+    }
+
+    #[test]
+    fn test_unbeaten_rating_is_finite_and_narrows() {
+        let few = Ladder::rate(&two_entrants(2, 0));
+        let many = Ladder::rate(&two_entrants(20, 0));
+        assert!(few[0].elo.is_finite() && many[0].elo > few[0].elo);
+
+        let even_few = Ladder::rate(&two_entrants(2, 2));
+        let even_many = Ladder::rate(&two_entrants(20, 20));
+        let width = |r: &Rating| r.high - r.low;
+        assert!(width(&even_many[0]) < width(&even_few[0]));
+    }
+
+    #[test]
+    fn test_identical_strategies_rate_equally() {
+        let mut ladder = Ladder::new(GameConfig::default(), 5, 2);
+        ladder
+            .register("a", || Box::new(TrusteePlayer::new()))
+            .register("b", || Box::new(TrusteePlayer::new()))
+            .register("c", || Box::new(TrusteePlayer::new()));
+
+        let ratings = ladder.run().unwrap();
+        assert_eq!(ratings, ladder.run().unwrap());
+        for rating in &ratings {
+            assert!((rating.elo - BASE_RATING).abs() < 1e-6);
+            assert_eq!(rating.deals, 4);
+        }
+    }
+}
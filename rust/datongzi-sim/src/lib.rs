@@ -0,0 +1,21 @@
+//! # Datongzi Simulation
+//!
+//! 基于 `datongzi-rules` 的自动出牌与对抗赛工具。规则库只负责规则判定与计分，
+//! 游戏循环和出牌策略放在本 crate。
+//!
+//! ## 模块结构
+//!
+//! - [`autoplay`]: 托管（断线玩家的保守自动出牌）与可调性格的启发式机器人
+//! - [`ladder`]: 策略天梯（带置信区间的 Elo 评分）
+//! - [`tournament`]: 策略对抗赛（循环赛 / 瑞士制）
+
+#![warn(missing_docs)]
+#![warn(clippy::all)]
+
+pub mod autoplay;
+pub mod ladder;
+pub mod tournament;
+
+pub use autoplay::{BotProfile, ProfileBot, Strategy, TrusteePlayer};
+pub use ladder::{Ladder, Rating};
+pub use tournament::{CrossTable, MatchResult, Pairing, StrategyFactory, Tournament};
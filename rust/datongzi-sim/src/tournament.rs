@@ -0,0 +1,468 @@
+//! Reproducible tournaments between automatic players.
+
+use std::collections::BTreeMap;
+
+use datongzi_rules::ai_helpers::{GenerationOptions, PlayGenerator};
+use datongzi_rules::error::{DatongziError, Result};
+use datongzi_rules::i18n::Message;
+use datongzi_rules::models::{Card, GameConfig, Seating};
+use datongzi_rules::patterns::{PatternRecognizer, PlayPattern, PlayValidator};
+use datongzi_rules::puzzles::deal_from_seed;
+use datongzi_rules::scoring::{score_record, GameRecord, RecordedAction};
+
+use crate::autoplay::{Strategy, TrusteePlayer};
+
+/// Seed stride between deals and pairings, as in
+/// [`generate_daily_deal`](datongzi_rules::puzzles::generate_daily_deal).
+const SEED_STRIDE: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Builds a fresh strategy for each game.
+pub type StrategyFactory = Box<dyn Fn() -> Box<dyn Strategy>>;
+
+/// How entrants are paired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pairing {
+    /// Every entrant meets every other entrant once
+    RoundRobin,
+    /// `rounds` rounds, each pairing entrants with similar scores who have
+    /// not met yet; with an odd field the lowest unpaired entrant sits out
+    Swiss {
+        /// Number of rounds
+        rounds: usize,
+    },
+}
+
+/// One side's results against one opponent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MatchResult {
+    /// Deals won
+    pub wins: u32,
+    /// Deals lost
+    pub losses: u32,
+    /// Deals drawn
+    pub draws: u32,
+    /// Total points scored
+    pub points_for: i64,
+    /// Total points conceded to the opponent
+    pub points_against: i64,
+}
+
+impl MatchResult {
+    fn mirrored(&self) -> Self {
+        Self {
+            wins: self.losses,
+            losses: self.wins,
+            draws: self.draws,
+            points_for: self.points_against,
+            points_against: self.points_for,
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.wins += other.wins;
+        self.losses += other.losses;
+        self.draws += other.draws;
+        self.points_for += other.points_for;
+        self.points_against += other.points_against;
+    }
+
+    /// Match points: 2 per win, 1 per draw
+    #[must_use]
+    pub const fn half_points(&self) -> u32 {
+        self.wins * 2 + self.draws
+    }
+}
+
+/// Results of a [`Tournament`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrossTable {
+    /// Entrant names in registration order
+    pub names: Vec<String>,
+    /// `results[i][j]` is entrant `i`'s record against `j` (`None` if they
+    /// never met)
+    pub results: Vec<Vec<Option<MatchResult>>>,
+}
+
+impl CrossTable {
+    /// Total match points of entrant `i` (2 per win, 1 per draw)
+    #[must_use]
+    pub fn half_points(&self, i: usize) -> u32 {
+        self.results[i]
+            .iter()
+            .flatten()
+            .map(MatchResult::half_points)
+            .sum()
+    }
+
+    /// Total point difference of entrant `i` across all its deals
+    #[must_use]
+    pub fn point_difference(&self, i: usize) -> i64 {
+        self.results[i]
+            .iter()
+            .flatten()
+            .map(|r| r.points_for - r.points_against)
+            .sum()
+    }
+
+    /// Entrant indices, best first: by match points, then point
+    /// difference, then registration order
+    #[must_use]
+    pub fn standings(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.names.len()).collect();
+        order.sort_by_key(|i| {
+            (
+                std::cmp::Reverse(self.half_points(*i)),
+                std::cmp::Reverse(self.point_difference(*i)),
+                *i,
+            )
+        });
+        order
+    }
+}
+
+/// Round-robin or Swiss tournament between registered strategies.
+///
+/// Each pairing plays `deals_per_pairing` duplicate deals: every deal is
+/// played twice with the two strategies swapping seats, and the side with
+/// more total points over both games wins the deal. Deal seeds derive only
+/// from the tournament seed, the round and the pairing, so a rerun, or the
+/// same pairing in a larger field, sees exactly the same cards.
+///
+/// A strategy that passes when it must lead, or returns cards it does not
+/// hold or that do not beat the current play, has its move replaced by
+/// [`TrusteePlayer`]'s.
+///
+/// # Example
+/// ```
+/// use datongzi_sim::autoplay::TrusteePlayer;
+/// use datongzi_sim::tournament::{Pairing, Tournament};
+/// use datongzi_rules::GameConfig;
+///
+/// let mut tournament = Tournament::new(GameConfig::default(), 7, 1);
+/// tournament
+///     .register("trustee-a", || Box::new(TrusteePlayer::new()))
+///     .register("trustee-b", || Box::new(TrusteePlayer::new()));
+///
+/// let table = tournament.run(Pairing::RoundRobin).unwrap();
+/// assert_eq!(table, tournament.run(Pairing::RoundRobin).unwrap());
+/// // Identical strategies on duplicate deals always draw
+/// assert_eq!(table.results[0][1].unwrap().draws, 1);
+/// ```
+pub struct Tournament {
+    config: GameConfig,
+    seed: u64,
+    deals_per_pairing: u64,
+    entrants: Vec<(String, StrategyFactory)>,
+}
+
+impl Tournament {
+    /// Create an empty tournament
+    #[must_use]
+    pub fn new(config: GameConfig, seed: u64, deals_per_pairing: u64) -> Self {
+        Self {
+            config,
+            seed,
+            deals_per_pairing,
+            entrants: Vec::new(),
+        }
+    }
+
+    /// Register an entrant; `factory` is called once per game
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        factory: impl Fn() -> Box<dyn Strategy> + 'static,
+    ) -> &mut Self {
+        self.entrants.push((name.into(), Box::new(factory)));
+        self
+    }
+
+    /// Play every pairing and collect the cross-table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fewer than two entrants are registered,
+    /// `deals_per_pairing` is 0, or the configuration cannot be dealt
+    pub fn run(&self, pairing: Pairing) -> Result<CrossTable> {
+        let n = self.entrants.len();
+        if n < 2 {
+            return Err(DatongziError::InvalidInput(Message::TooFewEntrants {
+                count: n,
+            }));
+        }
+        if self.deals_per_pairing == 0 {
+            return Err(DatongziError::InvalidInput(Message::NoSeeds));
+        }
+
+        let mut table = CrossTable {
+            names: self.entrants.iter().map(|(name, _)| name.clone()).collect(),
+            results: vec![vec![None; n]; n],
+        };
+        match pairing {
+            Pairing::RoundRobin => {
+                for a in 0..n {
+                    for b in a + 1..n {
+                        self.record(&mut table, 0, a, b)?;
+                    }
+                }
+            }
+            Pairing::Swiss { rounds } => {
+                for round in 0..rounds {
+                    for (a, b) in swiss_pairs(&table) {
+                        self.record(&mut table, round as u64, a, b)?;
+                    }
+                }
+            }
+        }
+        Ok(table)
+    }
+
+    fn record(&self, table: &mut CrossTable, round: u64, a: usize, b: usize) -> Result<()> {
+        let result = self.play_pairing(round, a, b)?;
+        table.results[a][b]
+            .get_or_insert_with(MatchResult::default)
+            .merge(&result);
+        table.results[b][a]
+            .get_or_insert_with(MatchResult::default)
+            .merge(&result.mirrored());
+        Ok(())
+    }
+
+    /// Duplicate deals between entrants `a` and `b`, from `a`'s side
+    fn play_pairing(&self, round: u64, a: usize, b: usize) -> Result<MatchResult> {
+        let pairing_seed =
+            self.seed ^ (round << 32 | (a as u64) << 16 | b as u64).wrapping_mul(SEED_STRIDE);
+        let mut result = MatchResult::default();
+        for deal in 0..self.deals_per_pairing {
+            let (hands, _) = deal_from_seed(
+                &self.config,
+                pairing_seed.wrapping_add(deal.wrapping_mul(SEED_STRIDE)),
+            )?;
+            let (mut points_a, mut points_b) = (0, 0);
+            for swap in [0, 1] {
+                // Alternate seats between the two sides, then swap
+                let a_seats: Vec<bool> = (0..hands.len()).map(|s| (s + swap) % 2 == 0).collect();
+                let mut players: Vec<Box<dyn Strategy>> = a_seats
+                    .iter()
+                    .map(|is_a| (self.entrants[if *is_a { a } else { b }].1)())
+                    .collect();
+                let scores = play_game(&self.config, hands.clone(), &mut players)?;
+                for (score, is_a) in scores.iter().zip(&a_seats) {
+                    if *is_a {
+                        points_a += i64::from(*score);
+                    } else {
+                        points_b += i64::from(*score);
+                    }
+                }
+            }
+            match points_a.cmp(&points_b) {
+                std::cmp::Ordering::Greater => result.wins += 1,
+                std::cmp::Ordering::Less => result.losses += 1,
+                std::cmp::Ordering::Equal => result.draws += 1,
+            }
+            result.points_for += points_a;
+            result.points_against += points_b;
+        }
+        Ok(result)
+    }
+}
+
+/// Pair entrants with similar scores who have not met; the first unpaired
+/// opponent is used when everyone left has already been met.
+fn swiss_pairs(table: &CrossTable) -> Vec<(usize, usize)> {
+    let mut unpaired = table.standings();
+    let mut pairs = Vec::new();
+    while unpaired.len() >= 2 {
+        let a = unpaired.remove(0);
+        let opponent = unpaired
+            .iter()
+            .position(|b| table.results[a][*b].is_none())
+            .unwrap_or(0);
+        pairs.push((a, unpaired.remove(opponent)));
+    }
+    pairs
+}
+
+fn is_legal(
+    config: &GameConfig,
+    hand: &[Card],
+    cards: &[Card],
+    current: Option<&PlayPattern>,
+) -> bool {
+    PlayValidator::validate_play_in_hand(cards, current, hand.len(), config).is_ok()
+        && cards.iter().all(|card| {
+            cards.iter().filter(|c| *c == card).count()
+                <= hand.iter().filter(|c| *c == card).count()
+        })
+}
+
+/// A beating play for a hand the cards-left alert bars from passing.
+fn forced_beat(
+    config: &GameConfig,
+    hand: &[Card],
+    current: Option<&PlayPattern>,
+) -> Option<Vec<Card>> {
+    let current = current?;
+    PlayValidator::validate_pass(hand, current, config).err()?;
+    PlayGenerator::generate_beating_plays_with_options(
+        hand,
+        current,
+        &GenerationOptions::for_config(config),
+    )
+    .into_iter()
+    .find(|cards| is_legal(config, hand, cards, Some(current)))
+}
+
+/// Play one game from dealt hands; returns final scores by seat.
+fn play_game(
+    config: &GameConfig,
+    hands: Vec<Vec<Card>>,
+    players: &mut [Box<dyn Strategy>],
+) -> Result<Vec<i32>> {
+    let player_ids: Vec<String> = (0..hands.len()).map(|s| s.to_string()).collect();
+    let seating = Seating::from_config(config, 0);
+    let mut record = GameRecord {
+        player_ids: player_ids.clone(),
+        hands: hands.clone(),
+        actions: Vec::new(),
+        reveals: Vec::new(),
+        shuffle: None,
+        annotations: BTreeMap::new(),
+        aside: Vec::new(),
+    };
+
+    let mut hands = hands;
+    // Pattern to beat, its player, and passes since
+    let mut open: Option<(PlayPattern, usize, usize)> = None;
+    let mut turn = seating.first_leader(config.first_leader(), None);
+    while hands.iter().filter(|h| !h.is_empty()).count() > 1 {
+        let current = open.as_ref().map(|(pattern, _, _)| pattern);
+        let hand = &hands[turn];
+        let choice = players[turn]
+            .choose_play(hand, current)
+            .filter(|cards| is_legal(config, hand, cards, current))
+            .or_else(|| {
+                TrusteePlayer::new()
+                    .choose_play(hand, current)
+                    .filter(|cards| is_legal(config, hand, cards, current))
+            })
+            .or_else(|| current.is_none().then(|| vec![hand[0]]))
+            .or_else(|| forced_beat(config, hand, current));
+
+        match choice.and_then(|cards| PatternRecognizer::analyze_cards(&cards).map(|p| (cards, p)))
+        {
+            Some((cards, pattern)) => {
+                for card in &cards {
+                    if let Some(position) = hands[turn].iter().position(|c| c == card) {
+                        hands[turn].swap_remove(position);
+                    }
+                }
+                record.actions.push(RecordedAction::Play {
+                    player_id: player_ids[turn].clone(),
+                    cards,
+                });
+                open = Some((pattern, turn, 0));
+            }
+            None => {
+                record.actions.push(RecordedAction::Pass {
+                    player_id: player_ids[turn].clone(),
+                });
+                if let Some((_, _, passes)) = open.as_mut() {
+                    *passes += 1;
+                }
+            }
+        }
+
+        let cards_left: Vec<usize> = hands.iter().map(Vec::len).collect();
+        let next = match open {
+            Some((_, leader, passes))
+                if passes
+                    >= (0..hands.len())
+                        .filter(|s| *s != leader && cards_left[*s] > 0)
+                        .count() =>
+            {
+                open = None;
+                if cards_left[leader] > 0 {
+                    Some(leader)
+                } else {
+                    seating.next_with_cards(leader, &cards_left)
+                }
+            }
+            _ => seating.next_with_cards(turn, &cards_left),
+        };
+        match next {
+            Some(seat) => turn = seat,
+            None => break,
+        }
+    }
+
+    let summary = score_record(&record, config)?;
+    Ok(player_ids
+        .iter()
+        .map(|id| summary.final_scores.get(id).copied().unwrap_or(0))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Always leads or answers with its first legal single, else passes.
+    struct SinglesOnly(GameConfig);
+
+    impl Strategy for SinglesOnly {
+        fn choose_play(
+            &mut self,
+            hand: &[Card],
+            current_pattern: Option<&PlayPattern>,
+        ) -> Option<Vec<Card>> {
+            hand.iter().map(|card| vec![*card]).find(|play| {
+                PlayValidator::can_beat_play_with_config(play, current_pattern, &self.0)
+            })
+        }
+    }
+
+    fn tournament() -> Tournament {
+        let mut tournament = Tournament::new(GameConfig::default(), 42, 2);
+        tournament
+            .register("trustee", || Box::new(TrusteePlayer::new()))
+            .register("singles", || Box::new(SinglesOnly(GameConfig::default())))
+            .register("trustee-2", || Box::new(TrusteePlayer::new()));
+        tournament
+    }
+
+    #[test]
+    fn test_round_robin_cross_table() {
+        let table = tournament().run(Pairing::RoundRobin).unwrap();
+        assert_eq!(table, tournament().run(Pairing::RoundRobin).unwrap());
+
+        for a in 0..3 {
+            assert!(table.results[a][a].is_none());
+            for b in (0..3).filter(|b| *b != a) {
+                let result = table.results[a][b].unwrap();
+                assert_eq!(result.wins + result.losses + result.draws, 2);
+                assert_eq!(table.results[b][a].unwrap(), result.mirrored());
+            }
+        }
+        assert_eq!(table.results[0][2].unwrap().draws, 2);
+    }
+
+    #[test]
+    fn test_swiss_avoids_rematches() {
+        let mut tournament = tournament();
+        tournament.register("singles-2", || Box::new(SinglesOnly(GameConfig::default())));
+        let table = tournament.run(Pairing::Swiss { rounds: 2 }).unwrap();
+
+        // Two rounds of two pairings with no rematch: everyone met two opponents
+        for a in 0..4 {
+            assert_eq!(table.results[a].iter().flatten().count(), 2);
+        }
+    }
+
+    #[test]
+    fn test_rejects_small_fields() {
+        let mut tournament = Tournament::new(GameConfig::default(), 0, 1);
+        tournament.register("alone", || Box::new(TrusteePlayer::new()));
+        assert!(tournament.run(Pairing::RoundRobin).is_err());
+    }
+}